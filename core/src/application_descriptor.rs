@@ -0,0 +1,165 @@
+//! AIR application descriptors
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ApplicationDescriptorError {
+    #[error("Invalid XML: {0}")]
+    InvalidXml(#[from] quick_xml::Error),
+
+    #[error("Missing <application> root element")]
+    MissingApplication,
+
+    #[error("Missing <id> element")]
+    MissingId,
+}
+
+/// The application descriptor of an AIR application, which is the
+/// `application.xml` file that is packaged with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApplicationDescriptor {
+    xml: String,
+    id: String,
+}
+
+impl ApplicationDescriptor {
+    /// Parses an application descriptor, which must at least contain the ID of the application.
+    pub fn parse(xml: String) -> Result<Self, ApplicationDescriptorError> {
+        let mut reader = Reader::from_str(&xml);
+        let mut depth = 0;
+        let mut has_application = false;
+        let mut in_id = false;
+        let mut id = String::new();
+
+        loop {
+            match reader.read_event()? {
+                Event::Start(start) => {
+                    depth += 1;
+                    match (depth, start.local_name().as_ref()) {
+                        (1, b"application") => has_application = true,
+                        (1, _) => return Err(ApplicationDescriptorError::MissingApplication),
+                        (2, b"id") => in_id = true,
+                        _ => {}
+                    }
+                }
+                Event::Empty(empty) if depth == 0 => {
+                    has_application = empty.local_name().as_ref() == b"application";
+                    break;
+                }
+                Event::End(_) => {
+                    depth -= 1;
+                    in_id = false;
+                }
+                Event::Text(text) if in_id => id.push_str(&text.unescape()?),
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        let id = id.trim();
+        if !has_application {
+            Err(ApplicationDescriptorError::MissingApplication)
+        } else if id.is_empty() {
+            Err(ApplicationDescriptorError::MissingId)
+        } else {
+            let id = id.to_string();
+            Ok(Self { xml, id })
+        }
+    }
+
+    /// Creates the descriptor of an application that doesn't come with one,
+    /// using the file name of its SWF.
+    pub fn for_movie(url: &str) -> Self {
+        let file_name = url
+            .split(['?', '#'])
+            .next()
+            .and_then(|path| path.rsplit(['/', '\\']).next())
+            .filter(|name| !name.is_empty())
+            .unwrap_or("movie.swf");
+        let name = file_name
+            .rsplit_once('.')
+            .map_or(file_name, |(name, _)| name);
+
+        // Application IDs may only contain letters, digits, dots and hyphens.
+        let id: String = name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        let id = if id.is_empty() {
+            "movie".to_string()
+        } else {
+            id
+        };
+        let file_name = quick_xml::escape::escape(file_name);
+
+        let xml = format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<application xmlns="http://ns.adobe.com/air/application/20.0">
+  <id>{id}</id>
+  <filename>{id}</filename>
+  <versionNumber>1.0.0</versionNumber>
+  <initialWindow>
+    <content>{file_name}</content>
+  </initialWindow>
+</application>
+"#
+        );
+        Self { xml, id }
+    }
+
+    /// The XML source of the descriptor.
+    pub fn xml(&self) -> &str {
+        &self.xml
+    }
+
+    /// The ID of the application, from the `<id>` element.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_descriptor() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+            <application xmlns="http://ns.adobe.com/air/application/3.0">
+                <id>com.example.Game</id>
+                <versionNumber>1.2.3</versionNumber>
+                <initialWindow><content>game.swf</content></initialWindow>
+            </application>"#;
+        let descriptor = ApplicationDescriptor::parse(xml.to_string()).unwrap();
+        assert_eq!(descriptor.id(), "com.example.Game");
+        assert_eq!(descriptor.xml(), xml);
+    }
+
+    #[test]
+    fn parse_invalid_descriptor() {
+        assert!(matches!(
+            ApplicationDescriptor::parse("<application><name>Game</name></application>".into()),
+            Err(ApplicationDescriptorError::MissingId)
+        ));
+        assert!(matches!(
+            ApplicationDescriptor::parse("<project><id>Game</id></project>".into()),
+            Err(ApplicationDescriptorError::MissingApplication)
+        ));
+    }
+
+    #[test]
+    fn descriptor_for_movie() {
+        let descriptor = ApplicationDescriptor::for_movie("file:///games/My Game.swf?v=1");
+        assert_eq!(descriptor.id(), "My-Game");
+        let parsed = ApplicationDescriptor::parse(descriptor.xml().to_string()).unwrap();
+        assert_eq!(parsed, descriptor);
+    }
+}
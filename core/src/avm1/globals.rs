@@ -47,18 +47,21 @@ pub(crate) mod netstream;
 pub(crate) mod number;
 mod object;
 mod point;
+mod print_job;
 mod rectangle;
 mod selection;
 pub(crate) mod shared_object;
 pub(crate) mod sound;
 mod stage;
 pub(crate) mod string;
+mod style_sheet;
 pub(crate) mod system;
 pub(crate) mod system_capabilities;
 pub(crate) mod system_ime;
 pub(crate) mod system_security;
 pub(crate) mod text_field;
 mod text_format;
+mod text_snapshot;
 pub(crate) mod transform;
 mod video;
 pub(crate) mod xml;
@@ -479,6 +482,7 @@ pub struct SystemPrototypes<'gc> {
     pub sound: Object<'gc>,
     pub text_field: Object<'gc>,
     pub text_format: Object<'gc>,
+    pub text_snapshot: Object<'gc>,
     pub array: Object<'gc>,
     pub array_constructor: Object<'gc>,
     pub xml_node_constructor: Object<'gc>,
@@ -538,6 +542,8 @@ pub fn create_globals<'gc>(
 
     let text_field_proto = text_field::create_proto(context, object_proto, function_proto);
     let text_format_proto = text_format::create_proto(context, object_proto, function_proto);
+    let text_snapshot_proto = text_snapshot::create_proto(context, object_proto, function_proto);
+    let style_sheet_proto = style_sheet::create_proto(context, object_proto, function_proto);
 
     let array_proto = array::create_proto(context, object_proto, function_proto);
 
@@ -584,6 +590,7 @@ pub fn create_globals<'gc>(
     let netstream_proto = netstream::create_proto(context, object_proto, function_proto);
     let netconnection_proto = netconnection::create_proto(context, object_proto, function_proto);
     let xml_socket_proto = xml_socket::create_proto(context, object_proto, function_proto);
+    let print_job_proto = print_job::create_proto(context, object_proto, function_proto);
 
     //TODO: These need to be constructors and should also set `.prototype` on each one
     let object = object::create_object_object(context, object_proto, function_proto);
@@ -663,6 +670,19 @@ pub fn create_globals<'gc>(
         function_proto,
         text_format_proto,
     );
+    let style_sheet = FunctionObject::constructor(
+        gc_context,
+        Executable::Native(style_sheet::constructor),
+        constructor_to_fn!(style_sheet::constructor),
+        function_proto,
+        style_sheet_proto,
+    );
+    text_field.define_value(
+        gc_context,
+        "StyleSheet",
+        style_sheet.into(),
+        Attribute::DONT_ENUM | Attribute::VERSION_7,
+    );
     let array = array::create_array_object(context, array_proto, function_proto);
     let xmlnode = FunctionObject::constructor(
         gc_context,
@@ -679,6 +699,7 @@ pub fn create_globals<'gc>(
     let netstream = netstream::create_class(context, netstream_proto, function_proto);
     let netconnection = netconnection::create_class(context, netconnection_proto, function_proto);
     let xml_socket = xml_socket::create_class(context, xml_socket_proto, function_proto);
+    let print_job = print_job::create_class(context, print_job_proto, function_proto);
 
     let flash = ScriptObject::new(gc_context, Some(object_proto));
 
@@ -1091,6 +1112,12 @@ pub fn create_globals<'gc>(
         xml_socket.into(),
         Attribute::DONT_ENUM,
     );
+    globals.define_value(
+        gc_context,
+        "PrintJob",
+        print_job.into(),
+        Attribute::DONT_ENUM,
+    );
 
     define_properties_on(GLOBAL_DECLS, context, globals, function_proto);
 
@@ -1104,6 +1131,7 @@ pub fn create_globals<'gc>(
             sound: sound_proto,
             text_field: text_field_proto,
             text_format: text_format_proto,
+            text_snapshot: text_snapshot_proto,
             array: array_proto,
             array_constructor: array,
             xml_node_constructor: xmlnode,
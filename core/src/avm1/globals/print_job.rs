@@ -0,0 +1,152 @@
+//! `PrintJob` class impl
+
+use crate::avm1::function::FunctionObject;
+use crate::avm1::object::NativeObject;
+use crate::avm1::property_decl::{define_properties_on, Declaration};
+use crate::avm1::{Activation, Error, Executable, Object, ScriptObject, TObject, Value};
+use crate::context::GcContext;
+use crate::display_object::DisplayObject;
+use crate::printing::rasterize_page;
+use gc_arena::Gc;
+use std::cell::Cell;
+use swf::{Rectangle, Twips};
+
+const PROTO_DECLS: &[Declaration] = declare_properties! {
+    "start" => method(start; DONT_ENUM | DONT_DELETE);
+    "addPage" => method(add_page; DONT_ENUM | DONT_DELETE);
+    "send" => method(send; DONT_ENUM | DONT_DELETE);
+};
+
+fn constructor<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    this.set_native(
+        activation.gc(),
+        NativeObject::PrintJob(Gc::new(activation.gc(), Cell::new(0))),
+    );
+    Ok(this.into())
+}
+
+fn start<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let NativeObject::PrintJob(job) = this.native() else {
+        return Ok(false.into());
+    };
+
+    let context = &mut activation.context;
+    let Some((id, paper)) = context.print_jobs.start(context.print) else {
+        return Ok(false.into());
+    };
+    job.set(id);
+
+    this.set("paperWidth", paper.paper_width.into(), activation)?;
+    this.set("paperHeight", paper.paper_height.into(), activation)?;
+    this.set("pageWidth", paper.page_width.into(), activation)?;
+    this.set("pageHeight", paper.page_height.into(), activation)?;
+    this.set("orientation", paper.orientation.as_str().into(), activation)?;
+    Ok(true.into())
+}
+
+/// Resolves the target of `addPage`, which is either a level number or a movie clip.
+fn page_target<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    target: Value<'gc>,
+) -> Result<Option<DisplayObject<'gc>>, Error<'gc>> {
+    if let Value::Number(level) = target {
+        return Ok(activation.get_level(level as i32));
+    }
+    let start = activation.target_clip_or_root();
+    activation.resolve_target_display_object(start, target, false)
+}
+
+fn add_page<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let NativeObject::PrintJob(job) = this.native() else {
+        return Ok(false.into());
+    };
+
+    let target = args.get(0).copied().unwrap_or(Value::Undefined);
+    let Some(target) = page_target(activation, target)? else {
+        return Ok(false.into());
+    };
+
+    // The print area is an object with `xMin`, `xMax`, `yMin` and `yMax` properties.
+    let area = match args.get(1) {
+        Some(Value::Object(area)) => {
+            let mut get = |name: &'static str| -> Result<Twips, Error<'gc>> {
+                let value = area.get(name, activation)?.coerce_to_f64(activation)?;
+                Ok(Twips::from_pixels(value))
+            };
+            Some(Rectangle {
+                x_min: get("xMin")?,
+                x_max: get("xMax")?,
+                y_min: get("yMin")?,
+                y_max: get("yMax")?,
+            })
+        }
+        _ => None,
+    };
+
+    // Pages are always rasterised, so the `printAsBitmap` option makes no difference.
+    let frame = match args.get(3) {
+        Some(frame) => frame.coerce_to_i32(activation)?,
+        None => 0,
+    };
+    let frame = (frame > 0).then(|| frame.min(u16::MAX.into()) as u16);
+
+    let Some(paper) = activation.context.print_jobs.paper(job.get()) else {
+        return Ok(false.into());
+    };
+    let page = rasterize_page(&mut activation.context, target, area, frame, &paper);
+    Ok(activation
+        .context
+        .print_jobs
+        .add_page(job.get(), page)
+        .into())
+}
+
+fn send<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let NativeObject::PrintJob(job) = this.native() {
+        let context = &mut activation.context;
+        if let Err(e) = context.print_jobs.send(job.replace(0), context.print) {
+            tracing::error!("Unable to print: {e}");
+        }
+    }
+    Ok(Value::Undefined)
+}
+
+pub fn create_proto<'gc>(
+    context: &mut GcContext<'_, 'gc>,
+    proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    let print_job_proto = ScriptObject::new(context.gc_context, Some(proto));
+    define_properties_on(PROTO_DECLS, context, print_job_proto, fn_proto);
+    print_job_proto.into()
+}
+
+pub fn create_class<'gc>(
+    context: &mut GcContext<'_, 'gc>,
+    print_job_proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    FunctionObject::constructor(
+        context.gc_context,
+        Executable::Native(constructor),
+        constructor_to_fn!(constructor),
+        fn_proto,
+        print_job_proto,
+    )
+}
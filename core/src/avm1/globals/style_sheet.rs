@@ -0,0 +1,246 @@
+//! `TextField.StyleSheet` impl
+
+use crate::avm1::activation::Activation;
+use crate::avm1::error::Error;
+use crate::avm1::function::ExecutionReason;
+use crate::avm1::object::NativeObject;
+use crate::avm1::property_decl::{define_properties_on, Declaration};
+use crate::avm1::{ArrayObject, Object, ScriptObject, TObject, Value};
+use crate::backend::navigator::Request;
+use crate::context::GcContext;
+use crate::html::{transform_style, StyleProperties, StyleSheet};
+use crate::string::AvmString;
+use gc_arena::Gc;
+use std::cell::RefCell;
+
+const PROTO_DECLS: &[Declaration] = declare_properties! {
+    "clear" => method(clear; DONT_ENUM | DONT_DELETE);
+    "getStyle" => method(get_style; DONT_ENUM | DONT_DELETE);
+    "getStyleNames" => method(get_style_names; DONT_ENUM | DONT_DELETE);
+    "load" => method(load; DONT_ENUM | DONT_DELETE);
+    "onData" => method(on_data; DONT_ENUM | DONT_DELETE);
+    "parseCSS" => method(parse_css; DONT_ENUM | DONT_DELETE);
+    "setStyle" => method(set_style; DONT_ENUM | DONT_DELETE);
+    "transform" => method(transform; DONT_ENUM | DONT_DELETE);
+};
+
+/// Implements `TextField.StyleSheet`
+pub fn constructor<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    this.set_native(
+        activation.context.gc_context,
+        NativeObject::StyleSheet(Gc::new(
+            activation.context.gc_context,
+            RefCell::new(StyleSheet::default()),
+        )),
+    );
+    Ok(this.into())
+}
+
+fn style_sheet<'gc>(this: Object<'gc>) -> Option<Gc<'gc, RefCell<StyleSheet>>> {
+    match this.native() {
+        NativeObject::StyleSheet(style_sheet) => Some(style_sheet),
+        _ => None,
+    }
+}
+
+/// Reads the properties of a style object, coercing their values to strings.
+fn style_properties<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    object: Object<'gc>,
+) -> Result<StyleProperties, Error<'gc>> {
+    let mut properties = StyleProperties::new();
+    for key in object.get_keys(activation, false) {
+        let value = object.get(key, activation)?.coerce_to_string(activation)?;
+        properties.insert(key.as_wstr().into(), value.as_wstr().into());
+    }
+    Ok(properties)
+}
+
+fn clear<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(style_sheet) = style_sheet(this) {
+        style_sheet.borrow_mut().clear();
+    }
+    Ok(Value::Undefined)
+}
+
+fn get_style<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(style_sheet) = style_sheet(this) else {
+        return Ok(Value::Undefined);
+    };
+    let name = args
+        .get(0)
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_string(activation)?;
+
+    let Some(properties) = style_sheet.borrow().style(&name).cloned() else {
+        return Ok(Value::Null);
+    };
+
+    // Return a copy, so that changing it doesn't affect the style sheet.
+    let style = ScriptObject::new(
+        activation.context.gc_context,
+        Some(activation.context.avm1.prototypes().object),
+    );
+    for (key, value) in properties {
+        let key = AvmString::new(activation.context.gc_context, key);
+        let value = AvmString::new(activation.context.gc_context, value);
+        style.set(key, value.into(), activation)?;
+    }
+    Ok(style.into())
+}
+
+fn get_style_names<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(style_sheet) = style_sheet(this) else {
+        return Ok(Value::Undefined);
+    };
+
+    let names: Vec<Value<'gc>> = style_sheet
+        .borrow()
+        .style_names()
+        .map(|name| AvmString::new(activation.context.gc_context, name).into())
+        .collect();
+    Ok(ArrayObject::new(
+        activation.context.gc_context,
+        activation.context.avm1.prototypes().array,
+        names,
+    )
+    .into())
+}
+
+fn load<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let url = match args.get(0) {
+        Some(val) => val.coerce_to_string(activation)?,
+        None => return Ok(false.into()),
+    };
+
+    // Style sheets are loaded like `LoadVars`, which calls `onData` with the loaded text.
+    let request = Request::get(url.to_utf8_lossy().into_owned());
+    let future = activation.context.load_manager.load_form_into_load_vars(
+        activation.context.player.clone(),
+        this,
+        request,
+    );
+    activation.context.navigator.spawn_future(future);
+
+    Ok(true.into())
+}
+
+fn on_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    // Default implementation forwards to parseCSS and onLoad.
+    let success = match args.get(0).unwrap_or(&Value::Undefined) {
+        Value::Undefined | Value::Null => false.into(),
+        val => {
+            let success = this.call_method(
+                "parseCSS".into(),
+                &[*val],
+                activation,
+                ExecutionReason::FunctionCall,
+            )?;
+            this.set("loaded", true.into(), activation)?;
+            success
+        }
+    };
+
+    this.call_method(
+        "onLoad".into(),
+        &[success],
+        activation,
+        ExecutionReason::FunctionCall,
+    )?;
+
+    Ok(Value::Undefined)
+}
+
+fn parse_css<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(style_sheet) = style_sheet(this) else {
+        return Ok(Value::Undefined);
+    };
+    let css = args
+        .get(0)
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_string(activation)?;
+
+    Ok(style_sheet.borrow_mut().parse_css(&css).into())
+}
+
+fn set_style<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(style_sheet) = style_sheet(this) else {
+        return Ok(Value::Undefined);
+    };
+    let name = args
+        .get(0)
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_string(activation)?;
+
+    // Setting a style to anything but an object removes it.
+    let properties = match args.get(1) {
+        Some(Value::Object(style)) => Some(style_properties(activation, *style)?),
+        _ => None,
+    };
+    style_sheet.borrow_mut().set_style(&name, properties);
+
+    Ok(Value::Undefined)
+}
+
+fn transform<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(Value::Object(style)) = args.get(0) else {
+        return Ok(Value::Null);
+    };
+
+    let text_format = transform_style(&style_properties(activation, *style)?);
+    let object = ScriptObject::new(
+        activation.context.gc_context,
+        Some(activation.context.avm1.prototypes().text_format),
+    );
+    object.set_native(
+        activation.context.gc_context,
+        NativeObject::TextFormat(Gc::new(activation.context.gc_context, text_format.into())),
+    );
+    Ok(object.into())
+}
+
+pub fn create_proto<'gc>(
+    context: &mut GcContext<'_, 'gc>,
+    proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    let object = ScriptObject::new(context.gc_context, Some(proto));
+    define_properties_on(PROTO_DECLS, context, object, fn_proto);
+    object.into()
+}
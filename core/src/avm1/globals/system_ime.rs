@@ -4,7 +4,9 @@ use crate::avm1::globals::as_broadcaster::BroadcasterFunctions;
 use crate::avm1::object::Object;
 use crate::avm1::property_decl::{define_properties_on, Declaration};
 use crate::avm1::{ScriptObject, Value};
+use crate::backend::ui::ImeConversionMode;
 use crate::context::GcContext;
+use crate::string::{AvmString, FromWStr};
 
 const OBJECT_DECLS: &[Declaration] = declare_properties! {
     "ALPHANUMERIC_FULL" => string("ALPHANUMERIC_FULL"; DONT_ENUM | DONT_DELETE | READ_ONLY);
@@ -40,19 +42,20 @@ fn do_conversion<'gc>(
 }
 
 fn get_conversion_mode<'gc>(
-    _activation: &mut Activation<'_, 'gc>,
+    activation: &mut Activation<'_, 'gc>,
     _this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    Ok("KOREAN".into())
+    let mode = activation.context.ui.ime_conversion_mode();
+    Ok(AvmString::new_utf8(activation.context.gc_context, mode.to_string()).into())
 }
 
 fn get_enabled<'gc>(
-    _activation: &mut Activation<'_, 'gc>,
+    activation: &mut Activation<'_, 'gc>,
     _this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    Ok(false.into())
+    Ok(activation.context.ui.ime_enabled().into())
 }
 
 fn set_composition_string<'gc>(
@@ -64,19 +67,34 @@ fn set_composition_string<'gc>(
 }
 
 fn set_conversion_mode<'gc>(
-    _activation: &mut Activation<'_, 'gc>,
+    activation: &mut Activation<'_, 'gc>,
     _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    Ok(false.into())
+    let mode = args
+        .get(0)
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_string(activation)?;
+    let Ok(mode) = ImeConversionMode::from_wstr(&mode) else {
+        return Ok(false.into());
+    };
+
+    activation.context.ui.set_ime_conversion_mode(mode);
+    Ok((activation.context.ui.ime_conversion_mode() == mode).into())
 }
 
 fn set_enabled<'gc>(
-    _activation: &mut Activation<'_, 'gc>,
+    activation: &mut Activation<'_, 'gc>,
     _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    Ok(false.into())
+    let enabled = args
+        .get(0)
+        .unwrap_or(&Value::Undefined)
+        .as_bool(activation.swf_version());
+
+    activation.context.ui.set_ime_enabled(enabled);
+    Ok((activation.context.ui.ime_enabled() == enabled).into())
 }
 
 pub fn create<'gc>(
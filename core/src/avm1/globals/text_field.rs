@@ -11,6 +11,7 @@ use crate::display_object::{
 use crate::font::round_down_to_pixel;
 use crate::html::TextFormat;
 use crate::string::{AvmString, WStr};
+use crate::vminterface::AvmObject;
 use gc_arena::Gc;
 use swf::Color;
 
@@ -84,6 +85,7 @@ const PROTO_DECLS: &[Declaration] = declare_properties! {
     "restrict" => property(tf_getter!(restrict), tf_setter!(set_restrict));
     "scroll" => property(tf_getter!(scroll), tf_setter!(set_scroll));
     "selectable" => property(tf_getter!(selectable), tf_setter!(set_selectable));
+    "styleSheet" => property(tf_getter!(style_sheet), tf_setter!(set_style_sheet); VERSION_7);
     "text" => property(tf_getter!(text), tf_setter!(set_text));
     "textColor" => property(tf_getter!(text_color), tf_setter!(set_text_color));
     "textHeight" => property(tf_getter!(text_height));
@@ -917,6 +919,32 @@ fn set_restrict<'gc>(
     Ok(())
 }
 
+fn style_sheet<'gc>(
+    this: EditText<'gc>,
+    _activation: &mut Activation<'_, 'gc>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    match this.style_sheet() {
+        Some(AvmObject::Avm1(style_sheet)) => Ok(style_sheet.into()),
+        _ => Ok(Value::Undefined),
+    }
+}
+
+fn set_style_sheet<'gc>(
+    this: EditText<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+    value: Value<'gc>,
+) -> Result<(), Error<'gc>> {
+    // Only actual style sheets can be set, anything else removes the style sheet.
+    let style_sheet = match value {
+        Value::Object(object) if matches!(object.native(), NativeObject::StyleSheet(_)) => {
+            Some(AvmObject::Avm1(object))
+        }
+        _ => None,
+    };
+    this.set_style_sheet(activation.context.gc_context, style_sheet);
+    Ok(())
+}
+
 pub fn tab_index<'gc>(
     this: EditText<'gc>,
     _activation: &mut Activation<'_, 'gc>,
@@ -0,0 +1,261 @@
+//! `TextSnapshot` impl
+
+use crate::avm1::object::NativeObject;
+use crate::avm1::property_decl::{define_properties_on, Declaration};
+use crate::avm1::{Activation, ArrayObject, Error, Object, ScriptObject, TObject, Value};
+use crate::context::GcContext;
+use crate::display_object::DisplayObject;
+use crate::prelude::*;
+use crate::string::AvmString;
+use crate::text_snapshot::TextSnapshot;
+
+macro_rules! method {
+    ($name:ident) => {
+        |activation, this, args| {
+            if let NativeObject::TextSnapshot(snapshot) = this.native() {
+                return $name(activation, &snapshot, args);
+            }
+            Ok(Value::Undefined)
+        }
+    };
+}
+
+const PROTO_DECLS: &[Declaration] = declare_properties! {
+    "findText" => method(method!(find_text); DONT_ENUM | DONT_DELETE);
+    "getCount" => method(method!(get_count); DONT_ENUM | DONT_DELETE);
+    "getSelected" => method(method!(get_selected); DONT_ENUM | DONT_DELETE);
+    "getSelectedText" => method(method!(get_selected_text); DONT_ENUM | DONT_DELETE);
+    "getText" => method(method!(get_text); DONT_ENUM | DONT_DELETE);
+    "getTextRunInfo" => method(method!(get_text_run_info); DONT_ENUM | DONT_DELETE);
+    "hitTestTextNearPos" => method(method!(hit_test_text_near_pos); DONT_ENUM | DONT_DELETE);
+    "setSelectColor" => method(method!(set_select_color); DONT_ENUM | DONT_DELETE);
+    "setSelected" => method(method!(set_selected); DONT_ENUM | DONT_DELETE);
+};
+
+/// Creates a `TextSnapshot` of the static text in the given display object.
+pub fn create<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    container: DisplayObject<'gc>,
+) -> Object<'gc> {
+    let object = ScriptObject::new(
+        activation.context.gc_context,
+        Some(activation.context.avm1.prototypes().text_snapshot),
+    );
+    object.set_native(
+        activation.context.gc_context,
+        NativeObject::TextSnapshot(TextSnapshot::new(container)),
+    );
+    object.into()
+}
+
+fn get_index<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    args: &[Value<'gc>],
+    index: usize,
+) -> Result<usize, Error<'gc>> {
+    let value = args.get(index).unwrap_or(&Value::Undefined);
+    Ok(value.coerce_to_i32(activation)?.max(0) as usize)
+}
+
+fn find_text<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    snapshot: &TextSnapshot<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let begin_index = get_index(activation, args, 0)?;
+    let text = args
+        .get(1)
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_string(activation)?;
+    let case_sensitive = args
+        .get(2)
+        .unwrap_or(&Value::Undefined)
+        .as_bool(activation.swf_version());
+
+    let index = snapshot.find_text(
+        activation.context.library,
+        begin_index,
+        &text,
+        case_sensitive,
+    );
+    Ok(index.map_or(-1.0, |index| index as f64).into())
+}
+
+fn get_count<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    snapshot: &TextSnapshot<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(snapshot.char_count(activation.context.library).into())
+}
+
+fn get_selected<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    snapshot: &TextSnapshot<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let begin_index = get_index(activation, args, 0)?;
+    let end_index = get_index(activation, args, 1)?;
+
+    Ok(snapshot
+        .is_selected(activation.context.library, begin_index..end_index)
+        .into())
+}
+
+fn get_selected_text<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    snapshot: &TextSnapshot<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let include_line_endings = args
+        .get(0)
+        .unwrap_or(&Value::Undefined)
+        .as_bool(activation.swf_version());
+
+    let text = snapshot.selected_text(activation.context.library, include_line_endings);
+    Ok(AvmString::new(activation.context.gc_context, text).into())
+}
+
+fn get_text<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    snapshot: &TextSnapshot<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let begin_index = get_index(activation, args, 0)?;
+    let end_index = get_index(activation, args, 1)?;
+    let include_line_endings = args
+        .get(2)
+        .unwrap_or(&Value::Undefined)
+        .as_bool(activation.swf_version());
+
+    let text = snapshot.text(
+        activation.context.library,
+        begin_index..end_index,
+        include_line_endings,
+    );
+    Ok(AvmString::new(activation.context.gc_context, text).into())
+}
+
+fn get_text_run_info<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    snapshot: &TextSnapshot<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let begin_index = get_index(activation, args, 0)?;
+    let end_index = get_index(activation, args, 1)?;
+
+    let glyphs = snapshot.glyphs(activation.context.library);
+    let mut infos: Vec<Value<'gc>> = vec![];
+    for (index, glyph) in glyphs.iter().enumerate().take(end_index).skip(begin_index) {
+        let info = ScriptObject::new(
+            activation.context.gc_context,
+            Some(activation.context.avm1.prototypes().object),
+        );
+        let font_name = AvmString::new_utf8(
+            activation.context.gc_context,
+            glyph.glyph.font.descriptor().name(),
+        );
+
+        info.set("indexInRun", index.into(), activation)?;
+        info.set("selected", glyph.is_selected().into(), activation)?;
+        info.set("font", font_name.into(), activation)?;
+        info.set("color", glyph.glyph.color.to_rgb().into(), activation)?;
+        info.set("height", glyph.glyph.height.to_pixels().into(), activation)?;
+        info.set("matrix_a", glyph.matrix.a.into(), activation)?;
+        info.set("matrix_b", glyph.matrix.b.into(), activation)?;
+        info.set("matrix_c", glyph.matrix.c.into(), activation)?;
+        info.set("matrix_d", glyph.matrix.d.into(), activation)?;
+        info.set("matrix_tx", glyph.matrix.tx.to_pixels().into(), activation)?;
+        info.set("matrix_ty", glyph.matrix.ty.to_pixels().into(), activation)?;
+
+        let corners = glyph.corners();
+        info.set("corner0x", corners[0].x.to_pixels().into(), activation)?;
+        info.set("corner0y", corners[0].y.to_pixels().into(), activation)?;
+        info.set("corner1x", corners[1].x.to_pixels().into(), activation)?;
+        info.set("corner1y", corners[1].y.to_pixels().into(), activation)?;
+        info.set("corner2x", corners[2].x.to_pixels().into(), activation)?;
+        info.set("corner2y", corners[2].y.to_pixels().into(), activation)?;
+        info.set("corner3x", corners[3].x.to_pixels().into(), activation)?;
+        info.set("corner3y", corners[3].y.to_pixels().into(), activation)?;
+
+        infos.push(info.into());
+    }
+
+    Ok(ArrayObject::new(
+        activation.context.gc_context,
+        activation.context.avm1.prototypes().array,
+        infos,
+    )
+    .into())
+}
+
+fn hit_test_text_near_pos<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    snapshot: &TextSnapshot<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let x = args
+        .get(0)
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_f64(activation)?;
+    let y = args
+        .get(1)
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_f64(activation)?;
+    let max_distance = match args.get(2) {
+        Some(value) => value.coerce_to_f64(activation)?,
+        None => 0.0,
+    };
+
+    let index = snapshot.hit_test_text_near_pos(
+        activation.context.library,
+        Point::from_pixels(x, y),
+        Twips::from_pixels(max_distance),
+    );
+    Ok(index.map_or(-1.0, |index| index as f64).into())
+}
+
+fn set_select_color<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    snapshot: &TextSnapshot<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let color = match args.get(0) {
+        Some(value) => value.coerce_to_u32(activation)?,
+        None => 0xFFFF00,
+    };
+
+    snapshot.set_select_color(activation.context.gc_context, Color::from_rgb(color, 0xFF));
+    Ok(Value::Undefined)
+}
+
+fn set_selected<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    snapshot: &TextSnapshot<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let begin_index = get_index(activation, args, 0)?;
+    let end_index = get_index(activation, args, 1)?;
+    let selected = args
+        .get(2)
+        .unwrap_or(&Value::Undefined)
+        .as_bool(activation.swf_version());
+
+    snapshot.set_selected(
+        activation.context.library,
+        activation.context.gc_context,
+        begin_index..end_index,
+        selected,
+    );
+    Ok(Value::Undefined)
+}
+
+pub fn create_proto<'gc>(
+    context: &mut GcContext<'_, 'gc>,
+    proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    let object = ScriptObject::new(context.gc_context, Some(proto));
+    define_properties_on(PROTO_DECLS, context, object, fn_proto);
+    object.into()
+}
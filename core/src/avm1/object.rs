@@ -23,9 +23,11 @@ use crate::avm1::{Activation, Attribute, Error, ScriptObject, SoundObject, Stage
 use crate::bitmap::bitmap_data::BitmapDataWrapper;
 use crate::display_object::DisplayObject;
 use crate::display_object::TDisplayObject;
-use crate::html::TextFormat;
+use crate::html::{StyleSheet, TextFormat};
+use crate::printing::PrintJobId;
 use crate::streams::NetStream;
 use crate::string::AvmString;
+use crate::text_snapshot::TextSnapshot;
 use crate::xml::XmlNode;
 use gc_arena::{Collect, Gc, GcCell, Mutation};
 use ruffle_macros::enum_trait_object;
@@ -64,6 +66,9 @@ pub enum NativeObject<'gc> {
     SharedObject(GcCell<'gc, SharedObject>),
     XmlSocket(XmlSocket<'gc>),
     FileReference(FileReferenceObject<'gc>),
+    TextSnapshot(TextSnapshot<'gc>),
+    StyleSheet(Gc<'gc, RefCell<StyleSheet>>),
+    PrintJob(Gc<'gc, Cell<PrintJobId>>),
 }
 
 /// Represents an object that can be directly interacted with by the AVM
@@ -292,13 +292,15 @@ impl<'gc> Avm1<'gc> {
             active_clip,
         );
 
-        let broadcaster = activation
-            .context
-            .avm1
-            .global_object()
-            .get(broadcaster_name, &mut activation)
-            .unwrap()
-            .coerce_to_object(&mut activation);
+        // Nested broadcasters, like `System.IME`, are given by their path.
+        let mut broadcaster = activation.context.avm1.global_object();
+        for name in broadcaster_name.split(b'.') {
+            let name = AvmString::new(activation.context.gc_context, name);
+            broadcaster = broadcaster
+                .get(name, &mut activation)
+                .unwrap()
+                .coerce_to_object(&mut activation);
+        }
 
         let has_listener =
             as_broadcaster::broadcast_internal(&mut activation, broadcaster, args, method)
@@ -2,6 +2,7 @@
 
 use std::rc::Rc;
 
+use crate::application_descriptor::ApplicationDescriptor;
 use crate::avm2::class::AllocatorFn;
 use crate::avm2::error::make_error_1107;
 use crate::avm2::function::Executable;
@@ -92,7 +93,15 @@ use self::object::WeakObject;
 use self::scope::Scope;
 use num_traits::FromPrimitive;
 
-const BROADCAST_WHITELIST: [&str; 4] = ["enterFrame", "exitFrame", "frameConstructed", "render"];
+const BROADCAST_WHITELIST: [&str; 7] = [
+    "enterFrame",
+    "exitFrame",
+    "frameConstructed",
+    "render",
+    "imeComposition",
+    "deviceAdded",
+    "deviceRemoved",
+];
 
 /// The state of an AVM2 interpreter.
 #[derive(Collect)]
@@ -146,8 +155,12 @@ pub struct Avm2<'gc> {
     pub flash_utils_internal: Namespace<'gc>,
     pub flash_geom_internal: Namespace<'gc>,
     pub flash_events_internal: Namespace<'gc>,
+    pub flash_text_internal: Namespace<'gc>,
     pub flash_text_engine_internal: Namespace<'gc>,
     pub flash_net_internal: Namespace<'gc>,
+    pub flash_ui_internal: Namespace<'gc>,
+    pub flash_system_internal: Namespace<'gc>,
+    pub flash_concurrent_internal: Namespace<'gc>,
 
     #[collect(require_static)]
     native_method_table: &'static [Option<(&'static str, NativeMethodImpl)>],
@@ -187,6 +200,10 @@ pub struct Avm2<'gc> {
     #[collect(require_static)]
     pub root_api_version: ApiVersion,
 
+    /// The descriptor of the running AIR application, if one was provided.
+    #[collect(require_static)]
+    application_descriptor: Option<ApplicationDescriptor>,
+
     #[cfg(feature = "avm_debug")]
     pub debug_output: bool,
 
@@ -244,8 +261,12 @@ impl<'gc> Avm2<'gc> {
             flash_utils_internal: Namespace::internal("flash.utils", context),
             flash_geom_internal: Namespace::internal("flash.geom", context),
             flash_events_internal: Namespace::internal("flash.events", context),
+            flash_text_internal: Namespace::internal("flash.text", context),
             flash_text_engine_internal: Namespace::internal("flash.text.engine", context),
             flash_net_internal: Namespace::internal("flash.net", context),
+            flash_ui_internal: Namespace::internal("flash.ui", context),
+            flash_system_internal: Namespace::internal("flash.system", context),
+            flash_concurrent_internal: Namespace::internal("flash.concurrent", context),
 
             native_method_table: Default::default(),
             native_instance_allocator_table: Default::default(),
@@ -258,6 +279,8 @@ impl<'gc> Avm2<'gc> {
             // Set the lowest version for now - this will be overridden when we set our movie
             root_api_version: ApiVersion::AllVersions,
 
+            application_descriptor: None,
+
             #[cfg(feature = "avm_debug")]
             debug_output: false,
 
@@ -755,6 +778,18 @@ impl<'gc> Avm2<'gc> {
     pub fn set_optimizer_enabled(&mut self, value: bool) {
         self.optimizer_enabled = value;
     }
+
+    /// Returns the application descriptor, creating it with `default` if the player wasn't given one.
+    pub fn application_descriptor_or_insert_with(
+        &mut self,
+        default: impl FnOnce() -> ApplicationDescriptor,
+    ) -> &ApplicationDescriptor {
+        self.application_descriptor.get_or_insert_with(default)
+    }
+
+    pub fn set_application_descriptor(&mut self, descriptor: Option<ApplicationDescriptor>) {
+        self.application_descriptor = descriptor;
+    }
 }
 
 /// If the provided `DisplayObjectWeak` should have frames run, returns
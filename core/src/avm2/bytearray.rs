@@ -97,6 +97,9 @@ pub struct ByteArrayStorage {
 
     /// The encoding used when serializing/deserializing using readObject/writeObject
     object_encoding: ObjectEncoding,
+
+    /// Whether this ByteArray is shared with other workers, instead of being copied.
+    shareable: bool,
 }
 
 impl ByteArrayStorage {
@@ -107,6 +110,7 @@ impl ByteArrayStorage {
             position: Cell::new(0),
             endian: Endian::Big,
             object_encoding: ObjectEncoding::Amf3,
+            shareable: false,
         }
     }
 
@@ -117,6 +121,7 @@ impl ByteArrayStorage {
             position: Cell::new(0),
             endian: Endian::Big,
             object_encoding: ObjectEncoding::Amf3,
+            shareable: false,
         }
     }
 
@@ -388,6 +393,22 @@ impl ByteArrayStorage {
         self.object_encoding = new_object_encoding;
     }
 
+    #[inline]
+    pub fn shareable(&self) -> bool {
+        self.shareable
+    }
+
+    #[inline]
+    pub fn set_shareable(&mut self, shareable: bool) {
+        self.shareable = shareable;
+    }
+
+    /// Replaces the contents of the ByteArray, keeping its position within bounds.
+    pub fn set_bytes(&mut self, bytes: Vec<u8>) {
+        self.position.set(self.position().min(bytes.len()));
+        self.bytes = bytes;
+    }
+
     #[inline]
     pub fn bytes_available(&self) -> usize {
         self.len().saturating_sub(self.position.get())
@@ -108,9 +108,13 @@ pub struct SystemClasses<'gc> {
     pub soundchannel: ClassObject<'gc>,
     pub bitmap: ClassObject<'gc>,
     pub bitmapdata: ClassObject<'gc>,
+    pub pngencoderoptions: ClassObject<'gc>,
+    pub jpegencoderoptions: ClassObject<'gc>,
+    pub jpegxrencoderoptions: ClassObject<'gc>,
     pub date: ClassObject<'gc>,
     pub qname: ClassObject<'gc>,
     pub mouseevent: ClassObject<'gc>,
+    pub touchevent: ClassObject<'gc>,
     pub progressevent: ClassObject<'gc>,
     pub httpstatusevent: ClassObject<'gc>,
     pub textevent: ClassObject<'gc>,
@@ -171,9 +175,20 @@ pub struct SystemClasses<'gc> {
     pub filefilter: ClassObject<'gc>,
     pub font: ClassObject<'gc>,
     pub textline: ClassObject<'gc>,
+    pub textsnapshot: ClassObject<'gc>,
+    pub groupelement: ClassObject<'gc>,
+    pub graphicelement: ClassObject<'gc>,
     pub sampledataevent: ClassObject<'gc>,
     pub avm1movie: ClassObject<'gc>,
     pub focusevent: ClassObject<'gc>,
+    pub imeevent: ClassObject<'gc>,
+    pub ime: ClassObject<'gc>,
+    pub gameinput: ClassObject<'gc>,
+    pub gameinputevent: ClassObject<'gc>,
+    pub worker: ClassObject<'gc>,
+    pub messagechannel: ClassObject<'gc>,
+    pub mutex: ClassObject<'gc>,
+    pub condition: ClassObject<'gc>,
     pub dictionary: ClassObject<'gc>,
     pub id3info: ClassObject<'gc>,
 }
@@ -242,9 +257,13 @@ impl<'gc> SystemClasses<'gc> {
             soundchannel: object,
             bitmap: object,
             bitmapdata: object,
+            pngencoderoptions: object,
+            jpegencoderoptions: object,
+            jpegxrencoderoptions: object,
             date: object,
             qname: object,
             mouseevent: object,
+            touchevent: object,
             progressevent: object,
             httpstatusevent: object,
             textevent: object,
@@ -305,9 +324,20 @@ impl<'gc> SystemClasses<'gc> {
             filefilter: object,
             font: object,
             textline: object,
+            textsnapshot: object,
+            groupelement: object,
+            graphicelement: object,
             sampledataevent: object,
             avm1movie: object,
             focusevent: object,
+            imeevent: object,
+            ime: object,
+            gameinput: object,
+            gameinputevent: object,
+            worker: object,
+            messagechannel: object,
+            mutex: object,
+            condition: object,
             dictionary: object,
             id3info: object,
         }
@@ -727,6 +757,13 @@ fn load_playerglobal<'gc>(
             ("flash.display", "AVM1Movie", avm1movie),
             ("flash.display", "Bitmap", bitmap),
             ("flash.display", "BitmapData", bitmapdata),
+            ("flash.display", "PNGEncoderOptions", pngencoderoptions),
+            ("flash.display", "JPEGEncoderOptions", jpegencoderoptions),
+            (
+                "flash.display",
+                "JPEGXREncoderOptions",
+                jpegxrencoderoptions
+            ),
             ("flash.display", "Scene", scene),
             ("flash.display", "FrameLabel", framelabel),
             ("flash.display", "IGraphicsData", igraphicsdata),
@@ -785,12 +822,14 @@ fn load_playerglobal<'gc>(
             ("flash.events", "SecurityErrorEvent", securityerrorevent),
             ("flash.events", "IOErrorEvent", ioerrorevent),
             ("flash.events", "MouseEvent", mouseevent),
+            ("flash.events", "TouchEvent", touchevent),
             ("flash.events", "FullScreenEvent", fullscreenevent),
             ("flash.events", "UncaughtErrorEvents", uncaughterrorevents),
             ("flash.events", "NetStatusEvent", netstatusevent),
             ("flash.events", "StatusEvent", statusevent),
             ("flash.events", "ContextMenuEvent", contextmenuevent),
             ("flash.events", "FocusEvent", focusevent),
+            ("flash.events", "IMEEvent", imeevent),
             ("flash.geom", "Matrix", matrix),
             ("flash.geom", "Point", point),
             ("flash.geom", "Rectangle", rectangle),
@@ -806,12 +845,22 @@ fn load_playerglobal<'gc>(
             ("flash.utils", "ByteArray", bytearray),
             ("flash.utils", "Dictionary", dictionary),
             ("flash.system", "ApplicationDomain", application_domain),
+            ("flash.system", "IME", ime),
+            ("flash.system", "Worker", worker),
+            ("flash.system", "MessageChannel", messagechannel),
+            ("flash.concurrent", "Mutex", mutex),
+            ("flash.concurrent", "Condition", condition),
+            ("flash.ui", "GameInput", gameinput),
+            ("flash.events", "GameInputEvent", gameinputevent),
             ("flash.text", "Font", font),
             ("flash.text", "StaticText", statictext),
             ("flash.text", "TextFormat", textformat),
             ("flash.text", "TextField", textfield),
             ("flash.text", "TextLineMetrics", textlinemetrics),
+            ("flash.text", "TextSnapshot", textsnapshot),
             ("flash.text.engine", "TextLine", textline),
+            ("flash.text.engine", "GroupElement", groupelement),
+            ("flash.text.engine", "GraphicElement", graphicelement),
             ("flash.filters", "BevelFilter", bevelfilter),
             ("flash.filters", "BitmapFilter", bitmapfilter),
             ("flash.filters", "BlurFilter", blurfilter),
@@ -0,0 +1,29 @@
+package flash.concurrent {
+    // The mutexes and conditions that were shared with this worker, by their ID.
+    internal var _mutexes:Object = {};
+    internal var _conditions:Object = {};
+
+    internal function _mutex(id:uint):Mutex {
+        var mutex:Mutex = _mutexes[id];
+        if (mutex == null) {
+            Mutex._constructing = true;
+            mutex = new Mutex();
+            Mutex._constructing = false;
+            mutex._id = id;
+            _mutexes[id] = mutex;
+        }
+        return mutex;
+    }
+
+    internal function _condition(id:uint):Condition {
+        var condition:Condition = _conditions[id];
+        if (condition == null) {
+            Condition._constructing = true;
+            condition = new Condition(_mutex(Condition.getMutexId(id)));
+            Condition._constructing = false;
+            condition._id = id;
+            _conditions[id] = condition;
+        }
+        return condition;
+    }
+}
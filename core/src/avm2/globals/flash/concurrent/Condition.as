@@ -1,7 +1,67 @@
 package flash.concurrent {
+    import flash.errors.IllegalOperationError;
+    import flash.errors.ScriptTimeoutError;
+
+    [API("684")]
     public final class Condition {
-        public static const isSupported: Boolean = false;
+        public static const isSupported: Boolean = true;
+
+        internal static var _constructing:Boolean = false;
+
+        // The ID of this condition, which is the same in all workers.
+        internal var _id:uint;
+
+        private var _mutex:Mutex;
+
+        public function Condition(mutex:Mutex) {
+            if (mutex == null) {
+                throw new TypeError("Error #2007: Parameter mutex must be non-null.", 2007);
+            }
+            this._mutex = mutex;
+            if (!_constructing) {
+                this._id = createCondition(mutex._id);
+                _conditions[this._id] = this;
+            }
+        }
+
+        private static native function createCondition(mutexId:uint):uint;
+        internal static native function getMutexId(id:uint):uint;
+        private static native function notifyCondition(id:uint):void;
+        private static native function waitCondition(id:uint):Boolean;
+
+        public function get mutex():Mutex {
+            return this._mutex;
+        }
+
+        public function notify():void {
+            checkOwner();
+            notifyCondition(this._id);
+        }
+
+        public function notifyAll():void {
+            checkOwner();
+            notifyCondition(this._id);
+        }
+
+        public function wait(timeout:Number = -1):Boolean {
+            checkOwner();
+            if (waitCondition(this._id)) {
+                return true;
+            }
+            // FIXME - Flash blocks until the condition is notified. Workers run one after
+            // another in Ruffle, so no other worker can notify it while this one waits.
+            // Flash would end such a wait with a script timeout, so throw that error
+            // right away instead of hanging.
+            if (timeout == -1) {
+                throw new ScriptTimeoutError("Error #1502: A script has executed for longer than the default timeout period of 15 seconds.", 1502);
+            }
+            return false;
+        }
 
-        public function Condition(mutex: Mutex) {}
+        private function checkOwner():void {
+            if (!Mutex.ownsMutex(this._mutex._id)) {
+                throw new IllegalOperationError("The current worker doesn't own the mutex of the condition.");
+            }
+        }
     }
-}
\ No newline at end of file
+}
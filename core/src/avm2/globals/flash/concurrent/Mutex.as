@@ -1,9 +1,46 @@
 package flash.concurrent {
+    import flash.errors.IllegalOperationError;
+    import flash.errors.ScriptTimeoutError;
+
+    [API("684")]
     public final class Mutex {
-        public static const isSupported: Boolean = false;
-        
+        public static const isSupported: Boolean = true;
+
+        internal static var _constructing:Boolean = false;
+
+        // The ID of this mutex, which is the same in all workers.
+        internal var _id:uint;
+
         public function Mutex() {
-            throw new Error("Error #1520: Mutex cannot be initialized.", 1520);
+            if (!_constructing) {
+                this._id = createMutex();
+                _mutexes[this._id] = this;
+            }
+        }
+
+        private static native function createMutex():uint;
+        private static native function tryLockMutex(id:uint):Boolean;
+        private static native function unlockMutex(id:uint):Boolean;
+        internal static native function ownsMutex(id:uint):Boolean;
+
+        public function lock():void {
+            // FIXME - Flash blocks until the mutex is unlocked. Workers run one after
+            // another in Ruffle, so the worker that owns the mutex can't run to unlock it
+            // while this one waits. Flash would end such a wait with a script timeout,
+            // so throw that error right away instead of hanging.
+            if (!tryLockMutex(this._id)) {
+                throw new ScriptTimeoutError("Error #1502: A script has executed for longer than the default timeout period of 15 seconds.", 1502);
+            }
+        }
+
+        public function tryLock():Boolean {
+            return tryLockMutex(this._id);
+        }
+
+        public function unlock():void {
+            if (!unlockMutex(this._id)) {
+                throw new IllegalOperationError("The current worker doesn't own the mutex.");
+            }
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,57 @@
+//! `flash.concurrent.Condition` native methods
+
+use crate::avm2::activation::Activation;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::avm2::{Error, Object};
+
+/// Implements `Condition.createCondition`
+pub fn create_condition<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let mutex = args.get_u32(activation, 0)?;
+    Ok(activation.context.workers.create_condition(mutex).into())
+}
+
+/// Implements `Condition.getMutexId`
+pub fn get_mutex_id<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    match activation.context.workers.condition_mutex(id) {
+        Some(mutex) => Ok(mutex.into()),
+        None => Ok(Value::Undefined),
+    }
+}
+
+/// Implements `Condition.notifyCondition`
+pub fn notify_condition<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    activation.context.workers.notify_condition(id);
+    Ok(Value::Undefined)
+}
+
+/// Implements `Condition.waitCondition`
+///
+/// Workers run one after another, so nothing can notify the condition while
+/// this worker waits. Waiting only succeeds if it was notified beforehand.
+pub fn wait_condition<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    Ok(activation
+        .context
+        .workers
+        .take_condition_notification(id)
+        .into())
+}
@@ -0,0 +1,49 @@
+//! `flash.concurrent.Mutex` native methods
+//!
+//! Workers run one after another, so a mutex that is owned by another worker
+//! can't be waited for, and `Mutex.lock` throws the script timeout error that
+//! would eventually end the wait in Flash instead.
+
+use crate::avm2::activation::Activation;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::avm2::{Error, Object};
+
+/// Implements `Mutex.createMutex`
+pub fn create_mutex<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(activation.context.workers.create_mutex().into())
+}
+
+/// Implements `Mutex.tryLockMutex`
+pub fn try_lock_mutex<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    Ok(activation.context.workers.try_lock_mutex(id).into())
+}
+
+/// Implements `Mutex.unlockMutex`
+pub fn unlock_mutex<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    Ok(activation.context.workers.unlock_mutex(id).into())
+}
+
+/// Implements `Mutex.ownsMutex`
+pub fn owns_mutex<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    Ok(activation.context.workers.owns_mutex(id).into())
+}
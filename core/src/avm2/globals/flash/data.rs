@@ -0,0 +1,3 @@
+//! `flash.data` namespace
+
+pub mod encrypted_local_store;
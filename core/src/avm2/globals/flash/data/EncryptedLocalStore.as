@@ -0,0 +1,19 @@
+package flash.data {
+    import flash.utils.ByteArray;
+
+    [API("661")] // AIR 1.0
+    public class EncryptedLocalStore {
+        [API("668")] // AIR 2.0
+        public static function get isSupported():Boolean {
+            return true;
+        }
+
+        public static native function getItem(name:String):ByteArray;
+
+        public static native function setItem(name:String, data:ByteArray, stronglyBound:Boolean = false):void;
+
+        public static native function removeItem(name:String):void;
+
+        public static native function reset():void;
+    }
+}
@@ -0,0 +1,194 @@
+//! `flash.data.EncryptedLocalStore` native methods
+//!
+//! All items of an application are kept in a single entry of the storage backend,
+//! named after the application ID. Like the rest of the storage backend, the
+//! items are not actually encrypted.
+
+use crate::avm2::bytearray::ByteArrayStorage;
+use crate::avm2::error::argument_error;
+use crate::avm2::globals::flash::desktop::native_application::application_descriptor;
+use crate::avm2::object::{ByteArrayObject, TObject};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, Error, Object, Value};
+use std::collections::BTreeMap;
+
+type Items = BTreeMap<String, Vec<u8>>;
+
+/// Returns the storage backend key of the store of the running application.
+fn storage_key(activation: &mut Activation<'_, '_>) -> String {
+    let id: String = application_descriptor(activation)
+        .id()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("#EncryptedLocalStore/{id}")
+}
+
+/// Decodes a store, which is a sequence of items made of a name and data,
+/// each prefixed with their little-endian `u32` length.
+fn decode_items(mut bytes: &[u8]) -> Option<Items> {
+    fn read_chunk<'a>(bytes: &mut &'a [u8]) -> Option<&'a [u8]> {
+        let length = u32::from_le_bytes(bytes.get(..4)?.try_into().ok()?) as usize;
+        let rest = bytes.get(4..)?;
+        let chunk = rest.get(..length)?;
+        *bytes = &rest[length..];
+        Some(chunk)
+    }
+
+    let mut items = Items::new();
+    while !bytes.is_empty() {
+        let name = String::from_utf8(read_chunk(&mut bytes)?.to_vec()).ok()?;
+        let data = read_chunk(&mut bytes)?.to_vec();
+        items.insert(name, data);
+    }
+    Some(items)
+}
+
+fn encode_items(items: &Items) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for (name, data) in items {
+        for chunk in [name.as_bytes(), data] {
+            bytes.extend_from_slice(&(chunk.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(chunk);
+        }
+    }
+    bytes
+}
+
+fn load_items(activation: &mut Activation<'_, '_>) -> Items {
+    let key = storage_key(activation);
+    let Some(bytes) = activation.context.storage.get(&key) else {
+        return Items::new();
+    };
+    decode_items(&bytes).unwrap_or_else(|| {
+        tracing::warn!("EncryptedLocalStore: Discarding corrupted store {key}");
+        Items::new()
+    })
+}
+
+fn save_items(activation: &mut Activation<'_, '_>, items: &Items) {
+    let key = storage_key(activation);
+    if items.is_empty() {
+        activation.context.storage.remove_key(&key);
+    } else if !activation.context.storage.put(&key, &encode_items(items)) {
+        tracing::warn!("EncryptedLocalStore: Unable to save store {key}");
+    }
+}
+
+fn get_name<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    args: &[Value<'gc>],
+) -> Result<String, Error<'gc>> {
+    let name = args.get_string_non_null(activation, 0, "name")?;
+    if name.is_empty() {
+        return Err(Error::AvmError(argument_error(
+            activation,
+            "Error #2004: One of the parameters is invalid.",
+            2004,
+        )?));
+    }
+    Ok(name.to_utf8_lossy().into_owned())
+}
+
+/// Implements `EncryptedLocalStore.getItem`
+pub fn get_item<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let name = get_name(activation, args)?;
+    match load_items(activation).remove(&name) {
+        Some(data) => {
+            let storage = ByteArrayStorage::from_vec(data);
+            Ok(ByteArrayObject::from_storage(activation, storage)?.into())
+        }
+        None => Ok(Value::Null),
+    }
+}
+
+/// Implements `EncryptedLocalStore.setItem`
+///
+/// `stronglyBound` is ignored, since it ties items to the publisher of the
+/// application, which we don't know.
+pub fn set_item<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let name = get_name(activation, args)?;
+    let data = args.get_object(activation, 1, "data")?;
+    let data = match data.as_bytearray() {
+        Some(bytearray) => bytearray.bytes().to_vec(),
+        None => return Ok(Value::Undefined),
+    };
+
+    let mut items = load_items(activation);
+    items.insert(name, data);
+    save_items(activation, &items);
+    Ok(Value::Undefined)
+}
+
+/// Implements `EncryptedLocalStore.removeItem`
+pub fn remove_item<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let name = get_name(activation, args)?;
+    let mut items = load_items(activation);
+    if items.remove(&name).is_some() {
+        save_items(activation, &items);
+    }
+    Ok(Value::Undefined)
+}
+
+/// Implements `EncryptedLocalStore.reset`
+pub fn reset<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let key = storage_key(activation);
+    activation.context.storage.remove_key(&key);
+    Ok(Value::Undefined)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn items_round_trip() {
+        let mut items = Items::new();
+        items.insert("token".to_string(), b"secret".to_vec());
+        items.insert("empty".to_string(), Vec::new());
+        items.insert("ünïcode".to_string(), vec![0, 255]);
+
+        let bytes = encode_items(&items);
+        assert_eq!(decode_items(&bytes), Some(items));
+        assert_eq!(decode_items(&[]), Some(Items::new()));
+    }
+
+    #[test]
+    fn truncated_items() {
+        let mut items = Items::new();
+        items.insert("token".to_string(), b"secret".to_vec());
+        let bytes = encode_items(&items);
+
+        for length in 1..bytes.len() {
+            assert_eq!(decode_items(&bytes[..length]), None, "length {length}");
+        }
+    }
+
+    #[test]
+    fn invalid_name() {
+        let bytes = [1, 0, 0, 0, 0xFF, 0, 0, 0, 0];
+        assert_eq!(decode_items(&bytes), None);
+    }
+}
@@ -1,54 +1,110 @@
 package flash.desktop {
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_method;
-    import flash.system.System;
-
     public class Clipboard {
-        private static var _generalClipboard = new Clipboard();
+        private static var _generalClipboard: Clipboard;
+
+        // Whether this is the operating system clipboard.
+        private var _isGeneral: Boolean = false;
+
+        // Data that isn't stored by the player, keyed by format.
+        // This holds custom formats of the general clipboard, and all data of other clipboards.
+        private var _data: Object = {};
+
+        // Data handlers that haven't been called yet, keyed by format.
+        private var _handlers: Object = {};
 
         public static function get generalClipboard(): Clipboard {
-            return Clipboard._generalClipboard;
+            if (_generalClipboard == null) {
+                _generalClipboard = new Clipboard();
+                _generalClipboard._isGeneral = true;
+            }
+            return _generalClipboard;
         }
 
         function Clipboard() {
             // TODO: This should only be callable in AIR
         }
 
+        private static native function getGeneralFormats(): Array;
+        private static native function getGeneralData(format: String): Object;
+        private static native function setGeneralData(format: String, data: Object): Boolean;
+        private static native function clearGeneralData(format: String): void;
+
+        private static function isStandardFormat(format: String): Boolean {
+            return format == ClipboardFormats.TEXT_FORMAT ||
+                format == ClipboardFormats.HTML_FORMAT ||
+                format == ClipboardFormats.RICH_TEXT_FORMAT ||
+                format == ClipboardFormats.URL_FORMAT ||
+                format == ClipboardFormats.BITMAP_FORMAT;
+        }
+
         public function get formats(): Array {
-            stub_getter("flash.desktop.Clipboard", "formats");
-            return new Array();
+            var formats: Array = _isGeneral ? getGeneralFormats() : [];
+            var format: String;
+            for (format in _data) {
+                formats.push(format);
+            }
+            for (format in _handlers) {
+                formats.push(format);
+            }
+            return formats;
         }
 
         public function clear(): void {
-            stub_method("flash.desktop.Clipboard", "clear");
+            if (_isGeneral) {
+                clearGeneralData(null);
+            }
+            _data = {};
+            _handlers = {};
         }
 
         public function clearData(format: String): void {
-            stub_method("flash.desktop.Clipboard", "clearData");
+            if (_isGeneral && isStandardFormat(format)) {
+                clearGeneralData(format);
+            }
+            delete _data[format];
+            delete _handlers[format];
         }
 
         public function getData(format: String, transferMode: String = ClipboardTransferMode.ORIGINAL_PREFERRED): Object {
-            stub_method("flash.desktop.Clipboard", "getData");
+            if (_handlers.hasOwnProperty(format)) {
+                var handler: Function = _handlers[format];
+                delete _handlers[format];
+                _data[format] = handler();
+            }
+            if (_data.hasOwnProperty(format)) {
+                return _data[format];
+            }
+            if (_isGeneral && isStandardFormat(format)) {
+                return getGeneralData(format);
+            }
             return null;
         }
 
         public function hasFormat(format: String): Boolean {
-            stub_method("flash.desktop.Clipboard", "hasFormat");
-            return false;
+            return formats.indexOf(format) != -1;
         }
 
         public function setData(format: String, data: Object, serializable: Boolean = true): Boolean {
-            stub_method("flash.desktop.Clipboard", "setData");
-            if (format == ClipboardFormats.TEXT_FORMAT) {
-                System.setClipboard(data);
-                return true;
+            if (_isGeneral && isStandardFormat(format)) {
+                return setGeneralData(format, data);
             }
-            return false;
+            delete _handlers[format];
+            _data[format] = data;
+            return true;
         }
 
         public function setDataHandler(format: String, handler: Function, serializable: Boolean = true): Boolean {
-            stub_method("flash.desktop.Clipboard", "setDataHandler");
-            return false;
+            if (handler == null) {
+                throw new TypeError("Error #2007: Parameter handler must be non-null.", 2007);
+            }
+            if (_isGeneral && isStandardFormat(format)) {
+                // The operating system clipboard can't call back into the movie,
+                // so the data has to be provided right away.
+                return setGeneralData(format, handler());
+            }
+            delete _data[format];
+            _handlers[format] = handler;
+            return true;
         }
     }
 }
@@ -0,0 +1,99 @@
+package flash.desktop {
+    import flash.events.EventDispatcher;
+    import __ruffle__.stub_method;
+    import __ruffle__.stub_setter;
+
+    [API("661")] // AIR 1.0
+    public final class NativeApplication extends EventDispatcher {
+        private static var _nativeApplication:NativeApplication;
+        private static var _constructing:Boolean = false;
+
+        public var autoExit:Boolean = true;
+        public var idleThreshold:int = 300;
+
+        public function NativeApplication() {
+            if (!_constructing) {
+                throw new Error("Error #3001: This operation is not supported.", 3001);
+            }
+        }
+
+        private static native function getApplicationDescriptor():String;
+        private static native function getApplicationId():String;
+
+        public static function get nativeApplication():NativeApplication {
+            if (_nativeApplication == null) {
+                _constructing = true;
+                _nativeApplication = new NativeApplication();
+                _constructing = false;
+            }
+            return _nativeApplication;
+        }
+
+        public static function get supportsDockIcon():Boolean {
+            return false;
+        }
+
+        public static function get supportsMenu():Boolean {
+            return false;
+        }
+
+        public static function get supportsSystemTrayIcon():Boolean {
+            return false;
+        }
+
+        [API("668")] // AIR 2.0
+        public static function get supportsDefaultApplication():Boolean {
+            return false;
+        }
+
+        [API("668")] // AIR 2.0
+        public static function get supportsStartAtLogin():Boolean {
+            return false;
+        }
+
+        public function get applicationDescriptor():XML {
+            return new XML(getApplicationDescriptor());
+        }
+
+        public function get applicationID():String {
+            return getApplicationId();
+        }
+
+        public function get publisherID():String {
+            return "";
+        }
+
+        public function get runtimeVersion():String {
+            return "20.0.0.0";
+        }
+
+        public function get runtimePatchLevel():uint {
+            return 0;
+        }
+
+        public function get openedWindows():Array {
+            return [];
+        }
+
+        public function get startAtLogin():Boolean {
+            return false;
+        }
+
+        public function set startAtLogin(value:Boolean):void {
+            stub_setter("flash.desktop.NativeApplication", "startAtLogin");
+        }
+
+        [API("668")] // AIR 2.0
+        public function get timeSinceLastUserInput():int {
+            return 0;
+        }
+
+        public function activate(window:Object = null):void {
+            stub_method("flash.desktop.NativeApplication", "activate");
+        }
+
+        public function exit(errorCode:int = 0):void {
+            stub_method("flash.desktop.NativeApplication", "exit");
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! `flash.desktop.Clipboard` native methods
+
+use crate::avm2::activation::Activation;
+use crate::avm2::bytearray::ByteArrayStorage;
+use crate::avm2::object::{BitmapDataObject, ByteArrayObject, TObject};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::avm2::{ArrayObject, ArrayStorage, Error, Object};
+use crate::backend::ui::{ClipboardData, ClipboardFormat};
+use crate::bitmap::bitmap_data::{BitmapData, BitmapDataWrapper, Color};
+use crate::bitmap::operations;
+use crate::string::{AvmString, FromWStr};
+use gc_arena::GcCell;
+
+/// Implements `Clipboard.getGeneralFormats`
+pub fn get_general_formats<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let mut storage = ArrayStorage::new(0);
+    for format in activation.context.ui.clipboard_formats() {
+        storage.push(AvmString::new_utf8(activation.context.gc_context, format.to_string()).into());
+    }
+    Ok(ArrayObject::from_storage(activation, storage)?.into())
+}
+
+/// Implements `Clipboard.getGeneralData`
+pub fn get_general_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let format = args.get_string(activation, 0)?;
+    let Ok(format) = ClipboardFormat::from_wstr(&format) else {
+        return Ok(Value::Null);
+    };
+
+    let value = match activation.context.ui.clipboard_data(format) {
+        Some(ClipboardData::Text(text) | ClipboardData::Html(text) | ClipboardData::Url(text)) => {
+            AvmString::new_utf8(activation.context.gc_context, text).into()
+        }
+        Some(ClipboardData::RichText(data)) => {
+            let storage = ByteArrayStorage::from_vec(data);
+            ByteArrayObject::from_storage(activation, storage)?.into()
+        }
+        Some(ClipboardData::Bitmap(bitmap)) => {
+            let bitmap = bitmap.to_rgba();
+            let bitmap_data = BitmapData::new_with_pixels(
+                bitmap.width(),
+                bitmap.height(),
+                true,
+                bitmap.as_colors().map(Color::from).collect(),
+            );
+            let bitmap_data =
+                BitmapDataWrapper::new(GcCell::new(activation.context.gc_context, bitmap_data));
+            let class = activation.avm2().classes().bitmapdata;
+            BitmapDataObject::from_bitmap_data_internal(activation, bitmap_data, class)?.into()
+        }
+        None => Value::Null,
+    };
+    Ok(value)
+}
+
+/// Implements `Clipboard.setGeneralData`
+pub fn set_general_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let format = args.get_string(activation, 0)?;
+    let Ok(format) = ClipboardFormat::from_wstr(&format) else {
+        return Ok(false.into());
+    };
+    let value = args.get_value(1);
+
+    let data = match format {
+        ClipboardFormat::Text | ClipboardFormat::Html | ClipboardFormat::Url => {
+            let text = value.coerce_to_string(activation)?.to_string();
+            match format {
+                ClipboardFormat::Text => ClipboardData::Text(text),
+                ClipboardFormat::Html => ClipboardData::Html(text),
+                _ => ClipboardData::Url(text),
+            }
+        }
+        ClipboardFormat::RichText => {
+            let Some(object) = value.as_object() else {
+                return Ok(false.into());
+            };
+            let Some(bytearray) = object.as_bytearray() else {
+                return Ok(false.into());
+            };
+            ClipboardData::RichText(bytearray.bytes().to_vec())
+        }
+        ClipboardFormat::Bitmap => {
+            let Some(bitmap_data) = value.as_object().and_then(|o| o.as_bitmap_data()) else {
+                return Ok(false.into());
+            };
+            bitmap_data.check_valid(activation)?;
+            ClipboardData::Bitmap(operations::to_bitmap(
+                bitmap_data,
+                activation.context.renderer,
+            ))
+        }
+    };
+
+    activation.context.ui.set_clipboard_data(data);
+    Ok(true.into())
+}
+
+/// Implements `Clipboard.clearGeneralData`
+pub fn clear_general_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let format = match args.try_get_string(activation, 0)? {
+        Some(format) => match ClipboardFormat::from_wstr(&format) {
+            Ok(format) => Some(format),
+            Err(_) => return Ok(Value::Undefined),
+        },
+        None => None,
+    };
+    activation.context.ui.clear_clipboard_data(format);
+    Ok(Value::Undefined)
+}
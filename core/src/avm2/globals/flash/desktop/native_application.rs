@@ -0,0 +1,39 @@
+//! `flash.desktop.NativeApplication` native methods
+
+use crate::application_descriptor::ApplicationDescriptor;
+use crate::avm2::{Activation, Error, Object, Value};
+use crate::string::AvmString;
+
+/// Returns the descriptor of the running application, generating one from
+/// the root movie if the player wasn't given any.
+pub fn application_descriptor<'a>(
+    activation: &'a mut Activation<'_, '_>,
+) -> &'a ApplicationDescriptor {
+    let context = &mut activation.context;
+    let url = context.swf.url();
+    context
+        .avm2
+        .application_descriptor_or_insert_with(|| ApplicationDescriptor::for_movie(url))
+}
+
+/// Implements `NativeApplication.getApplicationDescriptor`
+pub fn get_application_descriptor<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let gc_context = activation.context.gc_context;
+    let descriptor = application_descriptor(activation);
+    Ok(AvmString::new_utf8(gc_context, descriptor.xml()).into())
+}
+
+/// Implements `NativeApplication.getApplicationId`
+pub fn get_application_id<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let gc_context = activation.context.gc_context;
+    let descriptor = application_descriptor(activation);
+    Ok(AvmString::new_utf8(gc_context, descriptor.id()).into())
+}
@@ -355,6 +355,89 @@ pub fn copy_pixels_to_byte_array<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `BitmapData.encode`.
+pub fn encode<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(bitmap_data) = this.as_bitmap_data() {
+        bitmap_data.check_valid(activation)?;
+        let rectangle = args.get_object(activation, 0, "rect")?;
+        let compressor = args.get_object(activation, 1, "compressor")?;
+        let (x, y, width, height) = get_rectangle_x_y_width_height(activation, rectangle)?;
+
+        let classes = activation.avm2().classes();
+        let (png_class, jpeg_class, jpegxr_class) = (
+            classes.pngencoderoptions.inner_class_definition(),
+            classes.jpegencoderoptions.inner_class_definition(),
+            classes.jpegxrencoderoptions.inner_class_definition(),
+        );
+        let encoding = if compressor.is_of_type(png_class, &mut activation.context) {
+            let fast_compression = compressor
+                .get_public_property("fastCompression", activation)?
+                .coerce_to_boolean();
+            operations::ImageEncoding::Png { fast_compression }
+        } else if compressor.is_of_type(jpeg_class, &mut activation.context) {
+            let quality = compressor
+                .get_public_property("quality", activation)?
+                .coerce_to_u32(activation)?;
+            operations::ImageEncoding::Jpeg {
+                quality: quality.min(100) as u8,
+            }
+        } else if compressor.is_of_type(jpegxr_class, &mut activation.context) {
+            // TODO: The `jpegxr` library can only decode, so this needs an encoder.
+            avm2_stub_method!(
+                activation,
+                "flash.display.BitmapData",
+                "encode",
+                "with JPEGXREncoderOptions"
+            );
+            return Ok(Value::Null);
+        } else {
+            return Err(Error::AvmError(argument_error(
+                activation,
+                "Error #2004: One of the parameters is invalid.",
+                2004,
+            )?));
+        };
+
+        // An empty rect (after clamping to the bitmap) is the usual way to get here.
+        let data = match operations::encode(
+            bitmap_data,
+            activation.context.renderer,
+            x,
+            y,
+            width,
+            height,
+            encoding,
+        ) {
+            Ok(data) => data,
+            Err(e) => {
+                tracing::warn!("BitmapData.encode: failed to encode {encoding:?}: {e}");
+                return Err(Error::AvmError(argument_error(
+                    activation,
+                    "Error #2004: One of the parameters is invalid.",
+                    2004,
+                )?));
+            }
+        };
+
+        let bytearray = match args.try_get_object(activation, 2) {
+            Some(bytearray) => bytearray,
+            None => ByteArrayObject::from_storage(activation, ByteArrayStorage::new())?,
+        };
+        if let Some(mut storage) = bytearray.as_bytearray_mut(activation.context.gc_context) {
+            storage
+                .write_bytes(&data)
+                .map_err(|e| e.to_avm(activation))?;
+        }
+        return Ok(bytearray.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
 pub fn get_vector<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
@@ -11,7 +11,7 @@ use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
 use crate::avm2::ClassObject;
 use crate::avm2::Multiname;
-use crate::avm2::{Error, Object};
+use crate::avm2::{Domain, Error, Object};
 use crate::avm2_stub_method;
 use crate::backend::navigator::{NavigationMethod, Request};
 use crate::display_object::LoaderDisplay;
@@ -63,6 +63,23 @@ pub fn load<'gc>(
     let url_request = args.get_object(activation, 0, "request")?;
     let context = args.try_get_object(activation, 1);
 
+    let request = request_from_url_request(activation, url_request)?;
+    let default_domain = activation
+        .caller_domain()
+        .expect("Missing caller domain in Loader.load");
+    load_request(activation, this, request, context, default_domain)?;
+
+    Ok(Value::Undefined)
+}
+
+/// Start loading the movie or image of a request into a `Loader`.
+pub fn load_request<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    request: Request,
+    context: Option<Object<'gc>>,
+    default_domain: Domain<'gc>,
+) -> Result<(), Error<'gc>> {
     // This is a dummy MovieClip, which will get overwritten in `Loader`
     let content = MovieClip::new(
         Arc::new(SwfMovie::empty(activation.context.swf.version())),
@@ -93,8 +110,6 @@ pub fn load<'gc>(
             activation.context.gc_context,
         );
 
-    let request = request_from_url_request(activation, url_request)?;
-
     let url = request.url().to_string();
     let future = activation.context.load_manager.load_movie_into_clip(
         activation.context.player.clone(),
@@ -104,14 +119,12 @@ pub fn load<'gc>(
         MovieLoaderVMData::Avm2 {
             loader_info,
             context,
-            default_domain: activation
-                .caller_domain()
-                .expect("Missing caller domain in Loader.load"),
+            default_domain,
         },
     );
     activation.context.navigator.spawn_future(future);
 
-    Ok(Value::Undefined)
+    Ok(())
 }
 
 pub fn request_from_url_request<'gc>(
@@ -0,0 +1,23 @@
+package flash.events {
+    [API("661")] // AIR 1.0
+    public class FileListEvent extends Event {
+        public static const DIRECTORY_LISTING:String = "directoryListing";
+        public static const SELECT_MULTIPLE:String = "selectMultiple";
+
+        // An array of `File` objects.
+        public var files:Array;
+
+        public function FileListEvent(type:String, bubbles:Boolean = false, cancelable:Boolean = false, files:Array = null) {
+            super(type, bubbles, cancelable);
+            this.files = files;
+        }
+
+        override public function clone():Event {
+            return new FileListEvent(this.type, this.bubbles, this.cancelable, this.files);
+        }
+
+        override public function toString():String {
+            return this.formatToString("FileListEvent", "type", "bubbles", "cancelable", "eventPhase");
+        }
+    }
+}
@@ -0,0 +1,21 @@
+package flash.events {
+    public class IMEEvent extends TextEvent {
+        // Defines the value of the type property of an imeComposition event object.
+        public static const IME_COMPOSITION:String = "imeComposition";
+
+        // Defines the value of the type property of an imeStartComposition event object.
+        public static const IME_START_COMPOSITION:String = "imeStartComposition";
+
+        public function IMEEvent(type:String, bubbles:Boolean = false, cancelable:Boolean = false, text:String = "") {
+            super(type, bubbles, cancelable, text);
+        }
+
+        override public function clone():Event {
+            return new IMEEvent(this.type, this.bubbles, this.cancelable, this.text);
+        }
+
+        override public function toString():String {
+            return this.formatToString("IMEEvent", "type", "bubbles", "cancelable", "eventPhase", "text");
+        }
+    }
+}
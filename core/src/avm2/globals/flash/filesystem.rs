@@ -0,0 +1,49 @@
+//! `flash.filesystem` namespace
+
+use crate::avm2::error::io_error;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, Error, Value};
+use crate::backend::filesystem::{normalize_path, FilesystemError};
+
+pub mod file;
+pub mod file_stream;
+
+/// Reads a path argument, resolving it to a path inside of the sandbox.
+fn get_path<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    args: &[Value<'gc>],
+    index: usize,
+) -> Result<String, Error<'gc>> {
+    let path = args.get_string(activation, index)?;
+    match normalize_path(&path.to_utf8_lossy()) {
+        Some(path) => Ok(path),
+        None => Err(Error::AvmError(io_error(
+            activation,
+            "Error #3000: Illegal path name.",
+            3000,
+        )?)),
+    }
+}
+
+/// Converts a filesystem error to the `IOError` that AIR throws for it.
+fn filesystem_error<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    error: FilesystemError,
+) -> Error<'gc> {
+    let (message, code) = match error {
+        FilesystemError::NotFound => ("Error #3003: File or directory does not exist.", 3003),
+        FilesystemError::AlreadyExists => ("Error #3002: File or directory exists.", 3002),
+        FilesystemError::NotADirectory => ("Error #3007: Not a directory.", 3007),
+        FilesystemError::IsADirectory => ("Error #3006: Not a file.", 3006),
+        FilesystemError::DirectoryNotEmpty => ("Error #3010: Directory is not empty.", 3010),
+        FilesystemError::InvalidPath => ("Error #3000: Illegal path name.", 3000),
+        FilesystemError::Io(error) => {
+            tracing::warn!("Filesystem error: {error}");
+            ("Error #3001: File or directory access denied.", 3001)
+        }
+    };
+    match io_error(activation, message, code) {
+        Ok(error) => Error::AvmError(error),
+        Err(error) => error,
+    }
+}
@@ -0,0 +1,428 @@
+package flash.filesystem {
+    import flash.errors.IOError;
+    import flash.events.Event;
+    import flash.events.FileListEvent;
+    import flash.events.IOErrorEvent;
+    import flash.events.ProgressEvent;
+    import flash.net.FileReference;
+    import flash.utils.ByteArray;
+    import flash.utils.setTimeout;
+    import __ruffle__.stub_method;
+
+    // Paths are virtual paths of the player's sandboxed filesystem, see `backend/filesystem.rs`.
+    [API("661")] // AIR 1.0
+    public class File extends FileReference {
+        private static const APPLICATION_PATH:String = "/app";
+        private static const APPLICATION_STORAGE_PATH:String = "/app-storage";
+        private static const CACHE_PATH:String = "/cache";
+        private static const DESKTOP_PATH:String = "/desktop";
+        private static const DOCUMENTS_PATH:String = "/documents";
+        private static const USER_PATH:String = "/user";
+
+        private static var _tempCounter:uint = 0;
+
+        // The normalized path of this file, or null if it hasn't been set.
+        internal var _path:String;
+
+        private var _data:ByteArray;
+
+        public var downloaded:Boolean = false;
+
+        [API("687")] // AIR 3.6
+        public var preventBackup:Boolean = false;
+
+        public function File(path:String = null) {
+            if (path != null) {
+                this._path = pathFromString(path);
+            }
+        }
+
+        internal static native function normalizePath(path:String):String;
+        internal static native function getInfo(path:String):Object;
+        internal static native function readDirectory(path:String):Array;
+        internal static native function createDirectoryAt(path:String):void;
+        internal static native function deleteFileAt(path:String):void;
+        internal static native function deleteDirectoryAt(path:String, recursive:Boolean):void;
+        internal static native function moveAt(from:String, to:String):void;
+
+        // Accepts both native paths and `app:`, `app-storage:` and `file:` URLs.
+        private static function pathFromString(path:String):String {
+            if (path.indexOf("app:") == 0) {
+                return normalizePath(APPLICATION_PATH + "/" + decodeURI(path.substr(4)));
+            }
+            if (path.indexOf("app-storage:") == 0) {
+                return normalizePath(APPLICATION_STORAGE_PATH + "/" + decodeURI(path.substr(12)));
+            }
+            if (path.indexOf("file:") == 0) {
+                return normalizePath(decodeURI(path.substr(5)));
+            }
+            return normalizePath(path);
+        }
+
+        private static function directory(path:String):File {
+            var file:File = new File(path);
+            file.createDirectory();
+            return file;
+        }
+
+        public static function get applicationDirectory():File {
+            return new File(APPLICATION_PATH);
+        }
+
+        public static function get applicationStorageDirectory():File {
+            return directory(APPLICATION_STORAGE_PATH);
+        }
+
+        [API("687")] // AIR 3.6
+        public static function get cacheDirectory():File {
+            return directory(CACHE_PATH);
+        }
+
+        public static function get desktopDirectory():File {
+            return directory(DESKTOP_PATH);
+        }
+
+        public static function get documentsDirectory():File {
+            return directory(DOCUMENTS_PATH);
+        }
+
+        public static function get userDirectory():File {
+            return directory(USER_PATH);
+        }
+
+        public static function get lineEnding():String {
+            return "\n";
+        }
+
+        public static function get separator():String {
+            return "/";
+        }
+
+        public static function get systemCharset():String {
+            return "utf-8";
+        }
+
+        public static function get permissionStatus():String {
+            return "granted";
+        }
+
+        public static function getRootDirectories():Array {
+            return [new File("/")];
+        }
+
+        public static function createTempDirectory():File {
+            var file:File = new File(CACHE_PATH + "/tmp" + (_tempCounter++));
+            file.createDirectory();
+            return file;
+        }
+
+        public static function createTempFile():File {
+            var file:File = new File(CACHE_PATH + "/tmp" + (_tempCounter++) + ".tmp");
+            FileStream.writeFile(file._path, new ByteArray());
+            return file;
+        }
+
+        private function checkPath():void {
+            if (this._path == null) {
+                throw new ArgumentError("Error #2037: Functions called in incorrect sequence, or earlier call was unsuccessful.", 2037);
+            }
+        }
+
+        private function existingInfo():Object {
+            checkPath();
+            var info:Object = getInfo(this._path);
+            if (info == null) {
+                throw new IOError("Error #3003: File or directory does not exist.", 3003);
+            }
+            return info;
+        }
+
+        public function get nativePath():String {
+            return this._path;
+        }
+
+        public function set nativePath(value:String):void {
+            this._path = normalizePath(value);
+        }
+
+        public function get url():String {
+            if (this._path == null) {
+                return null;
+            }
+            if (this._path == APPLICATION_PATH || this._path.indexOf(APPLICATION_PATH + "/") == 0) {
+                return "app:/" + encodeURI(this._path.substr(APPLICATION_PATH.length + 1));
+            }
+            if (this._path == APPLICATION_STORAGE_PATH || this._path.indexOf(APPLICATION_STORAGE_PATH + "/") == 0) {
+                return "app-storage:/" + encodeURI(this._path.substr(APPLICATION_STORAGE_PATH.length + 1));
+            }
+            return "file://" + encodeURI(this._path);
+        }
+
+        public function set url(value:String):void {
+            this._path = pathFromString(value);
+        }
+
+        public function get exists():Boolean {
+            checkPath();
+            return getInfo(this._path) != null;
+        }
+
+        public function get isDirectory():Boolean {
+            checkPath();
+            var info:Object = getInfo(this._path);
+            return info != null && info.isDirectory;
+        }
+
+        public function get isHidden():Boolean {
+            return this.name.charAt(0) == ".";
+        }
+
+        public function get isPackage():Boolean {
+            return false;
+        }
+
+        public function get isSymbolicLink():Boolean {
+            return false;
+        }
+
+        public function get parent():File {
+            checkPath();
+            if (this._path == "/") {
+                return null;
+            }
+            return new File(this._path.substr(0, this._path.lastIndexOf("/")) || "/");
+        }
+
+        public function get spaceAvailable():Number {
+            // The sandbox doesn't have a meaningful size limit.
+            return 1024 * 1024 * 1024;
+        }
+
+        override public function get name():String {
+            checkPath();
+            return this._path.substr(this._path.lastIndexOf("/") + 1);
+        }
+
+        override public function get type():String {
+            var name:String = this.name;
+            var index:int = name.lastIndexOf(".");
+            return index > 0 ? name.substr(index) : null;
+        }
+
+        override public function get size():Number {
+            return existingInfo().size;
+        }
+
+        override public function get creationDate():Date {
+            return existingInfo().creationDate;
+        }
+
+        override public function get modificationDate():Date {
+            return existingInfo().modificationDate;
+        }
+
+        override public function get data():ByteArray {
+            return this._data;
+        }
+
+        override public function load():void {
+            checkPath();
+            this._data = FileStream.readFile(this._path);
+            var size:Number = this._data.length;
+            this.dispatchEvent(new Event(Event.OPEN));
+            this.dispatchEvent(new ProgressEvent(ProgressEvent.PROGRESS, false, false, size, size));
+            this.dispatchEvent(new Event(Event.COMPLETE));
+        }
+
+        override public function browse(typeFilter:Array = null):Boolean {
+            stub_method("flash.filesystem.File", "browse");
+            return false;
+        }
+
+        public function browseForDirectory(title:String):void {
+            stub_method("flash.filesystem.File", "browseForDirectory");
+        }
+
+        public function browseForOpen(title:String, typeFilter:Array = null):void {
+            stub_method("flash.filesystem.File", "browseForOpen");
+        }
+
+        public function browseForOpenMultiple(title:String, typeFilter:Array = null):void {
+            stub_method("flash.filesystem.File", "browseForOpenMultiple");
+        }
+
+        public function browseForSave(title:String):void {
+            stub_method("flash.filesystem.File", "browseForSave");
+        }
+
+        public function openWithDefaultApplication():void {
+            stub_method("flash.filesystem.File", "openWithDefaultApplication");
+        }
+
+        public function canonicalize():void {
+            // Paths are always kept in their canonical form.
+        }
+
+        public function clone():File {
+            var file:File = new File();
+            file._path = this._path;
+            return file;
+        }
+
+        public function resolvePath(path:String):File {
+            checkPath();
+            if (path.charAt(0) == "/" || path.indexOf(":") != -1) {
+                return new File(path);
+            }
+            return new File(this._path + "/" + path);
+        }
+
+        public function getRelativePath(ref:FileReference, useDotDot:Boolean = false):String {
+            checkPath();
+            var other:File = ref as File;
+            if (other == null || other._path == null) {
+                return null;
+            }
+            var from:Array = this._path == "/" ? [] : this._path.substr(1).split("/");
+            var to:Array = other._path == "/" ? [] : other._path.substr(1).split("/");
+            var common:int = 0;
+            while (common < from.length && common < to.length && from[common] == to[common]) {
+                common++;
+            }
+            if (common < from.length && !useDotDot) {
+                return null;
+            }
+            var parts:Array = [];
+            for (var i:int = common; i < from.length; i++) {
+                parts.push("..");
+            }
+            return parts.concat(to.slice(common)).join("/");
+        }
+
+        public function createDirectory():void {
+            checkPath();
+            createDirectoryAt(this._path);
+        }
+
+        public function deleteFile():void {
+            checkPath();
+            deleteFileAt(this._path);
+        }
+
+        public function deleteDirectory(deleteDirectoryContents:Boolean = false):void {
+            checkPath();
+            deleteDirectoryAt(this._path, deleteDirectoryContents);
+        }
+
+        public function moveToTrash():void {
+            if (this.isDirectory) {
+                deleteDirectory(true);
+            } else {
+                deleteFile();
+            }
+        }
+
+        public function getDirectoryListing():Array {
+            checkPath();
+            var listing:Array = [];
+            for each (var name:String in readDirectory(this._path)) {
+                listing.push(resolvePath(name));
+            }
+            return listing;
+        }
+
+        public function copyTo(newLocation:FileReference, overwrite:Boolean = false):void {
+            var destination:File = prepareDestination(newLocation, overwrite);
+            copy(this._path, destination._path);
+        }
+
+        public function moveTo(newLocation:FileReference, overwrite:Boolean = false):void {
+            var destination:File = prepareDestination(newLocation, overwrite);
+            moveAt(this._path, destination._path);
+        }
+
+        private function prepareDestination(newLocation:FileReference, overwrite:Boolean):File {
+            existingInfo();
+            var destination:File = newLocation as File;
+            if (destination == null || destination._path == null) {
+                throw new ArgumentError("Error #2004: One of the parameters is invalid.", 2004);
+            }
+            if (destination.exists) {
+                if (!overwrite) {
+                    throw new IOError("Error #3002: File or directory exists.", 3002);
+                }
+                destination.moveToTrash();
+            }
+            return destination;
+        }
+
+        private static function copy(from:String, to:String):void {
+            if (getInfo(from).isDirectory) {
+                createDirectoryAt(to);
+                for each (var name:String in readDirectory(from)) {
+                    copy(from + "/" + name, to + "/" + name);
+                }
+            } else {
+                FileStream.writeFile(to, FileStream.readFile(from));
+            }
+        }
+
+        // The asynchronous versions run the synchronous operation on the next frame,
+        // and report its result through events.
+        private function runAsync(operation:Function, complete:Function):void {
+            var self:File = this;
+            setTimeout(function():void {
+                try {
+                    operation();
+                } catch (e:Error) {
+                    self.dispatchEvent(new IOErrorEvent(IOErrorEvent.IO_ERROR, false, false, e.message, e.errorID));
+                    return;
+                }
+                complete();
+            }, 0);
+        }
+
+        private function runAsyncWithEvent(operation:Function):void {
+            var self:File = this;
+            runAsync(operation, function():void {
+                self.dispatchEvent(new Event(Event.COMPLETE));
+            });
+        }
+
+        public function copyToAsync(newLocation:FileReference, overwrite:Boolean = false):void {
+            runAsyncWithEvent(function():void {
+                copyTo(newLocation, overwrite);
+            });
+        }
+
+        public function moveToAsync(newLocation:FileReference, overwrite:Boolean = false):void {
+            runAsyncWithEvent(function():void {
+                moveTo(newLocation, overwrite);
+            });
+        }
+
+        public function deleteFileAsync():void {
+            runAsyncWithEvent(deleteFile);
+        }
+
+        public function deleteDirectoryAsync(deleteDirectoryContents:Boolean = false):void {
+            runAsyncWithEvent(function():void {
+                deleteDirectory(deleteDirectoryContents);
+            });
+        }
+
+        public function moveToTrashAsync():void {
+            runAsyncWithEvent(moveToTrash);
+        }
+
+        public function getDirectoryListingAsync():void {
+            var self:File = this;
+            var listing:Array;
+            runAsync(function():void {
+                listing = getDirectoryListing();
+            }, function():void {
+                self.dispatchEvent(new FileListEvent(FileListEvent.DIRECTORY_LISTING, false, false, listing));
+            });
+        }
+    }
+}
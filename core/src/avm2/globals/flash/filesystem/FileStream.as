@@ -0,0 +1,316 @@
+package flash.filesystem {
+    import flash.errors.IOError;
+    import flash.events.Event;
+    import flash.events.EventDispatcher;
+    import flash.events.IOErrorEvent;
+    import flash.events.ProgressEvent;
+    import flash.utils.ByteArray;
+    import flash.utils.IDataInput;
+    import flash.utils.IDataOutput;
+    import flash.utils.setTimeout;
+
+    // The whole file is read into a buffer when the stream is opened,
+    // and written back when the stream is closed.
+    [API("661")] // AIR 1.0
+    public class FileStream extends EventDispatcher implements IDataInput, IDataOutput {
+        private var _buffer:ByteArray = new ByteArray();
+
+        // The path of the open file, or null if the stream is closed.
+        private var _path:String;
+
+        private var _mode:String;
+        private var _async:Boolean = false;
+        private var _dirty:Boolean = false;
+
+        public var readAhead:Number = Infinity;
+
+        public function FileStream() {
+        }
+
+        internal static native function readFile(path:String):ByteArray;
+        internal static native function writeFile(path:String, data:ByteArray):void;
+
+        public function open(file:File, fileMode:String):void {
+            openFile(file, fileMode);
+            this._async = false;
+        }
+
+        public function openAsync(file:File, fileMode:String):void {
+            var self:FileStream = this;
+            try {
+                openFile(file, fileMode);
+            } catch (e:IOError) {
+                setTimeout(function():void {
+                    self.dispatchEvent(new IOErrorEvent(IOErrorEvent.IO_ERROR, false, false, e.message, e.errorID));
+                }, 0);
+                return;
+            }
+            this._async = true;
+
+            if (fileMode == FileMode.READ || fileMode == FileMode.UPDATE) {
+                var path:String = this._path;
+                setTimeout(function():void {
+                    if (self._path != path) {
+                        // The stream was closed or reopened in the meantime.
+                        return;
+                    }
+                    var length:Number = self._buffer.length;
+                    self.dispatchEvent(new ProgressEvent(ProgressEvent.PROGRESS, false, false, length, length));
+                    self.dispatchEvent(new Event(Event.COMPLETE));
+                }, 0);
+            }
+        }
+
+        private function openFile(file:File, fileMode:String):void {
+            if (file == null) {
+                throw new TypeError("Error #2007: Parameter file must be non-null.", 2007);
+            }
+            if (fileMode != FileMode.READ && fileMode != FileMode.WRITE &&
+                    fileMode != FileMode.APPEND && fileMode != FileMode.UPDATE) {
+                throw new ArgumentError("Error #2008: Parameter fileMode must be one of the accepted values.", 2008);
+            }
+            if (this._path != null) {
+                close();
+            }
+
+            var path:String = file.nativePath;
+            if (path == null) {
+                throw new IOError("Error #3003: File or directory does not exist.", 3003);
+            }
+            var info:Object = File.getInfo(path);
+            if (info != null && info.isDirectory) {
+                throw new IOError("Error #3006: Not a file.", 3006);
+            }
+
+            var buffer:ByteArray;
+            if (fileMode == FileMode.READ) {
+                if (info == null) {
+                    throw new IOError("Error #3003: File or directory does not exist.", 3003);
+                }
+                buffer = readFile(path);
+            } else if (fileMode == FileMode.WRITE || info == null) {
+                buffer = new ByteArray();
+                writeFile(path, buffer);
+            } else {
+                buffer = readFile(path);
+            }
+
+            buffer.endian = this._buffer.endian;
+            buffer.objectEncoding = this._buffer.objectEncoding;
+            buffer.position = fileMode == FileMode.APPEND ? buffer.length : 0;
+
+            this._buffer = buffer;
+            this._path = path;
+            this._mode = fileMode;
+            this._dirty = false;
+        }
+
+        public function close():void {
+            if (this._path == null) {
+                return;
+            }
+            if (this._dirty) {
+                writeFile(this._path, this._buffer);
+            }
+            this._path = null;
+            this._dirty = false;
+            this._buffer = new ByteArray();
+
+            if (this._async) {
+                var self:FileStream = this;
+                setTimeout(function():void {
+                    self.dispatchEvent(new Event(Event.CLOSE));
+                }, 0);
+            }
+        }
+
+        public function truncate():void {
+            checkWritable();
+            this._buffer.length = this._buffer.position;
+            this._dirty = true;
+        }
+
+        private function checkReadable():void {
+            if (this._path == null) {
+                throw new IOError("Error #2029: This FileStream object does not have a stream opened.", 2029);
+            }
+            if (this._mode != FileMode.READ && this._mode != FileMode.UPDATE) {
+                throw new IOError("Error #3013: File or directory is in use.", 3013);
+            }
+        }
+
+        private function checkWritable():void {
+            if (this._path == null) {
+                throw new IOError("Error #2029: This FileStream object does not have a stream opened.", 2029);
+            }
+            if (this._mode == FileMode.READ) {
+                throw new IOError("Error #3013: File or directory is in use.", 3013);
+            }
+            if (this._mode == FileMode.APPEND) {
+                this._buffer.position = this._buffer.length;
+            }
+            this._dirty = true;
+        }
+
+        public function get position():Number {
+            return this._buffer.position;
+        }
+
+        public function set position(value:Number):void {
+            if (this._mode == FileMode.APPEND) {
+                return;
+            }
+            this._buffer.position = value;
+        }
+
+        public function get bytesAvailable():uint {
+            return this._path == null ? 0 : this._buffer.bytesAvailable;
+        }
+
+        public function get endian():String {
+            return this._buffer.endian;
+        }
+
+        public function set endian(type:String):void {
+            this._buffer.endian = type;
+        }
+
+        public function get objectEncoding():uint {
+            return this._buffer.objectEncoding;
+        }
+
+        public function set objectEncoding(version:uint):void {
+            this._buffer.objectEncoding = version;
+        }
+
+        public function readBoolean():Boolean {
+            checkReadable();
+            return this._buffer.readBoolean();
+        }
+
+        public function readByte():int {
+            checkReadable();
+            return this._buffer.readByte();
+        }
+
+        public function readBytes(bytes:ByteArray, offset:uint = 0, length:uint = 0):void {
+            checkReadable();
+            this._buffer.readBytes(bytes, offset, length);
+        }
+
+        public function readDouble():Number {
+            checkReadable();
+            return this._buffer.readDouble();
+        }
+
+        public function readFloat():Number {
+            checkReadable();
+            return this._buffer.readFloat();
+        }
+
+        public function readInt():int {
+            checkReadable();
+            return this._buffer.readInt();
+        }
+
+        public function readMultiByte(length:uint, charSet:String):String {
+            checkReadable();
+            return this._buffer.readMultiByte(length, charSet);
+        }
+
+        public function readObject():* {
+            checkReadable();
+            return this._buffer.readObject();
+        }
+
+        public function readShort():int {
+            checkReadable();
+            return this._buffer.readShort();
+        }
+
+        public function readUnsignedByte():uint {
+            checkReadable();
+            return this._buffer.readUnsignedByte();
+        }
+
+        public function readUnsignedInt():uint {
+            checkReadable();
+            return this._buffer.readUnsignedInt();
+        }
+
+        public function readUnsignedShort():uint {
+            checkReadable();
+            return this._buffer.readUnsignedShort();
+        }
+
+        public function readUTF():String {
+            checkReadable();
+            return this._buffer.readUTF();
+        }
+
+        public function readUTFBytes(length:uint):String {
+            checkReadable();
+            return this._buffer.readUTFBytes(length);
+        }
+
+        public function writeBoolean(value:Boolean):void {
+            checkWritable();
+            this._buffer.writeBoolean(value);
+        }
+
+        public function writeByte(value:int):void {
+            checkWritable();
+            this._buffer.writeByte(value);
+        }
+
+        public function writeBytes(bytes:ByteArray, offset:uint = 0, length:uint = 0):void {
+            checkWritable();
+            this._buffer.writeBytes(bytes, offset, length);
+        }
+
+        public function writeDouble(value:Number):void {
+            checkWritable();
+            this._buffer.writeDouble(value);
+        }
+
+        public function writeFloat(value:Number):void {
+            checkWritable();
+            this._buffer.writeFloat(value);
+        }
+
+        public function writeInt(value:int):void {
+            checkWritable();
+            this._buffer.writeInt(value);
+        }
+
+        public function writeMultiByte(value:String, charSet:String):void {
+            checkWritable();
+            this._buffer.writeMultiByte(value, charSet);
+        }
+
+        public function writeObject(object:*):void {
+            checkWritable();
+            this._buffer.writeObject(object);
+        }
+
+        public function writeShort(value:int):void {
+            checkWritable();
+            this._buffer.writeShort(value);
+        }
+
+        public function writeUnsignedInt(value:uint):void {
+            checkWritable();
+            this._buffer.writeUnsignedInt(value);
+        }
+
+        public function writeUTF(value:String):void {
+            checkWritable();
+            this._buffer.writeUTF(value);
+        }
+
+        public function writeUTFBytes(value:String):void {
+            checkWritable();
+            this._buffer.writeUTFBytes(value);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+package flash.filesystem {
+    [API("668")] // AIR 2.0
+    public class StorageVolume {
+        private var _rootDirectory:File;
+        private var _name:String;
+        private var _isWritable:Boolean;
+        private var _isRemovable:Boolean;
+        private var _fileSystemType:String;
+        private var _drive:String;
+
+        public function StorageVolume(rootDirPath:File, name:String, writable:Boolean, removable:Boolean, fileSysType:String, drive:String) {
+            this._rootDirectory = rootDirPath;
+            this._name = name;
+            this._isWritable = writable;
+            this._isRemovable = removable;
+            this._fileSystemType = fileSysType;
+            this._drive = drive;
+        }
+
+        public function get drive():String {
+            return this._drive;
+        }
+
+        public function get fileSystemType():String {
+            return this._fileSystemType;
+        }
+
+        public function get isRemovable():Boolean {
+            return this._isRemovable;
+        }
+
+        public function get isWritable():Boolean {
+            return this._isWritable;
+        }
+
+        public function get name():String {
+            return this._name;
+        }
+
+        public function get rootDirectory():File {
+            return this._rootDirectory;
+        }
+    }
+}
@@ -0,0 +1,31 @@
+package flash.filesystem {
+    import flash.events.EventDispatcher;
+
+    [API("668")] // AIR 2.0
+    public final class StorageVolumeInfo extends EventDispatcher {
+        private static var _storageVolumeInfo:StorageVolumeInfo;
+
+        public static function get isSupported():Boolean {
+            // Volumes are never mounted or unmounted in the sandbox.
+            return false;
+        }
+
+        public static function get storageVolumeInfo():StorageVolumeInfo {
+            if (_storageVolumeInfo == null) {
+                _storageVolumeInfo = new StorageVolumeInfo();
+            }
+            return _storageVolumeInfo;
+        }
+
+        public function StorageVolumeInfo() {
+            if (_storageVolumeInfo != null) {
+                throw new Error("Error #2012: StorageVolumeInfo class cannot be instantiated.", 2012);
+            }
+        }
+
+        public function getStorageVolumes():Vector.<StorageVolume> {
+            var root:File = new File("/");
+            return new <StorageVolume>[new StorageVolume(root, "Ruffle", true, false, "ruffle", null)];
+        }
+    }
+}
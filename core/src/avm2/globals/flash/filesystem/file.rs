@@ -0,0 +1,133 @@
+//! `flash.filesystem.File` native methods
+
+use crate::avm2::globals::flash::filesystem::{filesystem_error, get_path};
+use crate::avm2::object::DateObject;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, ArrayObject, ArrayStorage, Error, Object, TObject, Value};
+use crate::string::AvmString;
+
+/// Implements `File.normalizePath`
+pub fn normalize_path<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let path = get_path(activation, args, 0)?;
+    Ok(AvmString::new_utf8(activation.context.gc_context, path).into())
+}
+
+/// Implements `File.getInfo`
+///
+/// Returns an object describing the file or directory at the given path,
+/// or `null` if it doesn't exist.
+pub fn get_info<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let path = get_path(activation, args, 0)?;
+    let Some(metadata) = activation.context.filesystem.metadata(&path) else {
+        return Ok(Value::Null);
+    };
+
+    let info = activation
+        .avm2()
+        .classes()
+        .object
+        .construct(activation, &[])?;
+    info.set_public_property("isDirectory", metadata.is_directory.into(), activation)?;
+    info.set_public_property("size", (metadata.size as f64).into(), activation)?;
+    for (name, time) in [
+        ("creationDate", metadata.creation_time),
+        ("modificationDate", metadata.modification_time),
+    ] {
+        let date = match time {
+            Some(time) => DateObject::from_date_time(activation, time)?.into(),
+            None => Value::Null,
+        };
+        info.set_public_property(name, date, activation)?;
+    }
+    Ok(info.into())
+}
+
+/// Implements `File.readDirectory`
+pub fn read_directory<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let path = get_path(activation, args, 0)?;
+    let names = activation
+        .context
+        .filesystem
+        .read_directory(&path)
+        .map_err(|e| filesystem_error(activation, e))?;
+
+    let mut storage = ArrayStorage::new(0);
+    for name in names {
+        storage.push(AvmString::new_utf8(activation.context.gc_context, name).into());
+    }
+    Ok(ArrayObject::from_storage(activation, storage)?.into())
+}
+
+/// Implements `File.createDirectoryAt`
+pub fn create_directory_at<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let path = get_path(activation, args, 0)?;
+    activation
+        .context
+        .filesystem
+        .create_directory(&path)
+        .map_err(|e| filesystem_error(activation, e))?;
+    Ok(Value::Undefined)
+}
+
+/// Implements `File.deleteFileAt`
+pub fn delete_file_at<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let path = get_path(activation, args, 0)?;
+    activation
+        .context
+        .filesystem
+        .delete_file(&path)
+        .map_err(|e| filesystem_error(activation, e))?;
+    Ok(Value::Undefined)
+}
+
+/// Implements `File.deleteDirectoryAt`
+pub fn delete_directory_at<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let path = get_path(activation, args, 0)?;
+    let recursive = args.get_bool(1);
+    activation
+        .context
+        .filesystem
+        .delete_directory(&path, recursive)
+        .map_err(|e| filesystem_error(activation, e))?;
+    Ok(Value::Undefined)
+}
+
+/// Implements `File.moveAt`
+pub fn move_at<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let from = get_path(activation, args, 0)?;
+    let to = get_path(activation, args, 1)?;
+    activation
+        .context
+        .filesystem
+        .rename(&from, &to)
+        .map_err(|e| filesystem_error(activation, e))?;
+    Ok(Value::Undefined)
+}
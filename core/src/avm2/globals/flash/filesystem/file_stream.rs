@@ -0,0 +1,45 @@
+//! `flash.filesystem.FileStream` native methods
+
+use crate::avm2::bytearray::ByteArrayStorage;
+use crate::avm2::globals::flash::filesystem::{filesystem_error, get_path};
+use crate::avm2::object::{ByteArrayObject, TObject};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, Error, Object, Value};
+
+/// Implements `FileStream.readFile`
+pub fn read_file<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let path = get_path(activation, args, 0)?;
+    let data = activation
+        .context
+        .filesystem
+        .read(&path)
+        .map_err(|e| filesystem_error(activation, e))?;
+
+    let storage = ByteArrayStorage::from_vec(data);
+    Ok(ByteArrayObject::from_storage(activation, storage)?.into())
+}
+
+/// Implements `FileStream.writeFile`
+pub fn write_file<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let path = get_path(activation, args, 0)?;
+    let data = args.get_object(activation, 1, "data")?;
+    let data = match data.as_bytearray() {
+        Some(bytearray) => bytearray.bytes().to_vec(),
+        None => return Ok(Value::Undefined),
+    };
+
+    activation
+        .context
+        .filesystem
+        .write(&path, &data)
+        .map_err(|e| filesystem_error(activation, e))?;
+    Ok(Value::Undefined)
+}
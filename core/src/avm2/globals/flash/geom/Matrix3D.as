@@ -404,8 +404,60 @@ package flash.geom {
 			other.rawData = rawData;
 		}
 
+		// Rotates this matrix so that its local `at` direction points toward `pos`,
+		// with its local `up` direction as close as possible to `up`.
+		// The position and the scale are kept, and nothing changes if `pos` is the position
+		// or `at` is zero.
 		public function pointAt(pos:Vector3D, at:Vector3D = null, up:Vector3D = null):void {
-			stub_method("flash.geom.Matrix3D", "pointAt");
+			if (at == null) {
+				at = new Vector3D(0, 0, -1);
+			}
+			if (up == null) {
+				up = new Vector3D(0, -1, 0);
+			}
+
+			var local:Vector.<Vector3D> = orthonormalBasis(at, up);
+			var target:Vector.<Vector3D> = orthonormalBasis(pos.subtract(this.position), up);
+			if (local == null || target == null) {
+				return;
+			}
+
+			// The rotation maps each local axis to the target axis with the same index.
+			var scale:Vector3D = this.decompose()[2];
+			var scales:Array = [scale.x, scale.y, scale.z];
+			for (var column:int = 0; column < 3; column++) {
+				for (var row:int = 0; row < 3; row++) {
+					var value:Number = 0;
+					for (var i:int = 0; i < 3; i++) {
+						value += vectorComponent(target[i], row) * vectorComponent(local[i], column);
+					}
+					this._rawData[column * 4 + row] = value * scales[column];
+				}
+			}
+		}
+
+		// Returns the normalized direction, the normalized part of `up` that's orthogonal
+		// to it, and their cross product, or null if the direction is zero.
+		// When `up` is parallel to the direction, any direction orthogonal to it is used instead.
+		private static function orthonormalBasis(direction:Vector3D, up:Vector3D):Vector.<Vector3D> {
+			var forward:Vector3D = direction.clone();
+			if (forward.normalize() == 0) {
+				return null;
+			}
+			var projected:Vector3D = forward.clone();
+			projected.scaleBy(up.dotProduct(forward));
+			var upward:Vector3D = up.subtract(projected);
+			if (upward.normalize() == 0) {
+				upward = forward.x != 0 || forward.y != 0
+					? new Vector3D(-forward.y, forward.x, 0)
+					: new Vector3D(1, 0, 0);
+				upward.normalize();
+			}
+			return new <Vector3D>[forward, upward, forward.crossProduct(upward)];
+		}
+
+		private static function vectorComponent(v:Vector3D, index:int):Number {
+			return index == 0 ? v.x : (index == 1 ? v.y : v.z);
 		}
 
 		// Based on OpenFL: https://github.com/openfl/openfl/blob/971a4c9e43b5472fd84d73920a2b7c1b3d8d9257/src/openfl/geom/Matrix3D.hx#L1437
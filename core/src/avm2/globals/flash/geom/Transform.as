@@ -2,9 +2,7 @@ package flash.geom {
 	import flash.display.DisplayObject;
 	import flash.geom.Matrix3D;
 	import flash.geom.PerspectiveProjection;
-	import __ruffle__.stub_getter;
-	import __ruffle__.stub_method;
-	import __ruffle__.stub_setter;
+	import flash.geom.Point;
 
 	public class Transform {
 		internal var _displayObject:DisplayObject;
@@ -24,26 +22,48 @@ package flash.geom {
 		public native function get pixelBounds():Rectangle;
 
 		public function get matrix3D():Matrix3D {
-			stub_getter("flash.geom.Transform", "matrix3D");
-			return new Matrix3D();
+			var rawData:Vector.<Number> = this.getMatrix3D();
+			return rawData ? new Matrix3D(rawData) : null;
 		}
 
 		public function set matrix3D(m:Matrix3D):void {
-			stub_setter("flash.geom.Transform", "matrix3D");
+			this.setMatrix3D(m ? m.rawData : null);
 		}
 
+		private native function getMatrix3D():Vector.<Number>;
+		private native function setMatrix3D(rawData:Vector.<Number>):void;
+
 		public function get perspectiveProjection():PerspectiveProjection {
-			stub_getter("flash.geom.Transform", "perspectiveProjection");
-			return new PerspectiveProjection();
+			// The width of the stage, the field of view and the projection center
+			var values:Array = this.getPerspectiveProjection();
+			if (!values) {
+				return null;
+			}
+			var projection:PerspectiveProjection = new PerspectiveProjection();
+			projection._stageWidth = values[0];
+			projection.fieldOfView = values[1];
+			projection.projectionCenter = new Point(values[2], values[3]);
+			return projection;
 		}
 
 		public function set perspectiveProjection(val: PerspectiveProjection):void {
-			stub_setter("flash.geom.Transform", "perspectiveProjection");
+			if (val) {
+				var center:Point = val.projectionCenter;
+				this.setPerspectiveProjection(val.fieldOfView, center.x, center.y);
+			} else {
+				this.clearPerspectiveProjection();
+			}
 		}
 
+		private native function getPerspectiveProjection():Array;
+		private native function setPerspectiveProjection(fieldOfView:Number, centerX:Number, centerY:Number):void;
+		private native function clearPerspectiveProjection():void;
+
 		public function getRelativeMatrix3D(relativeTo:DisplayObject):Matrix3D {
-			stub_method("flash.geom.Transform", "getRelativeMatrix3D");
-			return new Matrix3D();
+			var rawData:Vector.<Number> = this.relativeMatrix3D(relativeTo);
+			return rawData ? new Matrix3D(rawData) : null;
 		}
+
+		private native function relativeMatrix3D(relativeTo:DisplayObject):Vector.<Number>;
 	}
 }
@@ -1,9 +1,12 @@
+use crate::avm2::object::VectorObject;
 use crate::avm2::parameters::ParametersExt;
+use crate::avm2::vector::VectorStorage;
 use crate::avm2::Multiname;
-use crate::avm2::{Activation, Error, Object, TObject, Value};
+use crate::avm2::{Activation, ArrayObject, ArrayStorage, Error, Object, TObject, Value};
 use crate::avm2_stub_getter;
 use crate::display_object::TDisplayObject;
 use crate::prelude::{DisplayObject, Matrix, Twips};
+use ruffle_render::matrix3d::{Matrix3D, PerspectiveProjection};
 use ruffle_render::quality::StageQuality;
 use swf::{ColorTransform, Fixed8, Rectangle};
 
@@ -64,7 +67,12 @@ pub fn get_matrix<'gc>(
     this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    let matrix = *get_display_object(this, activation)?.base().matrix();
+    let dobj = get_display_object(this, activation)?;
+    // Objects with a 3D transform only have a `matrix3D`.
+    if dobj.matrix3d().is_some() {
+        return Ok(Value::Null);
+    }
+    let matrix = *dobj.base().matrix();
     matrix_to_object(matrix, activation)
 }
 
@@ -84,6 +92,162 @@ pub fn set_matrix<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements the `matrix3D` getter, which returns the raw data of the
+/// 3D transform, or `null` if the object doesn't have one.
+pub fn get_matrix_3d<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    match get_display_object(this, activation)?.matrix3d() {
+        Some(matrix3d) => matrix3d_to_raw_data(&matrix3d, activation),
+        None => Ok(Value::Null),
+    }
+}
+
+/// Implements the `matrix3D` setter, which takes the raw data of the
+/// 3D transform, or `null` to remove it.
+pub fn set_matrix_3d<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let matrix3d = match args.try_get_object(activation, 0) {
+        Some(raw_data) => Some(raw_data_to_matrix3d(raw_data, activation)?),
+        None => None,
+    };
+    let dobj = get_display_object(this, activation)?;
+    dobj.set_transformed_by_script(activation.context.gc_context, true);
+    dobj.set_matrix3d(activation.context.gc_context, matrix3d);
+    Ok(Value::Undefined)
+}
+
+/// Implements `getRelativeMatrix3D`, which returns the raw data of the transform
+/// from the coordinates of this object to those of another one.
+pub fn relative_matrix_3d<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let dobj = get_display_object(this, activation)?;
+    let relative_to = args
+        .try_get_object(activation, 0)
+        .and_then(|object| object.as_display_object());
+    let relative_to_matrix = relative_to.map_or(Matrix3D::IDENTITY, concatenated_matrix3d);
+    match relative_to_matrix.inverse() {
+        Some(inverse) => matrix3d_to_raw_data(&(inverse * concatenated_matrix3d(dobj)), activation),
+        None => Ok(Value::Null),
+    }
+}
+
+/// The 3D transform from the coordinates of an object to those of the stage,
+/// ignoring perspective projections.
+fn concatenated_matrix3d(dobj: DisplayObject<'_>) -> Matrix3D {
+    let mut matrix = Matrix3D::IDENTITY;
+    let mut node = Some(dobj);
+    while let Some(obj) = node {
+        if obj.as_stage().is_some() {
+            break;
+        }
+        let own_matrix = obj
+            .matrix3d()
+            .unwrap_or_else(|| Matrix3D::from_2d(obj.base().matrix()));
+        matrix = own_matrix * matrix;
+        node = obj.parent();
+    }
+    matrix
+}
+
+fn matrix3d_to_raw_data<'gc>(
+    matrix3d: &Matrix3D,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let storage = VectorStorage::from_values(
+        matrix3d.raw_data.iter().map(|v| (*v).into()).collect(),
+        false,
+        Some(activation.avm2().classes().number),
+    );
+    Ok(VectorObject::from_vector(storage, activation)?.into())
+}
+
+fn raw_data_to_matrix3d<'gc>(
+    raw_data: Object<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<Matrix3D, Error<'gc>> {
+    let values: Vec<Value<'gc>> = raw_data
+        .as_vector_storage()
+        .map(|storage| storage.iter().collect())
+        .unwrap_or_default();
+    let mut matrix3d = Matrix3D::IDENTITY;
+    for (raw, value) in matrix3d.raw_data.iter_mut().zip(values) {
+        *raw = value.coerce_to_number(activation)?;
+    }
+    Ok(matrix3d)
+}
+
+/// Implements the `perspectiveProjection` getter, which returns the width of the stage,
+/// the field of view and the projection center of the projection, or `null`.
+///
+/// Only the root has a projection by default, which is the one of the stage.
+pub fn get_perspective_projection<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let dobj = get_display_object(this, activation)?;
+    let (stage_width, stage_height) = activation.context.stage.stage_size();
+    let (stage_width, stage_height) = (f64::from(stage_width), f64::from(stage_height));
+    let is_root = dobj
+        .parent()
+        .is_none_or(|parent| parent.as_stage().is_some());
+
+    let projection = match dobj.perspective_projection() {
+        Some(projection) => projection,
+        None if is_root => PerspectiveProjection::for_stage(stage_width, stage_height),
+        None => return Ok(Value::Null),
+    };
+    let (center_x, center_y) = projection.projection_center;
+    let values: [Value<'gc>; 4] = [
+        stage_width.into(),
+        projection.field_of_view.into(),
+        center_x.into(),
+        center_y.into(),
+    ];
+    let storage = ArrayStorage::from_args(&values);
+    Ok(ArrayObject::from_storage(activation, storage)?.into())
+}
+
+/// Implements the `perspectiveProjection` setter.
+pub fn set_perspective_projection<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let field_of_view = args.get_f64(activation, 0)?;
+    let center_x = args.get_f64(activation, 1)?;
+    let center_y = args.get_f64(activation, 2)?;
+    let dobj = get_display_object(this, activation)?;
+    dobj.set_perspective_projection(
+        activation.context.gc_context,
+        Some(PerspectiveProjection {
+            field_of_view,
+            projection_center: (center_x, center_y),
+        }),
+    );
+    Ok(Value::Undefined)
+}
+
+/// Implements setting `perspectiveProjection` to `null`.
+pub fn clear_perspective_projection<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let dobj = get_display_object(this, activation)?;
+    dobj.set_perspective_projection(activation.context.gc_context, None);
+    Ok(Value::Undefined)
+}
+
 pub fn get_concatenated_matrix<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
@@ -1,6 +1,7 @@
 //! `flash.media.Sound` builtin/prototype
 
 use crate::avm2::activation::Activation;
+use crate::avm2::error::make_error_2008;
 use crate::avm2::object::{Object, QueuedPlay, SoundChannelObject, TObject};
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
@@ -11,7 +12,7 @@ use crate::character::Character;
 use crate::display_object::SoundTransform;
 use crate::string::AvmString;
 use crate::{avm2_stub_getter, avm2_stub_method};
-use swf::{SoundEvent, SoundInfo};
+use swf::{AudioCompression, SoundEvent, SoundFormat, SoundInfo};
 
 pub use crate::avm2::object::sound_allocator;
 
@@ -196,27 +197,57 @@ pub fn play<'gc>(
 /// `Sound.extract`
 pub fn extract<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    this: Object<'gc>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.media.Sound", "extract");
+    let bytearray = args.get_object(activation, 0, "target")?;
+    let length = args.get_f64(activation, 1)?;
+    let start_position = args.get_f64(activation, 2)?;
 
-    let bytearray = args
-        .get(0)
-        .unwrap_or(&Value::Undefined)
-        .coerce_to_object(activation)?;
-    let length = args
-        .get(1)
-        .unwrap_or(&Value::Number(0.0))
-        .coerce_to_number(activation)?;
+    let Some(sound_object) = this.as_sound_object() else {
+        return Ok(0.into());
+    };
+    let Some(sound_handle) = sound_object.sound_handle() else {
+        return Ok(0.into());
+    };
+
+    // A negative start position continues from where the last call left off.
+    let start_position = if start_position < 0.0 {
+        sound_object.extract_position()
+    } else {
+        start_position as u32
+    };
+    let length = if length > 0.0 { length as u32 } else { 0 };
+
+    let samples = match activation
+        .context
+        .audio
+        .extract_sound(sound_handle, start_position, length)
+    {
+        Ok(samples) => samples,
+        Err(e) => {
+            tracing::error!("Sound.extract: Failed to decode sound: {e}");
+            vec![]
+        }
+    };
 
     if let Some(mut bytearray) = bytearray.as_bytearray_mut(activation.context.gc_context) {
-        bytearray
-            .write_bytes(vec![0u8; length.ceil() as usize].as_slice())
-            .map_err(|e| e.to_avm(activation))?;
+        for [left, right] in &samples {
+            bytearray
+                .write_float(f32::from(*left) / 32768.0)
+                .map_err(|e| e.to_avm(activation))?;
+            bytearray
+                .write_float(f32::from(*right) / 32768.0)
+                .map_err(|e| e.to_avm(activation))?;
+        }
     }
 
-    Ok(Value::Undefined)
+    sound_object.set_extract_position(
+        activation.context.gc_context,
+        start_position.saturating_add(samples.len() as u32),
+    );
+
+    Ok(samples.len().into())
 }
 
 /// `Sound.close`
@@ -314,10 +345,69 @@ pub fn load_compressed_data_from_byte_array<'gc>(
 /// `Sound.loadPCMFromByteArray`
 pub fn load_pcm_from_byte_array<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.media.Sound", "loadPCMFromByteArray");
+    let bytearray = args.get_object(activation, 0, "bytes")?;
+    let num_samples = args.get_u32(activation, 1)?;
+    let format = args.get_string(activation, 2)?;
+    let is_stereo = args.get_bool(3);
+    let sample_rate = args.get_f64(activation, 4)?;
+
+    let is_float = if &format == b"float" {
+        true
+    } else if &format == b"short" {
+        false
+    } else {
+        return Err(make_error_2008(activation, "format"));
+    };
+    if !(1.0..=f64::from(u16::MAX)).contains(&sample_rate) {
+        return Err(make_error_2008(activation, "sampleRate"));
+    }
+
+    // Convert the samples to 16-bit little endian PCM, which the audio
+    // backend can play like an embedded uncompressed sound.
+    let bytearray = bytearray.as_bytearray().unwrap();
+    let num_channels = if is_stereo { 2 } else { 1 };
+    let sample_size = if is_float { 4 } else { 2 };
+    let num_samples =
+        num_samples.min((bytearray.bytes_available() / (num_channels * sample_size)) as u32);
+
+    let mut data = Vec::with_capacity(num_samples as usize * num_channels * 2);
+    for _ in 0..num_samples as usize * num_channels {
+        let sample = if is_float {
+            let sample = bytearray.read_float().map_err(|e| e.to_avm(activation))?;
+            (sample.clamp(-1.0, 1.0) * 32767.0) as i16
+        } else {
+            bytearray.read_short().map_err(|e| e.to_avm(activation))?
+        };
+        data.extend_from_slice(&sample.to_le_bytes());
+    }
+    drop(bytearray);
+
+    let sound = swf::Sound {
+        id: 0,
+        format: SoundFormat {
+            compression: AudioCompression::Uncompressed,
+            sample_rate: sample_rate as u16,
+            is_stereo,
+            is_16_bit: true,
+        },
+        num_samples,
+        data: &data,
+    };
+    let handle = activation
+        .context
+        .audio
+        .register_sound(&sound)
+        .map_err(|e| {
+            Error::RustError(format!("Failed to register sound from bytearray: {e:?}").into())
+        })?;
+
+    this.as_sound_object()
+        .unwrap()
+        .set_sound(&mut activation.context, handle)?;
+
     Ok(Value::Undefined)
 }
 
@@ -1,6 +1,79 @@
 package flash.printing {
+    import flash.display.Sprite;
     import flash.events.EventDispatcher;
+    import flash.geom.Rectangle;
 
     public class PrintJob extends EventDispatcher {
+        // The ID of the job in the player, or 0 if it isn't started or was already sent.
+        private var _id: int = 0;
+
+        private var _paperWidth: int = 0;
+        private var _paperHeight: int = 0;
+        private var _pageWidth: int = 0;
+        private var _pageHeight: int = 0;
+        private var _orientation: String = null;
+
+        [API("667")]
+        public static function get isSupported(): Boolean {
+            return isPrintingSupported();
+        }
+
+        private static native function isPrintingSupported(): Boolean;
+        private static native function startJob(): Array;
+        private static native function addPageToJob(id: int, sprite: Sprite, printArea: Rectangle, frameNum: int): Boolean;
+        private static native function sendJob(id: int): void;
+
+        public function get paperWidth(): int {
+            return _paperWidth;
+        }
+
+        public function get paperHeight(): int {
+            return _paperHeight;
+        }
+
+        public function get pageWidth(): int {
+            return _pageWidth;
+        }
+
+        public function get pageHeight(): int {
+            return _pageHeight;
+        }
+
+        public function get orientation(): String {
+            return _orientation;
+        }
+
+        public function start(): Boolean {
+            var job: Array = startJob();
+            if (job == null) {
+                return false;
+            }
+
+            _id = job[0];
+            _paperWidth = job[1];
+            _paperHeight = job[2];
+            _pageWidth = job[3];
+            _pageHeight = job[4];
+            _orientation = job[5];
+            return true;
+        }
+
+        public function addPage(sprite: Sprite, printArea: Rectangle = null, options: PrintJobOptions = null, frameNum: int = 0): void {
+            if (sprite == null) {
+                throw new TypeError("Error #2007: Parameter sprite must be non-null.", 2007);
+            }
+
+            // Pages are always rasterised, so `options.printAsBitmap` makes no difference.
+            if (!addPageToJob(_id, sprite, printArea, frameNum)) {
+                throw new Error("Error #2057: The page could not be added to the print job.", 2057);
+            }
+        }
+
+        public function send(): void {
+            if (_id != 0) {
+                sendJob(_id);
+                _id = 0;
+            }
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,93 @@
+//! `flash.printing.PrintJob` native methods
+
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, ArrayObject, ArrayStorage, Error, Object, TObject, Value};
+use crate::printing::rasterize_page;
+use swf::{Rectangle, Twips};
+
+/// Implements `PrintJob.isPrintingSupported`
+pub fn is_printing_supported<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(activation.context.print.is_supported().into())
+}
+
+/// Implements `PrintJob.startJob`
+///
+/// Returns the ID of the job followed by the size of its paper and page and
+/// its orientation, or `null` if the job was cancelled.
+pub fn start_job<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let context = &mut activation.context;
+    let Some((id, paper)) = context.print_jobs.start(context.print) else {
+        return Ok(Value::Null);
+    };
+
+    let storage = ArrayStorage::from_storage(vec![
+        Some(id.into()),
+        Some(paper.paper_width.into()),
+        Some(paper.paper_height.into()),
+        Some(paper.page_width.into()),
+        Some(paper.page_height.into()),
+        Some(paper.orientation.as_str().into()),
+    ]);
+    Ok(ArrayObject::from_storage(activation, storage)?.into())
+}
+
+/// Implements `PrintJob.addPageToJob`
+pub fn add_page_to_job<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    let sprite = args.get_object(activation, 1, "sprite")?;
+    let area = match args.try_get_object(activation, 2) {
+        Some(rectangle) => {
+            let mut get = |name: &'static str| -> Result<f64, Error<'gc>> {
+                rectangle
+                    .get_public_property(name, activation)?
+                    .coerce_to_number(activation)
+            };
+            let (x, y, width, height) = (get("x")?, get("y")?, get("width")?, get("height")?);
+            Some(Rectangle {
+                x_min: Twips::from_pixels(x),
+                y_min: Twips::from_pixels(y),
+                x_max: Twips::from_pixels(x + width),
+                y_max: Twips::from_pixels(y + height),
+            })
+        }
+        None => None,
+    };
+    let frame = args.get_i32(activation, 3)?;
+    let frame = (frame > 0).then(|| frame.min(u16::MAX.into()) as u16);
+
+    let Some(paper) = activation.context.print_jobs.paper(id) else {
+        return Ok(false.into());
+    };
+    let Some(object) = sprite.as_display_object() else {
+        return Ok(false.into());
+    };
+
+    let page = rasterize_page(&mut activation.context, object, area, frame, &paper);
+    Ok(activation.context.print_jobs.add_page(id, page).into())
+}
+
+/// Implements `PrintJob.sendJob`
+pub fn send_job<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    let context = &mut activation.context;
+    if let Err(e) = context.print_jobs.send(id, context.print) {
+        tracing::error!("Unable to print: {e}");
+    }
+    Ok(Value::Undefined)
+}
@@ -1,4 +1,48 @@
 package flash.system {
+    import flash.events.Event;
+
     public native function fscommand(command:String, args:String = ""):void;
-}
 
+    // The objects of the workers and message channels that this worker knows
+    // about, by their ID.
+    internal var _workers:Object = {};
+    internal var _channels:Object = {};
+
+    internal function _worker(id:uint):Worker {
+        var worker:Worker = _workers[id];
+        if (worker == null) {
+            Worker._constructing = true;
+            worker = new Worker();
+            Worker._constructing = false;
+            worker._id = id;
+            _workers[id] = worker;
+        }
+        return worker;
+    }
+
+    internal function _channel(id:uint):MessageChannel {
+        var channel:MessageChannel = _channels[id];
+        if (channel == null) {
+            MessageChannel._constructing = true;
+            channel = new MessageChannel();
+            MessageChannel._constructing = false;
+            channel._id = id;
+            _channels[id] = channel;
+        }
+        return channel;
+    }
+
+    internal function _dispatchWorkerEvent(id:uint, type:String):void {
+        var worker:Worker = _workers[id];
+        if (worker != null) {
+            worker.dispatchEvent(new Event(type));
+        }
+    }
+
+    internal function _dispatchChannelEvent(id:uint, type:String):void {
+        var channel:MessageChannel = _channels[id];
+        if (channel != null) {
+            channel.dispatchEvent(new Event(type));
+        }
+    }
+}
@@ -3,8 +3,12 @@
 
 pub mod application_domain;
 pub mod capabilities;
+pub mod ime;
+pub mod message_channel;
 pub mod security;
 pub mod system;
+pub mod worker;
+pub mod worker_domain;
 
 use crate::avm2::activation::Activation;
 use crate::avm2::object::Object;
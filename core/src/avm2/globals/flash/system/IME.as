@@ -7,17 +7,9 @@ package flash.system
 {
     import flash.events.EventDispatcher;
     import __ruffle__.stub_method;
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_setter;
 
     public final class IME extends EventDispatcher
     {
-        // The conversion mode of the current IME.
-        public static var _conversionMode: String = "ALPHANUMERIC_HALF";
-
-        // Indicates whether the system IME is enabled (true) or disabled (false).
-        public static var _enabled: Boolean;
-
         // The isSupported property is set to true if the IME class is available on the current platform, otherwise it is set to false.
         private static var _isSupported: Boolean;
 
@@ -50,28 +42,12 @@ package flash.system
             return _isSupported;
         }
 
-        public static function get enabled():Boolean
-        {
-            stub_getter("flash.system.IME", "enabled");
-            return _enabled;
-        }
-
-        public static function set enabled(value:Boolean):void
-        {
-            stub_setter("flash.system.IME", "enabled");
-            _enabled = value;
-        }
-
-        public static function get conversionMode():String
-        {
-            stub_getter("flash.system.IME", "conversionMode");
-            return _conversionMode;
-        }
+        // Indicates whether the system IME is enabled (true) or disabled (false).
+        public static native function get enabled():Boolean;
+        public static native function set enabled(value:Boolean):void;
 
-        public static function set conversionMode(value:String):void
-        {
-            stub_setter("flash.system.IME", "conversionMode");
-            _conversionMode = value;
-        }
+        // The conversion mode of the current IME.
+        public static native function get conversionMode():String;
+        public static native function set conversionMode(value:String):void;
     }
 }
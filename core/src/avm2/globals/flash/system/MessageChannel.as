@@ -2,8 +2,41 @@ package flash.system {
     import flash.events.EventDispatcher;
 
     public final class MessageChannel extends EventDispatcher {
+        internal static var _constructing:Boolean = false;
+
+        // The ID of this channel, which is the same in all workers.
+        internal var _id:uint;
+
         public function MessageChannel() {
-            super();
+            if (!_constructing) {
+                throw new ArgumentError("Error #2012: MessageChannel$ class cannot be instantiated.", 2012);
+            }
+        }
+
+        private static native function getState(id:uint):String;
+        private static native function isMessageAvailable(id:uint):Boolean;
+        private static native function sendMessage(id:uint, message:*):void;
+        private static native function receiveMessage(id:uint, blockUntilReceived:Boolean):*;
+        private static native function closeChannel(id:uint):void;
+
+        public function get messageAvailable():Boolean {
+            return isMessageAvailable(this._id);
+        }
+
+        public function get state():String {
+            return getState(this._id);
+        }
+
+        public function send(arg:*, queueLimit:int = -1):void {
+            sendMessage(this._id, arg);
+        }
+
+        public function receive(blockUntilReceived:Boolean = false):* {
+            return receiveMessage(this._id, blockUntilReceived);
+        }
+
+        public function close():void {
+            closeChannel(this._id);
         }
     }
 }
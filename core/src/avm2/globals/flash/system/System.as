@@ -2,6 +2,15 @@ package flash.system {
     public final class System {
         import __ruffle__.stub_method;
 
+        private static var _ime:IME;
+
+        public static function get ime():IME {
+            if (_ime == null) {
+                _ime = new IME();
+            }
+            return _ime;
+        }
+
         public static function gc(): void {
 
         }
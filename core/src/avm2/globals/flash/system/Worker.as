@@ -1,11 +1,63 @@
 package flash.system {
-    public final class Worker {
+    import flash.events.EventDispatcher;
+
+    public final class Worker extends EventDispatcher {
+        internal static var _constructing:Boolean = false;
+
+        // The ID of this worker, which is the same in all workers.
+        internal var _id:uint;
+
         public function Worker() {
-            throw new ArgumentError("Error #2012: Worker$ class cannot be instantiated.", 2012);
+            if (!_constructing) {
+                throw new ArgumentError("Error #2012: Worker$ class cannot be instantiated.", 2012);
+            }
         }
 
         public static function get isSupported():Boolean {
-            return false;
+            return true;
+        }
+
+        public static function get current():Worker {
+            return _worker(getCurrentId());
+        }
+
+        private static native function getCurrentId():uint;
+        private static native function getState(id:uint):String;
+        private static native function startWorker(id:uint):void;
+        private static native function terminateWorker(id:uint):Boolean;
+        private static native function getSharedPropertyOf(id:uint, key:String):*;
+        private static native function setSharedPropertyOf(id:uint, key:String, value:*):void;
+        private static native function createChannel(sender:uint, receiver:uint):uint;
+
+        public function get isPrimordial():Boolean {
+            return this._id == 0;
+        }
+
+        public function get state():String {
+            return getState(this._id);
+        }
+
+        public function start():void {
+            startWorker(this._id);
+        }
+
+        public function terminate():Boolean {
+            return terminateWorker(this._id);
+        }
+
+        public function getSharedProperty(key:String):* {
+            return getSharedPropertyOf(this._id, key);
+        }
+
+        public function setSharedProperty(key:String, value:*):void {
+            setSharedPropertyOf(this._id, key, value);
+        }
+
+        public function createMessageChannel(receiver:Worker):MessageChannel {
+            if (receiver == null) {
+                throw new TypeError("Error #2007: Parameter receiver must be non-null.", 2007);
+            }
+            return _channel(createChannel(this._id, receiver._id));
         }
     }
 }
@@ -1,9 +1,43 @@
 package flash.system {
+    import flash.utils.ByteArray;
+
     public final class WorkerDomain {
-        public static const isSupported: Boolean = false;
+        public static const isSupported: Boolean = true;
+
+        private static var _current:WorkerDomain;
+        private static var _constructing:Boolean = false;
 
         public function WorkerDomain() {
-            throw new ArgumentError("Error #2012: WorkerDomain$ class cannot be instantiated.", 2012)
+            if (!_constructing) {
+                throw new ArgumentError("Error #2012: WorkerDomain$ class cannot be instantiated.", 2012);
+            }
+        }
+
+        public static function get current():WorkerDomain {
+            if (_current == null) {
+                _constructing = true;
+                _current = new WorkerDomain();
+                _constructing = false;
+            }
+            return _current;
+        }
+
+        private static native function createWorkerFromBytes(swf:ByteArray):uint;
+        private static native function getWorkerIds():Array;
+
+        public function createWorker(swf:ByteArray, giveAppPrivileges:Boolean = false):Worker {
+            if (swf == null) {
+                throw new TypeError("Error #2007: Parameter swf must be non-null.", 2007);
+            }
+            return _worker(createWorkerFromBytes(swf));
+        }
+
+        public function listWorkers():Vector.<Worker> {
+            var workers:Vector.<Worker> = new Vector.<Worker>();
+            for each (var id:uint in getWorkerIds()) {
+                workers.push(_worker(id));
+            }
+            return workers;
         }
     }
-}
\ No newline at end of file
+}
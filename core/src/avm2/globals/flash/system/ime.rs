@@ -0,0 +1,53 @@
+//! `flash.system.IME` native methods
+
+use crate::avm2::activation::Activation;
+use crate::avm2::error::make_error_2008;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::avm2::{Error, Object};
+use crate::backend::ui::ImeConversionMode;
+use crate::string::{AvmString, FromWStr};
+
+/// Implements `IME.enabled`'s getter
+pub fn get_enabled<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(activation.context.ui.ime_enabled().into())
+}
+
+/// Implements `IME.enabled`'s setter
+pub fn set_enabled<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let enabled = args.get_bool(0);
+    activation.context.ui.set_ime_enabled(enabled);
+    Ok(Value::Undefined)
+}
+
+/// Implements `IME.conversionMode`'s getter
+pub fn get_conversion_mode<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let mode = activation.context.ui.ime_conversion_mode();
+    Ok(AvmString::new_utf8(activation.context.gc_context, mode.to_string()).into())
+}
+
+/// Implements `IME.conversionMode`'s setter
+pub fn set_conversion_mode<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let mode = args.get_string_non_null(activation, 0, "conversionMode")?;
+    let Ok(mode) = ImeConversionMode::from_wstr(&mode) else {
+        return Err(make_error_2008(activation, "conversionMode"));
+    };
+    activation.context.ui.set_ime_conversion_mode(mode);
+    Ok(Value::Undefined)
+}
@@ -0,0 +1,81 @@
+//! `flash.system.MessageChannel` native methods
+
+use crate::avm2::activation::Activation;
+use crate::avm2::globals::flash::system::worker::{from_shared_value, to_shared_value};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::avm2::{Error, Object};
+use crate::avm2_stub_method;
+
+/// Implements `MessageChannel.getState`
+pub fn get_state<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    match activation.context.workers.channel_state(id) {
+        Some(state) => Ok(state.as_str().into()),
+        None => Ok(Value::Null),
+    }
+}
+
+/// Implements `MessageChannel.isMessageAvailable`
+pub fn is_message_available<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    Ok(activation.context.workers.message_available(id).into())
+}
+
+/// Implements `MessageChannel.sendMessage`
+pub fn send_message<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    let message = to_shared_value(activation, args.get_value(1))?;
+    if !activation.context.workers.send_message(id, message) {
+        tracing::warn!("MessageChannel.send: Channel {id} is closed");
+    }
+    Ok(Value::Undefined)
+}
+
+/// Implements `MessageChannel.receiveMessage`
+///
+/// Workers run one after another, so a worker can't wait for a message.
+pub fn receive_message<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    match activation.context.workers.receive_message(id) {
+        Some(message) => from_shared_value(activation, &message),
+        None => {
+            if args.get_bool(1) {
+                avm2_stub_method!(
+                    activation,
+                    "flash.system.MessageChannel",
+                    "receive",
+                    "with blockUntilReceived"
+                );
+            }
+            Ok(Value::Null)
+        }
+    }
+}
+
+/// Implements `MessageChannel.closeChannel`
+pub fn close_channel<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    activation.context.workers.close_channel(id);
+    Ok(Value::Undefined)
+}
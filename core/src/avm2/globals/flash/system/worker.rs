@@ -0,0 +1,245 @@
+//! `flash.system.Worker` native methods
+//!
+//! Each worker has its own `Worker` and `MessageChannel` objects, which are
+//! kept by the `flash.system` package and identified by their ID in the
+//! `WorkerGroup`.
+
+use crate::avm2::activation::Activation;
+use crate::avm2::amf::{deserialize_value, serialize_value, ObjectTable};
+use crate::avm2::bytearray::ByteArrayStorage;
+use crate::avm2::object::{ByteArrayObject, TObject};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Error, Multiname, Namespace, Object, QName, Value};
+use crate::context::UpdateContext;
+use crate::worker::{SharedValue, WorkerEvent, Workers};
+use flash_lso::types::{AMFVersion, Value as AmfValue};
+
+/// Dispatches the events of the workers and message channels that changed
+/// since this worker last ran.
+pub fn dispatch_worker_events<'gc>(context: &mut UpdateContext<'_, 'gc>, events: &[WorkerEvent]) {
+    if events.is_empty() {
+        return;
+    }
+
+    let mut activation = Activation::from_nothing(context.reborrow());
+    let ns = activation.avm2().flash_system_internal;
+    for event in events {
+        let (function, id, event_type) = match *event {
+            WorkerEvent::WorkerState(id) => ("_dispatchWorkerEvent", id, "workerState"),
+            WorkerEvent::ChannelMessage(id) => ("_dispatchChannelEvent", id, "channelMessage"),
+            WorkerEvent::ChannelState(id) => ("_dispatchChannelEvent", id, "channelState"),
+        };
+        let args = [Value::from(id), event_type.into()];
+        if let Err(e) = call_internal_function(&mut activation, ns, function, &args) {
+            tracing::error!("Encountered AVM2 error when dispatching worker event: {e:?}");
+        }
+    }
+}
+
+/// Converts a value, so that it can be read by another worker.
+///
+/// Workers, message channels, mutexes, conditions and shareable `ByteArray`s
+/// are shared, while any other value is copied.
+pub fn to_shared_value<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    value: Value<'gc>,
+) -> Result<SharedValue, Error<'gc>> {
+    if let Some(object) = value.as_object() {
+        let classes = activation.avm2().classes();
+        let (worker, channel, mutex, condition) = (
+            classes.worker,
+            classes.messagechannel,
+            classes.mutex,
+            classes.condition,
+        );
+        let system = activation.avm2().flash_system_internal;
+        let concurrent = activation.avm2().flash_concurrent_internal;
+
+        let class = object.instance_of();
+        if class == Some(worker) {
+            return Ok(SharedValue::Worker(shared_id(activation, object, system)?));
+        } else if class == Some(channel) {
+            return Ok(SharedValue::Channel(shared_id(activation, object, system)?));
+        } else if class == Some(mutex) {
+            return Ok(SharedValue::Mutex(shared_id(
+                activation, object, concurrent,
+            )?));
+        } else if class == Some(condition) {
+            return Ok(SharedValue::Condition(shared_id(
+                activation, object, concurrent,
+            )?));
+        } else if object
+            .as_bytearray()
+            .is_some_and(|bytearray| bytearray.shareable())
+        {
+            let id = Workers::share_byte_array(&mut activation.context, object);
+            return Ok(SharedValue::ByteArray(id));
+        }
+    }
+
+    let mut object_table = ObjectTable::default();
+    let value = serialize_value(activation, value, AMFVersion::AMF3, &mut object_table)
+        .unwrap_or(AmfValue::Undefined);
+    Ok(SharedValue::Amf(value))
+}
+
+/// Converts a value that was shared by a worker to a value of this worker.
+pub fn from_shared_value<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    value: &SharedValue,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let system = activation.avm2().flash_system_internal;
+    let concurrent = activation.avm2().flash_concurrent_internal;
+    match *value {
+        SharedValue::Amf(ref value) => deserialize_value(activation, value),
+        SharedValue::Worker(id) => {
+            activation.context.workers.worker_state(id);
+            call_internal_function(activation, system, "_worker", &[id.into()])
+        }
+        SharedValue::Channel(id) => {
+            activation.context.workers.channel_state(id);
+            call_internal_function(activation, system, "_channel", &[id.into()])
+        }
+        SharedValue::Mutex(id) => {
+            call_internal_function(activation, concurrent, "_mutex", &[id.into()])
+        }
+        SharedValue::Condition(id) => {
+            call_internal_function(activation, concurrent, "_condition", &[id.into()])
+        }
+        SharedValue::ByteArray(id) => {
+            if let Some(object) = Workers::shared_byte_array(&mut activation.context, id) {
+                return Ok(object.into());
+            }
+
+            let bytes = activation.context.workers.shared_byte_array_bytes(id);
+            let mut storage = ByteArrayStorage::from_vec(bytes);
+            storage.set_shareable(true);
+            let object = ByteArrayObject::from_storage(activation, storage)?;
+            Workers::register_shared_byte_array(&mut activation.context, id, object);
+            Ok(object.into())
+        }
+    }
+}
+
+/// Reads the ID of a shared object, which is kept in its internal `_id` property.
+fn shared_id<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    object: Object<'gc>,
+    ns: Namespace<'gc>,
+) -> Result<u32, Error<'gc>> {
+    object
+        .get_property(&Multiname::new(ns, "_id"), activation)?
+        .coerce_to_u32(activation)
+}
+
+/// Calls one of the internal functions of the `flash.system` or `flash.concurrent` package.
+fn call_internal_function<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    ns: Namespace<'gc>,
+    name: &'static str,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let qname = QName::new(ns, name);
+    let function = activation
+        .avm2()
+        .playerglobals_domain
+        .get_defined_value(activation, qname)?;
+
+    function
+        .as_object()
+        .and_then(|function| function.as_function_object())
+        .expect("Worker internal function should exist")
+        .call(Value::Undefined, args, activation)
+}
+
+/// Implements `Worker.getCurrentId`
+pub fn get_current_id<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(activation.context.workers.current().into())
+}
+
+/// Implements `Worker.getState`
+pub fn get_state<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    match activation.context.workers.worker_state(id) {
+        Some(state) => Ok(state.as_str().into()),
+        None => Ok(Value::Null),
+    }
+}
+
+/// Implements `Worker.startWorker`
+pub fn start_worker<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    activation.context.workers.start_worker(id);
+    Ok(Value::Undefined)
+}
+
+/// Implements `Worker.terminateWorker`
+pub fn terminate_worker<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    Ok(activation.context.workers.terminate_worker(id).into())
+}
+
+/// Implements `Worker.getSharedPropertyOf`
+pub fn get_shared_property_of<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    let key = args.get_string(activation, 1)?;
+    let value = activation
+        .context
+        .workers
+        .shared_property(id, &key.to_utf8_lossy());
+    match value {
+        Some(value) => from_shared_value(activation, &value),
+        None => Ok(Value::Undefined),
+    }
+}
+
+/// Implements `Worker.setSharedPropertyOf`
+pub fn set_shared_property_of<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    let key = args.get_string(activation, 1)?.to_utf8_lossy().into_owned();
+    let value = to_shared_value(activation, args.get_value(2))?;
+    activation
+        .context
+        .workers
+        .set_shared_property(id, key, value);
+    Ok(Value::Undefined)
+}
+
+/// Implements `Worker.createChannel`
+pub fn create_channel<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let sender = args.get_u32(activation, 0)?;
+    let receiver = args.get_u32(activation, 1)?;
+    Ok(activation
+        .context
+        .workers
+        .create_channel(sender, receiver)
+        .into())
+}
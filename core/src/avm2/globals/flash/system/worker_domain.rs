@@ -0,0 +1,51 @@
+//! `flash.system.WorkerDomain` native methods
+
+use crate::avm2::activation::Activation;
+use crate::avm2::error::argument_error;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::avm2::{ArrayObject, ArrayStorage, Error, Object};
+use crate::tag_utils::SwfMovie;
+
+/// Implements `WorkerDomain.createWorkerFromBytes`
+///
+/// The worker runs in the same security context as the movie that created it.
+pub fn create_worker_from_bytes<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let swf = args.get_object(activation, 0, "swf")?;
+    let bytes = match swf.as_bytearray() {
+        Some(bytearray) => bytearray.bytes().to_vec(),
+        None => return Ok(Value::Undefined),
+    };
+
+    let url = activation.context.swf.url().to_string();
+    let movie = match SwfMovie::from_data(&bytes, url.clone(), Some(url)) {
+        Ok(movie) => movie,
+        Err(e) => {
+            tracing::warn!("WorkerDomain.createWorker: Invalid SWF: {e}");
+            return Err(Error::AvmError(argument_error(
+                activation,
+                "Error #2004: One of the parameters is invalid.",
+                2004,
+            )?));
+        }
+    };
+
+    Ok(activation.context.workers.create_worker(movie).into())
+}
+
+/// Implements `WorkerDomain.getWorkerIds`
+pub fn get_worker_ids<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let mut storage = ArrayStorage::new(0);
+    for id in activation.context.workers.list_workers() {
+        storage.push(id.into());
+    }
+    Ok(ArrayObject::from_storage(activation, storage)?.into())
+}
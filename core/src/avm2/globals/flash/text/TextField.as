@@ -139,52 +139,25 @@ package flash.text {
             stub_method("flash.text.TextField", "insertXMLText");
         }
 
-        public function getCharIndexAtPoint(x:Number, y:Number):int {
-            stub_method("flash.text.TextField", "getCharIndexAtPoint");
-            return 0;
-        }
+        public native function getCharIndexAtPoint(x:Number, y:Number):int;
 
-        public function getLineLength(lineIndex:int):int {
-            stub_method("flash.text.TextField", "getLineLength");
-            return 0;
-        }
+        public native function getLineLength(lineIndex:int):int;
 
         public native function getLineText(lineIndex:int):String;
 
-        public function getCharBoundaries(charIndex:int):Rectangle {
-            stub_method("flash.text.TextField", "getCharBoundaries");
-            return new Rectangle(0, 0, 1, 1);
-        }
+        public native function getCharBoundaries(charIndex:int):Rectangle;
 
-        public function getFirstCharInParagraph(charIndex:int):int {
-            stub_method("flash.text.TextField", "getFirstCharInParagraph");
-            return 0;
-        }
+        public native function getFirstCharInParagraph(charIndex:int):int;
 
-        public function getImageReference(id:String):DisplayObject {
-            stub_method("flash.text.TextField", "getImageReference");
-            return null;
-        }
+        public native function getImageReference(id:String):DisplayObject;
 
-        public function getLineIndexAtPoint(x:Number, y:Number):int {
-            stub_method("flash.text.TextField", "getLineIndexAtPoint");
-            return 0;
-        }
+        public native function getLineIndexAtPoint(x:Number, y:Number):int;
 
-        public function getLineIndexOfChar(charIndex:int):int {
-            stub_method("flash.text.TextField", "getLineIndexOfChar");
-            return 0;
-        }
+        public native function getLineIndexOfChar(charIndex:int):int;
 
-        public function getLineOffset(lineIndex:int):int {
-            stub_method("flash.text.TextField", "getLineOffset");
-            return 0;
-        }
+        public native function getLineOffset(lineIndex:int):int;
 
-        public function getParagraphLength(charIndex:int):int {
-            stub_method("flash.text.TextField", "getParagraphLength");
-            return 0;
-        }
+        public native function getParagraphLength(charIndex:int):int;
 
         public static function isFontCompatible(fontName:String, fontStyle:String):Boolean {
             stub_method("flash.text.TextField", "isFontCompatible");
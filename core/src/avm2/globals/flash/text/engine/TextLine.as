@@ -1,7 +1,6 @@
 package flash.text.engine {
     import __ruffle__.stub_getter;
     import __ruffle__.stub_setter;
-    import __ruffle__.stub_method;
 
     import flash.display.DisplayObjectContainer;
     import flash.errors.IllegalOperationError;
@@ -16,7 +15,10 @@ package flash.text.engine {
     public final class TextLine extends DisplayObjectContainer {
         internal var _specifiedWidth:Number = 0.0;
         internal var _textBlock:TextBlock = null;
+        internal var _textBlockBeginIndex:int = 0;
         internal var _rawTextLength:int = 0;
+        internal var _nextLine:TextLine = null;
+        internal var _previousLine:TextLine = null;
         internal var _validity:String = "valid";
 
         public static const MAX_LINE_WIDTH:int = 1000000;
@@ -32,8 +34,7 @@ package flash.text.engine {
         }
 
         public function get textBlockBeginIndex():int {
-            stub_getter("flash.text.engine.TextLine", "textBlockBeginIndex");
-            return 0;
+            return this._textBlockBeginIndex;
         }
 
         public function get specifiedWidth():Number {
@@ -44,19 +45,12 @@ package flash.text.engine {
             return this._textBlock;
         }
 
-        public function get ascent():Number {
-            stub_getter("flash.text.engine.TextLine", "ascent");
-            return 12.0;
-        }
-
-        public function get descent():Number {
-            stub_getter("flash.text.engine.TextLine", "descent");
-            return 3.0;
-        }
+        public native function get ascent():Number;
+        public native function get descent():Number;
 
         public function get unjustifiedTextWidth():Number {
-            stub_getter("flash.text.engine.TextLine", "unjustifiedTextWidth");
-            return this._specifiedWidth;
+            // FIXME: Update this when justification is supported.
+            return this.textWidth;
         }
 
         public native function get textWidth():Number;
@@ -78,21 +72,32 @@ package flash.text.engine {
         }
 
         public function get atomCount():int {
-            stub_getter("flash.text.engine.TextLine", "atomCount");
             return this._rawTextLength;
         }
 
         public function get nextLine():TextLine {
-            return null;
+            return this._nextLine;
         }
 
         public function get previousLine():TextLine {
-            return null;
+            return this._previousLine;
         }
 
         public function getBaselinePosition(baseline:String):Number {
-            stub_method("flash.text.engine.TextLine", "getBaselinePosition");
-            return 0.0;
+            switch (baseline) {
+                case TextBaseline.ROMAN:
+                    return 0.0;
+                case TextBaseline.ASCENT:
+                case TextBaseline.IDEOGRAPHIC_TOP:
+                    return -this.ascent;
+                case TextBaseline.DESCENT:
+                case TextBaseline.IDEOGRAPHIC_BOTTOM:
+                    return this.descent;
+                case TextBaseline.IDEOGRAPHIC_CENTER:
+                    return (this.descent - this.ascent) / 2;
+                default:
+                    throw new ArgumentError("Error #2004: One of the parameters is invalid.", 2004);
+            }
         }
 
         public function hasTabs():Boolean {
@@ -100,19 +105,38 @@ package flash.text.engine {
             return false;
         }
 
-        public function getAtomIndexAtPoint(stageX:Number, stageY:Number):int {
-            stub_method("flash.text.engine.TextLine", "getAtomIndexAtPoint");
-            return -1;
-        }
+        public native function getAtomIndexAtPoint(stageX:Number, stageY:Number):int;
 
+        // FIXME: Atoms are currently always single UTF-16 code units.
         public function getAtomIndexAtCharIndex(charIndex:int):int {
-            stub_method("flash.text.engine.TextLine", "getAtomIndexAtCharIndex");
-            return -1;
+            var atomIndex:int = charIndex - this._textBlockBeginIndex;
+            if (atomIndex < 0 || atomIndex >= this._rawTextLength) {
+                return -1;
+            }
+            return atomIndex;
+        }
+
+        public function getAtomTextBlockBeginIndex(atomIndex:int):int {
+            this.checkAtomIndex(atomIndex);
+            return this._textBlockBeginIndex + atomIndex;
+        }
+
+        public function getAtomTextBlockEndIndex(atomIndex:int):int {
+            this.checkAtomIndex(atomIndex);
+            return this._textBlockBeginIndex + atomIndex + 1;
         }
 
         public function getAtomBounds(index:int):Rectangle {
-            stub_method("flash.text.engine.TextLine", "getAtomBounds");
-            return new Rectangle(0, 0, 0, 0);
+            this.checkAtomIndex(index);
+            return this.getAtomBoundsInternal(index);
+        }
+
+        private native function getAtomBoundsInternal(index:int):Rectangle;
+
+        private function checkAtomIndex(index:int):void {
+            if (index < 0 || index >= this._rawTextLength) {
+                throw new RangeError("Error #2006: The supplied index is out of bounds.", 2006);
+            }
         }
 
         // This function does nothing in Flash Player 32
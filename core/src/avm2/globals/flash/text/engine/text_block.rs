@@ -1,197 +1,371 @@
 use crate::avm2::activation::Activation;
-use crate::avm2::error::Error;
+use crate::avm2::error::{argument_error, Error};
 use crate::avm2::globals::flash::display::display_object::initialize_for_allocator;
 use crate::avm2::object::{Object, TObject};
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
 use crate::avm2::Multiname;
-use crate::avm2_stub_method;
 use crate::display_object::{EditText, TDisplayObject};
 use crate::html::TextFormat;
-use crate::string::WStr;
+use crate::string::{utils as string_utils, WStr, WString};
 
+/// The maximum width of a line, as in `TextLine.MAX_LINE_WIDTH`.
+const MAX_LINE_WIDTH: f64 = 1000000.0;
+
+/// How much of the remaining text is laid out at first to find where a line
+/// breaks. It's doubled for as long as all of it fits on one line.
+const LINE_PREFIX_LENGTH: usize = 256;
+
+/// The character that a `GraphicElement` occupies in the raw text of a `TextBlock`.
+const GRAPHIC_ELEMENT_CHAR: u16 = 0xFDEF;
+
+/// A run of text in a `TextBlock` that belongs to a single `ContentElement`.
+struct TextRun<'gc> {
+    start: usize,
+    end: usize,
+    element_format: Option<Object<'gc>>,
+}
+
+pub(super) fn get_internal_property<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    object: Object<'gc>,
+    name: &'static str,
+) -> Result<Value<'gc>, Error<'gc>> {
+    object.get_property(
+        &Multiname::new(activation.avm2().flash_text_engine_internal, name),
+        activation,
+    )
+}
+
+pub(super) fn set_internal_property<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    object: Object<'gc>,
+    name: &'static str,
+    value: Value<'gc>,
+) -> Result<(), Error<'gc>> {
+    object.set_property(
+        &Multiname::new(activation.avm2().flash_text_engine_internal, name),
+        value,
+        activation,
+    )
+}
+
+/// Implements `TextBlock.createTextLine`.
+///
+/// Each call lays out the text following `previousLine` (or the start of the
+/// block) and returns the first line of it that fits in `width`, or `null` once
+/// all of the text has been used. Only about as much text as the line needs is
+/// laid out, rather than the rest of the block.
+///
+/// Known limitation: atoms are single UTF-16 code units, not surrogate pairs or graphic elements.
 pub fn create_text_line<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.text.TextBlock", "createTextLine");
-
-    let previous_text_line = args.try_get_object(activation, 0);
+    let previous_line = args.try_get_object(activation, 0);
     let width = args.get_f64(activation, 1)?;
+    let fit_something = args.get_bool(3);
 
-    let content = this.get_public_property("content", activation)?;
+    if !(0.0..=MAX_LINE_WIDTH).contains(&width) {
+        return Err(Error::AvmError(argument_error(
+            activation,
+            "Error #2004: One of the parameters is invalid.",
+            2004,
+        )?));
+    }
 
-    let content = if matches!(content, Value::Null) {
-        return Ok(Value::Null);
+    let begin_index = if let Some(previous_line) = previous_line {
+        let text_block = get_internal_property(activation, previous_line, "_textBlock")?;
+        if !matches!(text_block, Value::Object(text_block) if Object::ptr_eq(text_block, this)) {
+            return Err(Error::AvmError(argument_error(
+                activation,
+                "Error #2004: One of the parameters is invalid.",
+                2004,
+            )?));
+        }
+
+        let begin = get_internal_property(activation, previous_line, "_textBlockBeginIndex")?
+            .coerce_to_u32(activation)?;
+        let length = get_internal_property(activation, previous_line, "_rawTextLength")?
+            .coerce_to_u32(activation)?;
+        (begin + length) as usize
     } else {
-        content.as_object().unwrap()
+        0
     };
 
-    let text = match previous_text_line {
-        Some(_) => {
-            // Some SWFs rely on eventually getting `null` from createLineText.
-            // TODO: Support multiple lines
-            this.set_property(
-                &Multiname::new(
-                    activation.avm2().flash_text_engine_internal,
-                    "_textLineCreationResult",
-                ),
-                "complete".into(),
-                activation,
-            )?;
-            return Ok(Value::Null);
-        }
-        // Get the content element's text property.
-        // TODO: GraphicElement?
-        None => {
-            let txt = content
-                .get_public_property("text", activation)
-                .unwrap_or("".into());
-
-            if matches!(txt, Value::Null) {
-                // FP returns a null TextLine when `o` is null- note that
-                // `o` is already coerced to a String because of the AS bindings.
-                return Ok(Value::Null);
-            } else {
-                txt.coerce_to_string(activation)
-                    .expect("Guaranteed by AS bindings")
-            }
-        }
+    let content = this.get_public_property("content", activation)?;
+    let Some(content) = content.as_object() else {
+        return Ok(Value::Null);
     };
 
+    if matches!(
+        content.get_public_property("text", activation)?,
+        Value::Null
+    ) {
+        // FP returns a null TextLine when the content has no text.
+        return Ok(Value::Null);
+    }
+
+    let mut text = WString::new();
+    let mut runs = Vec::new();
+    collect_runs(activation, content, None, &mut text, &mut runs)?;
+
+    if previous_line.is_some() && begin_index >= text.len() {
+        // Some SWFs rely on eventually getting `null` from createTextLine.
+        set_internal_property(
+            activation,
+            this,
+            "_textLineCreationResult",
+            "complete".into(),
+        )?;
+        return Ok(Value::Null);
+    }
+
+    let remaining_text = &text[begin_index..];
     let class = activation.avm2().classes().textline;
     let movie = activation.caller_movie_or_root();
 
     // FIXME: TextLine should be its own DisplayObject
-    let display_object: EditText =
-        EditText::new_tlf(&mut activation.context, movie, 0.0, 0.0, width, 15.0);
+    // The text field reserves some padding on either side of the text,
+    // which shouldn't count against the width of the line.
+    let display_object: EditText = EditText::new_tlf(
+        &mut activation.context,
+        movie,
+        0.0,
+        0.0,
+        width + EditText::INTERNAL_PADDING * 2.0,
+        15.0,
+    );
 
-    display_object.set_text(text.as_wstr(), &mut activation.context);
+    // Lay out a growing prefix of the remaining text until it wraps, rather
+    // than all of it for every line. A break in a prefix is also where the
+    // whole text breaks, as whatever didn't fit can only get longer.
+    display_object.set_word_wrap(true, &mut activation.context);
+    let mut prefix_length = LINE_PREFIX_LENGTH.min(remaining_text.len());
+    let line_length = loop {
+        let prefix = &remaining_text[..prefix_length];
+        set_text(activation, display_object, prefix, begin_index, &runs)?;
+        if let Some(offset) = display_object.line_offset(1).filter(|&offset| offset > 0) {
+            break offset;
+        }
+        if prefix_length == remaining_text.len() {
+            break prefix_length;
+        }
+        prefix_length = (prefix_length * 2).min(remaining_text.len());
+    };
 
-    // FIXME: This needs to use `intrinsic_bounds` to measure the width
-    // of the provided text, and set the width of the EditText to that.
-    // Some games depend on this (e.g. Realm Grinder).
+    let line_text = &remaining_text[..line_length];
+    display_object.set_word_wrap(false, &mut activation.context);
+    set_text(activation, display_object, line_text, begin_index, &runs)?;
 
-    let element_format = content
-        .get_public_property("elementFormat", activation)?
-        .as_object();
+    let (text_width, text_height) = display_object.measure_text(&mut activation.context);
+    if !fit_something && text_width.to_pixels() > width {
+        // Not even a single atom fits in the given width.
+        set_internal_property(
+            activation,
+            this,
+            "_textLineCreationResult",
+            "insufficientWidth".into(),
+        )?;
+        return Ok(Value::Null);
+    }
+
+    display_object.set_width(
+        &mut activation.context,
+        text_width.to_pixels() + EditText::INTERNAL_PADDING * 2.0,
+    );
+    display_object.set_height(&mut activation.context, text_height.to_pixels());
 
-    apply_format(activation, display_object, text.as_wstr(), element_format)?;
+    // A line that wasn't broken at whitespace had to be broken in the middle of a word.
+    let is_emergency_break = line_length < remaining_text.len()
+        && !string_utils::swf_is_whitespace(remaining_text.at(line_length - 1))
+        && !string_utils::swf_is_whitespace(remaining_text.at(line_length));
 
     let instance = initialize_for_allocator(activation, display_object.into(), class)?;
     class.call_native_init(instance.into(), &[], activation)?;
 
-    instance.set_property(
-        &Multiname::new(activation.avm2().flash_text_engine_internal, "_textBlock"),
-        this.into(),
+    set_internal_property(activation, instance, "_textBlock", this.into())?;
+    set_internal_property(activation, instance, "_specifiedWidth", width.into())?;
+    set_internal_property(
         activation,
+        instance,
+        "_textBlockBeginIndex",
+        begin_index.into(),
     )?;
+    set_internal_property(activation, instance, "_rawTextLength", line_length.into())?;
 
-    instance.set_property(
-        &Multiname::new(
-            activation.avm2().flash_text_engine_internal,
-            "_specifiedWidth",
-        ),
-        args.get_value(1),
-        activation,
-    )?;
+    if let Some(previous_line) = previous_line {
+        set_internal_property(activation, instance, "_previousLine", previous_line.into())?;
+        set_internal_property(activation, previous_line, "_nextLine", instance.into())?;
+    } else {
+        set_internal_property(activation, this, "_firstLine", instance.into())?;
+    }
+    set_internal_property(activation, this, "_lastLine", instance.into())?;
 
-    instance.set_property(
-        &Multiname::new(
-            activation.avm2().flash_text_engine_internal,
-            "_rawTextLength",
-        ),
-        text.len().into(),
-        activation,
-    )?;
+    let result = if is_emergency_break {
+        "emergency"
+    } else {
+        "success"
+    };
+    set_internal_property(activation, this, "_textLineCreationResult", result.into())?;
 
-    this.set_property(
-        &Multiname::new(
-            activation.avm2().flash_text_engine_internal,
-            "_textLineCreationResult",
-        ),
-        "success".into(),
-        activation,
-    )?;
+    Ok(instance.into())
+}
 
-    this.set_property(
-        &Multiname::new(activation.avm2().flash_text_engine_internal, "_firstLine"),
-        instance.into(),
-        activation,
-    )?;
+/// Flattens a tree of `ContentElement`s into its raw text, and the runs of
+/// that text which belong to each element.
+fn collect_runs<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    element: Object<'gc>,
+    parent_format: Option<Object<'gc>>,
+    text: &mut WString,
+    runs: &mut Vec<TextRun<'gc>>,
+) -> Result<(), Error<'gc>> {
+    let element_format = element
+        .get_public_property("elementFormat", activation)?
+        .as_object()
+        .or(parent_format);
 
-    Ok(instance.into())
+    let group_element = activation.avm2().classes().groupelement;
+    let graphic_element = activation.avm2().classes().graphicelement;
+    if element.is_of_type(
+        group_element.inner_class_definition(),
+        &mut activation.context,
+    ) {
+        let count = element
+            .get_public_property("elementCount", activation)?
+            .coerce_to_u32(activation)?;
+        for index in 0..count {
+            let child =
+                element.call_public_property("getElementAt", &[index.into()], activation)?;
+            if let Some(child) = child.as_object() {
+                collect_runs(activation, child, element_format, text, runs)?;
+            }
+        }
+        return Ok(());
+    }
+
+    let start = text.len();
+    if element.is_of_type(
+        graphic_element.inner_class_definition(),
+        &mut activation.context,
+    ) {
+        // TODO: Lay out the graphic itself.
+        text.push(GRAPHIC_ELEMENT_CHAR);
+    } else {
+        let element_text = element.get_public_property("text", activation)?;
+        if !matches!(element_text, Value::Null) {
+            text.push_str(&element_text.coerce_to_string(activation)?);
+        }
+    }
+
+    runs.push(TextRun {
+        start,
+        end: text.len(),
+        element_format,
+    });
+
+    Ok(())
 }
 
-fn apply_format<'gc>(
+/// Sets the text of a `TextLine`, formatted by the runs that it overlaps.
+///
+/// `begin_index` is the index of the start of `text` in the raw text of the `TextBlock`.
+fn set_text<'gc>(
     activation: &mut Activation<'_, 'gc>,
     display_object: EditText<'gc>,
     text: &WStr,
-    element_format: Option<Object<'gc>>,
+    begin_index: usize,
+    runs: &[TextRun<'gc>],
 ) -> Result<(), Error<'gc>> {
-    if let Some(element_format) = element_format {
-        // TODO: Support more ElementFormat properties
-        let color = element_format
-            .get_public_property("color", activation)?
-            .coerce_to_u32(activation)?;
-        let size = element_format
-            .get_public_property("fontSize", activation)?
-            .coerce_to_number(activation)?;
-
-        let (font, bold, italic, is_device_font) = if let Value::Object(font_description) =
-            element_format.get_public_property("fontDescription", activation)?
-        {
-            (
-                Some(
-                    font_description
-                        .get_public_property("fontName", activation)?
-                        .coerce_to_string(activation)?
-                        .as_wstr()
-                        .into(),
-                ),
-                Some(
-                    &font_description
-                        .get_public_property("fontWeight", activation)?
-                        .coerce_to_string(activation)?
-                        == b"bold",
-                ),
-                Some(
-                    &font_description
-                        .get_public_property("fontPosture", activation)?
-                        .coerce_to_string(activation)?
-                        == b"italic",
-                ),
-                &font_description
-                    .get_public_property("fontLookup", activation)?
-                    .coerce_to_string(activation)?
-                    == b"device",
-            )
-        } else {
-            (None, None, None, true)
-        };
+    display_object.set_text(text, &mut activation.context);
 
-        let format = TextFormat {
-            color: Some(swf::Color::from_rgb(color, 0xFF)),
-            size: Some(size),
-            font,
-            bold,
-            italic,
-            ..TextFormat::default()
+    let end_index = begin_index + text.len();
+    let mut is_device_font = None;
+    for run in runs {
+        let start = run.start.max(begin_index);
+        let end = run.end.min(end_index);
+        if start >= end {
+            continue;
+        }
+
+        let Some(element_format) = run.element_format else {
+            continue;
         };
 
-        display_object.set_is_device_font(&mut activation.context, is_device_font);
-        display_object.set_text_format(0, text.len(), format.clone(), &mut activation.context);
-        display_object.set_new_text_format(format, &mut activation.context);
-    } else {
-        display_object.set_is_device_font(&mut activation.context, true);
+        let (format, device_font) = text_format(activation, element_format)?;
+        if is_device_font.is_none() {
+            is_device_font = Some(device_font);
+            display_object.set_new_text_format(format.clone(), &mut activation.context);
+        }
+        display_object.set_text_format(
+            start - begin_index,
+            end - begin_index,
+            format,
+            &mut activation.context,
+        );
     }
 
-    display_object.set_word_wrap(true, &mut activation.context);
+    // FIXME: Device and embedded fonts can't be mixed in a single line.
+    display_object.set_is_device_font(&mut activation.context, is_device_font.unwrap_or(true));
 
-    let measured_text = display_object.measure_text(&mut activation.context);
+    Ok(())
+}
 
-    display_object.set_height(&mut activation.context, measured_text.1.to_pixels());
+/// Converts an `ElementFormat` into a `TextFormat`, and whether it uses device fonts.
+fn text_format<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    element_format: Object<'gc>,
+) -> Result<(TextFormat, bool), Error<'gc>> {
+    // TODO: Support more ElementFormat properties
+    let color = element_format
+        .get_public_property("color", activation)?
+        .coerce_to_u32(activation)?;
+    let size = element_format
+        .get_public_property("fontSize", activation)?
+        .coerce_to_number(activation)?;
 
-    Ok(())
+    let (font, bold, italic, is_device_font) = if let Value::Object(font_description) =
+        element_format.get_public_property("fontDescription", activation)?
+    {
+        (
+            Some(
+                font_description
+                    .get_public_property("fontName", activation)?
+                    .coerce_to_string(activation)?
+                    .as_wstr()
+                    .into(),
+            ),
+            Some(
+                &font_description
+                    .get_public_property("fontWeight", activation)?
+                    .coerce_to_string(activation)?
+                    == b"bold",
+            ),
+            Some(
+                &font_description
+                    .get_public_property("fontPosture", activation)?
+                    .coerce_to_string(activation)?
+                    == b"italic",
+            ),
+            &font_description
+                .get_public_property("fontLookup", activation)?
+                .coerce_to_string(activation)?
+                == b"device",
+        )
+    } else {
+        (None, None, None, true)
+    };
+
+    let format = TextFormat {
+        color: Some(swf::Color::from_rgb(color, 0xFF)),
+        size: Some(size),
+        font,
+        bold,
+        italic,
+        ..TextFormat::default()
+    };
+
+    Ok((format, is_device_font))
 }
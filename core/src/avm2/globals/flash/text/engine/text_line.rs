@@ -1,8 +1,13 @@
+use super::text_block::get_internal_property;
 use crate::avm2::activation::Activation;
 use crate::avm2::error::Error;
+use crate::avm2::globals::flash::display::display_object::new_rectangle;
 use crate::avm2::object::{Object, TObject};
+use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
-use crate::display_object::TDisplayObject;
+use crate::display_object::{EditText, TDisplayObject};
+use crate::prelude::*;
+use crate::DefaultFont;
 
 pub fn native_instance_init<'gc>(
     activation: &mut Activation<'_, 'gc>,
@@ -36,3 +41,105 @@ pub fn get_text_height<'gc>(
     let measured_text = edit_text.measure_text(&mut activation.context);
     Ok(measured_text.1.to_pixels().into())
 }
+
+pub fn get_ascent<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let display_object = this.as_display_object().unwrap();
+    let edit_text = display_object.as_edit_text().unwrap();
+
+    let (ascent, _) = line_metrics(activation, edit_text);
+    Ok(ascent.to_pixels().into())
+}
+
+pub fn get_descent<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let display_object = this.as_display_object().unwrap();
+    let edit_text = display_object.as_edit_text().unwrap();
+
+    let (_, descent) = line_metrics(activation, edit_text);
+    Ok(descent.to_pixels().into())
+}
+
+/// Returns the ascent and descent of a line.
+///
+/// A line without any laid out text uses the default font at the size of its
+/// format, which comes from the `ElementFormat` of its text.
+fn line_metrics<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    edit_text: EditText<'gc>,
+) -> (Twips, Twips) {
+    if let Some(metrics) = edit_text.layout_metrics(Some(0)) {
+        return (metrics.ascent, metrics.descent);
+    }
+
+    let format = edit_text.new_text_format();
+    let size = Twips::from_pixels(format.size.unwrap_or(12.0));
+    let context = &mut activation.context;
+    let font = context
+        .library
+        .default_font(
+            DefaultFont::Sans,
+            format.bold.unwrap_or(false),
+            format.italic.unwrap_or(false),
+            context.ui,
+            context.renderer,
+            context.gc_context,
+        )
+        .first()
+        .copied();
+    match font {
+        Some(font) => (
+            font.get_baseline_for_height(size),
+            font.get_descent_for_height(size),
+        ),
+        // Without any font, the whole size is above the baseline.
+        None => (size, Twips::ZERO),
+    }
+}
+
+/// Implements `TextLine.getAtomBoundsInternal`. The index is checked in AS.
+pub fn get_atom_bounds_internal<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let display_object = this.as_display_object().unwrap();
+    let edit_text = display_object.as_edit_text().unwrap();
+
+    let index = args.get_u32(activation, 0)? as usize;
+    let bounds = edit_text.char_bounds(index).unwrap_or(Rectangle::ZERO);
+    Ok(new_rectangle(activation, bounds)?.into())
+}
+
+pub fn get_atom_index_at_point<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let display_object = this.as_display_object().unwrap();
+    let edit_text = display_object.as_edit_text().unwrap();
+
+    let stage_x = args.get_f64(activation, 0)?;
+    let stage_y = args.get_f64(activation, 1)?;
+    let Some(local) = edit_text.global_to_local(Point::from_pixels(stage_x, stage_y)) else {
+        return Ok((-1).into());
+    };
+
+    let atom_count =
+        get_internal_property(activation, this, "_rawTextLength")?.coerce_to_u32(activation)?;
+    for index in 0..atom_count {
+        if let Some(bounds) = edit_text.char_bounds(index as usize) {
+            if bounds.contains(local) {
+                return Ok(index.into());
+            }
+        }
+    }
+
+    Ok((-1).into())
+}
@@ -2,7 +2,9 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::error::{make_error_2006, make_error_2008};
-use crate::avm2::globals::flash::display::display_object::initialize_for_allocator;
+use crate::avm2::globals::flash::display::display_object::{
+    initialize_for_allocator, new_rectangle,
+};
 use crate::avm2::object::{ClassObject, Object, TObject, TextFormatObject};
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
@@ -11,7 +13,7 @@ use crate::display_object::{AutoSizeMode, EditText, TDisplayObject, TextSelectio
 use crate::html::TextFormat;
 use crate::string::AvmString;
 use crate::{avm2_stub_getter, avm2_stub_setter};
-use swf::Color;
+use swf::{Color, Point};
 
 pub fn text_field_allocator<'gc>(
     class: ClassObject<'gc>,
@@ -1167,6 +1169,201 @@ pub fn get_line_text<'gc>(
     Ok(Value::Undefined)
 }
 
+pub fn get_line_offset<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this
+        .as_display_object()
+        .and_then(|this| this.as_edit_text())
+    {
+        let line_num = args.get_i32(activation, 0)?;
+        if line_num < 0 {
+            return Err(make_error_2006(activation));
+        }
+
+        return if let Some(&offset) = this.line_starts().get(line_num as usize) {
+            Ok(offset.into())
+        } else {
+            Err(make_error_2006(activation))
+        };
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn get_line_length<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this
+        .as_display_object()
+        .and_then(|this| this.as_edit_text())
+    {
+        let line_num = args.get_i32(activation, 0)?;
+        return if let Some(length) = this.line_length(line_num as usize) {
+            Ok(length.into())
+        } else {
+            Err(make_error_2006(activation))
+        };
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn get_line_index_of_char<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this
+        .as_display_object()
+        .and_then(|this| this.as_edit_text())
+    {
+        let char_index = args.get_i32(activation, 0)?;
+        if char_index < 0 {
+            return Ok((-1).into());
+        }
+
+        let line = this.line_index_of_char(char_index as usize);
+        return Ok(line.map_or(-1, |line| line as i32).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn get_line_index_at_point<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this
+        .as_display_object()
+        .and_then(|this| this.as_edit_text())
+    {
+        let x = args.get_f64(activation, 0)?;
+        let y = args.get_f64(activation, 1)?;
+
+        let line = this.line_index_at_point(Point::from_pixels(x, y));
+        return Ok(line.map_or(-1, |line| line as i32).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn get_char_index_at_point<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this
+        .as_display_object()
+        .and_then(|this| this.as_edit_text())
+    {
+        let x = args.get_f64(activation, 0)?;
+        let y = args.get_f64(activation, 1)?;
+
+        let index = this.char_index_at_point(Point::from_pixels(x, y));
+        return Ok(index.map_or(-1, |index| index as i32).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn get_char_boundaries<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this
+        .as_display_object()
+        .and_then(|this| this.as_edit_text())
+    {
+        let char_index = args.get_i32(activation, 0)?;
+        if char_index < 0 {
+            return Ok(Value::Null);
+        }
+
+        // Characters on lines that are scrolled out of view have no boundaries.
+        let Some(line) = this.line_index_of_char(char_index as usize) else {
+            return Ok(Value::Null);
+        };
+        if line + 1 < this.scroll() || line + 1 > this.bottom_scroll() {
+            return Ok(Value::Null);
+        }
+
+        return if let Some(bounds) = this.char_bounds(char_index as usize) {
+            Ok(new_rectangle(activation, bounds)?.into())
+        } else {
+            Ok(Value::Null)
+        };
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn get_first_char_in_paragraph<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this
+        .as_display_object()
+        .and_then(|this| this.as_edit_text())
+    {
+        let char_index = args.get_i32(activation, 0)?;
+        if char_index < 0 {
+            return Ok((-1).into());
+        }
+
+        let index = this.first_char_in_paragraph(char_index as usize);
+        return Ok(index.map_or(-1, |index| index as i32).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn get_image_reference<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this
+        .as_display_object()
+        .and_then(|this| this.as_edit_text())
+    {
+        let id = args.get_string(activation, 0)?;
+        return Ok(this
+            .image_reference(&id)
+            .map_or(Value::Null, |image| image.object2()));
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn get_paragraph_length<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this
+        .as_display_object()
+        .and_then(|this| this.as_edit_text())
+    {
+        let char_index = args.get_i32(activation, 0)?;
+        if char_index < 0 {
+            return Ok((-1).into());
+        }
+
+        let length = this.paragraph_length(char_index as usize);
+        return Ok(length.map_or(-1, |length| length as i32).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
 pub fn get_bottom_scroll_v<'gc>(
     _activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
@@ -0,0 +1,216 @@
+//! `flash.text.TextSnapshot` native methods
+
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{
+    Activation, ArrayObject, ArrayStorage, Error, Multiname, Object, TObject, Value,
+};
+use crate::prelude::*;
+use crate::string::AvmString;
+use crate::text_snapshot::TextSnapshot;
+
+fn get_snapshot<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+) -> Result<TextSnapshot<'gc>, Error<'gc>> {
+    let container = this
+        .get_property(
+            &Multiname::new(activation.avm2().flash_text_internal, "_container"),
+            activation,
+        )?
+        .as_object()
+        .and_then(|container| container.as_display_object());
+
+    Ok(container.map(TextSnapshot::new).unwrap_or_default())
+}
+
+fn get_index<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    args: &[Value<'gc>],
+    index: usize,
+) -> Result<usize, Error<'gc>> {
+    Ok(args.get_i32(activation, index)?.max(0) as usize)
+}
+
+/// Implements `TextSnapshot.charCount`
+pub fn get_char_count<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let snapshot = get_snapshot(activation, this)?;
+    Ok(snapshot.char_count(activation.context.library).into())
+}
+
+/// Implements `TextSnapshot.findText`
+pub fn find_text<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let snapshot = get_snapshot(activation, this)?;
+    let begin_index = get_index(activation, args, 0)?;
+    let text = args.get_string(activation, 1)?;
+    let case_sensitive = args.get_bool(2);
+
+    let index = snapshot.find_text(
+        activation.context.library,
+        begin_index,
+        &text,
+        case_sensitive,
+    );
+    Ok(index.map_or(-1, |index| index as i32).into())
+}
+
+/// Implements `TextSnapshot.getSelected`
+pub fn get_selected<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let snapshot = get_snapshot(activation, this)?;
+    let begin_index = get_index(activation, args, 0)?;
+    let end_index = get_index(activation, args, 1)?;
+
+    Ok(snapshot
+        .is_selected(activation.context.library, begin_index..end_index)
+        .into())
+}
+
+/// Implements `TextSnapshot.getSelectedText`
+pub fn get_selected_text<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let snapshot = get_snapshot(activation, this)?;
+    let include_line_endings = args.get_bool(0);
+
+    let text = snapshot.selected_text(activation.context.library, include_line_endings);
+    Ok(AvmString::new(activation.context.gc_context, text).into())
+}
+
+/// Implements `TextSnapshot.getText`
+pub fn get_text<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let snapshot = get_snapshot(activation, this)?;
+    let begin_index = get_index(activation, args, 0)?;
+    let end_index = get_index(activation, args, 1)?;
+    let include_line_endings = args.get_bool(2);
+
+    let text = snapshot.text(
+        activation.context.library,
+        begin_index..end_index,
+        include_line_endings,
+    );
+    Ok(AvmString::new(activation.context.gc_context, text).into())
+}
+
+/// Implements `TextSnapshot.getTextRunInfo`
+pub fn get_text_run_info<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let snapshot = get_snapshot(activation, this)?;
+    let begin_index = get_index(activation, args, 0)?;
+    let end_index = get_index(activation, args, 1)?;
+
+    let glyphs = snapshot.glyphs(activation.context.library);
+    let mut storage = ArrayStorage::new(0);
+    for (index, glyph) in glyphs.iter().enumerate().take(end_index).skip(begin_index) {
+        let info = activation
+            .avm2()
+            .classes()
+            .object
+            .construct(activation, &[])?;
+        let font_name = AvmString::new_utf8(
+            activation.context.gc_context,
+            glyph.glyph.font.descriptor().name(),
+        );
+
+        info.set_public_property("indexInRun", index.into(), activation)?;
+        info.set_public_property("selected", glyph.is_selected().into(), activation)?;
+        info.set_public_property("font", font_name.into(), activation)?;
+        info.set_public_property("color", glyph.glyph.color.to_rgb().into(), activation)?;
+        info.set_public_property("height", glyph.glyph.height.to_pixels().into(), activation)?;
+        info.set_public_property("matrix_a", glyph.matrix.a.into(), activation)?;
+        info.set_public_property("matrix_b", glyph.matrix.b.into(), activation)?;
+        info.set_public_property("matrix_c", glyph.matrix.c.into(), activation)?;
+        info.set_public_property("matrix_d", glyph.matrix.d.into(), activation)?;
+        info.set_public_property("matrix_tx", glyph.matrix.tx.to_pixels().into(), activation)?;
+        info.set_public_property("matrix_ty", glyph.matrix.ty.to_pixels().into(), activation)?;
+
+        let corners = glyph.corners();
+        for (name, value) in [
+            ("corner0x", corners[0].x),
+            ("corner0y", corners[0].y),
+            ("corner1x", corners[1].x),
+            ("corner1y", corners[1].y),
+            ("corner2x", corners[2].x),
+            ("corner2y", corners[2].y),
+            ("corner3x", corners[3].x),
+            ("corner3y", corners[3].y),
+        ] {
+            info.set_public_property(name, value.to_pixels().into(), activation)?;
+        }
+
+        storage.push(info.into());
+    }
+
+    Ok(ArrayObject::from_storage(activation, storage)?.into())
+}
+
+/// Implements `TextSnapshot.hitTestTextNearPos`
+pub fn hit_test_text_near_pos<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let snapshot = get_snapshot(activation, this)?;
+    let x = args.get_f64(activation, 0)?;
+    let y = args.get_f64(activation, 1)?;
+    let max_distance = args.get_f64(activation, 2)?;
+
+    let index = snapshot.hit_test_text_near_pos(
+        activation.context.library,
+        Point::from_pixels(x, y),
+        Twips::from_pixels(max_distance),
+    );
+    Ok(index.map_or(-1.0, |index| index as f64).into())
+}
+
+/// Implements `TextSnapshot.setSelectColor`
+pub fn set_select_color<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let snapshot = get_snapshot(activation, this)?;
+    let color = args.get_u32(activation, 0)?;
+
+    snapshot.set_select_color(activation.context.gc_context, Color::from_rgb(color, 0xFF));
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextSnapshot.setSelected`
+pub fn set_selected<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let snapshot = get_snapshot(activation, this)?;
+    let begin_index = get_index(activation, args, 0)?;
+    let end_index = get_index(activation, args, 1)?;
+    let selected = args.get_bool(2);
+
+    snapshot.set_selected(
+        activation.context.library,
+        activation.context.gc_context,
+        begin_index..end_index,
+        selected,
+    );
+    Ok(Value::Undefined)
+}
@@ -0,0 +1,33 @@
+package flash.ui {
+    import flash.events.Event;
+
+    // The connected game input devices, in the order they were added.
+    internal var _devices:Vector.<GameInputDevice> = new Vector.<GameInputDevice>();
+
+    internal function _addDevice(id:String, name:String, numAxes:int, numButtons:int):GameInputDevice {
+        var device:GameInputDevice = new GameInputDevice(id, name, numAxes, numButtons);
+        _devices.push(device);
+        return device;
+    }
+
+    internal function _removeDevice(id:String):GameInputDevice {
+        for (var i:int = 0; i < _devices.length; i++) {
+            if (_devices[i].id == id) {
+                return _devices.removeAt(i);
+            }
+        }
+        return null;
+    }
+
+    internal function _setControlValue(id:String, index:int, value:Number):void {
+        for each (var device:GameInputDevice in _devices) {
+            if (device.id == id) {
+                var control:GameInputControl = device._setValue(index, value);
+                if (control != null) {
+                    control.dispatchEvent(new Event(Event.CHANGE));
+                }
+                return;
+            }
+        }
+    }
+}
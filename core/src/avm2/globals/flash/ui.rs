@@ -1,5 +1,7 @@
 //! `flash.ui` namespace
 
 pub mod context_menu;
+pub mod game_input;
 pub mod keyboard;
 pub mod mouse;
+pub mod multitouch;
@@ -4,15 +4,18 @@ package flash.ui {
     [API("688")]
     public final class GameInput extends EventDispatcher {
         public static function get isSupported():Boolean {
-            return false;
+            return true;
         }
 
         public static function get numDevices():int {
-            return 0;
+            return _devices.length;
         }
 
         public static function getDeviceAt(index:int):GameInputDevice {
-            throw new RangeError("Error #1506: The specified range is invalid.", 1506);
+            if (index < 0 || index >= _devices.length) {
+                throw new RangeError("Error #1506: The specified range is invalid.", 1506);
+            }
+            return _devices[index];
         }
     }
-}
\ No newline at end of file
+}
@@ -1,8 +1,43 @@
 package flash.ui {
+    import flash.events.EventDispatcher;
+
     [API("688")]
-    public dynamic class GameInputControl {
-        public function GameInputControl() {
-            throw new ArgumentError("Error #2012: GameInputControl$ class cannot be instantiated.", 2012)
+    public dynamic class GameInputControl extends EventDispatcher {
+        private var _device:GameInputDevice;
+        private var _id:String;
+        private var _minValue:Number;
+        private var _maxValue:Number;
+
+        internal var _value:Number = 0;
+
+        public function GameInputControl(device:GameInputDevice = null, id:String = null, minValue:Number = 0, maxValue:Number = 1) {
+            if (device == null) {
+                throw new ArgumentError("Error #2012: GameInputControl$ class cannot be instantiated.", 2012);
+            }
+            this._device = device;
+            this._id = id;
+            this._minValue = minValue;
+            this._maxValue = maxValue;
+        }
+
+        public function get device():GameInputDevice {
+            return this._device;
+        }
+
+        public function get id():String {
+            return this._id;
+        }
+
+        public function get minValue():Number {
+            return this._minValue;
+        }
+
+        public function get maxValue():Number {
+            return this._maxValue;
+        }
+
+        public function get value():Number {
+            return this._value;
         }
     }
-}
\ No newline at end of file
+}
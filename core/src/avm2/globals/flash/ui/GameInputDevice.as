@@ -1,4 +1,7 @@
 package flash.ui {
+    import flash.errors.MemoryError;
+    import flash.utils.ByteArray;
+
     // The AS3 docs say this is only available in AIR 3.7.
     // That was determined to be a lie.
     [API("688")]
@@ -7,5 +10,105 @@ package flash.ui {
         // control values. If `startCachingSamples` returns samples that
         // require more memory than you specify, it throws a memory error.
         public static const MAX_BUFFER_SIZE:int = 32000;
+
+        private var _id:String;
+        private var _name:String;
+        private var _controls:Vector.<GameInputControl> = new Vector.<GameInputControl>();
+
+        private var _cachedControls:Vector.<GameInputControl> = null;
+        private var _maxCachedSamples:int = 0;
+        private var _cachedSamples:Vector.<Number> = new Vector.<Number>();
+
+        public var enabled:Boolean = false;
+        public var sampleInterval:int = 0;
+
+        public function GameInputDevice(id:String = null, name:String = null, numAxes:int = 0, numButtons:int = 0) {
+            this._id = id;
+            this._name = name;
+            for (var i:int = 0; i < numAxes; i++) {
+                this._controls.push(new GameInputControl(this, "AXIS_" + i, -1, 1));
+            }
+            for (var j:int = 0; j < numButtons; j++) {
+                this._controls.push(new GameInputControl(this, "BUTTON_" + j, 0, 1));
+            }
+        }
+
+        public function get id():String {
+            return this._id;
+        }
+
+        public function get name():String {
+            return this._name;
+        }
+
+        public function get numControls():int {
+            return this._controls.length;
+        }
+
+        public function getControlAt(i:int):GameInputControl {
+            if (i < 0 || i >= this._controls.length) {
+                throw new RangeError("Error #1506: The specified range is invalid.", 1506);
+            }
+            return this._controls[i];
+        }
+
+        public function startCachingSamples(numSamples:int, controls:Vector.<String>):void {
+            if (numSamples * controls.length * 8 > MAX_BUFFER_SIZE) {
+                throw new MemoryError("Error #1000: The system is out of memory.", 1000);
+            }
+
+            var cachedControls:Vector.<GameInputControl> = new Vector.<GameInputControl>();
+            for each (var id:String in controls) {
+                for each (var control:GameInputControl in this._controls) {
+                    if (control.id == id) {
+                        cachedControls.push(control);
+                    }
+                }
+            }
+            this._cachedControls = cachedControls;
+            this._maxCachedSamples = numSamples;
+            this._cachedSamples.length = 0;
+        }
+
+        public function stopCachingSamples():void {
+            this._cachedControls = null;
+            this._cachedSamples.length = 0;
+        }
+
+        public function getCachedSamples(data:ByteArray, append:Boolean = false):int {
+            if (!append) {
+                data.length = 0;
+            }
+            if (this._cachedControls == null || this._cachedControls.length == 0) {
+                return 0;
+            }
+
+            for each (var value:Number in this._cachedSamples) {
+                data.writeDouble(value);
+            }
+            var numSamples:int = this._cachedSamples.length / this._cachedControls.length;
+            this._cachedSamples.length = 0;
+            return numSamples;
+        }
+
+        internal function _setValue(index:int, value:Number):GameInputControl {
+            if (!this.enabled || index < 0 || index >= this._controls.length) {
+                return null;
+            }
+
+            var control:GameInputControl = this._controls[index];
+            control._value = value;
+
+            if (this._cachedControls != null && this._cachedControls.length > 0) {
+                for each (var cached:GameInputControl in this._cachedControls) {
+                    this._cachedSamples.push(cached.value);
+                }
+                var maxLength:int = this._maxCachedSamples * this._cachedControls.length;
+                if (this._cachedSamples.length > maxLength) {
+                    this._cachedSamples.splice(0, this._cachedSamples.length - maxLength);
+                }
+            }
+            return control;
+        }
     }
 }
@@ -1,6 +1,26 @@
 package flash.ui {
     public final class Multitouch {
-        // FIXME - implement a setter and gett
-        public static var inputMode:String = MultitouchInputMode.GESTURE;
+        public static native function get inputMode():String;
+        public static native function set inputMode(value:String):void;
+
+        public static native function get mapTouchToMouse():Boolean;
+        public static native function set mapTouchToMouse(value:Boolean):void;
+
+        public static function get maxTouchPoints():int {
+            return 10;
+        }
+
+        public static function get supportedGestures():Vector.<String> {
+            // Gesture recognition isn't implemented.
+            return null;
+        }
+
+        public static function get supportsGestureEvents():Boolean {
+            return false;
+        }
+
+        public static function get supportsTouchEvents():Boolean {
+            return true;
+        }
     }
-}
\ No newline at end of file
+}
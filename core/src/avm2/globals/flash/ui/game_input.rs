@@ -0,0 +1,124 @@
+//! `flash.ui.GameInput` support
+
+use crate::avm2::activation::Activation;
+use crate::avm2::object::TObject;
+use crate::avm2::{Avm2, Error, QName, Value};
+use crate::context::UpdateContext;
+use crate::events::{GamepadAxis, GamepadButton, PlayerEvent};
+use crate::string::AvmString;
+
+/// Updates the game input devices of AVM2 for a gamepad event.
+///
+/// The devices themselves are kept by the `flash.ui` package, and each of
+/// them has one control for every `GamepadAxis`, followed by one for every
+/// `GamepadButton`.
+pub fn handle_gamepad_event<'gc>(context: &mut UpdateContext<'_, 'gc>, event: &PlayerEvent) {
+    let mut activation = Activation::from_nothing(context.reborrow());
+    if let Err(e) = dispatch_gamepad_event(&mut activation, event) {
+        tracing::error!("Encountered AVM2 error when handling gamepad event: {e:?}");
+    }
+}
+
+fn dispatch_gamepad_event<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    event: &PlayerEvent,
+) -> Result<(), Error<'gc>> {
+    match *event {
+        PlayerEvent::GamepadConnected { device, ref name } => {
+            let id = device_id(activation, device);
+            let name = AvmString::new_utf8(activation.context.gc_context, name);
+            let device = call_internal_function(
+                activation,
+                "_addDevice",
+                &[
+                    id.into(),
+                    name.into(),
+                    GamepadAxis::ALL.len().into(),
+                    GamepadButton::ALL.len().into(),
+                ],
+            )?;
+            broadcast_device_event(activation, "deviceAdded", device)?;
+        }
+        PlayerEvent::GamepadDisconnected { device } => {
+            let id = device_id(activation, device);
+            let device = call_internal_function(activation, "_removeDevice", &[id.into()])?;
+            if !matches!(device, Value::Null) {
+                broadcast_device_event(activation, "deviceRemoved", device)?;
+            }
+        }
+        PlayerEvent::GamepadButtonDown { device, button } => {
+            set_control_value(activation, device, button_control(button), 1.0)?;
+        }
+        PlayerEvent::GamepadButtonUp { device, button } => {
+            set_control_value(activation, device, button_control(button), 0.0)?;
+        }
+        PlayerEvent::GamepadAxisChanged {
+            device,
+            axis,
+            value,
+        } => {
+            // `GamepadAxis::ALL` is in declaration order.
+            set_control_value(activation, device, axis as usize, value)?;
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn device_id<'gc>(activation: &mut Activation<'_, 'gc>, device: usize) -> AvmString<'gc> {
+    AvmString::new_utf8(activation.context.gc_context, device.to_string())
+}
+
+fn button_control(button: GamepadButton) -> usize {
+    // `GamepadButton::ALL` is in declaration order.
+    GamepadAxis::ALL.len() + button as usize
+}
+
+fn set_control_value<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    device: usize,
+    index: usize,
+    value: f64,
+) -> Result<(), Error<'gc>> {
+    let id = device_id(activation, device);
+    call_internal_function(
+        activation,
+        "_setControlValue",
+        &[id.into(), index.into(), value.into()],
+    )?;
+    Ok(())
+}
+
+fn broadcast_device_event<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    event_type: &'static str,
+    device: Value<'gc>,
+) -> Result<(), Error<'gc>> {
+    let event = activation.avm2().classes().gameinputevent.construct(
+        activation,
+        &[event_type.into(), false.into(), false.into(), device],
+    )?;
+    let game_input_class = activation.avm2().classes().gameinput;
+    Avm2::broadcast_event(&mut activation.context, event, game_input_class);
+    Ok(())
+}
+
+/// Calls one of the internal functions of the `flash.ui` package.
+fn call_internal_function<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    name: &'static str,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let qname = QName::new(activation.avm2().flash_ui_internal, name);
+    let function = activation
+        .avm2()
+        .playerglobals_domain
+        .get_defined_value(activation, qname)?;
+
+    function
+        .as_object()
+        .and_then(|function| function.as_function_object())
+        .expect("flash.ui internal function should exist")
+        .call(Value::Undefined, args, activation)
+}
@@ -0,0 +1,52 @@
+//! `flash.ui.Multitouch` native methods
+
+use crate::avm2::activation::Activation;
+use crate::avm2::error::make_error_2008;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::avm2::{Error, Object};
+use crate::events::MultitouchInputMode;
+use crate::string::{AvmString, FromWStr};
+
+/// Implements `Multitouch.inputMode`'s getter
+pub fn get_input_mode<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let mode = activation.context.mouse_data.multitouch_input_mode;
+    Ok(AvmString::new_utf8(activation.context.gc_context, mode.to_string()).into())
+}
+
+/// Implements `Multitouch.inputMode`'s setter
+pub fn set_input_mode<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let mode = args.get_string_non_null(activation, 0, "inputMode")?;
+    let Ok(mode) = MultitouchInputMode::from_wstr(&mode) else {
+        return Err(make_error_2008(activation, "inputMode"));
+    };
+    activation.context.mouse_data.multitouch_input_mode = mode;
+    Ok(Value::Undefined)
+}
+
+/// Implements `Multitouch.mapTouchToMouse`'s getter
+pub fn get_map_touch_to_mouse<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(activation.context.mouse_data.map_touch_to_mouse.into())
+}
+
+/// Implements `Multitouch.mapTouchToMouse`'s setter
+pub fn set_map_touch_to_mouse<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    activation.context.mouse_data.map_touch_to_mouse = args.get_bool(0);
+    Ok(Value::Undefined)
+}
@@ -24,6 +24,11 @@ package flash.utils {
 		public native function get position():uint;
 		public native function set position(value:uint):void;
 
+		[API("684")]
+		public native function get shareable():Boolean;
+		[API("684")]
+		public native function set shareable(value:Boolean):void;
+
 		public function ByteArray() {
 			this.objectEncoding = _defaultObjectEncoding;
 		}
@@ -71,6 +76,11 @@ package flash.utils {
 		public native function writeUTF(value:String):void;
 		public native function writeUTFBytes(value:String):void;
 		public native function writeObject(object:*):void;
+
+		[API("684")]
+		public native function atomicCompareAndSwapIntAt(byteIndex:int, expectedValue:int, newValue:int):int;
+		[API("684")]
+		public native function atomicCompareAndSwapLength(expectedLength:int, newLength:int):int;
 		
 		prototype.toJSON = function(k:String):* {
 			return "ByteArray";
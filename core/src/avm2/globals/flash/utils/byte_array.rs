@@ -5,6 +5,7 @@ use crate::avm2::bytearray::{Endian, ObjectEncoding};
 use crate::avm2::error::make_error_2008;
 pub use crate::avm2::object::byte_array_allocator;
 use crate::avm2::object::{Object, TObject};
+use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use crate::string::AvmString;
@@ -861,3 +862,83 @@ pub fn set_object_encoding<'gc>(
 
     Ok(Value::Undefined)
 }
+
+pub fn get_shareable<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(bytearray) = this.as_bytearray() {
+        return Ok(bytearray.shareable().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn set_shareable<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(mut bytearray) = this.as_bytearray_mut(activation.context.gc_context) {
+        bytearray.set_shareable(args.get_bool(0));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `ByteArray.atomicCompareAndSwapIntAt`
+///
+/// Workers never run at the same time, so this doesn't need to be atomic.
+pub fn atomic_compare_and_swap_int_at<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let index = args.get_i32(activation, 0)?;
+    let expected = args.get_i32(activation, 1)?;
+    let new_value = args.get_i32(activation, 2)?;
+
+    if index < 0 || index % 4 != 0 {
+        return Err(make_error_2008(activation, "byteIndex"));
+    }
+
+    if let Some(mut bytearray) = this.as_bytearray_mut(activation.context.gc_context) {
+        let index = index as usize;
+        let value = bytearray
+            .read_int_at(index)
+            .map_err(|e| e.to_avm(activation))?;
+        if value == expected {
+            let bytes = match bytearray.endian() {
+                Endian::Big => new_value.to_be_bytes(),
+                Endian::Little => new_value.to_le_bytes(),
+            };
+            bytearray
+                .write_at_nongrowing(&bytes, index)
+                .map_err(|e| e.to_avm(activation))?;
+        }
+        return Ok(value.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `ByteArray.atomicCompareAndSwapLength`
+pub fn atomic_compare_and_swap_length<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let expected = args.get_i32(activation, 0)?;
+    let new_length = args.get_i32(activation, 1)?;
+
+    if let Some(mut bytearray) = this.as_bytearray_mut(activation.context.gc_context) {
+        let length = bytearray.len() as i32;
+        if length == expected && new_length >= 0 {
+            bytearray.set_length(new_length as usize);
+        }
+        return Ok(length.into());
+    }
+
+    Ok(Value::Undefined)
+}
@@ -34,6 +34,7 @@ include "flash/utils/IDataOutput.as"
 include "flash/utils/IExternalizable.as"
 include "flash/utils/ByteArray.as"
 include "flash/utils/Dictionary.as"
+include "flash/data/EncryptedLocalStore.as"
 include "flash/desktop/ClipboardFormats.as"
 include "flash/desktop/ClipboardTransferMode.as"
 include "flash/desktop/Clipboard.as"
@@ -42,6 +43,9 @@ include "flash/desktop/IFilePromise.as"
 include "flash/events/IEventDispatcher.as"
 include "flash/events/EventDispatcher.as"
 
+include "flash/desktop/NativeApplication.as"
+
+include "flash/concurrent.as"
 include "flash/concurrent/Condition.as"
 include "flash/concurrent/Mutex.as"
 
@@ -155,11 +159,13 @@ include "flash/events/DRMLicenseRequestEvent.as"
 include "flash/events/DRMReturnVoucherCompleteEvent.as"
 include "flash/events/DRMReturnVoucherErrorEvent.as"
 include "flash/events/EventPhase.as"
+include "flash/events/FileListEvent.as"
 include "flash/events/FocusEvent.as"
 include "flash/events/FullScreenEvent.as"
 include "flash/events/GameInputEvent.as"
 include "flash/events/GesturePhase.as"
 include "flash/events/HTTPStatusEvent.as"
+include "flash/events/IMEEvent.as"
 include "flash/events/IOErrorEvent.as"
 include "flash/events/KeyboardEvent.as"
 include "flash/events/NetDataEvent.as"
@@ -289,6 +295,13 @@ include "flash/net/URLStream.as"
 include "flash/net/URLVariables.as"
 include "flash/net/XMLSocket.as"
 
+// `File` extends `FileReference`, so `flash.filesystem` needs to come after `flash.net`.
+include "flash/filesystem/FileMode.as"
+include "flash/filesystem/File.as"
+include "flash/filesystem/FileStream.as"
+include "flash/filesystem/StorageVolume.as"
+include "flash/filesystem/StorageVolumeInfo.as"
+
 include "flash/printing/PrintJob.as"
 include "flash/printing/PrintJobOptions.as"
 include "flash/printing/PrintJobOrientation.as"
@@ -402,6 +415,7 @@ include "flash/ui/MouseCursorData.as"
 include "flash/ui/MultitouchInputMode.as"
 include "flash/ui/Multitouch.as"
 
+include "flash/ui.as"
 include "flash/utils.as"
 include "flash/utils/Proxy.as"
 include "flash/utils/CompressionAlgorithm.as"
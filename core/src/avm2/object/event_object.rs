@@ -14,6 +14,7 @@ use crate::string::AvmString;
 use gc_arena::{Collect, GcCell, GcWeakCell, Mutation};
 use std::cell::{Ref, RefMut};
 use std::fmt::Debug;
+use swf::{Point, Twips};
 
 /// A class instance allocator that allocates Event objects.
 pub fn event_allocator<'gc>(
@@ -145,6 +146,66 @@ impl<'gc> EventObject<'gc> {
             .unwrap() // we don't expect to break here
     }
 
+    /// Creates a `TouchEvent` for the touch point at the given stage position.
+    pub fn touch_event<S>(
+        activation: &mut Activation<'_, 'gc>,
+        event_type: S,
+        target: DisplayObject<'gc>,
+        related_object: Option<InteractiveObject<'gc>>,
+        touch_point_id: i32,
+        is_primary_touch_point: bool,
+        position: Point<Twips>,
+    ) -> Object<'gc>
+    where
+        S: Into<AvmString<'gc>>,
+    {
+        let local = target.global_to_local(position).unwrap_or(position);
+
+        let event_type: AvmString<'gc> = event_type.into();
+
+        let touch_event_cls = activation.avm2().classes().touchevent;
+        touch_event_cls
+            .construct(
+                activation,
+                &[
+                    event_type.into(),
+                    // bubbles
+                    true.into(),
+                    // cancellable
+                    false.into(),
+                    // touchPointID
+                    touch_point_id.into(),
+                    // isPrimaryTouchPoint
+                    is_primary_touch_point.into(),
+                    // localX
+                    local.x.to_pixels().into(),
+                    // localY
+                    local.y.to_pixels().into(),
+                    // sizeX
+                    f64::NAN.into(),
+                    // sizeY
+                    f64::NAN.into(),
+                    // pressure
+                    f64::NAN.into(),
+                    // relatedObject
+                    related_object
+                        .map(|o| o.as_displayobject().object2())
+                        .unwrap_or(Value::Null),
+                    // ctrlKey
+                    activation
+                        .context
+                        .input
+                        .is_key_down(KeyCode::Control)
+                        .into(),
+                    // altKey
+                    activation.context.input.is_key_down(KeyCode::Alt).into(),
+                    // shiftKey
+                    activation.context.input.is_key_down(KeyCode::Shift).into(),
+                ],
+            )
+            .unwrap() // we don't expect to break here
+    }
+
     pub fn text_event<S>(
         activation: &mut Activation<'_, 'gc>,
         event_type: S,
@@ -174,6 +235,33 @@ impl<'gc> EventObject<'gc> {
             .unwrap() // we don't expect to break here
     }
 
+    pub fn ime_event<S>(
+        activation: &mut Activation<'_, 'gc>,
+        event_type: S,
+        text: AvmString<'gc>,
+    ) -> Object<'gc>
+    where
+        S: Into<AvmString<'gc>>,
+    {
+        let event_type: AvmString<'gc> = event_type.into();
+
+        let ime_event_cls = activation.avm2().classes().imeevent;
+        ime_event_cls
+            .construct(
+                activation,
+                &[
+                    event_type.into(),
+                    // bubbles
+                    false.into(),
+                    // cancelable
+                    false.into(),
+                    // text
+                    text.into(),
+                ],
+            )
+            .unwrap() // we don't expect to break here
+    }
+
     pub fn net_status_event<S>(
         activation: &mut Activation<'_, 'gc>,
         event_type: S,
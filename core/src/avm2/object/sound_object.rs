@@ -35,6 +35,7 @@ pub fn sound_allocator<'gc>(
                 queued_plays: Vec::new(),
             },
             id3: None,
+            extract_position: 0,
         },
     ))
     .into())
@@ -67,6 +68,10 @@ pub struct SoundObjectData<'gc> {
 
     /// ID3Info Object
     id3: Option<Object<'gc>>,
+
+    /// The 44.1kHz sample frame that `Sound.extract` continues from when no
+    /// start position is given.
+    extract_position: u32,
 }
 
 #[derive(Collect)]
@@ -149,6 +154,14 @@ impl<'gc> SoundObject<'gc> {
         this.id3 = id3;
     }
 
+    pub fn extract_position(self) -> u32 {
+        self.0.read().extract_position
+    }
+
+    pub fn set_extract_position(self, mc: &Mutation<'gc>, position: u32) {
+        self.0.write(mc).extract_position = position;
+    }
+
     pub fn read_and_call_id3_event(self, activation: &mut Activation<'_, 'gc>, bytes: &[u8]) {
         let id3 = activation
             .avm2()
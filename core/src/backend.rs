@@ -1,5 +1,7 @@
 pub mod audio;
+pub mod filesystem;
 pub mod log;
 pub mod navigator;
+pub mod printing;
 pub mod storage;
 pub mod ui;
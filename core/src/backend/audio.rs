@@ -23,6 +23,11 @@ mod mixer;
 #[cfg(feature = "audio")]
 pub use mixer::*;
 
+#[cfg(feature = "audio")]
+mod offline;
+#[cfg(feature = "audio")]
+pub use offline::{write_wav, OfflineAudioBackend};
+
 #[cfg(not(feature = "audio"))]
 mod decoders {
     #[derive(Debug, thiserror::Error)]
@@ -129,6 +134,22 @@ pub trait AudioBackend: Downcast {
         stream_info: &SoundStreamInfo,
     ) -> Result<SoundInstanceHandle, DecodeError>;
 
+    /// Decodes part of a registered sound as 44.1kHz stereo samples.
+    ///
+    /// `start_sample_frame` and `num_sample_frames` are measured at 44.1kHz.
+    /// Fewer samples are returned if the sound ends before
+    /// `num_sample_frames` have been decoded.
+    ///
+    /// Backends that can't decode sounds return no samples.
+    fn extract_sound(
+        &self,
+        _sound: SoundHandle,
+        _start_sample_frame: u32,
+        _num_sample_frames: u32,
+    ) -> Result<Vec<[i16; 2]>, DecodeError> {
+        Ok(vec![])
+    }
+
     /// Stops a playing sound instance.
     /// No-op if the sound is not playing.
     fn stop_sound(&mut self, sound: SoundInstanceHandle);
@@ -159,8 +180,9 @@ pub trait AudioBackend: Downcast {
 
     /// Allows the audio backend to update.
     ///
-    /// Runs once per event loop iteration.
-    fn tick(&mut self) {}
+    /// Runs once per event loop iteration, with the time that passed since
+    /// the previous iteration in milliseconds.
+    fn tick(&mut self, _dt: f64) {}
 
     /// Inform the audio backend of the current stage frame rate.
     ///
@@ -1,5 +1,7 @@
 //! Audio decoders.
 
+#[cfg(feature = "aac")]
+mod aac;
 mod adpcm;
 #[cfg(feature = "mp3")]
 mod mp3;
@@ -7,6 +9,8 @@ mod mp3;
 mod nellymoser;
 mod pcm;
 
+#[cfg(feature = "aac")]
+pub use aac::{AacDecoder, AudioSpecificConfig};
 pub use adpcm::AdpcmDecoder;
 #[cfg(feature = "mp3")]
 pub use mp3::{mp3_metadata, Mp3Decoder};
@@ -23,6 +27,10 @@ use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum Error {
+    #[cfg(feature = "aac")]
+    #[error("Couldn't decode AAC")]
+    InvalidAac(#[from] aac::Error),
+
     #[cfg(feature = "mp3")]
     #[error("Couldn't decode MP3")]
     InvalidMp3(#[from] mp3::Error),
@@ -491,12 +499,25 @@ pub fn make_substream_decoder(
     stream_info: &SoundStreamInfo,
     data_stream: Substream,
 ) -> Result<Box<dyn Decoder + Send>, Error> {
-    let decoder: Box<dyn Decoder + Send> =
-        if stream_info.stream_format.compression == AudioCompression::Adpcm {
-            Box::new(AdpcmSubstreamDecoder::new(stream_info, data_stream)?)
-        } else {
-            Box::new(StandardSubstreamDecoder::new(stream_info, data_stream)?)
-        };
+    let decoder: Box<dyn Decoder + Send> = match stream_info.stream_format.compression {
+        AudioCompression::Adpcm => Box::new(AdpcmSubstreamDecoder::new(stream_info, data_stream)?),
+        #[cfg(feature = "aac")]
+        AudioCompression::Aac => Box::new(make_aac_substream_decoder(data_stream)?),
+        _ => Box::new(StandardSubstreamDecoder::new(stream_info, data_stream)?),
+    };
+    Ok(decoder)
+}
+
+/// AAC frames are not self-delimiting, so each chunk of an AAC `Substream`
+/// holds exactly one raw frame. The first chunk instead holds the
+/// `AudioSpecificConfig` that the decoder is configured with.
+#[cfg(feature = "aac")]
+fn make_aac_substream_decoder(
+    data_stream: Substream,
+) -> Result<AacDecoder<SubstreamChunksIter>, Error> {
+    let mut chunks = data_stream.iter_chunks();
+    let config = chunks.next().ok_or(aac::Error::MissingConfig)?;
+    let decoder = AacDecoder::new(&config.data(), chunks)?;
     Ok(decoder)
 }
 
@@ -0,0 +1,267 @@
+use crate::backend::audio::decoders::Decoder;
+use crate::buffer::Slice;
+use bitstream_io::{BigEndian, BitRead, BitReader};
+use symphonia::core::{audio, codecs, errors, formats};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Couldn't decode AAC frame")]
+    FrameDecode(#[from] errors::Error),
+
+    #[error("Missing AudioSpecificConfig")]
+    MissingConfig,
+
+    #[error("Invalid AudioSpecificConfig")]
+    InvalidConfig,
+
+    #[error("Unsupported audio object type {0}")]
+    UnsupportedObjectType(u8),
+
+    #[error("Unsupported sample rate")]
+    UnsupportedSampleRate,
+
+    #[error("Unsupported channel configuration {0}")]
+    UnsupportedChannels(u8),
+}
+
+/// Sample rates that can be signalled by index in an `AudioSpecificConfig`.
+const SAMPLE_RATES: [u32; 13] = [
+    96000, 88200, 64000, 48000, 44100, 32000, 24000, 22050, 16000, 12000, 11025, 8000, 7350,
+];
+
+/// Audio object types, as defined in ISO/IEC 14496-3.
+const AOT_AAC_LC: u8 = 2;
+const AOT_SBR: u8 = 5;
+const AOT_PS: u8 = 29;
+
+/// The parts of an MPEG-4 `AudioSpecificConfig` needed to decode AAC.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AudioSpecificConfig {
+    /// The audio object type of the core AAC stream.
+    ///
+    /// HE-AAC streams that explicitly signal SBR or PS report the type of
+    /// the underlying AAC stream here.
+    pub object_type: u8,
+
+    /// The sample rate of the core AAC stream.
+    pub sample_rate: u32,
+
+    /// The channel configuration; 1 is mono and 2 is stereo.
+    pub channel_config: u8,
+}
+
+impl AudioSpecificConfig {
+    pub fn parse(data: &[u8]) -> Result<Self, Error> {
+        fn read_object_type(reader: &mut BitReader<&[u8], BigEndian>) -> std::io::Result<u8> {
+            let object_type = reader.read::<u8>(5)?;
+            if object_type == 31 {
+                Ok(32 + reader.read::<u8>(6)?)
+            } else {
+                Ok(object_type)
+            }
+        }
+
+        fn read_sample_rate(reader: &mut BitReader<&[u8], BigEndian>) -> std::io::Result<u32> {
+            let index = reader.read::<u8>(4)?;
+            if index == 15 {
+                reader.read::<u32>(24)
+            } else {
+                Ok(SAMPLE_RATES.get(usize::from(index)).copied().unwrap_or(0))
+            }
+        }
+
+        let mut reader = BitReader::endian(data, BigEndian);
+        let mut read = || -> std::io::Result<Self> {
+            let mut object_type = read_object_type(&mut reader)?;
+            let sample_rate = read_sample_rate(&mut reader)?;
+            let channel_config = reader.read::<u8>(4)?;
+            if object_type == AOT_SBR || object_type == AOT_PS {
+                // The extension runs at a higher sample rate, but the core
+                // stream can still be decoded on its own.
+                read_sample_rate(&mut reader)?;
+                object_type = read_object_type(&mut reader)?;
+            }
+
+            Ok(Self {
+                object_type,
+                sample_rate,
+                channel_config,
+            })
+        };
+
+        read().map_err(|_| Error::InvalidConfig)
+    }
+
+    /// Encode a minimal `AudioSpecificConfig` for the core AAC-LC stream.
+    fn to_lc_config(&self) -> Result<[u8; 2], Error> {
+        let index = SAMPLE_RATES
+            .iter()
+            .position(|rate| *rate == self.sample_rate)
+            .ok_or(Error::UnsupportedSampleRate)? as u16;
+        let config =
+            (u16::from(AOT_AAC_LC) << 11) | (index << 7) | (u16::from(self.channel_config) << 3);
+
+        Ok(config.to_be_bytes())
+    }
+}
+
+/// Decoder for raw AAC-LC frames, as carried in FLV and MP4 files.
+///
+/// Each item of `packets` must be a single raw AAC frame.
+pub struct AacDecoder<I> {
+    packets: I,
+    decoder: Box<dyn codecs::Decoder>,
+    sample_buf: audio::SampleBuffer<i16>,
+    cur_sample: usize,
+    sample_rate: u16,
+    num_channels: usize,
+    stream_ended: bool,
+}
+
+impl<I: Iterator<Item = Slice>> AacDecoder<I> {
+    /// AAC-LC frames contain 1024 samples per channel.
+    const SAMPLE_BUFFER_DURATION: u64 = 1024;
+
+    /// Create a decoder configured by the `AudioSpecificConfig` in `config`.
+    pub fn new(config: &[u8], packets: I) -> Result<Self, Error> {
+        let config = AudioSpecificConfig::parse(config)?;
+        if config.object_type != AOT_AAC_LC {
+            return Err(Error::UnsupportedObjectType(config.object_type));
+        }
+        let channels = match config.channel_config {
+            1 => audio::Channels::FRONT_LEFT,
+            2 => audio::Channels::FRONT_LEFT | audio::Channels::FRONT_RIGHT,
+            channel_config => return Err(Error::UnsupportedChannels(channel_config)),
+        };
+        let sample_rate = config
+            .sample_rate
+            .try_into()
+            .map_err(|_| Error::UnsupportedSampleRate)?;
+
+        let mut codec_params = codecs::CodecParameters::new();
+        codec_params
+            .for_codec(codecs::CODEC_TYPE_AAC)
+            .with_sample_rate(config.sample_rate)
+            .with_channels(channels)
+            .with_extra_data(Box::new(config.to_lc_config()?));
+        let decoder = symphonia::default::get_codecs().make(&codec_params, &Default::default())?;
+
+        Ok(Self {
+            packets,
+            decoder,
+            sample_buf: audio::SampleBuffer::new(
+                Self::SAMPLE_BUFFER_DURATION,
+                audio::SignalSpec::new(config.sample_rate, channels),
+            ),
+            cur_sample: 0,
+            sample_rate,
+            num_channels: channels.count(),
+            stream_ended: false,
+        })
+    }
+
+    fn next_frame(&mut self) {
+        if self.stream_ended {
+            return;
+        }
+
+        self.cur_sample = 0;
+        self.sample_buf.clear();
+        for packet in self.packets.by_ref() {
+            let packet = formats::Packet::new_from_slice(0, 0, 0, &packet.data());
+            match self.decoder.decode(&packet) {
+                Ok(decoded) => {
+                    if self.sample_buf.capacity() < decoded.capacity() {
+                        // Ensure our buffer has enough space for the decoded samples.
+                        self.sample_buf = audio::SampleBuffer::new(
+                            decoded.capacity() as symphonia::core::units::Duration,
+                            *decoded.spec(),
+                        );
+                    }
+                    self.sample_buf.copy_interleaved_ref(decoded);
+                    if !self.sample_buf.is_empty() {
+                        return;
+                    }
+                }
+                // Decode errors are not fatal.
+                Err(errors::Error::DecodeError(_)) => (),
+                Err(_) => break,
+            }
+        }
+        // Out of packets.
+        self.stream_ended = true;
+    }
+}
+
+impl<I: Iterator<Item = Slice>> Iterator for AacDecoder<I> {
+    type Item = [i16; 2];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cur_sample >= self.sample_buf.len() {
+            self.next_frame();
+            if self.stream_ended {
+                return None;
+            }
+        }
+
+        let sample_buf = self.sample_buf.samples();
+        let sample_frame = if self.num_channels == 2 {
+            [sample_buf[self.cur_sample], sample_buf[self.cur_sample + 1]]
+        } else {
+            [sample_buf[self.cur_sample], sample_buf[self.cur_sample]]
+        };
+        self.cur_sample += self.num_channels;
+        Some(sample_frame)
+    }
+}
+
+impl<I: Iterator<Item = Slice> + Send + Sync> Decoder for AacDecoder<I> {
+    #[inline]
+    fn num_channels(&self) -> u8 {
+        self.num_channels as u8
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> u16 {
+        self.sample_rate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_audio_specific_config() {
+        // AAC-LC, 44.1kHz, stereo.
+        assert_eq!(
+            AudioSpecificConfig::parse(&[0x12, 0x10]).unwrap(),
+            AudioSpecificConfig {
+                object_type: AOT_AAC_LC,
+                sample_rate: 44100,
+                channel_config: 2,
+            }
+        );
+
+        // HE-AAC with explicit SBR signalling, 22.05kHz core, mono.
+        assert_eq!(
+            AudioSpecificConfig::parse(&[0x2B, 0x8A, 0x08, 0x00]).unwrap(),
+            AudioSpecificConfig {
+                object_type: AOT_AAC_LC,
+                sample_rate: 22050,
+                channel_config: 1,
+            }
+        );
+
+        assert!(AudioSpecificConfig::parse(&[0x12]).is_err());
+    }
+
+    #[test]
+    fn encode_lc_config() {
+        let config = AudioSpecificConfig::parse(&[0x11, 0x90]).unwrap();
+        assert_eq!(config.sample_rate, 48000);
+        assert_eq!(config.to_lc_config().unwrap(), [0x11, 0x90]);
+    }
+}
@@ -332,7 +332,12 @@ impl AudioMixer {
     }
 
     /// Transforms a `Stream` into a new `Stream` that matches the output sample rate.
-    fn make_resampler(&self, mut stream: impl Stream) -> impl Stream {
+    fn make_resampler(&self, stream: impl Stream) -> impl Stream {
+        Self::make_resampler_to(stream, self.output_sample_rate)
+    }
+
+    /// Transforms a `Stream` into a new `Stream` with the given sample rate.
+    fn make_resampler_to(mut stream: impl Stream, output_sample_rate: u32) -> impl Stream {
         // TODO: Allow interpolator to be user-configurable?
         let left = stream.next();
         let right = stream.next();
@@ -342,7 +347,7 @@ impl AudioMixer {
             stream,
             interpolator,
             sample_rate,
-            self.output_sample_rate.into(),
+            output_sample_rate.into(),
         ))
     }
 
@@ -603,6 +608,49 @@ impl AudioMixer {
         Ok(handle)
     }
 
+    /// Decodes part of a registered sound, resampled to 44.1kHz.
+    ///
+    /// `start_sample_frame` and `num_sample_frames` are measured in 44.1kHz
+    /// sample frames. Fewer sample frames are returned if the sound ends first.
+    pub fn extract_sound(
+        &self,
+        sound_handle: SoundHandle,
+        start_sample_frame: u32,
+        num_sample_frames: u32,
+    ) -> Result<Vec<[i16; 2]>, DecodeError> {
+        let Some(sound) = self.sounds.get(sound_handle) else {
+            return Ok(vec![]);
+        };
+        let data = Cursor::new(ArcAsRef(Arc::clone(&sound.data)));
+        let decoder = Self::make_seekable_decoder(&sound.format, data)?;
+
+        let settings = swf::SoundInfo {
+            event: swf::SoundEvent::Start,
+            in_sample: Some(start_sample_frame),
+            out_sample: None,
+            num_loops: 1,
+            envelope: None,
+        };
+        let stream = EventSoundStream::new_with_settings(
+            decoder,
+            &settings,
+            sound.num_sample_frames,
+            sound.skip_sample_frames,
+        );
+        let mut stream = Self::make_resampler_to(stream, 44100);
+
+        let total_sample_frames =
+            u64::from(sound.num_sample_frames) * 44100 / u64::from(sound.format.sample_rate.max(1));
+        let num_sample_frames = u64::from(num_sample_frames)
+            .min(total_sample_frames.saturating_sub(start_sample_frame.into()))
+            as usize;
+        let mut samples = Vec::with_capacity(num_sample_frames);
+        while samples.len() < num_sample_frames && !stream.is_exhausted() {
+            samples.push(stream.next());
+        }
+        Ok(samples)
+    }
+
     /// Starts a `Substream` backed audio stream.
     pub fn start_substream(
         &mut self,
@@ -1099,12 +1147,23 @@ macro_rules! impl_audio_mixer_backend {
         #[inline]
         fn start_substream(
             &mut self,
-            stream_data: ruffle_core::buffer::Substream,
+            stream_data: $crate::buffer::Substream,
             stream_info: &SoundStreamInfo,
         ) -> Result<SoundInstanceHandle, DecodeError> {
             self.$mixer.start_substream(stream_data, stream_info)
         }
 
+        #[inline]
+        fn extract_sound(
+            &self,
+            sound: SoundHandle,
+            start_sample_frame: u32,
+            num_sample_frames: u32,
+        ) -> Result<Vec<[i16; 2]>, DecodeError> {
+            self.$mixer
+                .extract_sound(sound, start_sample_frame, num_sample_frames)
+        }
+
         #[inline]
         fn stop_sound(&mut self, sound: SoundInstanceHandle) {
             self.$mixer.stop_sound(sound)
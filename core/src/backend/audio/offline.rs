@@ -0,0 +1,151 @@
+use super::{
+    swf, AudioBackend, AudioMixer, DecodeError, RegisterError, SoundHandle, SoundInstanceHandle,
+    SoundStreamInfo, SoundTransform,
+};
+use crate::impl_audio_mixer_backend;
+use std::io::{self, Write};
+
+/// An audio backend that mixes audio in lockstep with the player, rather than in real time, and
+/// records the result.
+///
+/// Every call to `tick` mixes as much audio as the time that the tick covers, so the recording
+/// is deterministic regardless of how quickly the movie is run. This is used to export the
+/// soundtrack of a movie, and to compare the audio output of tests.
+pub struct OfflineAudioBackend {
+    mixer: AudioMixer,
+
+    /// The time covered by all ticks so far, in milliseconds.
+    elapsed_time: f64,
+
+    /// The interleaved samples mixed so far.
+    recording: Vec<i16>,
+}
+
+impl OfflineAudioBackend {
+    pub const NUM_CHANNELS: u8 = 2;
+    pub const SAMPLE_RATE: u32 = 44100;
+
+    pub fn new() -> Self {
+        Self {
+            mixer: AudioMixer::new(Self::NUM_CHANNELS, Self::SAMPLE_RATE),
+            elapsed_time: 0.0,
+            recording: vec![],
+        }
+    }
+
+    /// The interleaved stereo samples that have been mixed so far.
+    pub fn recording(&self) -> &[i16] {
+        &self.recording
+    }
+
+    /// Writes the recording as a 16-bit stereo WAV file.
+    pub fn write_wav<W: Write>(&self, writer: W) -> io::Result<()> {
+        write_wav(
+            writer,
+            &self.recording,
+            Self::NUM_CHANNELS.into(),
+            Self::SAMPLE_RATE,
+        )
+    }
+}
+
+impl Default for OfflineAudioBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AudioBackend for OfflineAudioBackend {
+    impl_audio_mixer_backend!(mixer);
+    fn play(&mut self) {}
+    fn pause(&mut self) {}
+
+    fn tick(&mut self, dt: f64) {
+        // Tick durations rarely divide the sample rate evenly, so the length of
+        // the recording follows the total time rather than adding up each tick.
+        // This keeps it in sync with the timeline.
+        self.elapsed_time += dt.max(0.0);
+        let sample_frames = (self.elapsed_time * f64::from(Self::SAMPLE_RATE) / 1000.0).round();
+        let len = sample_frames as usize * usize::from(Self::NUM_CHANNELS);
+
+        let start = self.recording.len();
+        if len > start {
+            self.recording.resize(len, 0);
+            self.mixer.mix::<i16>(&mut self.recording[start..]);
+        }
+    }
+}
+
+/// Writes interleaved 16-bit samples as a WAV file.
+pub fn write_wav<W: Write>(
+    mut writer: W,
+    samples: &[i16],
+    num_channels: u16,
+    sample_rate: u32,
+) -> io::Result<()> {
+    let data_size = u32::try_from(samples.len() * 2)
+        .ok()
+        .filter(|size| *size <= u32::MAX - 36)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Recording is too long"))?;
+    let block_align = num_channels * 2;
+
+    writer.write_all(b"RIFF")?;
+    writer.write_all(&(36 + data_size).to_le_bytes())?;
+    writer.write_all(b"WAVE")?;
+
+    writer.write_all(b"fmt ")?;
+    writer.write_all(&16u32.to_le_bytes())?;
+    // Uncompressed PCM.
+    writer.write_all(&1u16.to_le_bytes())?;
+    writer.write_all(&num_channels.to_le_bytes())?;
+    writer.write_all(&sample_rate.to_le_bytes())?;
+    writer.write_all(&(sample_rate * u32::from(block_align)).to_le_bytes())?;
+    writer.write_all(&block_align.to_le_bytes())?;
+    writer.write_all(&16u16.to_le_bytes())?;
+
+    writer.write_all(b"data")?;
+    writer.write_all(&data_size.to_le_bytes())?;
+    for sample in samples {
+        writer.write_all(&sample.to_le_bytes())?;
+    }
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recorded_sample_frames(tick_durations: impl IntoIterator<Item = f64>) -> usize {
+        let mut backend = OfflineAudioBackend::new();
+        for dt in tick_durations {
+            backend.tick(dt);
+        }
+        backend.recording().len() / usize::from(OfflineAudioBackend::NUM_CHANNELS)
+    }
+
+    #[test]
+    fn tick_follows_elapsed_time() {
+        assert_eq!(recorded_sample_frames([10.0]), 441);
+        assert_eq!(recorded_sample_frames([25.0; 4]), 4410);
+        assert_eq!(recorded_sample_frames([5.0, 20.0, 0.0, 75.0]), 4410);
+
+        // A second at 60 frames per second doesn't drift, even though a frame
+        // isn't a whole number of samples.
+        assert_eq!(recorded_sample_frames([1000.0 / 60.0; 60]), 44100);
+        assert_eq!(recorded_sample_frames([1000.0 / 24.0; 24]), 44100);
+        assert_eq!(recorded_sample_frames([1000.0 / 30.0; 3]), 4410);
+    }
+
+    #[test]
+    fn wav_header() {
+        let mut wav = vec![];
+        write_wav(&mut wav, &[1, -1, 2, -2], 2, 44100).unwrap();
+        assert_eq!(wav.len(), 44 + 8);
+        assert_eq!(&wav[..4], b"RIFF");
+        assert_eq!(&wav[4..8], &44u32.to_le_bytes());
+        assert_eq!(&wav[36..40], b"data");
+        assert_eq!(&wav[40..44], &8u32.to_le_bytes());
+        assert_eq!(&wav[44..46], &1i16.to_le_bytes());
+        assert_eq!(&wav[46..48], &(-1i16).to_le_bytes());
+    }
+}
@@ -0,0 +1,306 @@
+//! Sandboxed filesystem access, used by the AIR `flash.filesystem` package.
+//!
+//! All paths given to a `FilesystemBackend` are virtual: they are absolute,
+//! use `/` as the separator and never contain `.` or `..` components
+//! (see `normalize_path`). How they map to real files is up to the backend.
+//!
+//! The directories that AIR exposes through `File` are top-level directories
+//! of this tree, such as `/app-storage` for `File.applicationStorageDirectory`.
+
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum FilesystemError {
+    #[error("No such file or directory")]
+    NotFound,
+
+    #[error("File or directory already exists")]
+    AlreadyExists,
+
+    #[error("Not a directory")]
+    NotADirectory,
+
+    #[error("Is a directory")]
+    IsADirectory,
+
+    #[error("Directory is not empty")]
+    DirectoryNotEmpty,
+
+    #[error("Path is outside of the sandbox")]
+    InvalidPath,
+
+    #[error("I/O error: {0}")]
+    Io(String),
+}
+
+/// Information about a file or directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileMetadata {
+    pub is_directory: bool,
+    pub size: u64,
+    pub creation_time: Option<DateTime<Utc>>,
+    pub modification_time: Option<DateTime<Utc>>,
+}
+
+pub trait FilesystemBackend {
+    /// Returns information about the file or directory at the given path,
+    /// or `None` if nothing exists there.
+    fn metadata(&self, path: &str) -> Option<FileMetadata>;
+
+    /// Reads the whole contents of a file.
+    fn read(&self, path: &str) -> Result<Vec<u8>, FilesystemError>;
+
+    /// Replaces the contents of a file, creating it and any missing parent
+    /// directories if needed.
+    fn write(&mut self, path: &str, data: &[u8]) -> Result<(), FilesystemError>;
+
+    /// Creates a directory and any missing parent directories.
+    ///
+    /// Succeeds if the directory already exists.
+    fn create_directory(&mut self, path: &str) -> Result<(), FilesystemError>;
+
+    /// Returns the names of the entries of a directory.
+    fn read_directory(&self, path: &str) -> Result<Vec<String>, FilesystemError>;
+
+    /// Deletes a file.
+    fn delete_file(&mut self, path: &str) -> Result<(), FilesystemError>;
+
+    /// Deletes a directory, and all of its contents if `recursive` is set.
+    fn delete_directory(&mut self, path: &str, recursive: bool) -> Result<(), FilesystemError>;
+
+    /// Moves a file or directory, replacing any file at the destination.
+    fn rename(&mut self, from: &str, to: &str) -> Result<(), FilesystemError>;
+}
+
+/// Resolves the `.` and `..` components of a path, and makes it absolute.
+///
+/// Both `/` and `\` are accepted as separators. Returns `None` if the path
+/// goes above the root.
+pub fn normalize_path(path: &str) -> Option<String> {
+    let mut components = Vec::new();
+    for component in path.split(['/', '\\']) {
+        match component {
+            "" | "." => {}
+            ".." => {
+                components.pop()?;
+            }
+            component => components.push(component),
+        }
+    }
+    Some(format!("/{}", components.join("/")))
+}
+
+/// Returns the parent directory of a normalized path, or `None` for the root.
+pub fn parent_path(path: &str) -> Option<&str> {
+    let index = path.rfind('/')?;
+    if path.len() == 1 {
+        None
+    } else if index == 0 {
+        Some("/")
+    } else {
+        Some(&path[..index])
+    }
+}
+
+#[derive(Debug, Clone)]
+enum MemoryEntry {
+    File(Vec<u8>),
+    Directory,
+}
+
+/// A filesystem that only lives as long as the player.
+pub struct MemoryFilesystemBackend {
+    entries: BTreeMap<String, MemoryEntry>,
+}
+
+impl MemoryFilesystemBackend {
+    pub fn new() -> Self {
+        let mut entries = BTreeMap::new();
+        entries.insert("/".to_string(), MemoryEntry::Directory);
+        Self { entries }
+    }
+
+    /// Returns the paths of the entries in the given directory and all of its subdirectories.
+    fn descendants<'a>(&'a self, path: &str) -> impl Iterator<Item = &'a String> {
+        let prefix = if path == "/" {
+            "/".to_string()
+        } else {
+            format!("{path}/")
+        };
+        self.entries
+            .range(prefix.clone()..)
+            .map(|(path, _)| path)
+            .take_while(move |path| path.starts_with(&prefix))
+    }
+}
+
+impl Default for MemoryFilesystemBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FilesystemBackend for MemoryFilesystemBackend {
+    fn metadata(&self, path: &str) -> Option<FileMetadata> {
+        let (is_directory, size) = match self.entries.get(path)? {
+            MemoryEntry::File(data) => (false, data.len() as u64),
+            MemoryEntry::Directory => (true, 0),
+        };
+        Some(FileMetadata {
+            is_directory,
+            size,
+            creation_time: None,
+            modification_time: None,
+        })
+    }
+
+    fn read(&self, path: &str) -> Result<Vec<u8>, FilesystemError> {
+        match self.entries.get(path) {
+            Some(MemoryEntry::File(data)) => Ok(data.clone()),
+            Some(MemoryEntry::Directory) => Err(FilesystemError::IsADirectory),
+            None => Err(FilesystemError::NotFound),
+        }
+    }
+
+    fn write(&mut self, path: &str, data: &[u8]) -> Result<(), FilesystemError> {
+        if let Some(MemoryEntry::Directory) = self.entries.get(path) {
+            return Err(FilesystemError::IsADirectory);
+        }
+        let parent = parent_path(path).ok_or(FilesystemError::IsADirectory)?;
+        self.create_directory(parent)?;
+        self.entries
+            .insert(path.to_string(), MemoryEntry::File(data.to_vec()));
+        Ok(())
+    }
+
+    fn create_directory(&mut self, path: &str) -> Result<(), FilesystemError> {
+        match self.entries.get(path) {
+            Some(MemoryEntry::Directory) => return Ok(()),
+            Some(MemoryEntry::File(_)) => return Err(FilesystemError::AlreadyExists),
+            None => {}
+        }
+        if let Some(parent) = parent_path(path) {
+            self.create_directory(parent)?;
+        }
+        self.entries
+            .insert(path.to_string(), MemoryEntry::Directory);
+        Ok(())
+    }
+
+    fn read_directory(&self, path: &str) -> Result<Vec<String>, FilesystemError> {
+        match self.entries.get(path) {
+            Some(MemoryEntry::Directory) => {}
+            Some(MemoryEntry::File(_)) => return Err(FilesystemError::NotADirectory),
+            None => return Err(FilesystemError::NotFound),
+        }
+        Ok(self
+            .descendants(path)
+            .filter(|child| parent_path(child) == Some(path))
+            .filter_map(|child| child.rsplit('/').next())
+            .map(str::to_string)
+            .collect())
+    }
+
+    fn delete_file(&mut self, path: &str) -> Result<(), FilesystemError> {
+        match self.entries.get(path) {
+            Some(MemoryEntry::File(_)) => {
+                self.entries.remove(path);
+                Ok(())
+            }
+            Some(MemoryEntry::Directory) => Err(FilesystemError::IsADirectory),
+            None => Err(FilesystemError::NotFound),
+        }
+    }
+
+    fn delete_directory(&mut self, path: &str, recursive: bool) -> Result<(), FilesystemError> {
+        match self.entries.get(path) {
+            Some(MemoryEntry::Directory) if path != "/" => {}
+            Some(MemoryEntry::Directory) => return Err(FilesystemError::InvalidPath),
+            Some(MemoryEntry::File(_)) => return Err(FilesystemError::NotADirectory),
+            None => return Err(FilesystemError::NotFound),
+        }
+        let descendants: Vec<String> = self.descendants(path).cloned().collect();
+        if !descendants.is_empty() && !recursive {
+            return Err(FilesystemError::DirectoryNotEmpty);
+        }
+        for descendant in descendants {
+            self.entries.remove(&descendant);
+        }
+        self.entries.remove(path);
+        Ok(())
+    }
+
+    fn rename(&mut self, from: &str, to: &str) -> Result<(), FilesystemError> {
+        if from == "/" || to == "/" || to.starts_with(&format!("{from}/")) {
+            return Err(FilesystemError::InvalidPath);
+        }
+        let entry = self
+            .entries
+            .get(from)
+            .cloned()
+            .ok_or(FilesystemError::NotFound)?;
+        if let Some(MemoryEntry::Directory) = self.entries.get(to) {
+            return Err(FilesystemError::AlreadyExists);
+        }
+        if let Some(parent) = parent_path(to) {
+            self.create_directory(parent)?;
+        }
+
+        let descendants: Vec<String> = self.descendants(from).cloned().collect();
+        for descendant in descendants {
+            if let Some(entry) = self.entries.remove(&descendant) {
+                let new_path = format!("{to}{}", &descendant[from.len()..]);
+                self.entries.insert(new_path, entry);
+            }
+        }
+        self.entries.remove(from);
+        self.entries.insert(to.to_string(), entry);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize() {
+        assert_eq!(normalize_path("").as_deref(), Some("/"));
+        assert_eq!(normalize_path("/a/./b//c/").as_deref(), Some("/a/b/c"));
+        assert_eq!(normalize_path("a\\b\\..\\c").as_deref(), Some("/a/c"));
+        assert_eq!(normalize_path("/a/../.."), None);
+    }
+
+    #[test]
+    fn parent() {
+        assert_eq!(parent_path("/"), None);
+        assert_eq!(parent_path("/a"), Some("/"));
+        assert_eq!(parent_path("/a/b"), Some("/a"));
+    }
+
+    #[test]
+    fn memory_backend() {
+        let mut fs = MemoryFilesystemBackend::new();
+        fs.write("/app-storage/saves/1.dat", b"save").unwrap();
+        assert!(fs.metadata("/app-storage/saves").unwrap().is_directory);
+        assert_eq!(fs.metadata("/app-storage/saves/1.dat").unwrap().size, 4);
+        assert_eq!(fs.read_directory("/app-storage").unwrap(), vec!["saves"]);
+
+        fs.rename("/app-storage/saves", "/app-storage/old").unwrap();
+        assert_eq!(fs.read("/app-storage/old/1.dat").unwrap(), b"save");
+        assert_eq!(
+            fs.read("/app-storage/saves/1.dat"),
+            Err(FilesystemError::NotFound)
+        );
+
+        assert_eq!(
+            fs.delete_directory("/app-storage", false),
+            Err(FilesystemError::DirectoryNotEmpty)
+        );
+        fs.delete_directory("/app-storage", true).unwrap();
+        assert!(fs.metadata("/app-storage/old/1.dat").is_none());
+        assert_eq!(fs.read_directory("/").unwrap(), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,504 @@
+//! Printing, used by `PrintJob`.
+//!
+//! Pages are rasterised by the player before they reach the backend, so a
+//! backend only has to deal with bitmaps. Sizes of paper are in points
+//! (1/72 inch), which is also the size of a pixel of printed content.
+
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use std::fs::File;
+use std::io::{ErrorKind, Write};
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PrintError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Unable to encode page: {0}")]
+    Encoding(String),
+
+    #[error("Every file name for {0} is already used")]
+    NoUnusedPath(PathBuf),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrintOrientation {
+    Portrait,
+    Landscape,
+}
+
+impl PrintOrientation {
+    /// The name of this orientation in `PrintJobOrientation`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PrintOrientation::Portrait => "portrait",
+            PrintOrientation::Landscape => "landscape",
+        }
+    }
+}
+
+/// The paper that a print job prints on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaperFormat {
+    /// The width of the paper, in points.
+    pub paper_width: u32,
+
+    /// The height of the paper, in points.
+    pub paper_height: u32,
+
+    /// The width of the printable area of the paper, in points.
+    /// This area is centered on the paper.
+    pub page_width: u32,
+
+    /// The height of the printable area of the paper, in points.
+    pub page_height: u32,
+
+    pub orientation: PrintOrientation,
+
+    /// The resolution that pages are rasterised at.
+    pub pixels_per_inch: u32,
+}
+
+impl PaperFormat {
+    /// A portrait A4 paper, without margins.
+    pub const A4: Self = Self::new(595, 842, 150);
+
+    /// A portrait US Letter paper, without margins.
+    pub const LETTER: Self = Self::new(612, 792, 150);
+
+    /// Creates a portrait paper of the given size in points, without margins.
+    pub const fn new(width: u32, height: u32, pixels_per_inch: u32) -> Self {
+        Self {
+            paper_width: width,
+            paper_height: height,
+            page_width: width,
+            page_height: height,
+            orientation: PrintOrientation::Portrait,
+            pixels_per_inch,
+        }
+    }
+
+    /// Turns this paper to the given orientation.
+    pub fn with_orientation(mut self, orientation: PrintOrientation) -> Self {
+        if orientation != self.orientation {
+            std::mem::swap(&mut self.paper_width, &mut self.paper_height);
+            std::mem::swap(&mut self.page_width, &mut self.page_height);
+            self.orientation = orientation;
+        }
+        self
+    }
+
+    /// The size of a rasterised page, in pixels.
+    pub fn page_pixel_size(&self) -> (u32, u32) {
+        let to_pixels = |points: u32| (points * self.pixels_per_inch).div_ceil(72).max(1);
+        (to_pixels(self.page_width), to_pixels(self.page_height))
+    }
+}
+
+/// A rasterised page, covering the printable area of the paper.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrintPage {
+    pub width: u32,
+    pub height: u32,
+
+    /// The RGB pixels of the page, row by row.
+    pub pixels: Vec<u8>,
+}
+
+pub trait PrintBackend {
+    /// Whether there is anything to print to, as reported by `PrintJob.isSupported`.
+    fn is_supported(&self) -> bool {
+        true
+    }
+
+    /// Starts a print job, which usually asks the user to pick a printer.
+    ///
+    /// Returns the paper to print on, or `None` if the job was cancelled.
+    fn start_job(&mut self) -> Option<PaperFormat>;
+
+    /// Prints the pages of a job that was started with `start_job`.
+    fn print(&mut self, paper: &PaperFormat, pages: Vec<PrintPage>) -> Result<(), PrintError>;
+}
+
+/// A print backend that has no printer, so every print job is cancelled.
+#[derive(Default)]
+pub struct NullPrintBackend;
+
+impl NullPrintBackend {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl PrintBackend for NullPrintBackend {
+    fn is_supported(&self) -> bool {
+        false
+    }
+
+    fn start_job(&mut self) -> Option<PaperFormat> {
+        None
+    }
+
+    fn print(&mut self, _paper: &PaperFormat, _pages: Vec<PrintPage>) -> Result<(), PrintError> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrintFileFormat {
+    /// A single PDF document per job.
+    Pdf,
+
+    /// A PNG image per page.
+    Png,
+}
+
+/// A print backend that prints to files in a directory instead of a printer,
+/// so that printing also works headlessly.
+pub struct FilePrintBackend {
+    directory: PathBuf,
+    file_format: PrintFileFormat,
+    paper: PaperFormat,
+    printed_files: Vec<PathBuf>,
+}
+
+impl FilePrintBackend {
+    /// How many files can share the same name, before their counter.
+    const MAX_FILES_PER_NAME: u32 = 9999;
+
+    pub fn new(directory: PathBuf, file_format: PrintFileFormat) -> Self {
+        Self {
+            directory,
+            file_format,
+            paper: PaperFormat::A4,
+            printed_files: Vec::new(),
+        }
+    }
+
+    /// Sets the paper that jobs are printed on.
+    pub fn with_paper(mut self, paper: PaperFormat) -> Self {
+        self.paper = paper;
+        self
+    }
+
+    /// The files that the last job was printed to, in the order of its pages.
+    pub fn printed_files(&self) -> &[PathBuf] {
+        &self.printed_files
+    }
+
+    /// Creates a file in the output directory that doesn't exist yet,
+    /// named after the given name and a counter.
+    fn create_file(&self, name: &str, extension: &str) -> Result<(File, PathBuf), PrintError> {
+        for n in 1..=Self::MAX_FILES_PER_NAME {
+            let path = self.directory.join(format!("{name}-{n}.{extension}"));
+            match File::options().write(true).create_new(true).open(&path) {
+                Ok(file) => return Ok((file, path)),
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Err(PrintError::NoUnusedPath(
+            self.directory.join(format!("{name}.{extension}")),
+        ))
+    }
+
+    fn write_file(&mut self, name: &str, extension: &str, data: &[u8]) -> Result<(), PrintError> {
+        let (mut file, path) = self.create_file(name, extension)?;
+        file.write_all(data)?;
+        tracing::info!("Printed to {}", path.display());
+        self.printed_files.push(path);
+        Ok(())
+    }
+}
+
+impl PrintBackend for FilePrintBackend {
+    fn start_job(&mut self) -> Option<PaperFormat> {
+        Some(self.paper)
+    }
+
+    fn print(&mut self, paper: &PaperFormat, pages: Vec<PrintPage>) -> Result<(), PrintError> {
+        self.printed_files.clear();
+        std::fs::create_dir_all(&self.directory)?;
+        let name = chrono::Local::now()
+            .format("print-%Y-%m-%d-%H%M%S")
+            .to_string();
+
+        match self.file_format {
+            PrintFileFormat::Pdf => self.write_file(&name, "pdf", &encode_pdf(paper, &pages)?),
+            PrintFileFormat::Png => {
+                for page in &pages {
+                    self.write_file(&name, "png", &encode_png(page)?)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Encodes a page as a PNG image.
+fn encode_png(page: &PrintPage) -> Result<Vec<u8>, PrintError> {
+    use image::codecs::png::PngEncoder;
+    use image::{ExtendedColorType, ImageEncoder};
+
+    let mut data = Vec::new();
+    PngEncoder::new(&mut data)
+        .write_image(
+            &page.pixels,
+            page.width,
+            page.height,
+            ExtendedColorType::Rgb8,
+        )
+        .map_err(|e| PrintError::Encoding(e.to_string()))?;
+    Ok(data)
+}
+
+/// Encodes pages as a PDF document, with one page per sheet of paper.
+///
+/// Each page is an image stretched over the printable area of its sheet.
+fn encode_pdf(paper: &PaperFormat, pages: &[PrintPage]) -> Result<Vec<u8>, PrintError> {
+    // Objects 1 and 2 are the catalog and the page tree, and each page is
+    // made of three objects: the page itself, its content and its image.
+    let page_id = |index: usize| 3 + index * 3;
+
+    let mut objects: Vec<Vec<u8>> = vec![
+        b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            (0..pages.len())
+                .map(|index| format!("{} 0 R", page_id(index)))
+                .collect::<Vec<_>>()
+                .join(" "),
+            pages.len()
+        )
+        .into_bytes(),
+    ];
+
+    let x = (paper.paper_width - paper.page_width.min(paper.paper_width)) / 2;
+    let y = (paper.paper_height - paper.page_height.min(paper.paper_height)) / 2;
+    for (index, page) in pages.iter().enumerate() {
+        let id = page_id(index);
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+                 /Resources << /XObject << /Page {} 0 R >> >> /Contents {} 0 R >>",
+                paper.paper_width,
+                paper.paper_height,
+                id + 2,
+                id + 1
+            )
+            .into_bytes(),
+        );
+
+        let content = format!(
+            "q {} 0 0 {} {x} {y} cm /Page Do Q",
+            paper.page_width, paper.page_height
+        );
+        objects.push(pdf_stream("", content.as_bytes()));
+
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&page.pixels)?;
+        let dictionary = format!(
+            "/Type /XObject /Subtype /Image /Width {} /Height {} \
+             /ColorSpace /DeviceRGB /BitsPerComponent 8 /Filter /FlateDecode",
+            page.width, page.height
+        );
+        objects.push(pdf_stream(&dictionary, &encoder.finish()?));
+    }
+
+    let mut data = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(data.len());
+        data.extend_from_slice(format!("{} 0 obj\n", index + 1).as_bytes());
+        data.extend_from_slice(object);
+        data.extend_from_slice(b"\nendobj\n");
+    }
+
+    let xref_offset = data.len();
+    data.extend_from_slice(format!("xref\n0 {}\n", objects.len() + 1).as_bytes());
+    data.extend_from_slice(b"0000000000 65535 f \n");
+    for offset in offsets {
+        data.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+    }
+    data.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+            objects.len() + 1
+        )
+        .as_bytes(),
+    );
+    Ok(data)
+}
+
+fn pdf_stream(dictionary: &str, data: &[u8]) -> Vec<u8> {
+    let mut stream = format!("<< {dictionary} /Length {} >>\nstream\n", data.len()).into_bytes();
+    stream.extend_from_slice(data);
+    stream.extend_from_slice(b"\nendstream");
+    stream
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paper_orientation() {
+        let paper = PaperFormat::A4.with_orientation(PrintOrientation::Landscape);
+        assert_eq!((paper.paper_width, paper.paper_height), (842, 595));
+        assert_eq!((paper.page_width, paper.page_height), (842, 595));
+        assert_eq!(paper.orientation.as_str(), "landscape");
+        assert_eq!(
+            paper.with_orientation(PrintOrientation::Portrait),
+            PaperFormat::A4
+        );
+        assert_eq!(PaperFormat::new(72, 144, 150).page_pixel_size(), (150, 300));
+    }
+
+    /// A page whose pixels are all different, so that flipped or shifted
+    /// pages don't compare equal.
+    fn page(width: u32, height: u32) -> PrintPage {
+        PrintPage {
+            width,
+            height,
+            pixels: (0..width * height * 3).map(|i| (i * 7) as u8).collect(),
+        }
+    }
+
+    fn find(data: &[u8], text: &str) -> Option<usize> {
+        data.windows(text.len()).position(|w| w == text.as_bytes())
+    }
+
+    /// Decodes the image of every page of a PDF written by `encode_pdf`.
+    fn pdf_images(pdf: &[u8]) -> Vec<Vec<u8>> {
+        use flate2::read::ZlibDecoder;
+        use std::io::Read;
+
+        let mut images = vec![];
+        let mut rest = pdf;
+        while let Some(start) = find(rest, "/Subtype /Image") {
+            rest = &rest[start..];
+            let length = find(rest, "/Length ").unwrap() + "/Length ".len();
+            let end = length + find(&rest[length..], " ").unwrap();
+            let length: usize = std::str::from_utf8(&rest[length..end])
+                .unwrap()
+                .parse()
+                .unwrap();
+            let stream = find(rest, "stream\n").unwrap() + "stream\n".len();
+            let mut image = vec![];
+            ZlibDecoder::new(&rest[stream..stream + length])
+                .read_to_end(&mut image)
+                .unwrap();
+            images.push(image);
+            rest = &rest[stream + length..];
+        }
+        images
+    }
+
+    #[test]
+    fn pdf_cross_references() {
+        let pdf = encode_pdf(&PaperFormat::A4, &[page(4, 3), page(2, 2)]).unwrap();
+        assert!(pdf.starts_with(b"%PDF-1.4\n"));
+        assert!(pdf.ends_with(b"%%EOF\n"));
+        assert!(find(&pdf, "/Kids [3 0 R 6 0 R] /Count 2").is_some());
+        assert!(find(&pdf, "/MediaBox [0 0 595 842]").is_some());
+
+        // Every entry of the cross-reference table points to its object.
+        let xref = pdf.windows(6).rposition(|w| w == b"\nxref\n").unwrap() + 1;
+        let trailer = std::str::from_utf8(&pdf[xref..]).unwrap();
+        for (index, entry) in trailer.lines().skip(3).take(8).enumerate() {
+            let offset: usize = entry[..10].parse().unwrap();
+            assert!(pdf[offset..].starts_with(format!("{} 0 obj\n", index + 1).as_bytes()));
+        }
+        let start_xref: usize = trailer.lines().rev().nth(1).unwrap().parse().unwrap();
+        assert_eq!(start_xref, xref);
+    }
+
+    #[test]
+    fn pdf_pages() {
+        let paper = PaperFormat {
+            page_width: 500,
+            page_height: 800,
+            ..PaperFormat::A4
+        };
+        let pages = [page(4, 3), page(2, 2)];
+        let pdf = encode_pdf(&paper, &pages).unwrap();
+        assert!(find(&pdf, "/Width 4 /Height 3").is_some());
+        assert!(find(&pdf, "/Width 2 /Height 2").is_some());
+        // The printable area is centered on the paper.
+        assert!(find(&pdf, "q 500 0 0 800 47 21 cm /Page Do Q").is_some());
+        assert_eq!(
+            pdf_images(&pdf),
+            pages
+                .map(|page| page.pixels)
+                .into_iter()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn png_page() {
+        let page = page(3, 2);
+        let png = encode_png(&page).unwrap();
+        let image = image::load_from_memory(&png).unwrap().to_rgb8();
+        assert_eq!(image.dimensions(), (3, 2));
+        assert_eq!(image.into_raw(), page.pixels);
+    }
+
+    #[test]
+    fn file_backend_png() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut backend =
+            FilePrintBackend::new(directory.path().to_path_buf(), PrintFileFormat::Png)
+                .with_paper(PaperFormat::LETTER);
+        assert_eq!(backend.start_job(), Some(PaperFormat::LETTER));
+
+        let pages = [page(4, 3), page(2, 2)];
+        backend.print(&PaperFormat::LETTER, pages.to_vec()).unwrap();
+        let files = backend.printed_files().to_vec();
+        assert_eq!(files.len(), 2);
+        for (file, page) in files.iter().zip(&pages) {
+            let image = image::open(file).unwrap().to_rgb8();
+            assert_eq!(image.into_raw(), page.pixels);
+        }
+
+        // Printing again never overwrites the files of another job.
+        backend
+            .print(&PaperFormat::LETTER, vec![page(1, 1)])
+            .unwrap();
+        assert!(!files.contains(&backend.printed_files()[0]));
+        assert_eq!(std::fs::read_dir(directory.path()).unwrap().count(), 3);
+    }
+
+    #[test]
+    fn file_backend_pdf() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut backend =
+            FilePrintBackend::new(directory.path().join("prints"), PrintFileFormat::Pdf);
+        assert_eq!(backend.start_job(), Some(PaperFormat::A4));
+
+        let pages = [page(4, 3), page(2, 2)];
+        backend.print(&PaperFormat::A4, pages.to_vec()).unwrap();
+        let [file] = backend.printed_files() else {
+            panic!("A job should be printed to a single PDF");
+        };
+        assert_eq!(file.extension().unwrap(), "pdf");
+        let pdf = std::fs::read(file).unwrap();
+        assert_eq!(pdf, encode_pdf(&PaperFormat::A4, &pages).unwrap());
+    }
+
+    #[test]
+    fn file_backend_unused_path() {
+        let directory = tempfile::tempdir().unwrap();
+        let backend = FilePrintBackend::new(directory.path().to_path_buf(), PrintFileFormat::Png);
+        std::fs::write(directory.path().join("print-1.png"), b"").unwrap();
+        let (_, path) = backend.create_file("print", "png").unwrap();
+        assert_eq!(path, directory.path().join("print-2.png"));
+
+        // A directory that can't be created is an error, not a panic.
+        let backend = FilePrintBackend::new(path.join("prints"), PrintFileFormat::Png);
+        assert!(backend.create_file("print", "png").is_err());
+    }
+}
@@ -1,12 +1,16 @@
 use crate::backend::navigator::OwnedFuture;
 use crate::events::{KeyCode, PlayerEvent, TextControlCode};
 pub use crate::loader::Error as DialogLoaderError;
+use crate::player::ParseEnumError;
+use crate::string::{FromWStr, WStr};
 use chrono::{DateTime, Utc};
 use downcast_rs::Downcast;
 use fluent_templates::loader::langid;
 pub use fluent_templates::LanguageIdentifier;
+use ruffle_render::bitmap::Bitmap;
 use std::borrow::Cow;
 use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
 use url::Url;
 
 pub type FullscreenError = Cow<'static, str>;
@@ -76,6 +80,19 @@ pub trait UiBackend: Downcast {
     /// Sets the clipboard to the given content.
     fn set_clipboard_content(&mut self, content: String);
 
+    /// Returns the formats of the data on the clipboard.
+    fn clipboard_formats(&mut self) -> Vec<ClipboardFormat>;
+
+    /// Returns the data on the clipboard in the given format, if available.
+    fn clipboard_data(&mut self, format: ClipboardFormat) -> Option<ClipboardData>;
+
+    /// Adds data to the clipboard, replacing any data in the same format.
+    fn set_clipboard_data(&mut self, data: ClipboardData);
+
+    /// Removes the data in the given format from the clipboard,
+    /// or all data if no format is given.
+    fn clear_clipboard_data(&mut self, format: Option<ClipboardFormat>);
+
     fn set_fullscreen(&mut self, is_full: bool) -> Result<(), FullscreenError>;
 
     /// Displays a message about an error during root movie download.
@@ -124,9 +141,151 @@ pub trait UiBackend: Downcast {
 
     /// Mark that any previously open dialog has been closed
     fn close_file_dialog(&mut self);
+
+    /// Whether the input method editor (IME) is enabled for text input.
+    fn ime_enabled(&self) -> bool;
+
+    /// Enables or disables the input method editor (IME) of the system.
+    fn set_ime_enabled(&mut self, enabled: bool);
+
+    /// The current conversion mode of the input method editor.
+    fn ime_conversion_mode(&self) -> ImeConversionMode;
+
+    /// Changes the conversion mode of the input method editor, if supported.
+    fn set_ime_conversion_mode(&mut self, mode: ImeConversionMode);
 }
 impl_downcast!(UiBackend);
 
+/// The conversion mode of an input method editor.
+/// Equivalent to AS3 `IMEConversionMode`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ImeConversionMode {
+    AlphanumericFull,
+    #[default]
+    AlphanumericHalf,
+    Chinese,
+    JapaneseHiragana,
+    JapaneseKatakanaFull,
+    JapaneseKatakanaHalf,
+    Korean,
+    Unknown,
+}
+
+impl Display for ImeConversionMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // Match string values returned by AS.
+        let s = match *self {
+            ImeConversionMode::AlphanumericFull => "ALPHANUMERIC_FULL",
+            ImeConversionMode::AlphanumericHalf => "ALPHANUMERIC_HALF",
+            ImeConversionMode::Chinese => "CHINESE",
+            ImeConversionMode::JapaneseHiragana => "JAPANESE_HIRAGANA",
+            ImeConversionMode::JapaneseKatakanaFull => "JAPANESE_KATAKANA_FULL",
+            ImeConversionMode::JapaneseKatakanaHalf => "JAPANESE_KATAKANA_HALF",
+            ImeConversionMode::Korean => "KOREAN",
+            ImeConversionMode::Unknown => "UNKNOWN",
+        };
+        f.write_str(s)
+    }
+}
+
+impl FromWStr for ImeConversionMode {
+    type Err = ParseEnumError;
+
+    fn from_wstr(s: &WStr) -> Result<Self, Self::Err> {
+        if s == b"ALPHANUMERIC_FULL" {
+            Ok(ImeConversionMode::AlphanumericFull)
+        } else if s == b"ALPHANUMERIC_HALF" {
+            Ok(ImeConversionMode::AlphanumericHalf)
+        } else if s == b"CHINESE" {
+            Ok(ImeConversionMode::Chinese)
+        } else if s == b"JAPANESE_HIRAGANA" {
+            Ok(ImeConversionMode::JapaneseHiragana)
+        } else if s == b"JAPANESE_KATAKANA_FULL" {
+            Ok(ImeConversionMode::JapaneseKatakanaFull)
+        } else if s == b"JAPANESE_KATAKANA_HALF" {
+            Ok(ImeConversionMode::JapaneseKatakanaHalf)
+        } else if s == b"KOREAN" {
+            Ok(ImeConversionMode::Korean)
+        } else if s == b"UNKNOWN" {
+            Ok(ImeConversionMode::Unknown)
+        } else {
+            Err(ParseEnumError)
+        }
+    }
+}
+
+/// A format of data on the clipboard.
+/// Equivalent to AS3 `ClipboardFormats`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ClipboardFormat {
+    Text,
+    Html,
+    RichText,
+    Url,
+    Bitmap,
+}
+
+impl Display for ClipboardFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // Match string values returned by AS.
+        let s = match *self {
+            ClipboardFormat::Text => "air:text",
+            ClipboardFormat::Html => "air:html",
+            ClipboardFormat::RichText => "air:rtf",
+            ClipboardFormat::Url => "air:url",
+            ClipboardFormat::Bitmap => "air:bitmap",
+        };
+        f.write_str(s)
+    }
+}
+
+impl FromWStr for ClipboardFormat {
+    type Err = ParseEnumError;
+
+    fn from_wstr(s: &WStr) -> Result<Self, Self::Err> {
+        if s == b"air:text" {
+            Ok(ClipboardFormat::Text)
+        } else if s == b"air:html" {
+            Ok(ClipboardFormat::Html)
+        } else if s == b"air:rtf" {
+            Ok(ClipboardFormat::RichText)
+        } else if s == b"air:url" {
+            Ok(ClipboardFormat::Url)
+        } else if s == b"air:bitmap" {
+            Ok(ClipboardFormat::Bitmap)
+        } else {
+            Err(ParseEnumError)
+        }
+    }
+}
+
+/// Data on the clipboard, in one of the `ClipboardFormat`s.
+#[derive(Clone, Debug)]
+pub enum ClipboardData {
+    Text(String),
+    Html(String),
+
+    /// A document in the Rich Text Format (RTF).
+    RichText(Vec<u8>),
+
+    Url(String),
+
+    /// An image, in the `Rgba` format.
+    Bitmap(Bitmap),
+}
+
+impl ClipboardData {
+    pub fn format(&self) -> ClipboardFormat {
+        match self {
+            ClipboardData::Text(_) => ClipboardFormat::Text,
+            ClipboardData::Html(_) => ClipboardFormat::Html,
+            ClipboardData::RichText(_) => ClipboardFormat::RichText,
+            ClipboardData::Url(_) => ClipboardFormat::Url,
+            ClipboardData::Bitmap(_) => ClipboardFormat::Bitmap,
+        }
+    }
+}
+
 /// A mouse cursor icon displayed by the Flash Player.
 /// Communicated from the core to the UI backend via `UiBackend::set_mouse_cursor`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -271,6 +430,18 @@ impl UiBackend for NullUiBackend {
 
     fn set_clipboard_content(&mut self, _content: String) {}
 
+    fn clipboard_formats(&mut self) -> Vec<ClipboardFormat> {
+        Vec::new()
+    }
+
+    fn clipboard_data(&mut self, _format: ClipboardFormat) -> Option<ClipboardData> {
+        None
+    }
+
+    fn set_clipboard_data(&mut self, _data: ClipboardData) {}
+
+    fn clear_clipboard_data(&mut self, _format: Option<ClipboardFormat>) {}
+
     fn set_fullscreen(&mut self, _is_full: bool) -> Result<(), FullscreenError> {
         Ok(())
     }
@@ -316,6 +487,18 @@ impl UiBackend for NullUiBackend {
     ) -> Option<DialogResultFuture> {
         None
     }
+
+    fn ime_enabled(&self) -> bool {
+        false
+    }
+
+    fn set_ime_enabled(&mut self, _enabled: bool) {}
+
+    fn ime_conversion_mode(&self) -> ImeConversionMode {
+        ImeConversionMode::Unknown
+    }
+
+    fn set_ime_conversion_mode(&mut self, _mode: ImeConversionMode) {}
 }
 
 impl Default for NullUiBackend {
@@ -11,7 +11,7 @@ use crate::context::{RenderContext, UpdateContext};
 use crate::display_object::TDisplayObject;
 use gc_arena::Mutation;
 use ruffle_render::backend::RenderBackend;
-use ruffle_render::bitmap::{PixelRegion, PixelSnapping};
+use ruffle_render::bitmap::{Bitmap, BitmapFormat, PixelRegion, PixelSnapping};
 use ruffle_render::commands::{CommandHandler, CommandList, RenderBlendMode};
 use ruffle_render::filters::Filter;
 use ruffle_render::matrix::Matrix;
@@ -1626,6 +1626,119 @@ pub fn get_vector<'gc>(
     result
 }
 
+/// An image format that a region of a bitmap can be encoded as.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageEncoding {
+    Png { fast_compression: bool },
+    Jpeg { quality: u8 },
+}
+
+/// Encodes a region of a bitmap as an image file.
+///
+/// The region is clamped to the bounds of the bitmap first; an empty region is an error.
+pub fn encode(
+    target: BitmapDataWrapper,
+    renderer: &mut dyn RenderBackend,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    encoding: ImageEncoding,
+) -> Result<Vec<u8>, image::ImageError> {
+    let mut region = PixelRegion::for_region_i32(x, y, width, height);
+    region.clamp(target.width(), target.height());
+
+    let transparency = target.transparency();
+    let read = target.read_area(region, renderer);
+    encode_pixels(
+        region,
+        |x, y| read.get_pixel32_raw(x, y),
+        transparency,
+        encoding,
+    )
+}
+
+fn empty_region_error() -> image::ImageError {
+    use image::error::{ParameterError, ParameterErrorKind};
+
+    image::ImageError::Parameter(ParameterError::from_kind(
+        ParameterErrorKind::DimensionMismatch,
+    ))
+}
+
+/// Encodes the premultiplied pixels of `region`, as returned by `get_pixel`.
+fn encode_pixels(
+    region: PixelRegion,
+    get_pixel: impl Fn(u32, u32) -> Color,
+    transparency: bool,
+    encoding: ImageEncoding,
+) -> Result<Vec<u8>, image::ImageError> {
+    use image::codecs::jpeg::JpegEncoder;
+    use image::codecs::png::{CompressionType, FilterType, PngEncoder};
+    use image::{ExtendedColorType, ImageEncoder};
+
+    if region.width() == 0 || region.height() == 0 {
+        return Err(empty_region_error());
+    }
+
+    // JPEG has no alpha channel, and opaque bitmaps don't need one.
+    let has_alpha = transparency && matches!(encoding, ImageEncoding::Png { .. });
+    let (color_type, bytes_per_pixel) = if has_alpha {
+        (ExtendedColorType::Rgba8, 4)
+    } else {
+        (ExtendedColorType::Rgb8, 3)
+    };
+
+    let mut pixels =
+        Vec::with_capacity((region.width() * region.height()) as usize * bytes_per_pixel);
+    for y in region.y_min..region.y_max {
+        for x in region.x_min..region.x_max {
+            let color = get_pixel(x, y).to_un_multiplied_alpha();
+            pixels.extend_from_slice(&[color.red(), color.green(), color.blue()]);
+            if has_alpha {
+                pixels.push(color.alpha());
+            }
+        }
+    }
+
+    let mut data = Vec::new();
+    match encoding {
+        ImageEncoding::Png { fast_compression } => {
+            let compression = if fast_compression {
+                CompressionType::Fast
+            } else {
+                CompressionType::Default
+            };
+            PngEncoder::new_with_quality(&mut data, compression, FilterType::Adaptive)
+                .write_image(&pixels, region.width(), region.height(), color_type)?;
+        }
+        ImageEncoding::Jpeg { quality } => {
+            JpegEncoder::new_with_quality(&mut data, quality.clamp(1, 100)).write_image(
+                &pixels,
+                region.width(),
+                region.height(),
+                color_type,
+            )?;
+        }
+    }
+
+    Ok(data)
+}
+
+/// Copies the pixels of a bitmap into a new `Rgba` bitmap.
+pub fn to_bitmap(target: BitmapDataWrapper, renderer: &mut dyn RenderBackend) -> Bitmap {
+    let (width, height) = (target.width(), target.height());
+    let mut pixels = Vec::with_capacity((width * height) as usize * 4);
+    let read = target.read_area(PixelRegion::for_whole_size(width, height), renderer);
+    for y in 0..height {
+        for x in 0..width {
+            let color = read.get_pixel32_raw(x, y);
+            pixels.extend_from_slice(&[color.red(), color.green(), color.blue(), color.alpha()]);
+        }
+    }
+    Bitmap::new(width, height, BitmapFormat::Rgba, pixels)
+}
+
 pub fn set_vector<'gc>(
     activation: &mut Activation<'_, 'gc>,
     target: BitmapDataWrapper<'gc>,
@@ -1953,3 +2066,120 @@ pub fn pixel_dissolve<'gc>(
 
     raw_perm_index as i32
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIDTH: u32 = 4;
+    const HEIGHT: u32 = 3;
+
+    /// A 4x3 bitmap with a distinct color in each pixel and a half-transparent bottom row.
+    fn pixel(x: u32, y: u32) -> Color {
+        let alpha = if y == HEIGHT - 1 { 0x80 } else { 0xFF };
+        Color::argb(alpha, (x * 60) as u8, (y * 100) as u8, 0xC0).to_premultiplied_alpha(true)
+    }
+
+    fn clamped_region(x: i32, y: i32, width: i32, height: i32) -> PixelRegion {
+        let mut region = PixelRegion::for_region_i32(x, y, width, height);
+        region.clamp(WIDTH, HEIGHT);
+        region
+    }
+
+    fn decode(data: &[u8]) -> image::RgbaImage {
+        image::load_from_memory(data)
+            .expect("Encoded image should decode")
+            .to_rgba8()
+    }
+
+    fn assert_matches_source(image: &image::RgbaImage, region: PixelRegion, transparency: bool) {
+        assert_eq!(image.dimensions(), (region.width(), region.height()));
+        for (x, y, decoded) in image.enumerate_pixels() {
+            let color = pixel(region.x_min + x, region.y_min + y).to_un_multiplied_alpha();
+            let alpha = if transparency { color.alpha() } else { 255 };
+            assert_eq!(
+                decoded.0,
+                [color.red(), color.green(), color.blue(), alpha],
+                "pixel ({x}, {y})"
+            );
+        }
+    }
+
+    #[test]
+    fn png_round_trip() {
+        let region = clamped_region(0, 0, WIDTH as i32, HEIGHT as i32);
+        for transparency in [true, false] {
+            let encoding = ImageEncoding::Png {
+                fast_compression: false,
+            };
+            let data = encode_pixels(region, pixel, transparency, encoding).expect("PNG encode");
+            assert!(data.starts_with(b"\x89PNG"));
+            assert_matches_source(&decode(&data), region, transparency);
+        }
+    }
+
+    #[test]
+    fn png_fast_compression() {
+        let region = clamped_region(0, 0, WIDTH as i32, HEIGHT as i32);
+        let encoding = ImageEncoding::Png {
+            fast_compression: true,
+        };
+        let data = encode_pixels(region, pixel, true, encoding).expect("PNG encode");
+        assert!(data.starts_with(b"\x89PNG"));
+        assert_matches_source(&decode(&data), region, true);
+    }
+
+    #[test]
+    fn jpeg_round_trip() {
+        let region = clamped_region(0, 0, WIDTH as i32, HEIGHT as i32);
+        let solid = |_, _| Color::argb(0xFF, 0x20, 0x80, 0xE0);
+        for quality in [50, 80, 255] {
+            let encoding = ImageEncoding::Jpeg { quality };
+            let data = encode_pixels(region, solid, true, encoding).expect("JPEG encode");
+            assert!(data.starts_with(&[0xFF, 0xD8]));
+
+            let image = decode(&data);
+            assert_eq!(image.dimensions(), (WIDTH, HEIGHT));
+            for decoded in image.pixels() {
+                // JPEG is lossy and has no alpha channel.
+                let [r, g, b, a] = decoded.0;
+                assert!(r.abs_diff(0x20) <= 8 && g.abs_diff(0x80) <= 8 && b.abs_diff(0xE0) <= 8);
+                assert_eq!(a, 255);
+            }
+        }
+    }
+
+    #[test]
+    fn out_of_bounds_region_is_clamped() {
+        let region = clamped_region(-2, 1, 4, 10);
+        assert_eq!((region.width(), region.height()), (2, 2));
+
+        let encoding = ImageEncoding::Png {
+            fast_compression: false,
+        };
+        let data = encode_pixels(region, pixel, true, encoding).expect("PNG encode");
+        assert_matches_source(&decode(&data), region, true);
+    }
+
+    #[test]
+    fn empty_region_is_an_error() {
+        let encoding = ImageEncoding::Png {
+            fast_compression: false,
+        };
+        for region in [
+            clamped_region(1, 1, 0, 2),
+            clamped_region(1, 1, 2, 0),
+            clamped_region(10, 0, 4, 4),
+            clamped_region(-10, -10, 4, 4),
+        ] {
+            assert!(encode_pixels(region, pixel, true, encoding).is_err());
+        }
+        assert!(encode_pixels(
+            clamped_region(10, 10, 1, 1),
+            pixel,
+            false,
+            ImageEncoding::Jpeg { quality: 80 }
+        )
+        .is_err());
+    }
+}
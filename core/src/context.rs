@@ -15,8 +15,10 @@ use crate::avm2::TObject as _;
 use crate::avm2::{Avm2, Object as Avm2Object, SoundChannelObject};
 use crate::backend::{
     audio::{AudioBackend, AudioManager, SoundHandle, SoundInstanceHandle},
+    filesystem::FilesystemBackend,
     log::LogBackend,
     navigator::NavigatorBackend,
+    printing::PrintBackend,
     storage::StorageBackend,
     ui::{InputManager, UiBackend},
 };
@@ -32,6 +34,7 @@ use crate::net_connection::NetConnections;
 use crate::player::PostFrameCallback;
 use crate::player::{MouseData, Player};
 use crate::prelude::*;
+use crate::printing::PrintJobs;
 use crate::socket::Sockets;
 use crate::streams::StreamManager;
 use crate::string::AvmString;
@@ -40,6 +43,7 @@ use crate::stub::StubCollection;
 use crate::tag_utils::{SwfMovie, SwfSlice};
 use crate::timer::Timers;
 use crate::vminterface::Instantiator;
+use crate::worker::Workers;
 use core::fmt;
 use gc_arena::{Collect, Mutation};
 use rand::rngs::SmallRng;
@@ -133,6 +137,12 @@ pub struct UpdateContext<'a, 'gc> {
     /// The storage backend, used for storing persistent state
     pub storage: &'a mut dyn StorageBackend,
 
+    /// The filesystem backend, used by AIR applications to access files.
+    pub filesystem: &'a mut dyn FilesystemBackend,
+
+    /// The print backend, used by `PrintJob` to print pages.
+    pub print: &'a mut dyn PrintBackend,
+
     /// The logging backend, used for trace output capturing.
     ///
     /// **DO NOT** use this field directly, use the `avm_trace` method instead.
@@ -244,6 +254,12 @@ pub struct UpdateContext<'a, 'gc> {
 
     pub local_connections: &'a mut LocalConnections<'gc>,
 
+    /// The workers of the movie, which share state with this player.
+    pub workers: &'a mut Workers,
+
+    /// The print job that hasn't been sent yet.
+    pub print_jobs: &'a mut PrintJobs,
+
     /// Dynamic root for allowing handles to GC objects to exist outside of the GC.
     pub dynamic_root: gc_arena::DynamicRootSet<'gc>,
 
@@ -499,6 +515,8 @@ impl<'a, 'gc> UpdateContext<'a, 'gc> {
             ui: self.ui,
             video: self.video,
             storage: self.storage,
+            filesystem: self.filesystem,
+            print: self.print,
             rng: self.rng,
             stage: self.stage,
             mouse_data: self.mouse_data,
@@ -532,6 +550,8 @@ impl<'a, 'gc> UpdateContext<'a, 'gc> {
             sockets: self.sockets,
             net_connections: self.net_connections,
             local_connections: self.local_connections,
+            workers: self.workers,
+            print_jobs: self.print_jobs,
             dynamic_root: self.dynamic_root,
             post_frame_callbacks: self.post_frame_callbacks,
         }
@@ -16,7 +16,11 @@ use crate::vminterface::Instantiator;
 use bitflags::bitflags;
 use gc_arena::{Collect, Mutation};
 use ruffle_macros::enum_trait_object;
+use ruffle_render::matrix3d::{
+    projected_tiles, Components3D, Matrix3D, PerspectiveProjection, Projection,
+};
 use ruffle_render::pixel_bender::PixelBenderShaderHandle;
+use ruffle_render::shape_utils::ScalingGrid;
 use ruffle_render::transform::{Transform, TransformStack};
 use std::cell::{Ref, RefMut};
 use std::fmt::Debug;
@@ -59,7 +63,7 @@ use ruffle_render::blend::ExtendedBlendMode;
 use ruffle_render::commands::{CommandHandler, CommandList, RenderBlendMode};
 use ruffle_render::filters::Filter;
 pub use stage::{Stage, StageAlign, StageDisplayState, StageScaleMode, WindowMode};
-pub use text::Text;
+pub use text::{StaticTextGlyph, Text};
 pub use video::Video;
 
 use self::loader_display::LoaderDisplayWeak;
@@ -265,6 +269,16 @@ pub struct DisplayObjectBase<'gc> {
     /// None means not cached, Some means cached.
     #[collect(require_static)]
     cache: Option<BitmapCache>,
+
+    /// The 3D transform of this display object, if it has one.
+    /// `transform.matrix` is then kept as the 2D part of it.
+    #[collect(require_static)]
+    matrix3d: Option<Box<Matrix3D>>,
+
+    /// The projection used to display the 3D children of this object,
+    /// or `None` to use the projection of its parent.
+    #[collect(require_static)]
+    perspective_projection: Option<PerspectiveProjection>,
 }
 
 impl<'gc> Default for DisplayObjectBase<'gc> {
@@ -294,6 +308,8 @@ impl<'gc> Default for DisplayObjectBase<'gc> {
             next_scroll_rect: Default::default(),
             scaling_grid: Default::default(),
             cache: None,
+            matrix3d: None,
+            perspective_projection: None,
         }
     }
 }
@@ -336,6 +352,52 @@ impl<'gc> DisplayObjectBase<'gc> {
     pub fn set_matrix(&mut self, matrix: Matrix) {
         self.transform.matrix = matrix;
         self.set_scale_rotation_cached(false);
+        if self.matrix3d.take().is_some() {
+            self.recheck_cache_as_bitmap();
+        }
+    }
+
+    pub fn matrix3d(&self) -> Option<&Matrix3D> {
+        self.matrix3d.as_deref()
+    }
+
+    /// Sets the 3D matrix of this object, which also sets its 2D matrix to the 2D part of it.
+    /// Removing the 3D matrix keeps the current 2D matrix.
+    pub fn set_matrix3d(&mut self, matrix3d: Option<Matrix3D>) {
+        if let Some(matrix3d) = &matrix3d {
+            self.transform.matrix = matrix3d.to_2d();
+            self.set_scale_rotation_cached(false);
+        }
+        self.matrix3d = matrix3d.map(Box::new);
+        self.recheck_cache_as_bitmap();
+    }
+
+    /// The components of the 3D matrix of this object, or of its 2D matrix if it has none.
+    fn components3d(&self) -> Components3D {
+        match self.matrix3d() {
+            Some(matrix3d) => matrix3d.components(),
+            None => Matrix3D::from_2d(&self.transform.matrix).components(),
+        }
+    }
+
+    /// Changes a component of the 3D matrix of this object,
+    /// which is created from its 2D matrix if it has none.
+    fn set_components3d(&mut self, f: impl FnOnce(&mut Components3D)) -> bool {
+        let mut components = self.components3d();
+        f(&mut components);
+        let matrix3d = Matrix3D::from_components(&components);
+        let changed = self.matrix3d() != Some(&matrix3d);
+        self.set_transformed_by_script(true);
+        self.set_matrix3d(Some(matrix3d));
+        changed
+    }
+
+    fn perspective_projection(&self) -> Option<PerspectiveProjection> {
+        self.perspective_projection
+    }
+
+    fn set_perspective_projection(&mut self, projection: Option<PerspectiveProjection>) {
+        self.perspective_projection = projection;
     }
 
     pub fn color_transform(&self) -> &ColorTransform {
@@ -358,6 +420,9 @@ impl<'gc> DisplayObjectBase<'gc> {
         let changed = self.transform.matrix.tx != x;
         self.set_transformed_by_script(true);
         self.transform.matrix.tx = x;
+        if let Some(matrix3d) = &mut self.matrix3d {
+            matrix3d.raw_data[12] = x.to_pixels();
+        }
         changed
     }
 
@@ -369,6 +434,9 @@ impl<'gc> DisplayObjectBase<'gc> {
         let changed = self.transform.matrix.ty != y;
         self.set_transformed_by_script(true);
         self.transform.matrix.ty = y;
+        if let Some(matrix3d) = &mut self.matrix3d {
+            matrix3d.raw_data[13] = y.to_pixels();
+        }
         changed
     }
 
@@ -409,11 +477,17 @@ impl<'gc> DisplayObjectBase<'gc> {
     }
 
     fn rotation(&mut self) -> Degrees {
+        if self.matrix3d.is_some() {
+            return self.components3d().rotation[2].into();
+        }
         self.cache_scale_rotation();
         self.rotation
     }
 
     fn set_rotation(&mut self, degrees: Degrees) -> bool {
+        if self.matrix3d.is_some() {
+            return self.set_components3d(|c| c.rotation[2] = degrees.into());
+        }
         self.set_transformed_by_script(true);
         self.cache_scale_rotation();
         let changed = self.rotation != degrees;
@@ -446,11 +520,23 @@ impl<'gc> DisplayObjectBase<'gc> {
     }
 
     fn scale_x(&mut self) -> Percent {
+        if self.matrix3d.is_some() {
+            return Percent::from_unit(self.components3d().scale[0]);
+        }
         self.cache_scale_rotation();
         self.scale_x
     }
 
     fn set_scale_x(&mut self, mut value: Percent) -> bool {
+        if self.matrix3d.is_some() {
+            // NaN is treated as 0, like below.
+            let scale = if value.percent().is_nan() {
+                0.0
+            } else {
+                value.unit()
+            };
+            return self.set_components3d(|c| c.scale[0] = scale);
+        }
         let changed = self.scale_x != value;
         self.set_transformed_by_script(true);
         self.cache_scale_rotation();
@@ -473,11 +559,23 @@ impl<'gc> DisplayObjectBase<'gc> {
     }
 
     fn scale_y(&mut self) -> Percent {
+        if self.matrix3d.is_some() {
+            return Percent::from_unit(self.components3d().scale[1]);
+        }
         self.cache_scale_rotation();
         self.scale_y
     }
 
     fn set_scale_y(&mut self, mut value: Percent) -> bool {
+        if self.matrix3d.is_some() {
+            // NaN is treated as 0, like below.
+            let scale = if value.percent().is_nan() {
+                0.0
+            } else {
+                value.unit()
+            };
+            return self.set_components3d(|c| c.scale[1] = scale);
+        }
         let changed = self.scale_y != value;
         self.set_transformed_by_script(true);
         self.cache_scale_rotation();
@@ -723,7 +821,10 @@ impl<'gc> DisplayObjectBase<'gc> {
     }
 
     fn recheck_cache_as_bitmap(&mut self) {
-        let should_cache = self.is_bitmap_cached_preference() || !self.filters.is_empty();
+        // 3D objects are drawn from a bitmap of their content.
+        let should_cache = self.is_bitmap_cached_preference()
+            || !self.filters.is_empty()
+            || self.matrix3d.is_some();
         if should_cache && self.cache.is_none() {
             self.cache = Some(Default::default());
         } else if !should_cache && self.cache.is_some() {
@@ -795,6 +896,7 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
     if this.maskee().is_some() {
         return;
     }
+    let parent_matrix = context.transform_stack.transform().matrix;
     context.transform_stack.push(this.base().transform());
     let blend_mode = this.blend_mode();
     let original_commands = if blend_mode != ExtendedBlendMode::Normal {
@@ -803,7 +905,13 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
         None
     };
 
-    let cache_info = if context.use_bitmap_cache && this.is_bitmap_cached() {
+    // 3D objects need a bitmap of their content, so they're drawn flat when caching is disabled.
+    let rendered_3d = context.use_bitmap_cache
+        && this
+            .matrix3d()
+            .is_some_and(|matrix3d| render_3d(this, context, &parent_matrix, &matrix3d));
+
+    let cache_info = if !rendered_3d && context.use_bitmap_cache && this.is_bitmap_cached() {
         let mut cache_info: Option<DrawCacheInfo> = None;
         let base_transform = context.transform_stack.transform();
         let bounds: Rectangle<Twips> = this.render_bounds_with_transform(
@@ -943,7 +1051,7 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
                 PixelSnapping::Always, // cacheAsBitmap forces pixel snapping
             )
         });
-    } else {
+    } else if !rendered_3d {
         if let Some(background) = this.opaque_background() {
             // This is intended for use with cacheAsBitmap, but can be set for non-cached objects too
             // It wants the entire bounding box to be cleared before any draws happen
@@ -983,6 +1091,160 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
     context.transform_stack.pop();
 }
 
+/// Renders a display object with a 3D transform, by projecting a bitmap of its content
+/// onto its parent. Filters aren't applied.
+///
+/// Returns `false` if the content couldn't be drawn to a bitmap, in which case
+/// the object should be drawn flat instead.
+fn render_3d<'gc>(
+    this: DisplayObject<'gc>,
+    context: &mut RenderContext<'_, 'gc>,
+    parent_matrix: &Matrix,
+    matrix3d: &Matrix3D,
+) -> bool {
+    /// The largest width or height of the bitmap that the content is drawn to.
+    const MAX_BITMAP_SIZE: f64 = 4096.0;
+
+    let bounds =
+        this.render_bounds_with_transform(&Matrix::IDENTITY, false, &context.stage.view_matrix());
+    if !bounds.is_valid() {
+        return true;
+    }
+    let (x_min, y_min) = (bounds.x_min.to_pixels(), bounds.y_min.to_pixels());
+    let (width, height) = (bounds.width().to_pixels(), bounds.height().to_pixels());
+    let projection = this.projection();
+
+    // Draw the content at about the largest size it's displayed at. The scale is
+    // rounded up to a quarter of an octave, so that the bitmap is rarely resized.
+    let m = &matrix3d.raw_data;
+    let matrix_scale = m[0]
+        .hypot(m[1])
+        .hypot(m[2])
+        .max(m[4].hypot(m[5]).hypot(m[6]));
+    let parent_scale = f64::from(parent_matrix.determinant()).abs().sqrt();
+    let magnification = [
+        (x_min, y_min),
+        (x_min + width, y_min),
+        (x_min, y_min + height),
+        (x_min + width, y_min + height),
+    ]
+    .into_iter()
+    .map(|(x, y)| {
+        let [_, _, z] = matrix3d.transform_point([x, y, 0.0]);
+        (projection.focal_length / (projection.focal_length + z)).clamp(0.0, 4.0)
+    })
+    .fold(0.0, f64::max);
+    let scale = parent_scale * matrix_scale * magnification;
+    let scale = 2f64
+        .powf((scale.log2() * 4.0).ceil() / 4.0)
+        .min(MAX_BITMAP_SIZE / width.max(height));
+    let bitmap_width = (width * scale).ceil();
+    let bitmap_height = (height * scale).ceil();
+    if !(scale > 0.0 && bitmap_width >= 1.0 && bitmap_height >= 1.0) {
+        return true;
+    }
+    let (bitmap_width, bitmap_height) = (bitmap_width as u16, bitmap_height as u16);
+
+    let swf_version = this.swf_version();
+    let cache_matrix = Matrix::scale(scale as f32, scale as f32);
+    let cache = this
+        .base_mut(context.gc_context)
+        .bitmap_cache_mut()
+        .and_then(|cache| {
+            let dirty = cache.is_dirty(&cache_matrix, bitmap_width, bitmap_height);
+            if dirty {
+                cache.update(
+                    context.renderer,
+                    cache_matrix,
+                    bitmap_width,
+                    bitmap_height,
+                    bitmap_width,
+                    bitmap_height,
+                    Point::new(0, 0),
+                    swf_version,
+                );
+            }
+            cache.handle().map(|handle| (handle, dirty))
+        });
+    let Some((handle, dirty)) = cache else {
+        return false;
+    };
+
+    if dirty {
+        let mut transform_stack = TransformStack::new();
+        transform_stack.push(&Transform {
+            color_transform: Default::default(),
+            matrix: Matrix {
+                tx: Twips::from_pixels(-x_min * scale),
+                ty: Twips::from_pixels(-y_min * scale),
+                ..cache_matrix
+            },
+        });
+        let mut offscreen_context = RenderContext {
+            renderer: context.renderer,
+            commands: CommandList::new(),
+            cache_draws: context.cache_draws,
+            gc_context: context.gc_context,
+            library: context.library,
+            transform_stack: &mut transform_stack,
+            is_offscreen: true,
+            use_bitmap_cache: true,
+            stage: context.stage,
+        };
+        this.render_self(&mut offscreen_context);
+        offscreen_context.cache_draws.push(BitmapCacheEntry {
+            handle: handle.clone(),
+            commands: offscreen_context.commands,
+            clear: this.opaque_background().unwrap_or_default(),
+            filters: vec![],
+        });
+    }
+
+    // Project each pixel of the bitmap onto the parent, and then onto the screen.
+    let (a, b, c, d) = (
+        f64::from(parent_matrix.a),
+        f64::from(parent_matrix.b),
+        f64::from(parent_matrix.c),
+        f64::from(parent_matrix.d),
+    );
+    let (tx, ty) = (parent_matrix.tx.to_pixels(), parent_matrix.ty.to_pixels());
+    let tiles = projected_tiles(bitmap_width.into(), bitmap_height.into(), |u, v| {
+        let point = matrix3d.transform_point([x_min + u / scale, y_min + v / scale, 0.0]);
+        let (x, y) = projection.project(point)?;
+        Some((a * x + c * y + tx, b * x + d * y + ty))
+    });
+    // The object is (at least partly) behind the viewer.
+    let Some(tiles) = tiles else {
+        return true;
+    };
+
+    let color_transform = context.transform_stack.transform().color_transform;
+    apply_standard_mask_and_scroll(this, context, |context| {
+        for tile in tiles {
+            if let Some(clip) = tile.clip {
+                context.commands.push_mask();
+                context.commands.draw_rect(Color::WHITE, clip);
+                context.commands.activate_mask();
+            }
+            context.commands.render_bitmap(
+                handle.clone(),
+                Transform {
+                    matrix: tile.matrix,
+                    color_transform,
+                },
+                true,
+                PixelSnapping::Never,
+            );
+            if let Some(clip) = tile.clip {
+                context.commands.deactivate_mask();
+                context.commands.draw_rect(Color::WHITE, clip);
+                context.commands.pop_mask();
+            }
+        }
+    });
+    true
+}
+
 /// This applies the **standard** method of `mask` and `scrollRect`.
 ///
 /// It uses the stencil buffer so that any pixel drawn in the mask will allow the inner contents to show.
@@ -1132,7 +1394,25 @@ pub trait TDisplayObject<'gc>:
 
     /// The world bounding box of this object including children, relative to the stage.
     fn world_bounds(&self) -> Rectangle<Twips> {
-        self.bounds_with_transform(&self.local_to_global_matrix())
+        if !self.is_3d() {
+            return self.bounds_with_transform(&self.local_to_global_matrix());
+        }
+
+        // A projected rectangle isn't a parallelogram, so project its corners instead.
+        let bounds = self.bounds_with_transform(&Matrix::IDENTITY);
+        if !bounds.is_valid() {
+            return bounds;
+        }
+        [
+            Point::new(bounds.x_min, bounds.y_min),
+            Point::new(bounds.x_max, bounds.y_min),
+            Point::new(bounds.x_min, bounds.y_max),
+            Point::new(bounds.x_max, bounds.y_max),
+        ]
+        .into_iter()
+        .fold(Rectangle::default(), |world_bounds, corner| {
+            world_bounds.encompass(self.local_to_global(corner))
+        })
     }
 
     /// Gets the bounds of this object and all children, transformed by a given matrix.
@@ -1253,13 +1533,57 @@ pub trait TDisplayObject<'gc>:
 
     /// Converts a local position to a global stage position
     fn local_to_global(&self, local: Point<Twips>) -> Point<Twips> {
-        self.local_to_global_matrix() * local
+        if !self.is_3d() {
+            return self.local_to_global_matrix() * local;
+        }
+
+        // Projections can't be combined into a single matrix,
+        // so each ancestor converts the point in turn.
+        let mut point = local;
+        if let Some(rect) = self.scroll_rect() {
+            point = Matrix::translate(-rect.x_min, -rect.y_min) * point;
+        }
+        let projected = self.matrix3d().and_then(|matrix3d| {
+            let [x, y, z] =
+                matrix3d.transform_point([point.x.to_pixels(), point.y.to_pixels(), 0.0]);
+            self.projection().project([x, y, z])
+        });
+        point = match projected {
+            Some((x, y)) => Point::from_pixels(x, y),
+            // Points behind the viewer aren't displayed, so ignore their depth.
+            None => *self.base().matrix() * point,
+        };
+        match self.parent() {
+            Some(parent) if parent.as_stage().is_none() => parent.local_to_global(point),
+            _ => point,
+        }
     }
 
     /// Converts a local position on the stage to a local position on this display object
-    /// Returns `None` if the object has zero scale.
+    /// Returns `None` if the object has zero scale, or if no point of a 3D object is
+    /// displayed at this position.
     fn global_to_local(&self, global: Point<Twips>) -> Option<Point<Twips>> {
-        self.global_to_local_matrix().map(|matrix| matrix * global)
+        if !self.is_3d() {
+            return self.global_to_local_matrix().map(|matrix| matrix * global);
+        }
+
+        let point = match self.parent() {
+            Some(parent) if parent.as_stage().is_none() => parent.global_to_local(global)?,
+            _ => global,
+        };
+        let mut point = match self.matrix3d() {
+            Some(matrix3d) => {
+                let (x, y) = self
+                    .projection()
+                    .unproject(&matrix3d, (point.x.to_pixels(), point.y.to_pixels()))?;
+                Point::from_pixels(x, y)
+            }
+            None => self.base().matrix().inverse()? * point,
+        };
+        if let Some(rect) = self.scroll_rect() {
+            point = Matrix::translate(rect.x_min, rect.y_min) * point;
+        }
+        Some(point)
     }
 
     /// Converts the mouse position on the stage to a local position on this display object.
@@ -1392,6 +1716,188 @@ pub trait TDisplayObject<'gc>:
         }
     }
 
+    /// The 3D transform of this display object, if it has one.
+    /// Returned by the `transform.matrix3D` ActionScript property.
+    fn matrix3d(&self) -> Option<Matrix3D> {
+        self.base().matrix3d().copied()
+    }
+
+    /// Sets or removes the 3D transform of this display object.
+    /// Set by the `transform.matrix3D` ActionScript property.
+    /// This invalidates any ancestors cacheAsBitmap automatically.
+    fn set_matrix3d(&self, gc_context: &Mutation<'gc>, matrix3d: Option<Matrix3D>) {
+        self.base_mut(gc_context).set_matrix3d(matrix3d);
+        if let Some(parent) = self.parent() {
+            parent.invalidate_cached_bitmap(gc_context);
+        }
+    }
+
+    /// The `z` position in pixels of this display object in local space.
+    /// Returned by the `z` ActionScript property.
+    fn z(&self) -> f64 {
+        self.base().components3d().translation[2]
+    }
+
+    /// Sets the `z` position in pixels of this display object in local space,
+    /// which gives it a 3D transform if it doesn't have one.
+    /// Set by the `z` ActionScript property.
+    /// This invalidates any ancestors cacheAsBitmap automatically.
+    fn set_z(&self, gc_context: &Mutation<'gc>, z: f64) {
+        if self
+            .base_mut(gc_context)
+            .set_components3d(|c| c.translation[2] = z)
+        {
+            if let Some(parent) = self.parent() {
+                parent.invalidate_cached_bitmap(gc_context);
+            }
+        }
+    }
+
+    /// The rotation in degrees of this display object around the X axis.
+    /// Returned by the `rotationX` ActionScript property.
+    fn rotation_x(&self) -> Degrees {
+        self.base().components3d().rotation[0].into()
+    }
+
+    /// Sets the rotation in degrees of this display object around the X axis,
+    /// which gives it a 3D transform if it doesn't have one.
+    /// Set by the `rotationX` ActionScript property.
+    /// This invalidates any ancestors cacheAsBitmap automatically.
+    fn set_rotation_x(&self, gc_context: &Mutation<'gc>, degrees: Degrees) {
+        if self
+            .base_mut(gc_context)
+            .set_components3d(|c| c.rotation[0] = degrees.into())
+        {
+            if let Some(parent) = self.parent() {
+                parent.invalidate_cached_bitmap(gc_context);
+            }
+        }
+    }
+
+    /// The rotation in degrees of this display object around the Y axis.
+    /// Returned by the `rotationY` ActionScript property.
+    fn rotation_y(&self) -> Degrees {
+        self.base().components3d().rotation[1].into()
+    }
+
+    /// Sets the rotation in degrees of this display object around the Y axis,
+    /// which gives it a 3D transform if it doesn't have one.
+    /// Set by the `rotationY` ActionScript property.
+    /// This invalidates any ancestors cacheAsBitmap automatically.
+    fn set_rotation_y(&self, gc_context: &Mutation<'gc>, degrees: Degrees) {
+        if self
+            .base_mut(gc_context)
+            .set_components3d(|c| c.rotation[1] = degrees.into())
+        {
+            if let Some(parent) = self.parent() {
+                parent.invalidate_cached_bitmap(gc_context);
+            }
+        }
+    }
+
+    /// The Z axis scale for this display object in local space.
+    /// Returned by the `scaleZ` ActionScript property.
+    fn scale_z(&self) -> Percent {
+        Percent::from_unit(self.base().components3d().scale[2])
+    }
+
+    /// Sets the Z axis scale for this display object in local space,
+    /// which gives it a 3D transform if it doesn't have one.
+    /// Set by the `scaleZ` ActionScript property.
+    /// This invalidates any ancestors cacheAsBitmap automatically.
+    fn set_scale_z(&self, gc_context: &Mutation<'gc>, value: Percent) {
+        if self
+            .base_mut(gc_context)
+            .set_components3d(|c| c.scale[2] = value.unit())
+        {
+            if let Some(parent) = self.parent() {
+                parent.invalidate_cached_bitmap(gc_context);
+            }
+        }
+    }
+
+    /// The projection used to display the 3D children of this display object,
+    /// if it has its own.
+    /// Returned by the `transform.perspectiveProjection` ActionScript property.
+    fn perspective_projection(&self) -> Option<PerspectiveProjection> {
+        self.base().perspective_projection()
+    }
+
+    /// Sets the projection used to display the 3D children of this display object,
+    /// or removes it to use the projection of its parent.
+    /// Set by the `transform.perspectiveProjection` ActionScript property.
+    fn set_perspective_projection(
+        &self,
+        gc_context: &Mutation<'gc>,
+        projection: Option<PerspectiveProjection>,
+    ) {
+        self.base_mut(gc_context)
+            .set_perspective_projection(projection);
+        self.invalidate_cached_bitmap(gc_context);
+    }
+
+    /// Whether this display object or any of its ancestors has a 3D transform.
+    fn is_3d(&self) -> bool {
+        let mut node: Option<DisplayObject<'gc>> = Some((*self).into());
+        while let Some(display_object) = node {
+            if display_object.base().matrix3d().is_some() {
+                return true;
+            }
+            node = display_object.parent();
+        }
+        false
+    }
+
+    /// The projection that this display object is displayed with,
+    /// in the coordinates of its parent.
+    ///
+    /// This is the `perspectiveProjection` of its closest ancestor which has one,
+    /// or the default projection of the stage.
+    fn projection(&self) -> Projection {
+        let mut stage_size = (500.0, 400.0);
+        let mut owner = None;
+        let mut node = self.parent();
+        while let Some(display_object) = node {
+            if let Some(stage) = display_object.as_stage() {
+                let (width, height) = stage.stage_size();
+                if width > 0 && height > 0 {
+                    stage_size = (width.into(), height.into());
+                }
+            } else if owner.is_none() {
+                owner = display_object
+                    .perspective_projection()
+                    .map(|projection| (display_object, projection));
+            }
+            node = display_object.parent();
+        }
+
+        let (owner, projection) = match owner {
+            Some((owner, projection)) => (Some(owner), projection),
+            None => (
+                None,
+                PerspectiveProjection::for_stage(stage_size.0, stage_size.1),
+            ),
+        };
+        let (center_x, center_y) = projection.projection_center;
+        let center = Point::from_pixels(center_x, center_y);
+
+        // The center is in the coordinates of the owner of the projection.
+        let parent = self.parent().filter(|parent| parent.as_stage().is_none());
+        let center = match (owner, parent) {
+            (Some(owner), Some(parent)) if DisplayObject::ptr_eq(owner, parent) => center,
+            (owner, Some(parent)) => {
+                let global = owner.map_or(center, |owner| owner.local_to_global(center));
+                parent.global_to_local(global).unwrap_or(center)
+            }
+            (_, None) => center,
+        };
+
+        Projection {
+            focal_length: projection.focal_length(stage_size.0),
+            center: (center.x.to_pixels(), center.y.to_pixels()),
+        }
+    }
+
     /// Gets the pixel width of the AABB containing this display object in local space.
     /// Returned by the ActionScript `_width`/`width` properties.
     fn width(&self) -> f64 {
@@ -1693,6 +2199,22 @@ pub trait TDisplayObject<'gc>:
 
     fn set_scaling_grid(&self, gc_context: &Mutation<'gc>, rect: Rectangle<Twips>) {
         self.base_mut(gc_context).scaling_grid = rect;
+        self.invalidate_cached_bitmap(gc_context);
+    }
+
+    /// The 9-slice scaling that currently applies to the vector content of this object,
+    /// resolved from its `scale9Grid`, its bounds and its scale.
+    ///
+    /// This affects the object's own drawing and its direct `Shape` children.
+    fn resolved_scaling_grid(&self) -> Option<ScalingGrid> {
+        let grid = self.scaling_grid();
+        if !grid.is_valid() {
+            return None;
+        }
+        let matrix = *self.base().matrix();
+        let scale_x = f64::from(matrix.a).hypot(f64::from(matrix.b));
+        let scale_y = f64::from(matrix.c).hypot(f64::from(matrix.d));
+        ScalingGrid::new(&self.bounds(), &grid, scale_x, scale_y)
     }
 
     /// Whether this object has been removed. Only applies to AVM1.
@@ -4,26 +4,31 @@ use crate::avm1::Avm1;
 use crate::avm1::ExecutionReason;
 use crate::avm1::{Activation as Avm1Activation, ActivationIdentifier};
 use crate::avm1::{
-    Object as Avm1Object, StageObject as Avm1StageObject, TObject as Avm1TObject,
+    NativeObject, Object as Avm1Object, StageObject as Avm1StageObject, TObject as Avm1TObject,
     Value as Avm1Value,
 };
+use crate::avm2::globals::flash::display::loader::load_request;
 use crate::avm2::Avm2;
 use crate::avm2::{
-    Activation as Avm2Activation, EventObject as Avm2EventObject, Object as Avm2Object,
-    StageObject as Avm2StageObject, TObject as _,
+    Activation as Avm2Activation, Domain as Avm2Domain, Error as Avm2Error,
+    EventObject as Avm2EventObject, Object as Avm2Object, StageObject as Avm2StageObject,
+    TObject as _,
 };
+use crate::backend::navigator::Request;
 use crate::backend::ui::MouseCursor;
 use crate::context::{RenderContext, UpdateContext};
 use crate::display_object::interactive::{
     InteractiveObject, InteractiveObjectBase, TInteractiveObject,
 };
-use crate::display_object::{DisplayObjectBase, DisplayObjectPtr};
+use crate::display_object::{DisplayObjectBase, DisplayObjectPtr, MovieClip};
 use crate::drawing::Drawing;
 use crate::events::{ClipEvent, ClipEventResult, TextControlCode};
 use crate::font::{round_down_to_pixel, FontType, Glyph, TextRenderSettings};
 use crate::html::{
-    BoxBounds, FormatSpans, LayoutBox, LayoutContent, LayoutMetrics, Position, TextFormat,
+    BoxBounds, FormatSpans, LayoutBox, LayoutContent, LayoutMetrics, Position, StyleSheet,
+    TextFormat,
 };
+use crate::loader::MovieLoaderVMData;
 use crate::prelude::*;
 use crate::string::{utils as string_utils, AvmString, SwfStrExt as _, WStr, WString};
 use crate::tag_utils::SwfMovie;
@@ -37,7 +42,8 @@ use ruffle_render::shape_utils::DrawCommand;
 use ruffle_render::transform::Transform;
 use ruffle_wstr::WStrToUtf8;
 use std::cmp::Ordering;
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
+use std::ops::Range;
 use std::{cell::Ref, cell::RefMut, sync::Arc};
 use swf::ColorTransform;
 use unic_segment::WordBoundIndices;
@@ -180,6 +186,20 @@ pub struct EditTextData<'gc> {
     /// Restrict what characters the user may input.
     #[collect(require_static)]
     restrict: EditTextRestrict,
+
+    /// The style sheet object that styles the HTML text of this text field.
+    style_sheet: Option<AvmObject<'gc>>,
+
+    /// The display objects of the images embedded into the HTML text.
+    ///
+    /// Each object belongs to the image with the same index in `text_spans`,
+    /// and is `None` if the image couldn't be created.
+    images: Vec<Option<DisplayObject<'gc>>>,
+
+    /// The range of the text taken by the pre-edit text of an IME
+    /// composition in progress.
+    #[collect(require_static)]
+    ime_preedit: Option<Range<usize>>,
 }
 
 impl<'gc> EditTextData<'gc> {
@@ -196,17 +216,55 @@ impl<'gc> EditTextData<'gc> {
             Twips::ZERO
         }
     }
+
+    /// The styles of the style sheet object of this text field, if any.
+    fn style_sheet(&self) -> Option<StyleSheet> {
+        match self.style_sheet? {
+            AvmObject::Avm1(object) => match object.native() {
+                NativeObject::StyleSheet(style_sheet) => Some(style_sheet.borrow().clone()),
+                _ => None,
+            },
+            // TODO: Support `flash.text.StyleSheet`.
+            AvmObject::Avm2(_) => None,
+        }
+    }
+
+    /// The size of each image, as given by its tag or by its content.
+    fn image_sizes(&self) -> Vec<(Twips, Twips)> {
+        self.text_spans
+            .images()
+            .iter()
+            .enumerate()
+            .map(|(index, image)| {
+                let natural = self
+                    .images
+                    .get(index)
+                    .copied()
+                    .flatten()
+                    .map(|object| object.bounds())
+                    .filter(Rectangle::is_valid)
+                    .map_or((Twips::ZERO, Twips::ZERO), |bounds| {
+                        (bounds.width(), bounds.height())
+                    });
+                (
+                    image.width.map_or(natural.0, Twips::from_pixels),
+                    image.height.map_or(natural.1, Twips::from_pixels),
+                )
+            })
+            .collect()
+    }
 }
 
 // TODO: would be nicer to compute (and return) this during layout, instead of afterwards
 /// Compute line (index, offset, extent) from the layout data.
 fn get_line_data(layout: &[LayoutBox]) -> Vec<LineData> {
+    // images float beside the lines, so they're not part of any line
+    let mut layout = layout.iter().filter(|layout_box| !layout_box.is_image());
+
     // if there are no boxes, there are no lines
-    if layout.is_empty() {
+    let Some(first_box) = layout.next() else {
         return Vec::new();
-    }
-
-    let first_box = &layout[0];
+    };
 
     let mut index = 1;
     let mut offset = first_box.bounds().offset_y();
@@ -214,7 +272,7 @@ fn get_line_data(layout: &[LayoutBox]) -> Vec<LineData> {
 
     let mut line_data = Vec::new();
 
-    for layout_box in layout.get(1..).unwrap() {
+    for layout_box in layout {
         let bounds = layout_box.bounds();
 
         // if the top of the new box is lower than the bottom of the old box, it's a new line
@@ -264,6 +322,7 @@ impl<'gc> EditText<'gc> {
             FormatSpans::from_html(
                 &text,
                 default_format,
+                None,
                 swf_tag.is_multiline(),
                 swf_movie.version(),
             )
@@ -294,6 +353,7 @@ impl<'gc> EditText<'gc> {
             swf_tag.bounds().width() - Twips::from_pixels(Self::INTERNAL_PADDING * 2.0),
             swf_tag.is_word_wrap(),
             font_type,
+            &[],
         );
         let line_data = get_line_data(&layout);
 
@@ -362,10 +422,16 @@ impl<'gc> EditText<'gc> {
                 mouse_wheel_enabled: true,
                 is_tlf: false,
                 restrict: EditTextRestrict::allow_all(),
+                style_sheet: None,
+                images: Vec::new(),
+                ime_preedit: None,
             },
         ));
 
-        if swf_tag.is_auto_size() {
+        if !et.0.read().text_spans.images().is_empty() {
+            et.load_images(context);
+            et.relayout(context);
+        } else if swf_tag.is_auto_size() {
             et.relayout(context);
         } else {
             et.redraw_border(context.gc_context);
@@ -434,6 +500,7 @@ impl<'gc> EditText<'gc> {
         edit_text.text_spans = FormatSpans::from_text(text.into(), default_format);
         drop(edit_text);
 
+        self.load_images(context);
         self.relayout(context);
     }
 
@@ -450,20 +517,222 @@ impl<'gc> EditText<'gc> {
         if self.is_html() {
             let mut write = self.0.write(context.gc_context);
             let default_format = write.text_spans.default_format().clone();
+            let style_sheet = write.style_sheet();
             write.text_spans = FormatSpans::from_html(
                 text,
                 default_format,
+                style_sheet.as_ref(),
                 write.flags.contains(EditTextFlag::MULTILINE),
                 write.static_data.swf.version(),
             );
             drop(write);
 
+            self.load_images(context);
             self.relayout(context);
         } else {
             self.set_text(text, context);
         }
     }
 
+    /// Create the display objects of the images embedded into the HTML text,
+    /// replacing any previous images.
+    fn load_images(self, context: &mut UpdateContext<'_, 'gc>) {
+        let old_images = std::mem::take(&mut self.0.write(context.gc_context).images);
+        for image in old_images.into_iter().flatten() {
+            image.set_parent(context, None);
+        }
+
+        let sources: Vec<WString> = self
+            .0
+            .read()
+            .text_spans
+            .images()
+            .iter()
+            .map(|image| image.src.clone())
+            .collect();
+        let images = sources
+            .iter()
+            .map(|src| {
+                let image = self.load_image(context, src);
+                if let Some(image) = image {
+                    image.set_parent(context, Some(self.into()));
+                }
+                image
+            })
+            .collect();
+        self.0.write(context.gc_context).images = images;
+    }
+
+    /// Create the display object of an image.
+    ///
+    /// The source of the image is either the name of a library symbol, or the
+    /// URL of an image or movie to load.
+    fn load_image(
+        self,
+        context: &mut UpdateContext<'_, 'gc>,
+        src: &WStr,
+    ) -> Option<DisplayObject<'gc>> {
+        let movie = self.movie();
+        let name = AvmString::new(context.gc_context, src);
+
+        if movie.is_action_script_3() {
+            let domain = context.library.library_for_movie_mut(movie).avm2_domain();
+            let mut activation = Avm2Activation::from_domain(context.reborrow(), domain);
+            return match Self::load_avm2_image(&mut activation, domain, name) {
+                Ok(image) => Some(image),
+                Err(e) => {
+                    tracing::error!("Unable to load image {}: {:?}", src, e);
+                    None
+                }
+            };
+        }
+
+        let symbol = context
+            .library
+            .library_for_movie(movie.clone())
+            .filter(|library| library.character_by_export_name(name).is_some())
+            .and_then(|library| {
+                library
+                    .instantiate_by_export_name(name, context.gc_context)
+                    .ok()
+            });
+        if let Some(image) = symbol {
+            image.post_instantiation(context, None, Instantiator::Avm1, false);
+            return Some(image);
+        }
+
+        let clip = MovieClip::new(movie, context.gc_context);
+        clip.post_instantiation(context, None, Instantiator::Avm1, false);
+        let future = context.load_manager.load_movie_into_clip(
+            context.player.clone(),
+            clip.into(),
+            Request::get(src.to_utf8_lossy().into_owned()),
+            None,
+            MovieLoaderVMData::Avm1 { broadcaster: None },
+        );
+        context.navigator.spawn_future(future);
+        Some(clip.into())
+    }
+
+    /// Create the display object of an image in AVM2.
+    ///
+    /// Library symbols are referenced by their class name, anything else is
+    /// loaded by a `Loader`.
+    fn load_avm2_image(
+        activation: &mut Avm2Activation<'_, 'gc>,
+        domain: Avm2Domain<'gc>,
+        src: AvmString<'gc>,
+    ) -> Result<DisplayObject<'gc>, Avm2Error<'gc>> {
+        if let Ok(Avm2Value::Object(class)) =
+            domain.get_defined_value_handling_vector(activation, src)
+        {
+            if let Some(class) = class.as_class_object() {
+                let image = class.construct(activation, &[])?;
+                if let Some(image) = image.as_display_object() {
+                    return Ok(image);
+                }
+            }
+        }
+
+        let loader = activation
+            .avm2()
+            .classes()
+            .loader
+            .construct(activation, &[])?;
+        let request = Request::get(src.to_utf8_lossy().into_owned());
+        load_request(activation, loader, request, None, domain)?;
+        Ok(loader
+            .as_display_object()
+            .expect("Loader is a display object"))
+    }
+
+    /// Returns the display object of the image with the given `id` attribute.
+    pub fn image_reference(self, id: &WStr) -> Option<DisplayObject<'gc>> {
+        let read = self.0.read();
+        let index = read
+            .text_spans
+            .images()
+            .iter()
+            .position(|image| image.id.as_deref() == Some(id))?;
+        read.images.get(index).copied().flatten()
+    }
+
+    /// Position the images beside the text, and scale them to their size.
+    fn update_image_transforms(self, gc_context: &Mutation<'gc>) {
+        let read = self.0.read();
+        let padding = Twips::from_pixels(Self::INTERNAL_PADDING);
+        for layout_box in read.layout.iter() {
+            let LayoutContent::Image { index } = layout_box.content() else {
+                continue;
+            };
+            let Some(Some(image)) = read.images.get(*index) else {
+                continue;
+            };
+
+            let bounds = layout_box.bounds();
+            let natural = image.bounds();
+            let (scale_x, scale_y, natural_origin) = if natural.is_valid() {
+                let scale = |size: Twips, natural: Twips| {
+                    if natural > Twips::ZERO {
+                        size.get() as f32 / natural.get() as f32
+                    } else {
+                        1.0
+                    }
+                };
+                (
+                    scale(bounds.width(), natural.width()),
+                    scale(bounds.height(), natural.height()),
+                    (natural.x_min, natural.y_min),
+                )
+            } else {
+                (1.0, 1.0, (Twips::ZERO, Twips::ZERO))
+            };
+
+            let matrix = Matrix::translate(
+                read.bounds.x_min + padding + bounds.offset_x(),
+                read.bounds.y_min + padding + bounds.offset_y(),
+            ) * Matrix::scale(scale_x, scale_y)
+                * Matrix::translate(-natural_origin.0, -natural_origin.1);
+            image.set_matrix(gc_context, matrix);
+        }
+    }
+
+    /// Lay out the text again if the natural size of any image changed, e.g.
+    /// because it finished loading.
+    fn update_images(self, context: &mut UpdateContext<'_, 'gc>) {
+        let read = self.0.read();
+        if read.images.is_empty() {
+            return;
+        }
+
+        let sizes = read.image_sizes();
+        let is_resized = read.layout.iter().any(|layout_box| {
+            let LayoutContent::Image { index } = layout_box.content() else {
+                return false;
+            };
+            let bounds = layout_box.bounds();
+            sizes.get(*index) != Some(&(bounds.width(), bounds.height()))
+        });
+        drop(read);
+
+        if is_resized {
+            self.relayout(context);
+        } else {
+            self.update_image_transforms(context.gc_context);
+        }
+    }
+
+    pub fn style_sheet(self) -> Option<AvmObject<'gc>> {
+        self.0.read().style_sheet
+    }
+
+    /// Sets the style sheet object of this text field.
+    ///
+    /// The styles are applied the next time that the HTML text is set.
+    pub fn set_style_sheet(self, gc_context: &Mutation<'gc>, style_sheet: Option<AvmObject<'gc>>) {
+        self.0.write(gc_context).style_sheet = style_sheet;
+    }
+
     pub fn text_length(self) -> usize {
         self.0.read().text_spans.text().len()
     }
@@ -796,7 +1065,7 @@ impl<'gc> EditText<'gc> {
 
     /// Internal padding between the bounds of the EditText and the text.
     /// Applies to each side.
-    const INTERNAL_PADDING: f64 = 2.0;
+    pub const INTERNAL_PADDING: f64 = 2.0;
 
     /// Relayout the `EditText`.
     ///
@@ -834,6 +1103,7 @@ impl<'gc> EditText<'gc> {
             FontType::Embedded
         };
 
+        let image_sizes = edit_text.image_sizes();
         let (new_layout, intrinsic_bounds) = LayoutBox::lower_from_text_spans(
             &edit_text.text_spans,
             context,
@@ -841,6 +1111,7 @@ impl<'gc> EditText<'gc> {
             content_width,
             is_word_wrap,
             font_type,
+            &image_sizes,
         );
 
         edit_text.line_data = get_line_data(&new_layout);
@@ -878,6 +1149,7 @@ impl<'gc> EditText<'gc> {
         }
         drop(edit_text);
         self.redraw_border(context.gc_context);
+        self.update_image_transforms(context.gc_context);
         self.invalidate_cached_bitmap(context.gc_context);
     }
 
@@ -1030,6 +1302,7 @@ impl<'gc> EditText<'gc> {
             let baseline_adjustment = baseline - params.height();
             let caret_height = baseline + descent;
             let mut caret_x = Twips::ZERO;
+            let mut caret_found = false;
             font.evaluate(
                 text,
                 self.text_transform(color, baseline_adjustment),
@@ -1058,12 +1331,15 @@ impl<'gc> EditText<'gc> {
                     }
 
                     // Update caret position
+                    // Right-to-left glyphs are visited in reverse, and their
+                    // caret positions are on the other side.
                     if let Some(caret) = caret {
                         if pos == caret {
-                            caret_x = x;
-                        } else if caret > 0 && pos == caret - 1 {
+                            caret_x = if params.is_rtl() { x + advance } else { x };
+                            caret_found = true;
+                        } else if caret > 0 && pos == caret - 1 && !caret_found {
                             // The caret may be rendered at the end, after all glyphs.
-                            caret_x = x + advance;
+                            caret_x = if params.is_rtl() { x } else { x + advance };
                         }
                     }
                 },
@@ -1345,12 +1621,21 @@ impl<'gc> EditText<'gc> {
                     match layout_box.bounds().extent_y().cmp(&closest_extent_y) {
                         Ordering::Less => {}
                         Ordering::Equal => {
-                            if position.x >= layout_box.bounds().offset_x()
-                                || closest_layout_box.is_none()
-                            {
+                            // Boxes of bidirectional rows aren't sorted by their
+                            // position, so look for the last box starting before
+                            // the X position, or the first box of the row otherwise.
+                            let offset_x = layout_box.bounds().offset_x();
+                            let is_closer = closest_layout_box.is_none_or(|closest| {
+                                let closest_x = closest.bounds().offset_x();
+                                match (position.x >= offset_x, position.x >= closest_x) {
+                                    (true, true) => offset_x >= closest_x,
+                                    (true, false) => true,
+                                    (false, true) => false,
+                                    (false, false) => offset_x < closest_x,
+                                }
+                            });
+                            if is_closer {
                                 closest_layout_box = Some(layout_box);
-                            } else {
-                                break;
                             }
                         }
                         Ordering::Greater => break,
@@ -1368,7 +1653,8 @@ impl<'gc> EditText<'gc> {
             if let Some((text, _tf, font, params, color)) =
                 layout_box.as_renderable_text(text.text_spans.text())
             {
-                let mut result = 0;
+                // Right-to-left text starts at the right edge of its box.
+                let mut result = if params.is_rtl() { text.len() } else { 0 };
                 let baseline_adjustment =
                     font.get_baseline_for_height(params.height()) - params.height();
                 font.evaluate(
@@ -1377,7 +1663,8 @@ impl<'gc> EditText<'gc> {
                     params,
                     |pos, _transform, _glyph: &Glyph, advance, x| {
                         if local_position.x >= x {
-                            if local_position.x > x + (advance / 2) {
+                            let is_after = local_position.x > x + (advance / 2);
+                            if is_after != params.is_rtl() {
                                 result = string_utils::next_char_boundary(text, pos);
                             } else {
                                 result = pos;
@@ -1425,6 +1712,7 @@ impl<'gc> EditText<'gc> {
         if let Some(selection) = self.selection() {
             let mut changed = false;
             let is_selectable = self.is_selectable();
+            let control_code = self.visual_control_code(control_code, selection.to);
             match control_code {
                 TextControlCode::Enter => {
                     self.text_input(Self::INPUT_NEWLINE, context);
@@ -1447,7 +1735,8 @@ impl<'gc> EditText<'gc> {
                 | TextControlCode::MoveRightWord
                 | TextControlCode::MoveRightLine
                 | TextControlCode::MoveRightDocument => {
-                    let new_pos = if selection.is_caret() && selection.to < self.text().len() {
+                    // The end of the text isn't always displayed at the right of the row.
+                    let new_pos = if selection.is_caret() {
                         self.find_new_position(control_code, selection.to)
                     } else {
                         selection.end()
@@ -1461,7 +1750,7 @@ impl<'gc> EditText<'gc> {
                 | TextControlCode::SelectLeftWord
                 | TextControlCode::SelectLeftLine
                 | TextControlCode::SelectLeftDocument => {
-                    if is_selectable && selection.to > 0 {
+                    if is_selectable {
                         let new_pos = self.find_new_position(control_code, selection.to);
                         self.set_selection(
                             Some(TextSelection::for_range(selection.from, new_pos)),
@@ -1473,7 +1762,7 @@ impl<'gc> EditText<'gc> {
                 | TextControlCode::SelectRightWord
                 | TextControlCode::SelectRightLine
                 | TextControlCode::SelectRightDocument => {
-                    if is_selectable && selection.to < self.text().len() {
+                    if is_selectable {
                         let new_pos = self.find_new_position(control_code, selection.to);
                         self.set_selection(
                             Some(TextSelection::for_range(selection.from, new_pos)),
@@ -1601,6 +1890,135 @@ impl<'gc> EditText<'gc> {
         }
     }
 
+    /// Returns whether the text at the given position is laid out from right to left.
+    fn is_rtl_at(self, index: usize) -> bool {
+        let read = self.0.read();
+        let level_at = |index: usize| {
+            read.layout
+                .iter()
+                .find_map(|layout_box| match layout_box.content() {
+                    LayoutContent::Text {
+                        start,
+                        end,
+                        bidi_level,
+                        ..
+                    } if (*start..*end).contains(&index) => Some(*bidi_level),
+                    _ => None,
+                })
+        };
+
+        // At the end of a run, the caret belongs to the previous character.
+        level_at(index)
+            .or_else(|| index.checked_sub(1).and_then(level_at))
+            .is_some_and(|level| level % 2 == 1)
+    }
+
+    /// Map a left/right word control code to its logical direction at the given position.
+    ///
+    /// Arrow keys move the caret visually, so they are swapped within
+    /// right-to-left text. Single characters are handled by [`Self::visual_caret_position`].
+    fn visual_control_code(self, control_code: TextControlCode, pos: usize) -> TextControlCode {
+        let swapped = match control_code {
+            TextControlCode::MoveLeftWord => TextControlCode::MoveRightWord,
+            TextControlCode::MoveRightWord => TextControlCode::MoveLeftWord,
+            TextControlCode::SelectLeftWord => TextControlCode::SelectRightWord,
+            TextControlCode::SelectRightWord => TextControlCode::SelectLeftWord,
+            _ => return control_code,
+        };
+        if self.is_rtl_at(pos) {
+            swapped
+        } else {
+            control_code
+        }
+    }
+
+    /// Find the caret position that is displayed next to the given one, to its
+    /// left or right, on a row containing right-to-left text.
+    ///
+    /// The caret of a position is displayed at the leading edge of the
+    /// character at that position, or at the trailing edge of the previous
+    /// character if there is none on the row. Positions displayed at the same
+    /// place are told apart by their distance from `pos`.
+    ///
+    /// Returns `None` if the row is left-to-right only, or if there is no
+    /// position in that direction on the row.
+    fn visual_caret_position(self, pos: usize, to_right: bool) -> Option<usize> {
+        let read = self.0.read();
+        let text_boxes = || {
+            read.layout
+                .iter()
+                .filter_map(|layout_box| match layout_box.content() {
+                    LayoutContent::Text {
+                        start,
+                        end,
+                        bidi_level,
+                        ..
+                    } => Some((layout_box, *start, *end, *bidi_level)),
+                    _ => None,
+                })
+        };
+
+        let row_y = text_boxes()
+            .find(|(_, start, end, _)| (*start..*end).contains(&pos))
+            .or_else(|| text_boxes().find(|(_, _, end, _)| *end == pos))
+            .map(|(layout_box, ..)| layout_box.bounds().offset_y())?;
+        let row =
+            || text_boxes().filter(|(layout_box, ..)| layout_box.bounds().offset_y() == row_y);
+        if row().all(|(.., bidi_level)| bidi_level % 2 == 0) {
+            return None;
+        }
+
+        let mut leading_edges = BTreeMap::new();
+        let mut trailing_edges = BTreeMap::new();
+        for (layout_box, start, _end, _level) in row() {
+            let Some((text, _tf, font, params, color)) =
+                layout_box.as_renderable_text(read.text_spans.text())
+            else {
+                continue;
+            };
+            let offset_x = layout_box.bounds().offset_x();
+            let baseline_adjustment =
+                font.get_baseline_for_height(params.height()) - params.height();
+            font.evaluate(
+                text,
+                self.text_transform(color, baseline_adjustment),
+                params,
+                |glyph_pos, _transform, _glyph: &Glyph, advance, x| {
+                    let (left, right) = (offset_x + x, offset_x + x + advance);
+                    let (leading, trailing) = if params.is_rtl() {
+                        (right, left)
+                    } else {
+                        (left, right)
+                    };
+                    let index = start + glyph_pos;
+                    leading_edges.insert(index, leading);
+                    trailing_edges.insert(
+                        string_utils::next_char_boundary(text, glyph_pos) + start,
+                        trailing,
+                    );
+                },
+            );
+        }
+        for (index, x) in trailing_edges {
+            leading_edges.entry(index).or_insert(x);
+        }
+
+        let &current_x = leading_edges.get(&pos)?;
+        let candidates = leading_edges.into_iter().filter(|&(_, x)| {
+            if to_right {
+                x > current_x
+            } else {
+                x < current_x
+            }
+        });
+        let closest = if to_right {
+            candidates.min_by_key(|&(index, x)| (x, index.abs_diff(pos)))
+        } else {
+            candidates.min_by_key(|&(index, x)| (current_x - x, index.abs_diff(pos)))
+        };
+        closest.map(|(index, _)| index)
+    }
+
     /// Find the new position in the text for the given control code.
     ///
     /// * For selection codes it will represent the "to" part of the selection.
@@ -1608,12 +2026,14 @@ impl<'gc> EditText<'gc> {
     /// * For backspace/delete it will represent the position to which the text should be deleted.
     fn find_new_position(self, control_code: TextControlCode, current_pos: usize) -> usize {
         match control_code {
-            TextControlCode::SelectRight | TextControlCode::MoveRight | TextControlCode::Delete => {
-                string_utils::next_char_boundary(&self.text(), current_pos)
-            }
-            TextControlCode::SelectLeft
-            | TextControlCode::MoveLeft
-            | TextControlCode::Backspace => {
+            TextControlCode::SelectRight | TextControlCode::MoveRight => self
+                .visual_caret_position(current_pos, true)
+                .unwrap_or_else(|| string_utils::next_char_boundary(&self.text(), current_pos)),
+            TextControlCode::SelectLeft | TextControlCode::MoveLeft => self
+                .visual_caret_position(current_pos, false)
+                .unwrap_or_else(|| string_utils::prev_char_boundary(&self.text(), current_pos)),
+            TextControlCode::Delete => string_utils::next_char_boundary(&self.text(), current_pos),
+            TextControlCode::Backspace => {
                 string_utils::prev_char_boundary(&self.text(), current_pos)
             }
             TextControlCode::SelectRightWord
@@ -1745,7 +2165,7 @@ impl<'gc> EditText<'gc> {
             &WString::from_char(character),
             context,
         );
-        let new_pos = selection.start() + character.len_utf8();
+        let new_pos = selection.start() + character.len_utf16();
         self.set_selection(
             Some(TextSelection::for_position(new_pos)),
             context.gc_context,
@@ -1760,6 +2180,92 @@ impl<'gc> EditText<'gc> {
         self.on_changed(&mut activation);
     }
 
+    /// Replace the pre-edit text of the IME composition in progress.
+    ///
+    /// The pre-edit text is shown underlined in place of the selection until
+    /// the composition is committed. The cursor is a range of byte indices
+    /// into the pre-edit text, and is shown as the selection.
+    pub fn set_ime_preedit(
+        self,
+        text: &str,
+        cursor: Option<(usize, usize)>,
+        context: &mut UpdateContext<'_, 'gc>,
+    ) {
+        if !self.is_editable() || (text.is_empty() && self.0.read().ime_preedit.is_none()) {
+            return;
+        }
+        let Some(start) = self.clear_ime_preedit(context) else {
+            return;
+        };
+        if text.is_empty() {
+            return;
+        }
+
+        let preedit = WString::from_utf8(text);
+        let end = start + preedit.len();
+        self.0
+            .write(context.gc_context)
+            .text_spans
+            .replace_text(start, start, &preedit, None);
+        self.set_text_format(
+            start,
+            end,
+            TextFormat {
+                underline: Some(true),
+                ..Default::default()
+            },
+            context,
+        );
+        self.0.write(context.gc_context).ime_preedit = Some(start..end);
+
+        let utf16_index =
+            |index: usize| start + text.get(..index).map_or(0, |s| s.encode_utf16().count());
+        let selection = match cursor {
+            Some((from, to)) => TextSelection::for_range(utf16_index(from), utf16_index(to)),
+            None => TextSelection::for_position(end),
+        };
+        self.set_selection(Some(selection), context.gc_context);
+    }
+
+    /// Remove the pre-edit text of the IME composition in progress, along
+    /// with the selected text it replaces.
+    ///
+    /// Returns the position at which the composition continues.
+    fn clear_ime_preedit(self, context: &mut UpdateContext<'_, 'gc>) -> Option<usize> {
+        let range = self.0.read().ime_preedit.clone();
+        let range = match range {
+            Some(range) => range,
+            None => {
+                let selection = self.selection()?;
+                selection.start()..selection.end()
+            }
+        };
+        self.0.write(context.gc_context).ime_preedit = None;
+
+        if !range.is_empty() {
+            self.replace_text(range.start, range.end, WStr::empty(), context);
+        }
+        self.set_selection(
+            Some(TextSelection::for_position(range.start)),
+            context.gc_context,
+        );
+        Some(range.start)
+    }
+
+    /// Finish the IME composition in progress, inserting the given text as
+    /// if it was typed.
+    pub fn commit_ime(self, text: &str, context: &mut UpdateContext<'_, 'gc>) {
+        if !self.is_editable() {
+            return;
+        }
+        if self.0.read().ime_preedit.is_some() {
+            self.clear_ime_preedit(context);
+        }
+        for character in text.chars() {
+            self.text_input(character, context);
+        }
+    }
+
     fn initialize_as_broadcaster(&self, activation: &mut Avm1Activation<'_, 'gc>) {
         if let Avm1Value::Object(object) = self.object() {
             activation.context.avm1.broadcaster_functions().initialize(
@@ -1918,7 +2424,7 @@ impl<'gc> EditText<'gc> {
                         font = Some(box_font);
                         text_format = Some(box_text_format);
                     }
-                    LayoutContent::Drawing { .. } => {}
+                    LayoutContent::Drawing { .. } | LayoutContent::Image { .. } => {}
                 }
             }
         }
@@ -1940,6 +2446,217 @@ impl<'gc> EditText<'gc> {
         })
     }
 
+    /// Returns the index of the first character laid out on the given line.
+    ///
+    /// Returns `None` if the line does not exist or contains no text.
+    pub fn line_offset(self, line: usize) -> Option<usize> {
+        let read = self.0.read();
+        let line = read.line_data.get(line).copied()?;
+
+        read.layout
+            .iter()
+            .filter(|layout_box| {
+                layout_box.bounds().offset_y() >= line.offset
+                    && layout_box.bounds().extent_y() <= line.extent
+            })
+            .filter_map(|layout_box| match layout_box.content() {
+                LayoutContent::Text { start, .. } => Some(*start),
+                _ => None,
+            })
+            .min()
+    }
+
+    /// Returns the bounds of the character at the given index, in the local
+    /// coordinate space of this text field.
+    ///
+    /// Returns `None` if the character is not laid out, e.g. because it is a
+    /// newline or out of range.
+    pub fn char_bounds(self, index: usize) -> Option<Rectangle<Twips>> {
+        let read = self.0.read();
+        let layout_box = read.layout.iter().find(|layout_box| {
+            matches!(layout_box.content(), LayoutContent::Text { start, end, .. } if (*start..*end).contains(&index))
+        })?;
+        let LayoutContent::Text { start, .. } = layout_box.content() else {
+            return None;
+        };
+        let (text, _tf, font, params, color) =
+            layout_box.as_renderable_text(read.text_spans.text())?;
+
+        let mut glyph_bounds = None;
+        let baseline_adjustment = font.get_baseline_for_height(params.height()) - params.height();
+        font.evaluate(
+            text,
+            self.text_transform(color, baseline_adjustment),
+            params,
+            |pos, _transform, _glyph: &Glyph, advance, x| {
+                if pos + start == index {
+                    glyph_bounds = Some((x, advance));
+                }
+            },
+        );
+        let (x, advance) = glyph_bounds?;
+
+        let padding = Twips::from_pixels(Self::INTERNAL_PADDING);
+        let bounds = layout_box.bounds();
+        let x_min = bounds.offset_x() + x + padding - Twips::from_pixels(read.hscroll);
+        let y_min = bounds.offset_y() + padding - read.vertical_scroll_offset();
+        Some(Rectangle {
+            x_min,
+            x_max: x_min + advance,
+            y_min,
+            y_max: y_min + bounds.height(),
+        })
+    }
+
+    /// Returns the index of the character under the given point, in the
+    /// local coordinate space of this text field.
+    ///
+    /// Returns `None` if there is no laid out character at the point.
+    pub fn char_index_at_point(self, point: Point<Twips>) -> Option<usize> {
+        let read = self.0.read();
+        let padding = Twips::from_pixels(Self::INTERNAL_PADDING);
+        let x = point.x - padding + Twips::from_pixels(read.hscroll);
+        let y = point.y - padding + read.vertical_scroll_offset();
+
+        for layout_box in read.layout.iter() {
+            let bounds = layout_box.bounds();
+            if y < bounds.offset_y() || y >= bounds.extent_y() {
+                continue;
+            }
+            let LayoutContent::Text { start, .. } = layout_box.content() else {
+                continue;
+            };
+            let Some((text, _tf, font, params, color)) =
+                layout_box.as_renderable_text(read.text_spans.text())
+            else {
+                continue;
+            };
+
+            let local_x = x - bounds.offset_x();
+            let mut result = None;
+            let baseline_adjustment =
+                font.get_baseline_for_height(params.height()) - params.height();
+            font.evaluate(
+                text,
+                self.text_transform(color, baseline_adjustment),
+                params,
+                |pos, _transform, _glyph: &Glyph, advance, glyph_x| {
+                    if local_x >= glyph_x && local_x < glyph_x + advance {
+                        result = Some(pos + start);
+                    }
+                },
+            );
+            if result.is_some() {
+                return result;
+            }
+        }
+
+        None
+    }
+
+    /// Returns the index of the line under the given point, in the local
+    /// coordinate space of this text field.
+    ///
+    /// Returns `None` if the point is outside of the field or below the last line.
+    pub fn line_index_at_point(self, point: Point<Twips>) -> Option<usize> {
+        let read = self.0.read();
+        let bounds = &read.bounds;
+        if point.x < bounds.x_min
+            || point.x > bounds.x_max
+            || point.y < bounds.y_min
+            || point.y > bounds.y_max
+        {
+            return None;
+        }
+
+        // The point is at or below the top of the first visible line, so this
+        // can never find a line that is scrolled out of view above the field.
+        let padding = Twips::from_pixels(Self::INTERNAL_PADDING);
+        let y = point.y - padding + read.vertical_scroll_offset();
+        if y < Twips::ZERO {
+            return None;
+        }
+
+        read.line_data.iter().position(|line| y < line.extent)
+    }
+
+    /// Returns the index of the first character of each laid out line.
+    ///
+    /// Empty lines have no layout boxes to take their offset from. They start
+    /// right after the line break that ends the previous line.
+    pub fn line_starts(self) -> Vec<usize> {
+        let text = self.text();
+        let mut starts: Vec<usize> = Vec::with_capacity(self.layout_lines());
+        for line in 0..self.layout_lines() {
+            let start = match starts.last() {
+                None => 0,
+                Some(&previous) => self.line_offset(line).unwrap_or_else(|| {
+                    text[previous..]
+                        .find(&b"\r\n"[..])
+                        .map_or(text.len(), |newline| previous + newline + 1)
+                }),
+            };
+            starts.push(start);
+        }
+        starts
+    }
+
+    /// Returns the index of the line that contains the character at the given index.
+    ///
+    /// Returns `None` if the index is out of range.
+    pub fn line_index_of_char(self, index: usize) -> Option<usize> {
+        if index >= self.text_length() {
+            return None;
+        }
+
+        // The first line starts at 0, so this finds a line for any index.
+        self.line_starts().iter().rposition(|&start| start <= index)
+    }
+
+    /// Returns the number of characters on the given line, including any
+    /// trailing line break.
+    ///
+    /// Returns `None` if the line does not exist.
+    pub fn line_length(self, line: usize) -> Option<usize> {
+        let starts = self.line_starts();
+        let start = *starts.get(line)?;
+        let next_start = starts
+            .get(line + 1)
+            .copied()
+            .unwrap_or_else(|| self.text_length());
+        Some(next_start.saturating_sub(start))
+    }
+
+    /// Returns the index of the first character of the paragraph that contains
+    /// the character at the given index.
+    ///
+    /// Returns `None` if the index is out of range.
+    pub fn first_char_in_paragraph(self, index: usize) -> Option<usize> {
+        let text = self.text();
+        if index >= text.len() {
+            return None;
+        }
+
+        Some(
+            text[..index]
+                .rfind(&b"\r\n"[..])
+                .map_or(0, |newline| newline + 1),
+        )
+    }
+
+    /// Returns the number of characters in the paragraph that contains the
+    /// character at the given index, including its trailing line break.
+    ///
+    /// Returns `None` if the index is out of range.
+    pub fn paragraph_length(self, index: usize) -> Option<usize> {
+        let start = self.first_char_in_paragraph(index)?;
+        let text = self.text();
+        let end = text[index..]
+            .find(&b"\r\n"[..])
+            .map_or(text.len(), |newline| index + newline + 1);
+        Some(end - start)
+    }
+
     pub fn line_text(self, line: usize) -> Option<WString> {
         let read = self.0.read();
         let line = read.line_data.get(line).copied()?;
@@ -2061,10 +2778,11 @@ impl<'gc> TDisplayObject<'gc> for EditText<'gc> {
             self.construct_as_avm2_object(context, (*self).into());
             self.on_construction_complete(context);
         }
+        self.update_images(context);
     }
 
-    fn run_frame_avm1(&self, _context: &mut UpdateContext) {
-        // Noop
+    fn run_frame_avm1(&self, context: &mut UpdateContext<'_, 'gc>) {
+        self.update_images(context);
     }
 
     fn as_edit_text(&self) -> Option<EditText<'gc>> {
@@ -2245,6 +2963,19 @@ impl<'gc> TDisplayObject<'gc> for EditText<'gc> {
 
         context.transform_stack.pop();
 
+        // Images are positioned in the coordinate space of the text field itself.
+        context.transform_stack.push(&Transform {
+            matrix: Matrix::translate(
+                -edit_text.bounds.x_min - Twips::from_pixels(edit_text.hscroll),
+                -edit_text.bounds.y_min - scroll_offset,
+            ),
+            ..Default::default()
+        });
+        for image in edit_text.images.iter().flatten() {
+            image.render(context);
+        }
+        context.transform_stack.pop();
+
         context.commands.deactivate_mask();
         context.commands.draw_rect(
             Color::WHITE,
@@ -2440,6 +3171,10 @@ impl<'gc> TInteractiveObject<'gc> for EditText<'gc> {
         focused: bool,
         _other: Option<InteractiveObject<'gc>>,
     ) {
+        if !focused && self.0.read().ime_preedit.is_some() {
+            self.clear_ime_preedit(context);
+        }
+
         let is_avm1 = !self.movie().is_action_script_3();
         if !focused && is_avm1 {
             self.0.write(context.gc_context).selection = None;
@@ -13,6 +13,7 @@ use core::fmt;
 use gc_arena::{Collect, GcCell, Mutation};
 use ruffle_render::backend::ShapeHandle;
 use ruffle_render::commands::CommandHandler;
+use ruffle_render::shape_utils::ScalingGrid;
 use std::cell::{Ref, RefMut};
 use std::sync::Arc;
 
@@ -36,6 +37,9 @@ pub struct GraphicData<'gc> {
     avm2_object: Option<Avm2Object<'gc>>,
     #[collect(require_static)]
     drawing: Option<Drawing>,
+    /// The shape tessellated with our parent's 9-slice scaling grid, and the grid and matrix it was made for.
+    #[collect(require_static)]
+    scaled_render_handle: Option<(ScalingGrid, Matrix, ShapeHandle)>,
 }
 
 impl<'gc> Graphic<'gc> {
@@ -65,6 +69,7 @@ impl<'gc> Graphic<'gc> {
                 static_data: gc_arena::Gc::new(context.gc_context, static_data),
                 avm2_object: None,
                 drawing: None,
+                scaled_render_handle: None,
             },
         ))
     }
@@ -98,6 +103,7 @@ impl<'gc> Graphic<'gc> {
                 static_data: gc_arena::Gc::new(context.gc_context, static_data),
                 avm2_object: None,
                 drawing: Some(drawing),
+                scaled_render_handle: None,
             },
         ))
     }
@@ -107,6 +113,58 @@ impl<'gc> Graphic<'gc> {
             w.drawing.get_or_insert_with(Drawing::new)
         })
     }
+
+    /// The 9-slice scaling grid of our parent, which applies to this shape.
+    fn parent_scaling_grid(&self) -> Option<ScalingGrid> {
+        self.parent()?.resolved_scaling_grid()
+    }
+
+    fn render_with_scaling_grid(
+        &self,
+        context: &mut RenderContext<'_, 'gc>,
+        scaling_grid: &ScalingGrid,
+    ) {
+        let matrix = *self.base().matrix();
+        let mut write = self.0.write(context.gc_context);
+        if let Some(drawing) = &write.drawing {
+            drawing.render_with_scaling_grid(context, scaling_grid, &matrix);
+            return;
+        }
+
+        let cached_handle = match &write.scaled_render_handle {
+            Some((cached_grid, cached_matrix, handle))
+                if cached_grid == scaling_grid && *cached_matrix == matrix =>
+            {
+                Some(handle.clone())
+            }
+            _ => None,
+        };
+        let handle = if let Some(handle) = cached_handle {
+            Some(handle)
+        } else {
+            let static_data = write.static_data;
+            let library = context
+                .library
+                .library_for_movie(static_data.movie.clone())
+                .unwrap();
+            let shape = scaling_grid.transform_shape((&static_data.shape).into(), &matrix);
+            let handle = shape.map(|shape| {
+                context
+                    .renderer
+                    .register_shape(shape, &MovieLibrarySource { library })
+            });
+            if let Some(handle) = &handle {
+                write.scaled_render_handle = Some((*scaling_grid, matrix, handle.clone()));
+            }
+            handle.or_else(|| static_data.render_handle.clone())
+        };
+
+        if let Some(handle) = handle {
+            context
+                .commands
+                .render_shape(handle, context.transform_stack.transform());
+        }
+    }
 }
 
 impl<'gc> TDisplayObject<'gc> for Graphic<'gc> {
@@ -168,7 +226,9 @@ impl<'gc> TDisplayObject<'gc> for Graphic<'gc> {
             .library_for_movie_mut(self.movie())
             .get_graphic(id)
         {
-            self.0.write(context.gc_context).static_data = new_graphic.0.read().static_data;
+            let mut write = self.0.write(context.gc_context);
+            write.static_data = new_graphic.0.read().static_data;
+            write.scaled_render_handle = None;
         } else {
             tracing::warn!("PlaceObject: expected Graphic at character ID {}", id);
         }
@@ -179,13 +239,22 @@ impl<'gc> TDisplayObject<'gc> for Graphic<'gc> {
         // Noop
     }
 
-    fn render_self(&self, context: &mut RenderContext) {
-        if !context.is_offscreen && !self.world_bounds().intersects(&context.stage.view_bounds()) {
+    fn render_self(&self, context: &mut RenderContext<'_, 'gc>) {
+        let scaling_grid = self.parent_scaling_grid();
+
+        // 9-slice scaling moves our content around within the parent's bounds, so cull against those instead.
+        let world_bounds = match (scaling_grid, self.parent()) {
+            (Some(_), Some(parent)) => parent.world_bounds(),
+            _ => self.world_bounds(),
+        };
+        if !context.is_offscreen && !world_bounds.intersects(&context.stage.view_bounds()) {
             // Off-screen; culled
             return;
         }
 
-        if let Some(drawing) = &self.0.read().drawing {
+        if let Some(scaling_grid) = scaling_grid {
+            self.render_with_scaling_grid(context, &scaling_grid);
+        } else if let Some(drawing) = &self.0.read().drawing {
             drawing.render(context);
         } else if let Some(render_handle) = self.0.read().static_data.render_handle.clone() {
             context
@@ -200,25 +269,45 @@ impl<'gc> TDisplayObject<'gc> for Graphic<'gc> {
         point: Point<Twips>,
         options: HitTestOptions,
     ) -> bool {
+        if options.contains(HitTestOptions::SKIP_INVISIBLE) && !self.visible() {
+            return false;
+        }
+
         // Transform point to local coordinates and test.
-        if (!options.contains(HitTestOptions::SKIP_INVISIBLE) || self.visible())
-            && self.world_bounds().contains(point)
-        {
-            let Some(local_matrix) = self.global_to_local_matrix() else {
-                return false;
-            };
-            let point = local_matrix * point;
-            if let Some(drawing) = &self.0.read().drawing {
-                if drawing.hit_test(point, &local_matrix) {
-                    return true;
+        let Some(local_matrix) = self.global_to_local_matrix() else {
+            return false;
+        };
+        let point = match (self.parent_scaling_grid(), self.parent()) {
+            (Some(scaling_grid), Some(parent)) => {
+                // Undo the 9-slice scaling in our parent's coordinate space first.
+                if !parent.world_bounds().contains(point) {
+                    return false;
                 }
-            } else {
-                let shape = &self.0.read().static_data.shape;
-                return ruffle_render::shape_utils::shape_hit_test(shape, point, &local_matrix);
+                let (Some(parent_matrix), Some(inverse_matrix)) = (
+                    parent.global_to_local_matrix(),
+                    self.base().matrix().inverse(),
+                ) else {
+                    return false;
+                };
+                inverse_matrix * scaling_grid.inverse_transform_point(parent_matrix * point)
             }
-        }
+            _ => {
+                if !self.world_bounds().contains(point) {
+                    return false;
+                }
+                let Some(point) = self.global_to_local(point) else {
+                    return false;
+                };
+                point
+            }
+        };
 
-        false
+        if let Some(drawing) = &self.0.read().drawing {
+            drawing.hit_test(point, &local_matrix)
+        } else {
+            let shape = &self.0.read().static_data.shape;
+            ruffle_render::shape_utils::shape_hit_test(shape, point, &local_matrix)
+        }
     }
 
     fn post_instantiation(
@@ -163,12 +163,14 @@ impl<'gc> TDisplayObject<'gc> for MorphShape<'gc> {
             && self.world_bounds().contains(point)
         {
             if let Some(frame) = self.0.read().static_data.frames.borrow().get(&self.ratio()) {
-                let Some(local_matrix) = self.global_to_local_matrix() else {
+                let (Some(local_matrix), Some(local_point)) =
+                    (self.global_to_local_matrix(), self.global_to_local(point))
+                else {
                     return false;
                 };
                 return ruffle_render::shape_utils::shape_hit_test(
                     &frame.shape,
-                    local_matrix * point,
+                    local_point,
                     &local_matrix,
                 );
             } else {
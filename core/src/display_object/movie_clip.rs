@@ -1121,6 +1121,89 @@ impl<'gc> MovieClip<'gc> {
         }
     }
 
+    /// Builds a detached copy of this clip showing the given frame, so that
+    /// the frame can be rendered without moving this clip's playhead.
+    ///
+    /// The copy only holds the display list of the timeline: its children
+    /// don't get AVM objects, and no actions, constructors or sounds are run.
+    /// Nested clips show their first frame.
+    pub fn timeline_frame_copy(
+        self,
+        context: &mut UpdateContext<'_, 'gc>,
+        frame: FrameNumber,
+    ) -> MovieClip<'gc> {
+        let read = self.0.read();
+        let copy = MovieClip::new_with_data(
+            context.gc_context,
+            read.id(),
+            read.static_data.swf.clone(),
+            read.total_frames(),
+        );
+        drop(read);
+        copy.place_timeline_frame(context, frame);
+        copy
+    }
+
+    /// Places the timeline's children of the given frame on a clip that
+    /// hasn't run any frame yet, without running any of their scripts.
+    fn place_timeline_frame(self, context: &mut UpdateContext<'_, 'gc>, frame: FrameNumber) {
+        let data = self.0.read().static_data.swf.clone();
+        let mut place_commands: Vec<GotoPlaceObject<'_>> = vec![];
+        let mut index = 0;
+
+        let mut reader = data.read_from(0);
+        while self.current_frame() < frame && !reader.get_ref().is_empty() {
+            self.0.write(context.gc_context).current_frame += 1;
+
+            let tag_callback = |reader: &mut _, tag_code, _tag_len| {
+                let version = match tag_code {
+                    TagCode::PlaceObject => 1,
+                    TagCode::PlaceObject2 => 2,
+                    TagCode::PlaceObject3 => 3,
+                    TagCode::PlaceObject4 => 4,
+                    TagCode::RemoveObject | TagCode::RemoveObject2 => {
+                        let remove_object = if let TagCode::RemoveObject = tag_code {
+                            SwfStream::read_remove_object_1(reader)
+                        } else {
+                            SwfStream::read_remove_object_2(reader)
+                        }?;
+                        let depth: Depth = remove_object.depth.into();
+                        place_commands.retain(|place| place.depth() != depth);
+                        return Ok(ControlFlow::Continue);
+                    }
+                    TagCode::ShowFrame => return Ok(ControlFlow::Exit),
+                    _ => return Ok(ControlFlow::Continue),
+                };
+                index += 1;
+                let mut mc = self.0.write(context.gc_context);
+                mc.goto_place_object(reader, version, &mut place_commands, true, index)?;
+                Ok(ControlFlow::Continue)
+            };
+            let _ = tag_utils::decode_tags(&mut reader, tag_callback);
+        }
+
+        place_commands.sort_by_key(|place| place.index);
+        for place in &place_commands {
+            let place_object = &place.place_object;
+            let id = match place_object.action {
+                swf::PlaceObjectAction::Place(id) | swf::PlaceObjectAction::Replace(id) => id,
+                swf::PlaceObjectAction::Modify => continue,
+            };
+            let library = context.library.library_for_movie_mut(self.movie());
+            let Ok(child) = library.instantiate_by_id(id, context.gc_context) else {
+                continue;
+            };
+            self.replace_at_depth(context, child, place.depth());
+            child.apply_place_object(context, place_object);
+            if let Some(clip_depth) = place_object.clip_depth {
+                child.set_clip_depth(context.gc_context, clip_depth.into());
+            }
+            if let Some(clip) = child.as_movie_clip() {
+                clip.place_timeline_frame(context, 1);
+            }
+        }
+    }
+
     pub fn current_frame(self) -> FrameNumber {
         self.0.read().current_frame()
     }
@@ -2381,6 +2464,15 @@ impl<'gc> MovieClip<'gc> {
         RefMut::map(self.0.write(gc_context), |s| &mut s.drawing)
     }
 
+    /// Hit tests the drawing of this clip, taking its 9-slice scaling grid into account.
+    fn hit_test_drawing(&self, point: Point<Twips>, local_matrix: &Matrix) -> bool {
+        let point = match self.resolved_scaling_grid() {
+            Some(scaling_grid) => scaling_grid.inverse_transform_point(point),
+            None => point,
+        };
+        self.0.read().drawing.hit_test(point, local_matrix)
+    }
+
     pub fn is_button_mode(&self, context: &mut UpdateContext<'_, 'gc>) -> bool {
         if self.forced_button_mode()
             || self
@@ -2791,7 +2883,15 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
     }
 
     fn render_self(&self, context: &mut RenderContext<'_, 'gc>) {
-        self.0.read().drawing.render(context);
+        if let Some(scaling_grid) = self.resolved_scaling_grid() {
+            self.0.read().drawing.render_with_scaling_grid(
+                context,
+                &scaling_grid,
+                &Matrix::IDENTITY,
+            );
+        } else {
+            self.0.read().drawing.render(context);
+        }
         self.render_children(context);
     }
 
@@ -2846,8 +2946,10 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
                 }
             }
 
-            let point = local_matrix * point;
-            if self.0.read().drawing.hit_test(point, &local_matrix) {
+            if self
+                .global_to_local(point)
+                .is_some_and(|point| self.hit_test_drawing(point, &local_matrix))
+            {
                 return true;
             }
         }
@@ -3194,8 +3296,10 @@ impl<'gc> TInteractiveObject<'gc> for MovieClip<'gc> {
 
             // Check drawing, because this selects the current clip, it must have mouse enabled
             if self.mouse_enabled() && check_non_interactive {
-                let point = local_matrix * point;
-                if self.0.read().drawing.hit_test(point, &local_matrix) {
+                if self
+                    .global_to_local(point)
+                    .is_some_and(|point| self.hit_test_drawing(point, &local_matrix))
+                {
                     return Some(this);
                 }
             }
@@ -3334,9 +3438,10 @@ impl<'gc> TInteractiveObject<'gc> for MovieClip<'gc> {
 
             // Check drawing, because this selects the current clip, it must have mouse enabled
             if self.world_bounds().contains(point) {
-                let point = local_matrix * point;
-
-                if self.0.read().drawing.hit_test(point, &local_matrix) {
+                if self
+                    .global_to_local(point)
+                    .is_some_and(|point| self.hit_test_drawing(point, &local_matrix))
+                {
                     return if self.mouse_enabled() {
                         Avm2MousePick::Hit((*self).into())
                     } else {
@@ -3,8 +3,10 @@ use crate::avm2::{
 };
 use crate::context::{RenderContext, UpdateContext};
 use crate::display_object::{DisplayObjectBase, DisplayObjectPtr};
-use crate::font::TextRenderSettings;
+use crate::font::{Font, TextRenderSettings};
+use crate::library::Library;
 use crate::prelude::*;
+use crate::string::WString;
 use crate::tag_utils::SwfMovie;
 use crate::vminterface::Instantiator;
 use core::fmt;
@@ -12,6 +14,7 @@ use gc_arena::{Collect, GcCell, Mutation};
 use ruffle_render::commands::CommandHandler;
 use ruffle_render::transform::Transform;
 use std::cell::{Ref, RefMut};
+use std::ops::Range;
 use std::sync::Arc;
 
 #[derive(Clone, Collect, Copy)]
@@ -34,6 +37,36 @@ pub struct TextData<'gc> {
     #[collect(require_static)]
     render_settings: TextRenderSettings,
     avm2_object: Option<Avm2Object<'gc>>,
+
+    /// Which glyphs have been selected through `TextSnapshot`.
+    #[collect(require_static)]
+    selection: Vec<bool>,
+
+    /// The color used to highlight selected glyphs.
+    #[collect(require_static)]
+    select_color: Color,
+}
+
+/// A single glyph of a static text, as exposed through `TextSnapshot`.
+#[derive(Clone, Debug)]
+pub struct StaticTextGlyph<'gc> {
+    /// The character that this glyph represents.
+    pub character: char,
+
+    /// The index of the line that this glyph is laid out on.
+    pub line: usize,
+
+    pub font: Font<'gc>,
+    pub height: Twips,
+    pub color: Color,
+
+    /// The transform from the glyph's space into the local space of the text.
+    /// The origin of the glyph is on its baseline.
+    pub matrix: Matrix,
+
+    /// The bounds of the glyph in its own space, spanning from the font's
+    /// ascent to its descent.
+    pub bounds: Rectangle<Twips>,
 }
 
 impl<'gc> Text<'gc> {
@@ -58,6 +91,8 @@ impl<'gc> Text<'gc> {
                 ),
                 render_settings: Default::default(),
                 avm2_object: None,
+                selection: Vec::new(),
+                select_color: Color::from_rgb(0xFFFF00, 0xFF),
             },
         ))
     }
@@ -66,6 +101,97 @@ impl<'gc> Text<'gc> {
         self.0.write(gc_context).render_settings = settings;
         self.invalidate_cached_bitmap(gc_context);
     }
+
+    /// Returns the glyphs of this text, in the order that they are laid out.
+    ///
+    /// Glyphs that are missing from their font are skipped, as they are not rendered.
+    pub fn glyphs(self, library: &Library<'gc>) -> Vec<StaticTextGlyph<'gc>> {
+        let tf = self.0.read();
+        let Some(library) = library.library_for_movie(self.movie()) else {
+            return vec![];
+        };
+
+        let mut glyphs = vec![];
+        let mut color = Color::from_rgba(0);
+        let mut font_id = 0;
+        let mut height = Twips::ZERO;
+        let mut x = Twips::ZERO;
+        let mut y = None;
+        let mut line = 0;
+        for block in &tf.static_data.text_blocks {
+            if let Some(x_offset) = block.x_offset {
+                x = x_offset;
+            }
+            if let Some(y_offset) = block.y_offset {
+                if y.is_some_and(|y| y != y_offset) {
+                    line += 1;
+                }
+                y = Some(y_offset);
+            }
+            color = block.color.unwrap_or(color);
+            font_id = block.font_id.unwrap_or(font_id);
+            height = block.height.unwrap_or(height);
+
+            let Some(font) = library.get_font(font_id) else {
+                continue;
+            };
+            let ascent = font.get_baseline_for_height(height);
+            let descent = font.get_descent_for_height(height);
+            for c in &block.glyphs {
+                if let Some(glyph) = font.get_glyph(c.index as usize) {
+                    let advance = Twips::new(c.advance);
+                    glyphs.push(StaticTextGlyph {
+                        character: glyph.character(),
+                        line,
+                        font,
+                        height,
+                        color,
+                        matrix: tf.static_data.text_transform
+                            * Matrix::translate(x, y.unwrap_or_default()),
+                        bounds: Rectangle {
+                            x_min: Twips::ZERO,
+                            x_max: advance,
+                            y_min: -ascent,
+                            y_max: descent,
+                        },
+                    });
+                    x += advance;
+                }
+            }
+        }
+
+        glyphs
+    }
+
+    /// Returns the text represented by the glyphs of this text.
+    pub fn text(self, library: &Library<'gc>) -> WString {
+        let mut text = WString::new();
+        for glyph in self.glyphs(library) {
+            text.push_char(glyph.character);
+        }
+        text
+    }
+
+    /// Returns whether the glyph at the given index is selected.
+    pub fn is_selected(self, index: usize) -> bool {
+        self.0.read().selection.get(index).copied().unwrap_or(false)
+    }
+
+    /// Selects or deselects a range of glyphs.
+    pub fn set_selected(self, gc_context: &Mutation<'gc>, range: Range<usize>, selected: bool) {
+        let mut write = self.0.write(gc_context);
+        if write.selection.len() < range.end {
+            write.selection.resize(range.end, false);
+        }
+        write.selection[range].fill(selected);
+        drop(write);
+        self.invalidate_cached_bitmap(gc_context);
+    }
+
+    pub fn set_select_color(self, gc_context: &Mutation<'gc>, color: Color) {
+        self.0.write(gc_context).select_color = color;
+        self.invalidate_cached_bitmap(gc_context);
+    }
 }
 
 impl<'gc> TDisplayObject<'gc> for Text<'gc> {
@@ -126,6 +252,7 @@ impl<'gc> TDisplayObject<'gc> for Text<'gc> {
         let mut font_id = 0;
         let mut height = Twips::ZERO;
         let mut transform: Transform = Default::default();
+        let mut index = 0;
         for block in &tf.static_data.text_blocks {
             if let Some(x) = block.x_offset {
                 transform.matrix.tx = x;
@@ -148,6 +275,21 @@ impl<'gc> TDisplayObject<'gc> for Text<'gc> {
                 transform.color_transform.set_mult_color(&color);
                 for c in &block.glyphs {
                     if let Some(glyph) = font.get_glyph(c.index as usize) {
+                        if tf.selection.get(index).copied().unwrap_or(false) {
+                            let ascent = font.get_baseline_for_height(height);
+                            let descent = font.get_descent_for_height(height);
+                            let selection_box = context.transform_stack.transform().matrix
+                                * Matrix::create_box(
+                                    Twips::new(c.advance).to_pixels() as f32,
+                                    (ascent + descent).to_pixels() as f32,
+                                    0.0,
+                                    transform.matrix.tx,
+                                    transform.matrix.ty - ascent,
+                                );
+                            context.commands.draw_rect(tf.select_color, selection_box);
+                        }
+                        index += 1;
+
                         if let Some(glyph_shape_handle) = glyph.shape_handle(context.renderer) {
                             context.transform_stack.push(&transform);
                             context.commands.render_shape(
@@ -191,7 +333,10 @@ impl<'gc> TDisplayObject<'gc> for Text<'gc> {
             let Some(text_matrix) = tf.static_data.text_transform.inverse() else {
                 return false;
             };
-            point = text_matrix * local_matrix * point;
+            let Some(local_point) = self.global_to_local(point) else {
+                return false;
+            };
+            point = text_matrix * local_point;
 
             let mut font_id = 0;
             let mut height = Twips::ZERO;
@@ -2,8 +2,10 @@ use crate::context::RenderContext;
 use ruffle_render::backend::{RenderBackend, ShapeHandle};
 use ruffle_render::bitmap::{BitmapHandle, BitmapInfo, BitmapSize, BitmapSource};
 use ruffle_render::commands::CommandHandler;
+use ruffle_render::matrix::Matrix;
 use ruffle_render::shape_utils::{
     cubic_curve_bounds, quadratic_curve_bounds, DistilledShape, DrawCommand, DrawPath, FillRule,
+    ScalingGrid,
 };
 use std::cell::{Cell, RefCell};
 use swf::{FillStyle, LineStyle, Point, Rectangle, Twips};
@@ -11,6 +13,7 @@ use swf::{FillStyle, LineStyle, Point, Rectangle, Twips};
 #[derive(Clone, Debug)]
 pub struct Drawing {
     render_handle: RefCell<Option<ShapeHandle>>,
+    scaled_render_handle: RefCell<Option<(ScalingGrid, Matrix, ShapeHandle)>>,
     shape_bounds: Rectangle<Twips>,
     edge_bounds: Rectangle<Twips>,
     dirty: Cell<bool>,
@@ -34,6 +37,7 @@ impl Drawing {
     pub fn new() -> Self {
         Self {
             render_handle: RefCell::new(None),
+            scaled_render_handle: RefCell::new(None),
             shape_bounds: Default::default(),
             edge_bounds: Default::default(),
             dirty: Cell::new(false),
@@ -51,6 +55,7 @@ impl Drawing {
     pub fn from_swf_shape(shape: &swf::Shape) -> Self {
         let mut this = Self {
             render_handle: RefCell::new(None),
+            scaled_render_handle: RefCell::new(None),
             shape_bounds: shape.shape_bounds.clone(),
             edge_bounds: shape.edge_bounds.clone(),
             dirty: Cell::new(true),
@@ -107,6 +112,7 @@ impl Drawing {
     pub fn copy_from(&mut self, other: &Drawing) {
         *self = Drawing {
             render_handle: RefCell::new(None),
+            scaled_render_handle: RefCell::new(None),
             dirty: Cell::new(true),
             shape_bounds: other.shape_bounds.clone(),
             edge_bounds: other.edge_bounds.clone(),
@@ -239,75 +245,79 @@ impl Drawing {
         id
     }
 
-    pub fn register_or_replace(&self, renderer: &mut dyn RenderBackend) -> ShapeHandle {
-        if self.dirty.get() || self.render_handle.borrow().is_none() {
-            self.dirty.set(false);
-            let mut paths = Vec::with_capacity(self.paths.len());
-
-            for path in &self.paths {
-                match path {
-                    DrawingPath::Fill(fill) => {
-                        paths.push(DrawPath::Fill {
-                            style: &fill.style,
-                            commands: fill.commands.to_owned(),
-                            winding_rule: FillRule::EvenOdd,
-                        });
-                    }
-                    DrawingPath::Line(line) => {
-                        paths.push(DrawPath::Stroke {
-                            style: &line.style,
-                            commands: line.commands.to_owned(),
-                            is_closed: line.is_closed,
-                        });
-                    }
-                }
-            }
+    fn distilled_shape(&self) -> DistilledShape<'_> {
+        let mut paths = Vec::with_capacity(self.paths.len());
 
-            if let Some(fill) = &self.current_fill {
-                paths.push(DrawPath::Fill {
-                    style: &fill.style,
-                    commands: fill.commands.to_owned(),
-                    winding_rule: FillRule::EvenOdd,
-                })
+        for path in &self.paths {
+            match path {
+                DrawingPath::Fill(fill) => {
+                    paths.push(DrawPath::Fill {
+                        style: &fill.style,
+                        commands: fill.commands.to_owned(),
+                        winding_rule: FillRule::EvenOdd,
+                    });
+                }
+                DrawingPath::Line(line) => {
+                    paths.push(DrawPath::Stroke {
+                        style: &line.style,
+                        commands: line.commands.to_owned(),
+                        is_closed: line.is_closed,
+                    });
+                }
             }
+        }
 
-            for line in &self.pending_lines {
-                let mut commands = line.commands.to_owned();
-                let is_closed = if self.current_fill.is_some() {
-                    commands.push(DrawCommand::LineTo(self.fill_start));
-                    true
-                } else {
-                    self.cursor == self.fill_start
-                };
-                paths.push(DrawPath::Stroke {
-                    style: &line.style,
-                    commands,
-                    is_closed,
-                })
-            }
+        if let Some(fill) = &self.current_fill {
+            paths.push(DrawPath::Fill {
+                style: &fill.style,
+                commands: fill.commands.to_owned(),
+                winding_rule: FillRule::EvenOdd,
+            })
+        }
 
-            if let Some(line) = &self.current_line {
-                let mut commands = line.commands.to_owned();
-                let is_closed = if self.current_fill.is_some() {
-                    commands.push(DrawCommand::LineTo(self.fill_start));
-                    true
-                } else {
-                    self.cursor == self.fill_start
-                };
-                paths.push(DrawPath::Stroke {
-                    style: &line.style,
-                    commands,
-                    is_closed,
-                })
-            }
+        for line in &self.pending_lines {
+            let mut commands = line.commands.to_owned();
+            let is_closed = if self.current_fill.is_some() {
+                commands.push(DrawCommand::LineTo(self.fill_start));
+                true
+            } else {
+                self.cursor == self.fill_start
+            };
+            paths.push(DrawPath::Stroke {
+                style: &line.style,
+                commands,
+                is_closed,
+            })
+        }
 
-            let shape = DistilledShape {
-                paths,
-                shape_bounds: self.shape_bounds.clone(),
-                edge_bounds: self.edge_bounds.clone(),
-                id: 0,
+        if let Some(line) = &self.current_line {
+            let mut commands = line.commands.to_owned();
+            let is_closed = if self.current_fill.is_some() {
+                commands.push(DrawCommand::LineTo(self.fill_start));
+                true
+            } else {
+                self.cursor == self.fill_start
             };
-            let handle = renderer.register_shape(shape, self);
+            paths.push(DrawPath::Stroke {
+                style: &line.style,
+                commands,
+                is_closed,
+            })
+        }
+
+        DistilledShape {
+            paths,
+            shape_bounds: self.shape_bounds.clone(),
+            edge_bounds: self.edge_bounds.clone(),
+            id: 0,
+        }
+    }
+
+    pub fn register_or_replace(&self, renderer: &mut dyn RenderBackend) -> ShapeHandle {
+        if self.dirty.get() || self.render_handle.borrow().is_none() {
+            self.dirty.set(false);
+            self.scaled_render_handle.take();
+            let handle = renderer.register_shape(self.distilled_shape(), self);
             self.render_handle.replace(Some(handle.clone()));
             handle
         } else {
@@ -325,6 +335,42 @@ impl Drawing {
             .render_shape(handle, context.transform_stack.transform());
     }
 
+    /// Renders this drawing with 9-slice scaling applied.
+    ///
+    /// `matrix` transforms this drawing into the local space of the object that owns the grid.
+    pub fn render_with_scaling_grid(
+        &self,
+        context: &mut RenderContext,
+        scaling_grid: &ScalingGrid,
+        matrix: &Matrix,
+    ) {
+        // Make sure any pending changes to the drawing also replace the scaled shape.
+        let _ = self.register_or_replace(context.renderer);
+
+        let cached_handle = match &*self.scaled_render_handle.borrow() {
+            Some((cached_grid, cached_matrix, handle))
+                if cached_grid == scaling_grid && cached_matrix == matrix =>
+            {
+                Some(handle.clone())
+            }
+            _ => None,
+        };
+        let handle = if let Some(handle) = cached_handle {
+            handle
+        } else if let Some(shape) = scaling_grid.transform_shape(self.distilled_shape(), matrix) {
+            let handle = context.renderer.register_shape(shape, self);
+            self.scaled_render_handle
+                .replace(Some((*scaling_grid, *matrix, handle.clone())));
+            handle
+        } else {
+            self.register_or_replace(context.renderer)
+        };
+
+        context
+            .commands
+            .render_shape(handle, context.transform_stack.transform());
+    }
+
     pub fn self_bounds(&self) -> &Rectangle<Twips> {
         &self.shape_bounds
     }
@@ -1,8 +1,11 @@
 use crate::display_object::InteractiveObject;
+use crate::player::ParseEnumError;
+use crate::string::{FromWStr, WStr};
 use serde::Deserialize;
+use std::fmt::{self, Display, Formatter};
 use swf::ClipEventFlag;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum PlayerEvent {
     KeyDown {
         key_code: KeyCode,
@@ -30,18 +33,110 @@ pub enum PlayerEvent {
     MouseWheel {
         delta: MouseWheelDelta,
     },
+    GamepadConnected {
+        device: usize,
+        name: String,
+    },
+    GamepadDisconnected {
+        device: usize,
+    },
     GamepadButtonDown {
+        device: usize,
         button: GamepadButton,
     },
     GamepadButtonUp {
+        device: usize,
         button: GamepadButton,
     },
+    GamepadAxisChanged {
+        device: usize,
+        axis: GamepadAxis,
+        value: f64,
+    },
     TextInput {
         codepoint: char,
     },
     TextControl {
         code: TextControlCode,
     },
+    Ime(ImeEvent),
+    TouchBegin {
+        id: u64,
+        x: f64,
+        y: f64,
+    },
+    TouchMove {
+        id: u64,
+        x: f64,
+        y: f64,
+    },
+    TouchEnd {
+        id: u64,
+        x: f64,
+        y: f64,
+    },
+    TouchCancel {
+        id: u64,
+    },
+}
+
+/// How touch input is reported to movies.
+/// Equivalent to AS3 `MultitouchInputMode`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MultitouchInputMode {
+    /// Touches are only reported as mouse events.
+    None,
+
+    /// Touches are interpreted as gestures.
+    #[default]
+    Gesture,
+
+    /// Touches are dispatched as `TouchEvent`s.
+    TouchPoint,
+}
+
+impl Display for MultitouchInputMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // Match string values returned by AS.
+        let s = match *self {
+            MultitouchInputMode::None => "none",
+            MultitouchInputMode::Gesture => "gesture",
+            MultitouchInputMode::TouchPoint => "touchPoint",
+        };
+        f.write_str(s)
+    }
+}
+
+impl FromWStr for MultitouchInputMode {
+    type Err = ParseEnumError;
+
+    fn from_wstr(s: &WStr) -> Result<Self, Self::Err> {
+        if s == b"none" {
+            Ok(MultitouchInputMode::None)
+        } else if s == b"gesture" {
+            Ok(MultitouchInputMode::Gesture)
+        } else if s == b"touchPoint" {
+            Ok(MultitouchInputMode::TouchPoint)
+        } else {
+            Err(ParseEnumError)
+        }
+    }
+}
+
+/// An event from the input method editor (IME) of the system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImeEvent {
+    /// A new composition was started.
+    Start,
+
+    /// The pre-edit text of the composition was changed.
+    ///
+    /// The cursor is given as a range of byte indices into the pre-edit text,
+    /// or `None` if it should be hidden. An empty text clears the composition.
+    Update(String, Option<(usize, usize)>),
+
+    /// The composition was finished, and the given text should be inserted.
+    Commit(String),
 }
 
 /// The distance scrolled by the mouse wheel.
@@ -701,3 +796,42 @@ pub enum GamepadButton {
     DPadLeft,
     DPadRight,
 }
+
+impl GamepadButton {
+    /// All buttons, in the order of their `GameInputControl`s.
+    pub const ALL: [GamepadButton; 14] = [
+        GamepadButton::South,
+        GamepadButton::East,
+        GamepadButton::North,
+        GamepadButton::West,
+        GamepadButton::LeftTrigger,
+        GamepadButton::LeftTrigger2,
+        GamepadButton::RightTrigger,
+        GamepadButton::RightTrigger2,
+        GamepadButton::Select,
+        GamepadButton::Start,
+        GamepadButton::DPadUp,
+        GamepadButton::DPadDown,
+        GamepadButton::DPadLeft,
+        GamepadButton::DPadRight,
+    ];
+}
+
+/// An analog axis of a gamepad, with values from -1.0 to 1.0.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum GamepadAxis {
+    LeftStickX,
+    LeftStickY,
+    RightStickX,
+    RightStickY,
+}
+
+impl GamepadAxis {
+    /// All axes, in the order of their `GameInputControl`s.
+    pub const ALL: [GamepadAxis; 4] = [
+        GamepadAxis::LeftStickX,
+        GamepadAxis::LeftStickY,
+        GamepadAxis::RightStickX,
+        GamepadAxis::RightStickY,
+    ];
+}
@@ -13,6 +13,8 @@ use std::cmp::max;
 use std::hash::{Hash, Hasher};
 use swf::FillStyle;
 
+mod shaping;
+
 pub use swf::TextGridFit;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
@@ -71,6 +73,12 @@ pub struct EvalParameters {
     /// pairs of letters, separate from the ordinary width between glyphs. This
     /// parameter allows enabling or disabling that feature.
     kerning: bool,
+
+    /// Whether the text is laid out from right to left.
+    ///
+    /// Right-to-left text is still evaluated from left to right, but its
+    /// characters are visited in reverse order and mirrored.
+    is_rtl: bool,
 }
 
 impl EvalParameters {
@@ -81,6 +89,7 @@ impl EvalParameters {
             height,
             letter_spacing,
             kerning,
+            is_rtl: false,
         }
     }
 
@@ -91,6 +100,7 @@ impl EvalParameters {
             height: Twips::from_pixels(span.font.size),
             letter_spacing: Twips::from_pixels(span.font.letter_spacing),
             kerning: span.font.kerning,
+            is_rtl: false,
         }
     }
 
@@ -98,6 +108,14 @@ impl EvalParameters {
     pub fn height(&self) -> Twips {
         self.height
     }
+
+    pub fn is_rtl(&self) -> bool {
+        self.is_rtl
+    }
+
+    pub fn with_rtl(self, is_rtl: bool) -> Self {
+        Self { is_rtl, ..self }
+    }
 }
 
 struct GlyphToDrawing<'a>(&'a mut Drawing);
@@ -221,6 +239,7 @@ impl FontFace {
                             shape_handle: Default::default(),
                             shape: GlyphShape::Drawing(drawing),
                             advance,
+                            character,
                         })
                     } else {
                         let advance = Twips::new(face.glyph_hor_advance(glyph_id)? as i32);
@@ -229,6 +248,7 @@ impl FontFace {
                             shape_handle: Default::default(),
                             shape: GlyphShape::None,
                             advance,
+                            character,
                         })
                     }
                 })
@@ -428,6 +448,8 @@ impl<'gc> Font<'gc> {
                     shape_handle: None.into(),
                     advance: Twips::new(swf_glyph.advance.into()),
                     shape: GlyphShape::Swf(RefCell::new(SwfGlyphOrShape::Glyph(swf_glyph))),
+                    // TODO: Properly handle UTF-16 surrogates.
+                    character: char::from_u32(code.into()).unwrap_or(char::REPLACEMENT_CHARACTER),
                 };
 
                 // Eager-load ASCII characters.
@@ -581,9 +603,48 @@ impl<'gc> Font<'gc> {
     /// of transforms and glyphs which will be consumed by the `glyph_func`
     /// closure. This corresponds to the series of drawing operations necessary
     /// to render the text on a single horizontal line.
+    ///
+    /// Glyphs are always visited from left to right, and `glyph_func` is given
+    /// the position of each glyph's character in `text`. For right-to-left
+    /// text, the positions are visited in reverse.
     pub fn evaluate<FGlyph>(
         &self,
         text: &WStr, // TODO: take an `IntoIterator<Item=char>`, to not depend on string representation?
+        transform: Transform,
+        params: EvalParameters,
+        glyph_func: FGlyph,
+    ) where
+        FGlyph: FnMut(usize, &Transform, &Glyph, Twips, Twips),
+    {
+        let char_indices = text
+            .char_indices()
+            .map(|(pos, c)| (pos, c.unwrap_or(char::REPLACEMENT_CHARACTER)));
+
+        let needs_shaping = self.font_type() == FontType::Device
+            && text.is_wide()
+            && text.chars().any(|c| c.is_ok_and(shaping::needs_shaping));
+        if !needs_shaping && !params.is_rtl {
+            self.evaluate_chars(char_indices, transform, params, glyph_func);
+            return;
+        }
+
+        let mut chars: Vec<_> = char_indices.collect();
+        if needs_shaping {
+            shaping::shape_arabic(&mut chars, |c| self.get_glyph_for_char(c).is_some());
+        }
+        if params.is_rtl {
+            chars.reverse();
+            for (_, c) in &mut chars {
+                *c = shaping::mirror(*c);
+            }
+        }
+        self.evaluate_chars(chars.into_iter(), transform, params, glyph_func);
+    }
+
+    /// Evaluate this font against characters in the order that they are laid out.
+    fn evaluate_chars<FGlyph>(
+        &self,
+        chars: impl Iterator<Item = (usize, char)>,
         mut transform: Transform,
         params: EvalParameters,
         mut glyph_func: FGlyph,
@@ -595,16 +656,14 @@ impl<'gc> Font<'gc> {
 
         transform.matrix.a = scale;
         transform.matrix.d = scale;
-        let mut char_indices = text.char_indices().peekable();
+        let mut char_indices = chars.peekable();
         let has_kerning_info = self.has_kerning_info();
         let mut x = Twips::ZERO;
         while let Some((pos, c)) = char_indices.next() {
-            let c = c.unwrap_or(char::REPLACEMENT_CHARACTER);
             if let Some(glyph) = self.get_glyph_for_char(c) {
                 let mut advance = glyph.advance();
                 if has_kerning_info && params.kerning {
-                    let next_char = char_indices.peek().cloned().unwrap_or((0, Ok('\0'))).1;
-                    let next_char = next_char.unwrap_or(char::REPLACEMENT_CHARACTER);
+                    let next_char = char_indices.peek().map_or('\0', |&(_, c)| c);
                     advance += self.get_kerning_offset(c, next_char);
                 }
                 let twips_advance = if self.font_type() == FontType::Device {
@@ -820,6 +879,9 @@ pub struct Glyph {
 
     shape: GlyphShape,
     advance: Twips,
+
+    /// The character that this glyph represents.
+    character: char,
 }
 
 impl Glyph {
@@ -837,6 +899,10 @@ impl Glyph {
     pub fn advance(&self) -> Twips {
         self.advance
     }
+
+    pub fn character(&self) -> char {
+        self.character
+    }
 }
 
 /// Structure which identifies a particular font by name and properties.
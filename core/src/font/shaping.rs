@@ -0,0 +1,357 @@
+//! Minimal text shaping for device fonts.
+//!
+//! Device fonts are rendered from their glyph outlines one character at a
+//! time, so scripts with contextual forms have to be mapped to their
+//! presentation forms before rendering.
+
+/// How an Arabic character joins with its neighbours.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Joining {
+    /// Never joins, like a hamza.
+    None,
+
+    /// Only joins with the preceding character, like an alef.
+    Right,
+
+    /// Joins with both the preceding and following characters, like a beh.
+    Dual,
+
+    /// Joins with both neighbours, but doesn't change its own shape (tatweel).
+    Causing,
+
+    /// Doesn't affect joining, like harakat.
+    Transparent,
+}
+
+/// The first character of the Arabic letters that have presentation forms.
+const ARABIC_LETTERS_START: u32 = 0x0621;
+
+/// The isolated presentation forms and joining types of U+0621 to U+064A.
+///
+/// For right-joining letters the isolated form is followed by the final form.
+/// For dual-joining letters it is followed by the final, initial and medial forms.
+const ARABIC_LETTERS: [(u16, Joining); 42] = [
+    (0xFE80, Joining::None),  // HAMZA
+    (0xFE81, Joining::Right), // ALEF WITH MADDA ABOVE
+    (0xFE83, Joining::Right), // ALEF WITH HAMZA ABOVE
+    (0xFE85, Joining::Right), // WAW WITH HAMZA ABOVE
+    (0xFE87, Joining::Right), // ALEF WITH HAMZA BELOW
+    (0xFE89, Joining::Dual),  // YEH WITH HAMZA ABOVE
+    (0xFE8D, Joining::Right), // ALEF
+    (0xFE8F, Joining::Dual),  // BEH
+    (0xFE93, Joining::Right), // TEH MARBUTA
+    (0xFE95, Joining::Dual),  // TEH
+    (0xFE99, Joining::Dual),  // THEH
+    (0xFE9D, Joining::Dual),  // JEEM
+    (0xFEA1, Joining::Dual),  // HAH
+    (0xFEA5, Joining::Dual),  // KHAH
+    (0xFEA9, Joining::Right), // DAL
+    (0xFEAB, Joining::Right), // THAL
+    (0xFEAD, Joining::Right), // REH
+    (0xFEAF, Joining::Right), // ZAIN
+    (0xFEB1, Joining::Dual),  // SEEN
+    (0xFEB5, Joining::Dual),  // SHEEN
+    (0xFEB9, Joining::Dual),  // SAD
+    (0xFEBD, Joining::Dual),  // DAD
+    (0xFEC1, Joining::Dual),  // TAH
+    (0xFEC5, Joining::Dual),  // ZAH
+    (0xFEC9, Joining::Dual),  // AIN
+    (0xFECD, Joining::Dual),  // GHAIN
+    (0, Joining::None),       // U+063B
+    (0, Joining::None),       // U+063C
+    (0, Joining::None),       // U+063D
+    (0, Joining::None),       // U+063E
+    (0, Joining::None),       // U+063F
+    (0, Joining::Causing),    // TATWEEL
+    (0xFED1, Joining::Dual),  // FEH
+    (0xFED5, Joining::Dual),  // QAF
+    (0xFED9, Joining::Dual),  // KAF
+    (0xFEDD, Joining::Dual),  // LAM
+    (0xFEE1, Joining::Dual),  // MEEM
+    (0xFEE5, Joining::Dual),  // NOON
+    (0xFEE9, Joining::Dual),  // HEH
+    (0xFEED, Joining::Right), // WAW
+    (0xFEEF, Joining::Right), // ALEF MAKSURA
+    (0xFEF1, Joining::Dual),  // YEH
+];
+
+/// The isolated presentation forms and joining types of the additional
+/// letters used by Persian and Urdu, sorted by character.
+///
+/// Their forms follow each other in the same order as in `ARABIC_LETTERS`.
+const EXTENDED_ARABIC_LETTERS: [(char, u16, Joining); 16] = [
+    ('\u{0671}', 0xFB50, Joining::Right), // ALEF WASLA
+    ('\u{0679}', 0xFB66, Joining::Dual),  // TTEH
+    ('\u{067E}', 0xFB56, Joining::Dual),  // PEH
+    ('\u{0686}', 0xFB7A, Joining::Dual),  // TCHEH
+    ('\u{0688}', 0xFB88, Joining::Right), // DDAL
+    ('\u{0691}', 0xFB8C, Joining::Right), // RREH
+    ('\u{0698}', 0xFB8A, Joining::Right), // JEH
+    ('\u{06A4}', 0xFB6A, Joining::Dual),  // VEH
+    ('\u{06A9}', 0xFB8E, Joining::Dual),  // KEHEH
+    ('\u{06AF}', 0xFB92, Joining::Dual),  // GAF
+    ('\u{06BE}', 0xFBAA, Joining::Dual),  // HEH DOACHASHMEE
+    ('\u{06C0}', 0xFBA4, Joining::Right), // HEH WITH YEH ABOVE
+    ('\u{06C1}', 0xFBA6, Joining::Dual),  // HEH GOAL
+    ('\u{06CC}', 0xFBFC, Joining::Dual),  // FARSI YEH
+    ('\u{06D2}', 0xFBAE, Joining::Right), // YEH BARREE
+    ('\u{06D3}', 0xFBB0, Joining::Right), // YEH BARREE WITH HAMZA ABOVE
+];
+
+const LAM: char = '\u{0644}';
+
+/// The isolated forms of the mandatory ligatures of a lam followed by an alef,
+/// by alef. Each is followed by its final form.
+const LAM_ALEF_LIGATURES: [(char, u16); 4] = [
+    ('\u{0622}', 0xFEF5), // LAM WITH ALEF WITH MADDA ABOVE
+    ('\u{0623}', 0xFEF7), // LAM WITH ALEF WITH HAMZA ABOVE
+    ('\u{0625}', 0xFEF9), // LAM WITH ALEF WITH HAMZA BELOW
+    ('\u{0627}', 0xFEFB), // LAM WITH ALEF
+];
+
+/// Returns the isolated presentation form and the joining type of a letter.
+fn letter(c: char) -> Option<(u16, Joining)> {
+    match c as u32 {
+        c @ 0x0621..=0x064A => Some(ARABIC_LETTERS[(c - ARABIC_LETTERS_START) as usize]),
+        _ => EXTENDED_ARABIC_LETTERS
+            .binary_search_by_key(&c, |&(letter, _, _)| letter)
+            .ok()
+            .map(|index| {
+                let (_, isolated, joining) = EXTENDED_ARABIC_LETTERS[index];
+                (isolated, joining)
+            }),
+    }
+}
+
+fn joining(c: char) -> Joining {
+    match c as u32 {
+        0x064B..=0x065F | 0x0670 => Joining::Transparent,
+        _ => letter(c).map_or(Joining::None, |(_, joining)| joining),
+    }
+}
+
+/// Returns whether the given character may need shaping.
+pub fn needs_shaping(c: char) -> bool {
+    letter(c).is_some()
+}
+
+/// Replaces Arabic letters with the presentation form of their position in a word.
+///
+/// Characters are given in logical order, and are only replaced if
+/// `has_glyph` returns true for the presentation form.
+///
+/// A lam directly followed by an alef is replaced with their ligature, and
+/// the alef is removed.
+pub fn shape_arabic(chars: &mut Vec<(usize, char)>, has_glyph: impl Fn(char) -> bool) {
+    let joinings: Vec<Joining> = chars.iter().map(|&(_, c)| joining(c)).collect();
+    let neighbour = |indices: &mut dyn Iterator<Item = usize>| {
+        indices
+            .map(|i| joinings[i])
+            .find(|joining| *joining != Joining::Transparent)
+            .unwrap_or(Joining::None)
+    };
+
+    let mut ligature_alefs = vec![];
+    for i in 0..chars.len() {
+        let c = chars[i].1;
+        let current = joinings[i];
+        if !matches!(current, Joining::Right | Joining::Dual) || ligature_alefs.last() == Some(&i) {
+            continue;
+        }
+        let Some((isolated, _)) = letter(c) else {
+            continue;
+        };
+
+        let previous = neighbour(&mut (0..i).rev());
+        let joins_previous = matches!(previous, Joining::Dual | Joining::Causing);
+
+        if c == LAM {
+            let ligature = chars
+                .get(i + 1)
+                .and_then(|&(_, alef)| {
+                    LAM_ALEF_LIGATURES
+                        .iter()
+                        .find(|&&(ligature_alef, _)| ligature_alef == alef)
+                })
+                .and_then(|&(_, ligature)| {
+                    char::from_u32(u32::from(ligature) + u32::from(joins_previous))
+                })
+                .filter(|&ligature| has_glyph(ligature));
+            if let Some(ligature) = ligature {
+                chars[i].1 = ligature;
+                ligature_alefs.push(i + 1);
+                continue;
+            }
+        }
+
+        let next = neighbour(&mut (i + 1..chars.len()));
+        let joins_next = current == Joining::Dual
+            && matches!(next, Joining::Right | Joining::Dual | Joining::Causing);
+
+        let form = match (joins_previous, joins_next) {
+            (false, false) => isolated,
+            (true, false) => isolated + 1,
+            (false, true) => isolated + 2,
+            (true, true) => isolated + 3,
+        };
+        if let Some(form) = char::from_u32(form.into()).filter(|&form| has_glyph(form)) {
+            chars[i].1 = form;
+        }
+    }
+
+    if !ligature_alefs.is_empty() {
+        let mut index = 0;
+        chars.retain(|_| {
+            index += 1;
+            !ligature_alefs.contains(&(index - 1))
+        });
+    }
+}
+
+/// Returns the mirrored glyph of a character in right-to-left text.
+pub fn mirror(c: char) -> char {
+    match c {
+        '(' => ')',
+        ')' => '(',
+        '<' => '>',
+        '>' => '<',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '«' => '»',
+        '»' => '«',
+        '‹' => '›',
+        '›' => '‹',
+        '⁅' => '⁆',
+        '⁆' => '⁅',
+        '≤' => '≥',
+        '≥' => '≤',
+        '⟨' => '⟩',
+        '⟩' => '⟨',
+        '〈' => '〉',
+        '〉' => '〈',
+        '《' => '》',
+        '》' => '《',
+        '「' => '」',
+        '」' => '「',
+        '『' => '』',
+        '』' => '『',
+        '【' => '】',
+        '】' => '【',
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mirror, shape_arabic};
+
+    fn shape(text: &str) -> String {
+        shape_with_glyphs(text, |_| true)
+    }
+
+    fn shape_with_glyphs(text: &str, has_glyph: impl Fn(char) -> bool) -> String {
+        let mut chars: Vec<_> = text.chars().enumerate().collect();
+        shape_arabic(&mut chars, has_glyph);
+        chars.into_iter().map(|(_, c)| c).collect()
+    }
+
+    #[test]
+    fn shape_word() {
+        // BEH, ALEF, BEH: initial, final, isolated (alef doesn't join the following letter).
+        assert_eq!(
+            shape("\u{0628}\u{0627}\u{0628}"),
+            "\u{FE91}\u{FE8E}\u{FE8F}"
+        );
+        // BEH, BEH, BEH: initial, medial, final.
+        assert_eq!(
+            shape("\u{0628}\u{0628}\u{0628}"),
+            "\u{FE91}\u{FE92}\u{FE90}"
+        );
+    }
+
+    #[test]
+    fn shape_with_harakat() {
+        // A fatha between two letters doesn't prevent them from joining.
+        assert_eq!(
+            shape("\u{0628}\u{064E}\u{0628}"),
+            "\u{FE91}\u{064E}\u{FE90}"
+        );
+    }
+
+    #[test]
+    fn shape_lam_alef() {
+        // LAM, ALEF: isolated ligature.
+        assert_eq!(shape("\u{0644}\u{0627}"), "\u{FEFB}");
+        // BEH, LAM, ALEF WITH HAMZA ABOVE: initial, final ligature.
+        assert_eq!(shape("\u{0628}\u{0644}\u{0623}"), "\u{FE91}\u{FEF8}");
+        // LAM, ALEF WITH MADDA ABOVE, BEH, LAM, ALEF WITH HAMZA BELOW.
+        assert_eq!(
+            shape("\u{0644}\u{0622}\u{0628}\u{0644}\u{0625}"),
+            "\u{FEF5}\u{FE91}\u{FEFA}"
+        );
+    }
+
+    #[test]
+    fn shape_lam_alef_positions() {
+        let mut chars = vec![
+            (0, '\u{0628}'),
+            (1, '\u{0644}'),
+            (2, '\u{0627}'),
+            (3, '\u{0628}'),
+        ];
+        shape_arabic(&mut chars, |_| true);
+        assert_eq!(chars, [(0, '\u{FE91}'), (1, '\u{FEFC}'), (3, '\u{FE8F}')]);
+    }
+
+    #[test]
+    fn shape_lam_alef_without_ligature_glyph() {
+        // Without a glyph for the ligature, LAM and ALEF are shaped separately.
+        assert_eq!(
+            shape_with_glyphs("\u{0644}\u{0627}", |c| c != '\u{FEFB}'),
+            "\u{FEDF}\u{FE8E}"
+        );
+    }
+
+    #[test]
+    fn shape_persian() {
+        // PEH, TCHEH, KEHEH: initial, medial, final.
+        assert_eq!(
+            shape("\u{067E}\u{0686}\u{06A9}"),
+            "\u{FB58}\u{FB7D}\u{FB8F}"
+        );
+        // GAF, FARSI YEH: initial, final.
+        assert_eq!(shape("\u{06AF}\u{06CC}"), "\u{FB94}\u{FBFD}");
+        // BEH, JEH, FARSI YEH: initial, final, isolated.
+        assert_eq!(
+            shape("\u{0628}\u{0698}\u{06CC}"),
+            "\u{FE91}\u{FB8B}\u{FBFC}"
+        );
+    }
+
+    #[test]
+    fn shape_urdu() {
+        // TTEH, HEH DOACHASHMEE, YEH BARREE: initial, medial, final.
+        assert_eq!(
+            shape("\u{0679}\u{06BE}\u{06D2}"),
+            "\u{FB68}\u{FBAD}\u{FBAF}"
+        );
+        // HEH GOAL, DDAL, RREH: initial, final, isolated.
+        assert_eq!(
+            shape("\u{06C1}\u{0688}\u{0691}"),
+            "\u{FBA8}\u{FB89}\u{FB8C}"
+        );
+    }
+
+    #[test]
+    fn shape_latin() {
+        assert_eq!(shape("abc"), "abc");
+    }
+
+    #[test]
+    fn mirror_brackets() {
+        assert_eq!(mirror('('), ')');
+        assert_eq!(mirror('a'), 'a');
+    }
+}
@@ -1,5 +1,6 @@
 //! HTML related utilities
 
+mod bidi;
 mod dimensions;
 mod iterators;
 mod layout;
@@ -8,7 +9,10 @@ mod text_format;
 pub use dimensions::BoxBounds;
 pub use dimensions::Position;
 pub use layout::{LayoutBox, LayoutContent, LayoutMetrics};
-pub use stylesheet::{transform_dashes_to_camel_case, CssStream};
+pub use stylesheet::{
+    parse_color, parse_font_family, transform_dashes_to_camel_case, transform_style, CssStream,
+    StyleProperties, StyleSheet,
+};
 pub use text_format::{FormatSpans, TextDisplay, TextFormat, TextSpan};
 
 mod stylesheet;
@@ -0,0 +1,88 @@
+//! Bidirectional text support
+
+use crate::string::WStr;
+use unicode_bidi::BidiInfo;
+
+/// Resolve the embedding level of every code unit of the given text, using
+/// the Unicode Bidirectional Algorithm.
+///
+/// Even levels are laid out from left to right, and odd levels from right to
+/// left. An empty list is returned if all of the text is left-to-right.
+pub fn resolve_levels(text: &WStr) -> Vec<u8> {
+    // Right-to-left scripts are all outside of Latin-1.
+    if !text.is_wide() {
+        return Vec::new();
+    }
+
+    let utf8 = text.to_utf8_lossy();
+    let info = BidiInfo::new(&utf8, None);
+    let mut levels = Vec::with_capacity(text.len());
+    for (i, c) in utf8.char_indices() {
+        let level = info.levels[i].number();
+        levels.extend(std::iter::repeat(level).take(c.len_utf16()));
+    }
+
+    if levels.iter().all(|&level| level == 0) {
+        return Vec::new();
+    }
+    levels
+}
+
+/// Returns the indices of the given runs in visual order, from left to right.
+///
+/// This reverses every sequence of runs at or above each level, from the
+/// highest level down to the lowest odd level (rule L2).
+pub fn visual_order(levels: &[u8]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..levels.len()).collect();
+    let Some(&highest) = levels.iter().max() else {
+        return order;
+    };
+    let lowest_odd = levels.iter().copied().filter(|level| level % 2 == 1).min();
+    let Some(lowest_odd) = lowest_odd else {
+        return order;
+    };
+
+    for level in (lowest_odd..=highest).rev() {
+        let mut i = 0;
+        while i < order.len() {
+            if levels[order[i]] < level {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < order.len() && levels[order[i]] >= level {
+                i += 1;
+            }
+            order[start..i].reverse();
+        }
+    }
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{resolve_levels, visual_order};
+    use crate::string::WString;
+
+    #[test]
+    fn visual_order_ltr() {
+        assert_eq!(visual_order(&[0, 0, 0]), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn visual_order_rtl_in_ltr() {
+        assert_eq!(visual_order(&[0, 1, 1, 0]), vec![0, 2, 1, 3]);
+    }
+
+    #[test]
+    fn visual_order_ltr_in_rtl() {
+        assert_eq!(visual_order(&[1, 2, 2, 1]), vec![3, 1, 2, 0]);
+    }
+
+    #[test]
+    fn resolve_hebrew() {
+        let text = WString::from_utf8("ab \u{05D0}\u{05D1}");
+        assert_eq!(resolve_levels(&text), vec![0, 0, 0, 1, 1]);
+        assert!(resolve_levels(&WString::from_utf8("abc")).is_empty());
+    }
+}
@@ -3,8 +3,9 @@
 use crate::context::UpdateContext;
 use crate::drawing::Drawing;
 use crate::font::{EvalParameters, Font, FontType};
+use crate::html::bidi;
 use crate::html::dimensions::{BoxBounds, Position, Size};
-use crate::html::text_format::{FormatSpans, TextFormat, TextSpan};
+use crate::html::text_format::{FormatSpans, HtmlImage, ImageAlign, TextFormat, TextSpan};
 use crate::string::{utils as string_utils, WStr};
 use crate::tag_utils::SwfMovie;
 use crate::DefaultFont;
@@ -63,6 +64,11 @@ pub struct LayoutContext<'a, 'gc> {
     /// The underlying bundle of text being formatted.
     text: &'a WStr,
 
+    /// The bidirectional embedding level of each code unit of the text.
+    ///
+    /// Empty if all of the text is left-to-right.
+    bidi_levels: Vec<u8>,
+
     /// The highest font size observed within the current line.
     max_font_size: Twips,
 
@@ -99,6 +105,20 @@ pub struct LayoutContext<'a, 'gc> {
 
     /// The total width of the text field being laid out.
     max_bounds: Twips,
+
+    /// The boxes of all images laid out so far.
+    ///
+    /// Images float beside the lines of text, so they're kept apart from the
+    /// boxes of the current line.
+    images: Vec<LayoutBox<'gc>>,
+
+    /// The reserved width and the bottom edge of each image floating at the
+    /// left edge of the text.
+    left_floats: Vec<(Twips, Twips)>,
+
+    /// The reserved width and the bottom edge of each image floating at the
+    /// right edge of the text.
+    right_floats: Vec<(Twips, Twips)>,
 }
 
 impl<'a, 'gc> LayoutContext<'a, 'gc> {
@@ -108,6 +128,7 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
             cursor: Default::default(),
             font: None,
             text,
+            bidi_levels: bidi::resolve_levels(text),
             max_font_size: Default::default(),
             boxes: Vec::new(),
             exterior_bounds: None,
@@ -116,6 +137,9 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
             current_line: 0,
             current_line_span: Default::default(),
             max_bounds,
+            images: Vec::new(),
+            left_floats: Vec::new(),
+            right_floats: Vec::new(),
         }
     }
 
@@ -159,6 +183,10 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
         let mut has_underline: bool = false;
 
         if let Some(linelist) = self.boxes.get(self.current_line..) {
+            // Bidirectional lines may not be laid out in the order of their boxes.
+            let mut linelist: Vec<_> = linelist.iter().collect();
+            linelist.sort_by_key(|linebox| linebox.bounds().offset_x());
+
             for linebox in linelist {
                 if linebox.is_text_box() {
                     if let Some((_t, tf, font, params, color)) =
@@ -279,9 +307,11 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
 
         let mut line_bounds = line_bounds.unwrap_or_default();
 
+        let (left_float, right_float) = self.float_offsets();
         let left_adjustment =
-            Self::left_alignment_offset(&self.current_line_span, self.is_first_line);
-        let right_adjustment = Twips::from_pixels(self.current_line_span.right_margin);
+            Self::left_alignment_offset(&self.current_line_span, self.is_first_line) + left_float;
+        let right_adjustment =
+            Twips::from_pixels(self.current_line_span.right_margin) + right_float;
 
         let misalignment =
             self.max_bounds - left_adjustment - right_adjustment - line_bounds.width();
@@ -330,6 +360,8 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
             box_count += 1;
         }
 
+        self.reorder_line();
+
         if let Some((text, end, span)) = text {
             if box_count == 0 {
                 self.append_text(&text[end..end], end, end, span);
@@ -351,6 +383,47 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
         }
     }
 
+    /// Move the text boxes of the current line into visual order, if the line
+    /// contains any right-to-left text.
+    ///
+    /// Boxes keep their logical order within the line, only their positions
+    /// change. Each box keeps the advance it was laid out with.
+    fn reorder_line(&mut self) {
+        let Some(linelist) = self.boxes.get_mut(self.current_line..) else {
+            return;
+        };
+
+        let mut text_boxes: Vec<_> = linelist
+            .iter_mut()
+            .filter_map(|linebox| match linebox.content {
+                LayoutContent::Text { bidi_level, .. } => Some((bidi_level, linebox)),
+                _ => None,
+            })
+            .collect();
+        if text_boxes.iter().all(|(level, _)| *level == 0) {
+            return;
+        }
+
+        let levels: Vec<u8> = text_boxes.iter().map(|(level, _)| *level).collect();
+        let advances: Vec<Twips> = (0..text_boxes.len())
+            .map(|i| match text_boxes.get(i + 1) {
+                Some((_, next)) => next.bounds.offset_x() - text_boxes[i].1.bounds.offset_x(),
+                None => text_boxes[i].1.bounds.width(),
+            })
+            .collect();
+
+        let mut x = text_boxes
+            .iter()
+            .map(|(_, linebox)| linebox.bounds.offset_x())
+            .min()
+            .unwrap_or_default();
+        for i in bidi::visual_order(&levels) {
+            let linebox = &mut text_boxes[i].1;
+            linebox.bounds += Position::from((x - linebox.bounds.offset_x(), Twips::ZERO));
+            x += advances[i];
+        }
+    }
+
     /// Adjust the text layout cursor down to the next line in response to an
     /// explicit newline.
     ///
@@ -569,12 +642,48 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
     ///
     /// This function bypasses the text fragmentation necessary for justify to
     /// work and it should only be called internally.
+    ///
+    /// The text is further split into runs of the same bidirectional level.
     fn append_text_fragment(&mut self, text: &'a WStr, start: usize, end: usize, span: &TextSpan) {
+        let level_at = |pos: usize| self.bidi_levels.get(start + pos).copied().unwrap_or(0);
+
+        let mut runs = vec![];
+        let mut run_start = 0;
+        loop {
+            let level = level_at(run_start);
+            let run_end = (run_start..text.len()).find(|&pos| level_at(pos) != level);
+            match run_end {
+                Some(run_end) => {
+                    runs.push((run_start..run_end, start + run_end, level));
+                    run_start = run_end;
+                }
+                None => {
+                    runs.push((run_start..text.len(), end, level));
+                    break;
+                }
+            }
+        }
+
+        for (range, run_end, level) in runs {
+            let run_start = start + range.start;
+            self.append_text_run(&text[range], run_start, run_end, span, level);
+        }
+    }
+
+    /// Append a run of text at a single bidirectional level to the current line.
+    fn append_text_run(
+        &mut self,
+        text: &'a WStr,
+        start: usize,
+        end: usize,
+        span: &TextSpan,
+        bidi_level: u8,
+    ) {
         if let Some(font) = self.font {
-            let params = EvalParameters::from_span(span);
+            let params = EvalParameters::from_span(span).with_rtl(bidi_level % 2 == 1);
             let text_size = Size::from(font.measure(text, params, false));
             let text_bounds = BoxBounds::from_position_and_size(self.cursor, text_size);
-            let mut new_text = LayoutBox::from_text(start, end, font, span);
+            let mut new_text = LayoutBox::from_text(start, end, font, span, bidi_level);
 
             new_text.bounds = text_bounds;
 
@@ -614,6 +723,51 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
         }
     }
 
+    /// Place an image at the edge of the current line.
+    ///
+    /// The lines of text beside the image are shortened until they're past
+    /// the bottom of the image.
+    fn append_image(&mut self, index: usize, image: &HtmlImage, size: (Twips, Twips)) {
+        let (left_float, right_float) = self.float_offsets();
+        let (width, height) = size;
+        let reserved_width = width + Twips::from_pixels(image.hspace);
+        let top = self.cursor.y();
+        let bottom = top + height + Twips::from_pixels(image.vspace);
+
+        let x = match image.align {
+            ImageAlign::Left => {
+                self.left_floats.push((reserved_width, bottom));
+                left_float
+            }
+            ImageAlign::Right => {
+                self.right_floats.push((reserved_width, bottom));
+                self.max_bounds - right_float - width
+            }
+        };
+
+        let mut new_image = LayoutBox::from_image(index);
+        new_image.bounds =
+            BoxBounds::from_position_and_size(Position::from((x, top)), Size::from(size));
+        self.images.push(new_image);
+    }
+
+    /// Calculate the space taken by floating images at the left and right
+    /// edges of the current line.
+    fn float_offsets(&self) -> (Twips, Twips) {
+        let top = self.cursor.y();
+        let reserved_width = |floats: &[(Twips, Twips)]| {
+            floats
+                .iter()
+                .filter(|(_, bottom)| *bottom > top)
+                .fold(Twips::ZERO, |width, (float_width, _)| width + *float_width)
+        };
+
+        (
+            reserved_width(&self.left_floats),
+            reserved_width(&self.right_floats),
+        )
+    }
+
     /// Add a box to the current line of text.
     ///
     /// The box should have been positioned according to the current cursor
@@ -658,8 +812,10 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
     ///
     /// Offsets returned by this function should not be considered final;
     fn wrap_dimensions(&self, current_span: &TextSpan) -> (Twips, Twips) {
-        let width = self.max_bounds - Twips::from_pixels(self.current_line_span.right_margin);
-        let offset = Self::left_alignment_offset(current_span, self.is_first_line);
+        let (left_float, right_float) = self.float_offsets();
+        let width =
+            self.max_bounds - Twips::from_pixels(self.current_line_span.right_margin) - right_float;
+        let offset = Self::left_alignment_offset(current_span, self.is_first_line) + left_float;
 
         (width, offset + self.cursor.x())
     }
@@ -680,6 +836,15 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
             font_type,
         );
 
+        for image in &self.images {
+            if let Some(eb) = &mut self.exterior_bounds {
+                *eb += image.bounds;
+            } else {
+                self.exterior_bounds = Some(image.bounds);
+            }
+        }
+        self.boxes.append(&mut self.images);
+
         (self.boxes, self.exterior_bounds.unwrap_or_default())
     }
 
@@ -735,6 +900,11 @@ pub enum LayoutContent<'gc> {
         /// The color to render the font with.
         #[collect(require_static)]
         color: swf::Color,
+
+        /// The bidirectional embedding level of the text.
+        ///
+        /// Text at odd levels is laid out from right to left.
+        bidi_level: u8,
     },
 
     /// A layout box containing a bullet.
@@ -765,12 +935,25 @@ pub enum LayoutContent<'gc> {
     /// layout box's bounds. The size of those bounds do not affect the
     /// rendering of the drawing.
     Drawing(#[collect(require_static)] Drawing),
+
+    /// A layout box reserving space for an image.
+    ///
+    /// The image is the one with the given index in the `FormatSpans` that
+    /// generated this layout box. The image itself is rendered by its text
+    /// field.
+    Image { index: usize },
 }
 
 impl<'gc> LayoutBox<'gc> {
     /// Construct a text box for a text node.
-    pub fn from_text(start: usize, end: usize, font: Font<'gc>, span: &TextSpan) -> Self {
-        let params = EvalParameters::from_span(span);
+    pub fn from_text(
+        start: usize,
+        end: usize,
+        font: Font<'gc>,
+        span: &TextSpan,
+        bidi_level: u8,
+    ) -> Self {
+        let params = EvalParameters::from_span(span).with_rtl(bidi_level % 2 == 1);
 
         Self {
             bounds: Default::default(),
@@ -781,6 +964,7 @@ impl<'gc> LayoutBox<'gc> {
                 font,
                 params,
                 color: span.font.color,
+                bidi_level,
             },
         }
     }
@@ -808,10 +992,20 @@ impl<'gc> LayoutBox<'gc> {
         }
     }
 
+    /// Construct an image.
+    pub fn from_image(index: usize) -> Self {
+        Self {
+            bounds: Default::default(),
+            content: LayoutContent::Image { index },
+        }
+    }
+
     /// Construct a new layout hierarchy from text spans.
     ///
     /// The returned bounds will include both the text bounds itself, as well
     /// as left and right margins on any of the lines.
+    ///
+    /// `image_sizes` holds the size of each image of the format spans.
     pub fn lower_from_text_spans(
         fs: &FormatSpans,
         context: &mut UpdateContext<'_, 'gc>,
@@ -819,10 +1013,22 @@ impl<'gc> LayoutBox<'gc> {
         bounds: Twips,
         is_word_wrap: bool,
         font_type: FontType,
+        image_sizes: &[(Twips, Twips)],
     ) -> (Vec<LayoutBox<'gc>>, BoxBounds<Twips>) {
         let mut layout_context = LayoutContext::new(movie, bounds, fs.displayed_text());
+        let images = fs.images();
+        let mut next_image = 0;
+
+        for (span_start, span_text, span) in Self::split_spans_at_images(fs) {
+            while let Some(image) = images
+                .get(next_image)
+                .filter(|image| image.position <= span_start)
+            {
+                let size = image_sizes.get(next_image).copied().unwrap_or_default();
+                layout_context.append_image(next_image, image, size);
+                next_image += 1;
+            }
 
-        for (span_start, _end, span_text, span) in fs.iter_spans() {
             if let Some(font) = layout_context.resolve_font(context, span, font_type) {
                 layout_context.font = Some(font);
                 layout_context.newspan(span);
@@ -940,9 +1146,43 @@ impl<'gc> LayoutBox<'gc> {
             }
         }
 
+        // Images at the end of the text are placed beside the last line.
+        for (index, image) in images.iter().enumerate().skip(next_image) {
+            let size = image_sizes.get(index).copied().unwrap_or_default();
+            layout_context.append_image(index, image, size);
+        }
+
         layout_context.end_layout(context, fs, font_type)
     }
 
+    /// Split text spans at the positions of images, so that each image can be
+    /// placed beside the line in which it appears.
+    ///
+    /// Yields the start position, text and format of each piece of the spans.
+    fn split_spans_at_images(fs: &FormatSpans) -> Vec<(usize, &WStr, &TextSpan)> {
+        let mut pieces = vec![];
+        for (span_start, span_end, span_text, span) in fs.iter_spans() {
+            let mut boundaries = vec![span_start];
+            boundaries.extend(
+                fs.images()
+                    .iter()
+                    .map(|image| image.position)
+                    .filter(|position| (span_start + 1..span_end).contains(position)),
+            );
+            boundaries.push(span_end);
+            boundaries.dedup();
+
+            if let [start] = boundaries[..] {
+                pieces.push((start, span_text, span));
+            }
+            for piece in boundaries.windows(2) {
+                let text = &span_text[piece[0] - span_start..piece[1] - span_start];
+                pieces.push((piece[0], text, span));
+            }
+        }
+        pieces
+    }
+
     pub fn bounds(&self) -> BoxBounds<Twips> {
         self.bounds
     }
@@ -979,6 +1219,7 @@ impl<'gc> LayoutBox<'gc> {
                 font,
                 params,
                 color,
+                ..
             } => Some((
                 text.slice(*start..*end)?,
                 text_format,
@@ -998,7 +1239,7 @@ impl<'gc> LayoutBox<'gc> {
                 *params,
                 swf::Color::from_rgb(color.to_rgb(), 0xFF),
             )),
-            LayoutContent::Drawing(..) => None,
+            LayoutContent::Drawing(..) | LayoutContent::Image { .. } => None,
         }
     }
 
@@ -1008,6 +1249,7 @@ impl<'gc> LayoutBox<'gc> {
             LayoutContent::Text { .. } => None,
             LayoutContent::Bullet { .. } => None,
             LayoutContent::Drawing(drawing) => Some(drawing),
+            LayoutContent::Image { .. } => None,
         }
     }
 
@@ -1018,6 +1260,10 @@ impl<'gc> LayoutBox<'gc> {
     pub fn is_bullet(&self) -> bool {
         matches!(&self.content, LayoutContent::Bullet { .. })
     }
+
+    pub fn is_image(&self) -> bool {
+        matches!(&self.content, LayoutContent::Image { .. })
+    }
 }
 
 pub struct LayoutMetrics {
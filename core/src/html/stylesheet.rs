@@ -1,4 +1,7 @@
+use crate::html::{TextDisplay, TextFormat};
 use fnv::FnvHashMap;
+use gc_arena::Collect;
+use indexmap::IndexMap;
 use ruffle_wstr::{WStr, WString};
 use std::borrow::Cow;
 
@@ -210,6 +213,246 @@ impl<'a> CssStream<'a> {
     }
 }
 
+/// The properties of a single style, keyed by their camel case names.
+pub type StyleProperties = IndexMap<WString, WString>;
+
+/// A set of named styles that can be applied to HTML text, as in
+/// `TextField.StyleSheet` and `flash.text.StyleSheet`.
+///
+/// Style names are case insensitive, and are stored in lowercase.
+#[derive(Clone, Debug, Default, Collect)]
+#[collect(require_static)]
+pub struct StyleSheet {
+    styles: IndexMap<WString, StyleProperties>,
+}
+
+impl StyleSheet {
+    pub fn style(&self, name: &WStr) -> Option<&StyleProperties> {
+        self.styles.get(&name.to_ascii_lowercase())
+    }
+
+    pub fn style_names(&self) -> impl Iterator<Item = &WStr> {
+        self.styles.keys().map(WString::as_wstr)
+    }
+
+    /// Sets the properties of the given style, or removes it if `properties` is `None`.
+    pub fn set_style(&mut self, name: &WStr, properties: Option<StyleProperties>) {
+        let name = name.to_ascii_lowercase();
+        if let Some(properties) = properties {
+            self.styles.insert(name, properties);
+        } else {
+            self.styles.shift_remove(&name);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.styles.clear();
+    }
+
+    /// Adds the styles of the given CSS document to this style sheet.
+    ///
+    /// Returns `false` without changing any styles if the document is invalid.
+    pub fn parse_css(&mut self, css: &WStr) -> bool {
+        let Ok(parsed) = CssStream::new(css).parse() else {
+            return false;
+        };
+
+        for (selector, properties) in parsed {
+            let properties = properties
+                .into_iter()
+                .map(|(key, value)| {
+                    (
+                        transform_dashes_to_camel_case(key).into_owned(),
+                        value.into(),
+                    )
+                })
+                .collect();
+            self.set_style(selector, Some(properties));
+        }
+        true
+    }
+
+    /// Returns the text format of the given style, if it exists.
+    pub fn text_format(&self, name: &WStr) -> Option<TextFormat> {
+        self.style(name).map(transform_style)
+    }
+}
+
+/// Converts the properties of a style into the `TextFormat` that they describe.
+pub fn transform_style(properties: &StyleProperties) -> TextFormat {
+    let property = |name: &str| {
+        properties
+            .get(WStr::from_units(name.as_bytes()))
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    };
+
+    let mut format = TextFormat::default();
+
+    if let Some(color) = property("color") {
+        let color = parse_color(color).unwrap_or(0);
+        format.color = Some(swf::Color::from_rgb(color, 0xFF));
+    }
+
+    if let Some(display) = property("display") {
+        format.display = match display.to_utf8_lossy().as_ref() {
+            "none" => Some(TextDisplay::None),
+            "inline" => Some(TextDisplay::Inline),
+            "block" => Some(TextDisplay::Block),
+            _ => None,
+        };
+    }
+
+    if let Some(font_family) = property("fontFamily") {
+        format.font = Some(parse_font_family(font_family));
+    }
+
+    if let Some(size) = property("fontSize").and_then(parse_int) {
+        if size > 0 {
+            format.size = Some(size as f64);
+        }
+    }
+
+    match property("fontStyle") {
+        Some(style) if style == b"italic" => format.italic = Some(true),
+        Some(style) if style == b"normal" => format.italic = Some(false),
+        _ => {}
+    }
+
+    match property("fontWeight") {
+        Some(weight) if weight == b"bold" => format.bold = Some(true),
+        Some(weight) if weight == b"normal" => format.bold = Some(false),
+        _ => {}
+    }
+
+    // Kerning is always set, even if the property is missing.
+    format.kerning = Some(match property("kerning") {
+        Some(kerning) if kerning == b"true" => true,
+        Some(kerning) if kerning == b"false" => false,
+        kerning => kerning
+            .and_then(parse_int)
+            .is_some_and(|kerning| kerning != 0),
+    });
+
+    if let Some(leading) = property("leading").and_then(parse_int) {
+        format.leading = Some(leading as f64);
+    }
+
+    if let Some(letter_spacing) = property("letterSpacing").and_then(parse_float) {
+        format.letter_spacing = Some(letter_spacing);
+    }
+
+    if let Some(left_margin) = property("marginLeft").and_then(parse_float) {
+        format.left_margin = Some(left_margin);
+    }
+
+    if let Some(right_margin) = property("marginRight").and_then(parse_float) {
+        format.right_margin = Some(right_margin);
+    }
+
+    if let Some(align) = property("textAlign") {
+        format.align = match align.to_utf8_lossy().as_ref() {
+            "left" => Some(swf::TextAlign::Left),
+            "center" => Some(swf::TextAlign::Center),
+            "right" => Some(swf::TextAlign::Right),
+            "justify" => Some(swf::TextAlign::Justify),
+            _ => None,
+        };
+    }
+
+    match property("textDecoration") {
+        Some(decoration) if decoration == b"underline" => format.underline = Some(true),
+        Some(decoration) if decoration == b"none" => format.underline = Some(false),
+        _ => {}
+    }
+
+    if let Some(indent) = property("textIndent").and_then(parse_int) {
+        format.indent = Some(indent as f64);
+    }
+
+    format
+}
+
+/// Parses a CSS color in the form `#RRGGBB`.
+pub fn parse_color(input: &WStr) -> Option<u32> {
+    let stripped = input.strip_prefix(WStr::from_units(b"#"))?;
+    if stripped.len() > 6 {
+        return None;
+    }
+    u32::from_str_radix(&stripped.to_string(), 16).ok()
+}
+
+/// Parses a comma separated list of CSS font families into a list of Flash font names.
+pub fn parse_font_family(input: &WStr) -> WString {
+    let mut result = WString::new();
+
+    let mut pos = 0;
+    while pos < input.len() {
+        // Skip whitespace
+        while input.get(pos) == Some(' ' as u16) {
+            pos += 1;
+        }
+
+        // Find the whole value
+        let start = pos;
+        while input.get(pos) != Some(',' as u16) && pos < input.len() {
+            pos += 1;
+        }
+
+        let mut value = &input[start..pos];
+
+        if pos < input.len() {
+            pos += 1; // move past the comma
+        }
+
+        // Transform some names
+        if value == b"mono" {
+            value = WStr::from_units(b"_typewriter");
+        } else if value == b"sans-serif" {
+            value = WStr::from_units(b"_sans");
+        } else if value == b"serif" {
+            value = WStr::from_units(b"_serif");
+        }
+
+        // Add it to the result (without any extra space)
+        if !value.is_empty() {
+            if !result.is_empty() {
+                result.push_char(',');
+            }
+            result.push_str(value);
+        }
+    }
+
+    result
+}
+
+/// Returns the leading number of the input, ignoring any trailing characters (like `parseInt`).
+fn leading_number(input: &WStr, allow_fraction: bool) -> &WStr {
+    let input = input.trim_start();
+    let mut end = 0;
+    if matches!(input.get(0), Some(c) if c == '-' as u16 || c == '+' as u16) {
+        end += 1;
+    }
+    let mut seen_dot = !allow_fraction;
+    while let Some(c) = input.get(end) {
+        if c == '.' as u16 && !seen_dot {
+            seen_dot = true;
+        } else if !(('0' as u16)..=('9' as u16)).contains(&c) {
+            break;
+        }
+        end += 1;
+    }
+    &input[..end]
+}
+
+fn parse_int(input: &WStr) -> Option<i32> {
+    leading_number(input, false).parse().ok()
+}
+
+fn parse_float(input: &WStr) -> Option<f64> {
+    leading_number(input, true).parse().ok()
+}
+
 pub fn transform_dashes_to_camel_case(input: &WStr) -> Cow<WStr> {
     if !input.contains(b'-') {
         return Cow::Borrowed(input);
@@ -237,7 +480,7 @@ pub fn transform_dashes_to_camel_case(input: &WStr) -> Cow<WStr> {
 // These are just some useful ones extracted out
 #[cfg(test)]
 mod tests {
-    use super::{CssError, CssStream};
+    use super::{CssError, CssStream, StyleSheet};
     use fnv::FnvHashMap;
     use ruffle_wstr::WStr;
 
@@ -390,4 +633,28 @@ mod tests {
         let mut stream = CssStream::new(WStr::from_units(b"a{:"));
         assert_eq!(stream.parse(), Err(CssError::PropertyValueMissing));
     }
+
+    #[test]
+    fn style_sheet_text_format() {
+        let mut style_sheet = StyleSheet::default();
+        assert!(style_sheet.parse_css(WStr::from_units(
+            b".Title { font-size: 24px; font-weight: bold; color: #FF0000; text-align: center }"
+        )));
+
+        let format = style_sheet
+            .text_format(WStr::from_units(b".title"))
+            .unwrap();
+        assert_eq!(format.size, Some(24.0));
+        assert_eq!(format.bold, Some(true));
+        assert_eq!(format.color, Some(swf::Color::from_rgb(0xFF0000, 0xFF)));
+        assert_eq!(format.align, Some(swf::TextAlign::Center));
+        assert!(style_sheet.text_format(WStr::from_units(b"p")).is_none());
+    }
+
+    #[test]
+    fn style_sheet_invalid_css() {
+        let mut style_sheet = StyleSheet::default();
+        assert!(!style_sheet.parse_css(WStr::from_units(b"a b { color: #000000 }")));
+        assert_eq!(style_sheet.style_names().count(), 0);
+    }
 }
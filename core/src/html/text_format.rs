@@ -2,6 +2,7 @@
 
 use crate::context::UpdateContext;
 use crate::html::iterators::TextSpanIter;
+use crate::html::StyleSheet;
 use crate::string::{Integer, SwfStrExt as _, Units, WStr, WString};
 use crate::tag_utils::SwfMovie;
 use gc_arena::Collect;
@@ -563,6 +564,49 @@ impl TextSpan {
     }
 }
 
+/// Which side of the text an image is placed at.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ImageAlign {
+    #[default]
+    Left,
+    Right,
+}
+
+/// An image embedded into HTML text with an `<img>` tag.
+///
+/// Images are not part of the text itself. They float at the left or right
+/// edge of the line containing their position, and text flows around them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HtmlImage {
+    /// The position in the text that the image was placed at.
+    pub position: usize,
+
+    /// The URL or library symbol name of the image.
+    pub src: WString,
+
+    /// The identifier used to retrieve the image with `getImageReference`.
+    pub id: Option<WString>,
+
+    /// The width of the image in pixels, or `None` to use its natural width.
+    pub width: Option<f64>,
+
+    /// The height of the image in pixels, or `None` to use its natural height.
+    pub height: Option<f64>,
+
+    pub align: ImageAlign,
+
+    /// The horizontal space between the image and the text, in pixels.
+    pub hspace: f64,
+
+    /// The vertical space between the image and the text, in pixels.
+    pub vspace: f64,
+}
+
+impl HtmlImage {
+    /// The default space around images, in pixels.
+    const DEFAULT_SPACE: f64 = 8.0;
+}
+
 /// Struct which contains text formatted by `TextSpan`s.
 #[derive(Clone, Debug)]
 pub struct FormatSpans {
@@ -570,6 +614,7 @@ pub struct FormatSpans {
     displayed_text: WString,
     spans: Vec<TextSpan>,
     default_format: TextFormat,
+    images: Vec<HtmlImage>,
 }
 
 impl Default for FormatSpans {
@@ -585,6 +630,7 @@ impl FormatSpans {
             displayed_text: WString::new(),
             spans: vec![TextSpan::default()],
             default_format: TextFormat::default(),
+            images: Vec::new(),
         }
     }
 
@@ -596,6 +642,7 @@ impl FormatSpans {
             displayed_text: WString::new(),
             spans: spans.to_vec(),
             default_format: Default::default(),
+            images: Vec::new(),
         }
     }
 
@@ -606,6 +653,7 @@ impl FormatSpans {
             displayed_text: WString::new(),
             spans: vec![TextSpan::with_length_and_format(len, &format)],
             default_format: format,
+            images: Vec::new(),
         }
     }
 
@@ -613,11 +661,12 @@ impl FormatSpans {
     ///
     /// This is the "legacy" implementation of this process: it only looks for
     /// a handful of presentational attributes in the HTML tree to generate
-    /// styling. There's also a `lower_from_css` that respects both
-    /// presentational markup and CSS stylesheets.
+    /// styling, along with the styles of `style_sheet` that match the tag
+    /// names and `class` attributes of the tree.
     pub fn from_html(
         html: &WStr,
         default_format: TextFormat,
+        style_sheet: Option<&StyleSheet>,
         is_multiline: bool,
         swf_version: u8,
     ) -> Self {
@@ -628,6 +677,7 @@ impl FormatSpans {
         let mut format_stack = vec![default_format.clone()];
         let mut text = WString::new();
         let mut spans: Vec<TextSpan> = Vec::new();
+        let mut images: Vec<HtmlImage> = Vec::new();
 
         // quick_xml::Reader requires a [u8] slice, but doesn't actually care about Unicode;
         // this means we can pass the raw buffer in the Latin1 case.
@@ -707,6 +757,31 @@ impl FormatSpans {
                             // Skip push to `format_stack`.
                             continue;
                         }
+                        b"img" => {
+                            if let Some(src) = attribute(b"src") {
+                                let pixels = |name| {
+                                    attribute(name).and_then(|value| value.trim().parse().ok())
+                                };
+                                let right = WStr::from_units(b"right");
+                                let align = match attribute(b"align") {
+                                    Some(align) if align.eq_ignore_case(right) => ImageAlign::Right,
+                                    _ => ImageAlign::Left,
+                                };
+                                images.push(HtmlImage {
+                                    position: text.len(),
+                                    src,
+                                    id: attribute(b"id"),
+                                    width: pixels(b"width"),
+                                    height: pixels(b"height"),
+                                    align,
+                                    hspace: pixels(b"hspace").unwrap_or(HtmlImage::DEFAULT_SPACE),
+                                    vspace: pixels(b"vspace").unwrap_or(HtmlImage::DEFAULT_SPACE),
+                                });
+                            }
+
+                            // Skip push to `format_stack`.
+                            continue;
+                        }
                         b"p" => {
                             p_open = true;
                             if let Some(align) = attribute(b"align") {
@@ -853,6 +928,24 @@ impl FormatSpans {
                         }
                         _ => {}
                     }
+                    if let Some(style_sheet) = style_sheet {
+                        // Styles override presentational markup, and class
+                        // styles override the styles of the tag itself.
+                        let mut selectors = vec![decode_to_wstr(tag_name)];
+                        if tag_name == b"a" {
+                            selectors.push(WString::from_utf8("a:link"));
+                        }
+                        if let Some(class) = attribute(b"class") {
+                            let mut selector = WString::from_utf8(".");
+                            selector.push_str(&class);
+                            selectors.push(selector);
+                        }
+                        for selector in selectors {
+                            if let Some(style) = style_sheet.text_format(&selector) {
+                                format = style.mix_with(format);
+                            }
+                        }
+                    }
                     opened_starts.push(opened_buffer.len());
                     opened_buffer.extend(tag_name);
                     format_stack.push(format);
@@ -886,7 +979,7 @@ impl FormatSpans {
                     }
 
                     match tag_name {
-                        b"br" | b"sbr" => {
+                        b"br" | b"sbr" | b"img" => {
                             // Skip pop from `format_stack`.
                             continue;
                         }
@@ -946,6 +1039,7 @@ impl FormatSpans {
             displayed_text: WString::new(),
             spans,
             default_format,
+            images,
         };
         ret.normalize();
         ret
@@ -972,6 +1066,11 @@ impl FormatSpans {
     }
 
     /// Retrieve the text backing the format spans.
+    /// The images embedded into the text, in the order of their positions.
+    pub fn images(&self) -> &[HtmlImage] {
+        &self.images
+    }
+
     pub fn text(&self) -> &WStr {
         &self.text
     }
@@ -1274,6 +1373,15 @@ impl FormatSpans {
 
         self.text = new_string;
 
+        // Keep images at the same place in the text around the replacement.
+        for image in &mut self.images {
+            if image.position >= to {
+                image.position = image.position - (to - from) + with.len();
+            } else if image.position > from {
+                image.position = from;
+            }
+        }
+
         self.normalize();
     }
 
@@ -43,15 +43,19 @@ mod net_connection;
 pub mod pixel_bender;
 mod player;
 mod prelude;
+mod printing;
 pub mod socket;
 mod streams;
 pub mod string;
 pub mod tag_utils;
+mod text_snapshot;
 pub mod timer;
 mod types;
 mod vminterface;
+mod worker;
 mod xml;
 
+pub mod application_descriptor;
 pub mod backend;
 pub mod compatibility_rules;
 pub mod config;
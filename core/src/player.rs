@@ -1,3 +1,4 @@
+use crate::application_descriptor::ApplicationDescriptor;
 use crate::avm1::globals::system::SandboxType;
 use crate::avm1::Attribute;
 use crate::avm1::Avm1;
@@ -7,13 +8,16 @@ use crate::avm1::VariableDumper;
 use crate::avm1::{Activation, ActivationIdentifier};
 use crate::avm1::{TObject, Value};
 use crate::avm2::{
-    object::TObject as _, Activation as Avm2Activation, Avm2, CallStack, Object as Avm2Object,
+    object::TObject as _, Activation as Avm2Activation, Avm2, CallStack,
+    EventObject as Avm2EventObject, Object as Avm2Object, Value as Avm2Value,
 };
 use crate::backend::ui::FontDefinition;
 use crate::backend::{
     audio::{AudioBackend, AudioManager},
+    filesystem::FilesystemBackend,
     log::LogBackend,
     navigator::{NavigatorBackend, Request},
+    printing::PrintBackend,
     storage::StorageBackend,
     ui::{InputManager, MouseCursor, UiBackend},
 };
@@ -30,7 +34,10 @@ use crate::display_object::{
     TInteractiveObject, WindowMode,
 };
 use crate::events::GamepadButton;
-use crate::events::{ButtonKeyCode, ClipEvent, ClipEventResult, KeyCode, MouseButton, PlayerEvent};
+use crate::events::{
+    ButtonKeyCode, ClipEvent, ClipEventResult, ImeEvent, KeyCode, MouseButton, MultitouchInputMode,
+    PlayerEvent,
+};
 use crate::external::{ExternalInterface, ExternalInterfaceProvider, NullFsCommandProvider};
 use crate::external::{FsCommandProvider, Value as ExternalValue};
 use crate::frame_lifecycle::{run_all_phases_avm2, FramePhase};
@@ -41,6 +48,7 @@ use crate::local_connection::LocalConnections;
 use crate::locale::get_current_date_time;
 use crate::net_connection::NetConnections;
 use crate::prelude::*;
+use crate::printing::PrintJobs;
 use crate::socket::Sockets;
 use crate::streams::StreamManager;
 use crate::string::{AvmString, AvmStringInterner};
@@ -48,6 +56,7 @@ use crate::stub::StubCollection;
 use crate::tag_utils::SwfMovie;
 use crate::timer::Timers;
 use crate::vminterface::Instantiator;
+use crate::worker::Workers;
 use crate::DefaultFont;
 use gc_arena::{Collect, DynamicRootSet, GcCell, Rootable};
 use rand::{rngs::SmallRng, SeedableRng};
@@ -118,6 +127,43 @@ pub struct MouseData<'gc> {
 
     /// If the mouse is down, the object that the mouse is currently pressing.
     pub pressed: Option<InteractiveObject<'gc>>,
+
+    /// The touch points that are currently in contact with the screen.
+    pub touches: Vec<TouchPoint<'gc>>,
+
+    /// The `touchPointID` given to the next touch point.
+    pub next_touch_point_id: i32,
+
+    /// How touch input is reported, as set by `Multitouch.inputMode`.
+    #[collect(require_static)]
+    pub multitouch_input_mode: MultitouchInputMode,
+
+    /// Whether the primary touch point is also reported as the mouse,
+    /// as set by `Multitouch.mapTouchToMouse`.
+    pub map_touch_to_mouse: bool,
+}
+
+#[derive(Clone, Copy, Collect)]
+#[collect(no_drop)]
+pub struct TouchPoint<'gc> {
+    /// The ID of this touch point given by the backend.
+    pub id: u64,
+
+    /// The ID of this touch point given to ActionScript as `touchPointID`.
+    pub touch_point_id: i32,
+
+    /// Whether this was the first touch point in contact with the screen.
+    pub is_primary: bool,
+
+    /// The last known position of this touch point, in stage coordinates.
+    #[collect(require_static)]
+    pub position: Point<Twips>,
+
+    /// The object that was touched when this touch point began.
+    pub pressed: Option<InteractiveObject<'gc>>,
+
+    /// The object that this touch point is currently over.
+    pub hovered: Option<InteractiveObject<'gc>>,
 }
 
 #[derive(Collect)]
@@ -252,7 +298,9 @@ impl<'gc> GcRootData<'gc> {
 type GcArena = gc_arena::Arena<Rootable![GcRoot<'_>]>;
 
 type Audio = Box<dyn AudioBackend>;
+type Filesystem = Box<dyn FilesystemBackend>;
 type Navigator = Box<dyn NavigatorBackend>;
+type Print = Box<dyn PrintBackend>;
 type Renderer = Box<dyn RenderBackend>;
 type Storage = Box<dyn StorageBackend>;
 type Log = Box<dyn LogBackend>;
@@ -286,6 +334,8 @@ pub struct Player {
     audio: Audio,
     navigator: Navigator,
     storage: Storage,
+    filesystem: Filesystem,
+    print: Print,
     log: Log,
     ui: Ui,
     video: Video,
@@ -364,6 +414,12 @@ pub struct Player {
     /// A map from gamepad buttons to key codes.
     gamepad_button_mapping: HashMap<GamepadButton, KeyCode>,
 
+    /// The workers of the movie, which share state with this player.
+    workers: Workers,
+
+    /// The print job that hasn't been sent yet.
+    print_jobs: PrintJobs,
+
     /// Debug UI windows
     #[cfg(feature = "egui")]
     debug_ui: Rc<RefCell<crate::debug_ui::DebugUi>>,
@@ -484,7 +540,7 @@ impl Player {
             self.update(|context| {
                 StreamManager::tick(context, dt);
             });
-            self.audio.tick();
+            self.audio.tick(dt);
         }
     }
     pub fn time_til_next_timer(&self) -> Option<f64> {
@@ -870,9 +926,21 @@ impl Player {
     /// 8. Mouse state is updated. This triggers button rollovers, which are a
     ///    second wave of event processing.
     pub fn handle_event(&mut self, event: PlayerEvent) {
+        // Gamepads are exposed to AVM2 as game input devices.
+        if let PlayerEvent::GamepadConnected { .. }
+        | PlayerEvent::GamepadDisconnected { .. }
+        | PlayerEvent::GamepadButtonDown { .. }
+        | PlayerEvent::GamepadButtonUp { .. }
+        | PlayerEvent::GamepadAxisChanged { .. } = event
+        {
+            self.mutate_with_update_context(|context| {
+                crate::avm2::globals::flash::ui::game_input::handle_gamepad_event(context, &event);
+            });
+        }
+
         // Optionally transform gamepad button events into key events.
         let event = match event {
-            PlayerEvent::GamepadButtonDown { button } => {
+            PlayerEvent::GamepadButtonDown { button, .. } => {
                 if let Some(key_code) = self.gamepad_button_mapping.get(&button) {
                     PlayerEvent::KeyDown {
                         key_code: *key_code,
@@ -883,7 +951,7 @@ impl Player {
                     return;
                 }
             }
-            PlayerEvent::GamepadButtonUp { button } => {
+            PlayerEvent::GamepadButtonUp { button, .. } => {
                 if let Some(key_code) = self.gamepad_button_mapping.get(&button) {
                     PlayerEvent::KeyUp {
                         key_code: *key_code,
@@ -894,6 +962,16 @@ impl Player {
                     return;
                 }
             }
+            PlayerEvent::GamepadConnected { .. }
+            | PlayerEvent::GamepadDisconnected { .. }
+            | PlayerEvent::GamepadAxisChanged { .. } => return,
+            PlayerEvent::TouchBegin { .. }
+            | PlayerEvent::TouchMove { .. }
+            | PlayerEvent::TouchEnd { .. }
+            | PlayerEvent::TouchCancel { .. } => {
+                self.handle_touch_event(event);
+                return;
+            }
             _ => event,
         };
 
@@ -1125,6 +1203,10 @@ impl Player {
                 }
             }
 
+            if let PlayerEvent::Ime(ime_event) = &event {
+                Self::handle_ime_event(context, ime_event);
+            }
+
             // KeyPress events also take precedence over keyboard navigation.
             // Note that keyboard navigation works only when the highlight is visible.
             if !key_press_handled && context.focus_tracker.highlight().is_visible() {
@@ -1219,6 +1301,219 @@ impl Player {
         }
     }
 
+    /// Delivers an IME event to the focused text field, and notifies the
+    /// movie when a composition is finished.
+    fn handle_ime_event(context: &mut UpdateContext<'_, '_>, event: &ImeEvent) {
+        match event {
+            ImeEvent::Start => {
+                let Some(focus) = context.focus_tracker.get() else {
+                    return;
+                };
+                if let Avm2Value::Object(target) = focus.as_displayobject().object2() {
+                    let mut activation = Avm2Activation::from_nothing(context.reborrow());
+                    let ime_event = Avm2EventObject::ime_event(
+                        &mut activation,
+                        "imeStartComposition",
+                        Default::default(),
+                    );
+                    Avm2::dispatch_event(&mut activation.context, ime_event, target);
+                }
+            }
+            ImeEvent::Update(text, cursor) => {
+                if let Some(text_field) = context.focus_tracker.get_as_edit_text() {
+                    text_field.set_ime_preedit(text, *cursor, context);
+                }
+            }
+            ImeEvent::Commit(text) => {
+                if let Some(text_field) = context.focus_tracker.get_as_edit_text() {
+                    text_field.commit_ime(text, context);
+                }
+
+                let text = AvmString::new_utf8(context.gc_context, text);
+
+                let mut activation = Avm2Activation::from_nothing(context.reborrow());
+                let ime_event = Avm2EventObject::ime_event(&mut activation, "imeComposition", text);
+                let ime_class = activation.avm2().classes().ime;
+                Avm2::broadcast_event(&mut activation.context, ime_event, ime_class);
+
+                if let Some(root_clip) = context.stage.root_clip() {
+                    context.action_queue.queue_action(
+                        root_clip,
+                        ActionType::NotifyListeners {
+                            listener: "System.IME",
+                            method: "onIMEComposition",
+                            args: vec![text.into()],
+                        },
+                        false,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Handles a touch event.
+    ///
+    /// Touch points are dispatched as AVM2 `TouchEvent`s when `Multitouch.inputMode`
+    /// is `TOUCH_POINT`. The primary touch point is then also reported as the mouse,
+    /// unless disabled with `Multitouch.mapTouchToMouse`.
+    fn handle_touch_event(&mut self, event: PlayerEvent) {
+        let map_to_mouse =
+            self.mutate_with_update_context(|context| Self::update_touch_points(context, &event));
+        if !map_to_mouse {
+            return;
+        }
+
+        match event {
+            PlayerEvent::TouchBegin { x, y, .. } => {
+                self.handle_event(PlayerEvent::MouseMove { x, y });
+                self.handle_event(PlayerEvent::MouseDown {
+                    x,
+                    y,
+                    button: MouseButton::Left,
+                });
+            }
+            PlayerEvent::TouchMove { x, y, .. } => {
+                self.handle_event(PlayerEvent::MouseMove { x, y });
+            }
+            PlayerEvent::TouchEnd { x, y, .. } => {
+                self.handle_event(PlayerEvent::MouseUp {
+                    x,
+                    y,
+                    button: MouseButton::Left,
+                });
+            }
+            PlayerEvent::TouchCancel { .. } => {
+                // Release the mouse where it was last seen.
+                let position = self.mutate_with_update_context(|context| {
+                    context.stage.view_matrix() * *context.mouse_position
+                });
+                self.handle_event(PlayerEvent::MouseUp {
+                    x: position.x.to_pixels(),
+                    y: position.y.to_pixels(),
+                    button: MouseButton::Left,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// Updates the touch point of a touch event, and dispatches its `TouchEvent`s.
+    ///
+    /// Returns whether the event should also be reported as the mouse.
+    fn update_touch_points(context: &mut UpdateContext<'_, '_>, event: &PlayerEvent) -> bool {
+        let (id, position) = match *event {
+            PlayerEvent::TouchBegin { id, x, y }
+            | PlayerEvent::TouchMove { id, x, y }
+            | PlayerEvent::TouchEnd { id, x, y } => (
+                id,
+                Some(context.stage.inverse_view_matrix() * Point::from_pixels(x, y)),
+            ),
+            PlayerEvent::TouchCancel { id } => (id, None),
+            _ => return false,
+        };
+
+        let index = context.mouse_data.touches.iter().position(|t| t.id == id);
+        let mut touch = match (event, index) {
+            (PlayerEvent::TouchBegin { .. }, None) => {
+                let position = position.unwrap_or_default();
+                let target = run_mouse_pick(context, position, false);
+                let touch = TouchPoint {
+                    id,
+                    touch_point_id: context.mouse_data.next_touch_point_id,
+                    is_primary: context.mouse_data.touches.is_empty(),
+                    position,
+                    pressed: target,
+                    hovered: None,
+                };
+                context.mouse_data.next_touch_point_id =
+                    context.mouse_data.next_touch_point_id.wrapping_add(1);
+                context.mouse_data.touches.push(touch);
+                touch
+            }
+            (PlayerEvent::TouchMove { .. }, Some(index)) => {
+                context.mouse_data.touches[index].position = position.unwrap_or_default();
+                context.mouse_data.touches[index]
+            }
+            (PlayerEvent::TouchEnd { .. } | PlayerEvent::TouchCancel { .. }, Some(index)) => {
+                let mut touch = context.mouse_data.touches.remove(index);
+                if let Some(position) = position {
+                    touch.position = position;
+                }
+                touch
+            }
+            // Ignore events for unknown touch points, or touch points that already began.
+            _ => return false,
+        };
+
+        if context.mouse_data.multitouch_input_mode == MultitouchInputMode::TouchPoint {
+            let target = run_mouse_pick(context, touch.position, false);
+            if !InteractiveObject::option_ptr_eq(target, touch.hovered) {
+                if let Some(hovered) = touch.hovered {
+                    Self::dispatch_touch_event(
+                        context,
+                        "touchOut",
+                        hovered.as_displayobject(),
+                        target,
+                        &touch,
+                    );
+                }
+                if let Some(target) = target {
+                    Self::dispatch_touch_event(
+                        context,
+                        "touchOver",
+                        target.as_displayobject(),
+                        touch.hovered,
+                        &touch,
+                    );
+                }
+                touch.hovered = target;
+                if let Some(index) = context.mouse_data.touches.iter().position(|t| t.id == id) {
+                    context.mouse_data.touches[index].hovered = target;
+                }
+            }
+
+            // Touches that don't hit anything are dispatched to the stage.
+            let target_object = target.map_or(context.stage.into(), |t| t.as_displayobject());
+            let event_type = match event {
+                PlayerEvent::TouchBegin { .. } => "touchBegin",
+                PlayerEvent::TouchMove { .. } => "touchMove",
+                _ => "touchEnd",
+            };
+            Self::dispatch_touch_event(context, event_type, target_object, None, &touch);
+
+            if matches!(event, PlayerEvent::TouchEnd { .. })
+                && target.is_some()
+                && InteractiveObject::option_ptr_eq(target, touch.pressed)
+            {
+                Self::dispatch_touch_event(context, "touchTap", target_object, None, &touch);
+            }
+        }
+
+        touch.is_primary && context.mouse_data.map_touch_to_mouse
+    }
+
+    fn dispatch_touch_event<'gc>(
+        context: &mut UpdateContext<'_, 'gc>,
+        event_type: &'static str,
+        target: DisplayObject<'gc>,
+        related_object: Option<InteractiveObject<'gc>>,
+        touch: &TouchPoint<'gc>,
+    ) {
+        if let Avm2Value::Object(object) = target.object2() {
+            let mut activation = Avm2Activation::from_nothing(context.reborrow());
+            let touch_event = Avm2EventObject::touch_event(
+                &mut activation,
+                event_type,
+                target,
+                related_object,
+                touch.touch_point_id,
+                touch.is_primary,
+                touch.position,
+            );
+            Avm2::dispatch_event(&mut activation.context, touch_event, object);
+        }
+    }
+
     fn should_reset_highlight(&self, event: PlayerEvent) -> bool {
         if matches!(
             event,
@@ -1294,7 +1589,7 @@ impl Player {
                 let was_visible = display_object.visible();
                 display_object.set_visible(context.gc_context, false);
                 // Set `_droptarget` to the object the mouse is hovering over.
-                let drop_target_object = run_mouse_pick(context, false);
+                let drop_target_object = run_mouse_pick(context, *context.mouse_position, false);
                 movie_clip.set_drop_target(
                     context.gc_context,
                     drop_target_object.map(|d| d.as_displayobject()),
@@ -1319,7 +1614,7 @@ impl Player {
                 !is_mouse_moved && !is_mouse_button_changed && context.mouse_data.hovered.is_some();
 
             let new_over_object = if mouse_in_stage {
-                run_mouse_pick(context, true)
+                run_mouse_pick(context, *context.mouse_position, true)
             } else {
                 None
             };
@@ -1694,6 +1989,42 @@ impl Player {
         });
 
         self.needs_render = true;
+
+        if self.workers.is_primordial() {
+            self.run_background_workers();
+        }
+    }
+
+    /// Runs a frame of every running background worker, after a frame of the
+    /// primordial worker.
+    fn run_background_workers(&mut self) {
+        if !self.workers.is_active() {
+            return;
+        }
+
+        self.mutate_with_update_context(Workers::store_shared_state);
+        let frame_time = 1000.0 / self.frame_rate;
+        for player in self.workers.update_players() {
+            let mut player = player.lock().unwrap();
+            player.update_workers();
+            player.run_frame();
+            player.update_timers(frame_time);
+            player.mutate_with_update_context(Workers::store_shared_state);
+        }
+
+        for message in self.workers.take_traces() {
+            self.log.avm_trace(&message);
+        }
+        self.update_workers();
+    }
+
+    /// Loads the state that this worker shares with others, and dispatches the
+    /// events of the workers and message channels that changed.
+    fn update_workers(&mut self) {
+        self.mutate_with_update_context(|context| {
+            let events = Workers::load_shared_state(context);
+            crate::avm2::globals::flash::system::worker::dispatch_worker_events(context, &events);
+        });
     }
 
     #[instrument(level = "debug", skip_all)]
@@ -1959,6 +2290,8 @@ impl Player {
                 page_url: &mut self.page_url,
                 instance_counter: &mut self.instance_counter,
                 storage: self.storage.deref_mut(),
+                filesystem: self.filesystem.deref_mut(),
+                print: self.print.deref_mut(),
                 log: self.log.deref_mut(),
                 video: self.video.deref_mut(),
                 avm1_shared_objects,
@@ -1986,6 +2319,8 @@ impl Player {
                 sockets,
                 net_connections,
                 local_connections,
+                workers: &mut self.workers,
+                print_jobs: &mut self.print_jobs,
                 dynamic_root,
                 post_frame_callbacks,
             };
@@ -2185,8 +2520,10 @@ pub struct PlayerBuilder {
 
     // Backends
     audio: Option<Audio>,
+    filesystem: Option<Filesystem>,
     log: Option<Log>,
     navigator: Option<Navigator>,
+    print: Option<Print>,
     renderer: Option<Renderer>,
     storage: Option<Storage>,
     ui: Option<Ui>,
@@ -2211,6 +2548,8 @@ pub struct PlayerBuilder {
     gamepad_button_mapping: HashMap<GamepadButton, KeyCode>,
     player_version: Option<u8>,
     player_runtime: PlayerRuntime,
+    application_descriptor: Option<ApplicationDescriptor>,
+    workers: Option<Workers>,
     quality: StageQuality,
     sandbox_type: SandboxType,
     page_url: Option<String>,
@@ -2233,8 +2572,10 @@ impl PlayerBuilder {
             movie: None,
 
             audio: None,
+            filesystem: None,
             log: None,
             navigator: None,
+            print: None,
             renderer: None,
             storage: None,
             ui: None,
@@ -2263,6 +2604,8 @@ impl PlayerBuilder {
             gamepad_button_mapping: HashMap::new(),
             player_version: None,
             player_runtime: PlayerRuntime::default(),
+            application_descriptor: None,
+            workers: None,
             quality: StageQuality::High,
             sandbox_type: SandboxType::LocalTrusted,
             page_url: None,
@@ -2324,6 +2667,20 @@ impl PlayerBuilder {
         self
     }
 
+    /// Sets the filesystem backend of the player, used by AIR applications.
+    #[inline]
+    pub fn with_filesystem(mut self, filesystem: Box<dyn FilesystemBackend>) -> Self {
+        self.filesystem = Some(filesystem);
+        self
+    }
+
+    /// Sets the print backend of the player, used by `PrintJob`.
+    #[inline]
+    pub fn with_print(mut self, print: Box<dyn PrintBackend>) -> Self {
+        self.print = Some(print);
+        self
+    }
+
     /// Sets the UI backend of the player.
     #[inline]
     pub fn with_ui(mut self, ui: impl 'static + UiBackend) -> Self {
@@ -2431,6 +2788,20 @@ impl PlayerBuilder {
         self
     }
 
+    /// Sets the descriptor of the AIR application being played.
+    ///
+    /// Without one, a descriptor is generated from the URL of the root movie.
+    pub fn with_application_descriptor(mut self, descriptor: ApplicationDescriptor) -> Self {
+        self.application_descriptor = Some(descriptor);
+        self
+    }
+
+    /// Makes the player run a background worker, instead of the primordial one.
+    pub(crate) fn with_workers(mut self, workers: Workers) -> Self {
+        self.workers = Some(workers);
+        self
+    }
+
     /// Configures the security sandbox type (default is `SandboxType::LocalTrusted`)
     pub fn with_sandbox_type(mut self, sandbox_type: SandboxType) -> Self {
         self.sandbox_type = sandbox_type;
@@ -2516,6 +2887,10 @@ impl PlayerBuilder {
                     mouse_data: MouseData {
                         hovered: None,
                         pressed: None,
+                        touches: Vec::new(),
+                        next_touch_point_id: 0,
+                        multitouch_input_mode: MultitouchInputMode::default(),
+                        map_touch_to_mouse: true,
                     },
                     avm1_shared_objects: HashMap::new(),
                     avm2_shared_objects: HashMap::new(),
@@ -2540,12 +2915,18 @@ impl PlayerBuilder {
         let audio = self
             .audio
             .unwrap_or_else(|| Box::new(audio::NullAudioBackend::new()));
+        let filesystem = self
+            .filesystem
+            .unwrap_or_else(|| Box::new(filesystem::MemoryFilesystemBackend::new()));
         let log = self
             .log
             .unwrap_or_else(|| Box::new(log::NullLogBackend::new()));
         let navigator = self
             .navigator
             .unwrap_or_else(|| Box::new(navigator::NullNavigatorBackend::new()));
+        let print = self
+            .print
+            .unwrap_or_else(|| Box::new(printing::NullPrintBackend::new()));
         let renderer = self.renderer.unwrap_or_else(|| {
             Box::new(NullRenderer::new(ViewportDimensions {
                 width: self.viewport_width,
@@ -2573,8 +2954,10 @@ impl PlayerBuilder {
             Mutex::new(Player {
                 // Backends
                 audio,
+                filesystem,
                 log,
                 navigator,
+                print,
                 renderer,
                 storage,
                 ui,
@@ -2618,6 +3001,10 @@ impl PlayerBuilder {
                 spoofed_url: self.spoofed_url.clone(),
                 compatibility_rules: self.compatibility_rules.clone(),
                 gamepad_button_mapping: self.gamepad_button_mapping,
+                workers: self
+                    .workers
+                    .unwrap_or_else(|| Workers::primordial(player_version, self.player_runtime)),
+                print_jobs: PrintJobs::new(),
                 stub_tracker: StubCollection::new(),
                 #[cfg(feature = "egui")]
                 debug_ui: Default::default(),
@@ -2665,6 +3052,9 @@ impl PlayerBuilder {
             context
                 .avm2
                 .set_optimizer_enabled(self.avm2_optimizer_enabled);
+            context
+                .avm2
+                .set_application_descriptor(self.application_descriptor);
             Avm2::load_player_globals(context).expect("Unable to load AVM2 globals");
 
             let stage = context.stage;
@@ -2733,6 +3123,7 @@ pub struct DragObject<'gc> {
 
 fn run_mouse_pick<'gc>(
     context: &mut UpdateContext<'_, 'gc>,
+    point: Point<Twips>,
     require_button_mode: bool,
 ) -> Option<InteractiveObject<'gc>> {
     context.stage.iter_render_list().rev().find_map(|level| {
@@ -2740,7 +3131,7 @@ fn run_mouse_pick<'gc>(
             if l.as_displayobject().movie().is_action_script_3() {
                 let mut res = None;
                 if let Avm2MousePick::Hit(target) =
-                    l.mouse_pick_avm2(context, *context.mouse_position, require_button_mode)
+                    l.mouse_pick_avm2(context, point, require_button_mode)
                 {
                     // Flash Player appears to never target events at the root object
                     if !target.as_displayobject().is_root() {
@@ -2750,7 +3141,7 @@ fn run_mouse_pick<'gc>(
 
                 res
             } else {
-                l.mouse_pick_avm1(context, *context.mouse_position, require_button_mode)
+                l.mouse_pick_avm1(context, point, require_button_mode)
             }
         })
     })
@@ -0,0 +1,169 @@
+//! Print jobs, which are started by `PrintJob` in both AVMs.
+//!
+//! Pages are rasterised as soon as they are added to a job, like Flash Player
+//! does, and kept until the job is sent to the `PrintBackend`.
+
+use crate::backend::printing::{PaperFormat, PrintBackend, PrintError, PrintPage};
+use crate::bitmap::bitmap_data::{BitmapData, BitmapDataWrapper, IBitmapDrawable};
+use crate::bitmap::operations;
+use crate::context::UpdateContext;
+use crate::prelude::*;
+use gc_arena::GcCell;
+use ruffle_render::quality::StageQuality;
+use ruffle_render::transform::Transform;
+use swf::BlendMode;
+
+pub type PrintJobId = u32;
+
+struct PendingJob {
+    id: PrintJobId,
+    paper: PaperFormat,
+    pages: Vec<PrintPage>,
+}
+
+/// The print job of a player that hasn't been sent yet.
+///
+/// Only one job can be pending at a time: starting a job abandons the
+/// previous one if it wasn't sent.
+#[derive(Default)]
+pub struct PrintJobs {
+    next_id: PrintJobId,
+    pending: Option<PendingJob>,
+}
+
+impl PrintJobs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a print job, returning its ID and paper,
+    /// or `None` if the backend cancelled it.
+    pub fn start(&mut self, backend: &mut dyn PrintBackend) -> Option<(PrintJobId, PaperFormat)> {
+        let paper = backend.start_job()?;
+        self.next_id = self.next_id.wrapping_add(1);
+        self.pending = Some(PendingJob {
+            id: self.next_id,
+            paper,
+            pages: Vec::new(),
+        });
+        Some((self.next_id, paper))
+    }
+
+    fn job(&mut self, id: PrintJobId) -> Option<&mut PendingJob> {
+        self.pending.as_mut().filter(|job| job.id == id)
+    }
+
+    /// Returns the paper of a pending job.
+    pub fn paper(&mut self, id: PrintJobId) -> Option<PaperFormat> {
+        self.job(id).map(|job| job.paper)
+    }
+
+    /// Adds a page to a pending job. Returns `false` if the job isn't pending.
+    pub fn add_page(&mut self, id: PrintJobId, page: PrintPage) -> bool {
+        match self.job(id) {
+            Some(job) => {
+                job.pages.push(page);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sends a pending job to the backend, if it has any pages.
+    /// Returns `false` if the job isn't pending.
+    pub fn send(
+        &mut self,
+        id: PrintJobId,
+        backend: &mut dyn PrintBackend,
+    ) -> Result<bool, PrintError> {
+        if self.job(id).is_none() {
+            return Ok(false);
+        }
+        let job = self.pending.take().expect("Job should be pending");
+        if !job.pages.is_empty() {
+            backend.print(&job.paper, job.pages)?;
+        }
+        Ok(true)
+    }
+}
+
+/// Rasterises a page of a print job, using the offscreen rendering of the renderer.
+///
+/// `area` is the part of `object` to print, in its own coordinates. It's
+/// placed at the top-left corner of the printable area of the paper, where
+/// each pixel takes a point, and anything that doesn't fit is cropped.
+/// Without an area, the page starts at the origin of the object.
+///
+/// A movie clip can be printed at another `frame` than its current one,
+/// which shows the objects of that frame's timeline without running any
+/// of its actions.
+pub fn rasterize_page<'gc>(
+    context: &mut UpdateContext<'_, 'gc>,
+    object: DisplayObject<'gc>,
+    area: Option<Rectangle<Twips>>,
+    frame: Option<u16>,
+    paper: &PaperFormat,
+) -> PrintPage {
+    let (width, height) = paper.page_pixel_size();
+    let scale = paper.pixels_per_inch as f64 / 72.0;
+    let target = BitmapDataWrapper::new(GcCell::new(
+        context.gc_context,
+        BitmapData::new(width, height, false, 0xFFFFFFFF),
+    ));
+
+    let (origin_x, origin_y) = area.map_or((0.0, 0.0), |area| {
+        (area.x_min.to_pixels(), area.y_min.to_pixels())
+    });
+    let transform = Transform {
+        matrix: Matrix {
+            a: scale as f32,
+            d: scale as f32,
+            tx: Twips::from_pixels(-origin_x * scale),
+            ty: Twips::from_pixels(-origin_y * scale),
+            ..Default::default()
+        },
+        color_transform: Default::default(),
+    };
+    let clip_rect = area.map(|area| Rectangle {
+        x_min: Twips::ZERO,
+        y_min: Twips::ZERO,
+        x_max: Twips::from_pixels(area.width().to_pixels() * scale),
+        y_max: Twips::from_pixels(area.height().to_pixels() * scale),
+    });
+
+    // Printing mustn't run any script or move the playhead, so another frame
+    // is printed from a copy of the clip that only holds its display list.
+    let object = match (object.as_movie_clip(), frame) {
+        (Some(clip), Some(frame)) if frame != clip.current_frame() => {
+            clip.timeline_frame_copy(context, frame).into()
+        }
+        _ => object,
+    };
+
+    let result = operations::draw(
+        context,
+        target,
+        IBitmapDrawable::DisplayObject(object),
+        transform,
+        true,
+        BlendMode::Normal,
+        clip_rect,
+        StageQuality::High,
+    );
+    if result.is_err() {
+        tracing::warn!("Render backend does not support printing, printing a blank page");
+    }
+
+    let data = target.sync(context.renderer);
+    let pixels = data
+        .read()
+        .pixels()
+        .iter()
+        .flat_map(|color| [color.red(), color.green(), color.blue()])
+        .collect();
+    PrintPage {
+        width,
+        height,
+        pixels,
+    }
+}
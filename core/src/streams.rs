@@ -21,18 +21,23 @@ use crate::loader::Error;
 use crate::string::AvmString;
 use crate::vminterface::AvmObject;
 use flv_rs::{
-    AudioData as FlvAudioData, AudioDataType as FlvAudioDataType, Error as FlvError, FlvReader,
-    FrameType as FlvFrameType, Header as FlvHeader, ScriptData as FlvScriptData,
-    SoundFormat as FlvSoundFormat, SoundRate as FlvSoundRate, SoundSize as FlvSoundSize,
-    SoundType as FlvSoundType, Tag as FlvTag, TagData as FlvTagData, Value as FlvValue,
-    VideoData as FlvVideoData, VideoPacket as FlvVideoPacket,
+    AudioData as FlvAudioData, AudioDataType as FlvAudioDataType, CodecId as FlvCodecId,
+    Error as FlvError, FlvReader, FrameType as FlvFrameType, Header as FlvHeader,
+    ScriptData as FlvScriptData, SoundFormat as FlvSoundFormat, SoundRate as FlvSoundRate,
+    SoundSize as FlvSoundSize, SoundType as FlvSoundType, Tag as FlvTag, TagData as FlvTagData,
+    Value as FlvValue, Variable as FlvVariable, VideoData as FlvVideoData,
+    VideoPacket as FlvVideoPacket,
 };
 use gc_arena::{Collect, GcCell, Mutation};
+use ruffle_mp4::{
+    Error as Mp4Error, Media as Mp4Media, Movie as Mp4Movie, Sample as Mp4Sample, Track as Mp4Track,
+};
 use ruffle_render::bitmap::BitmapInfo;
 use ruffle_video::frame::EncodedFrame;
 use ruffle_video::VideoStreamHandle;
 use std::cmp::max;
 use std::io::{Seek, SeekFrom};
+use std::sync::Arc;
 use swf::{AudioCompression, SoundFormat, VideoCodec, VideoDeblocking};
 use thiserror::Error;
 use url::Url;
@@ -47,6 +52,9 @@ enum NetstreamError {
 
     #[error("Unknown codec")]
     UnknownCodec,
+
+    #[error("AAC audio data before any AAC sequence header")]
+    MissingAacConfig,
 }
 
 impl From<DecodeError> for NetstreamError {
@@ -177,6 +185,28 @@ pub enum NetStreamType {
         /// frame IDs ourselves for various API related purposes.
         frame_id: u32,
     },
+
+    /// The stream is an ISO base media file, such as an MP4 or F4V.
+    Mp4 {
+        /// The parsed movie box, which locates every sample in the buffer.
+        movie: Arc<Mp4Movie>,
+
+        /// The index of the next sample on the movie's timeline to play.
+        position: usize,
+
+        /// The currently playing video track's stream instance.
+        video_stream: Option<VideoStreamHandle>,
+
+        /// The index of the last processed frame.
+        frame_id: u32,
+
+        /// True once the video decoder configuration has been sent down the
+        /// video pipeline.
+        sent_video_config: bool,
+
+        /// True once `onMetaData` has been generated from the movie box.
+        has_dispatched_metadata: bool,
+    },
 }
 
 #[derive(Clone, Debug, Collect)]
@@ -239,6 +269,11 @@ pub struct NetStreamData<'gc> {
     #[collect(require_static)]
     audio_stream: Option<(Substream, SoundStreamInfo)>,
 
+    /// The most recent AAC sequence header, which configures the decoder of
+    /// any new AAC audio stream.
+    #[collect(require_static)]
+    aac_config: Option<Slice>,
+
     /// The currently playing sound stream
     #[collect(require_static)]
     sound_instance: Option<SoundInstanceHandle>,
@@ -268,6 +303,7 @@ impl<'gc> NetStream<'gc> {
                 avm2_client: None,
                 url: None,
                 audio_stream: None,
+                aac_config: None,
                 sound_instance: None,
                 attached_to: None,
                 playing: false,
@@ -315,6 +351,7 @@ impl<'gc> NetStream<'gc> {
         write.stream_time = 0.0;
         write.queued_seek_time = None;
         write.audio_stream = None;
+        write.aac_config = None;
         write.sound_instance = None;
         write.expected_length = Some(0);
     }
@@ -442,6 +479,19 @@ impl<'gc> NetStream<'gc> {
             write.audio_stream = None;
         }
 
+        let skipping_forward = write.stream_time < offset;
+        if let Some(NetStreamType::Mp4 {
+            movie, position, ..
+        }) = &mut write.stream_type
+        {
+            *position = movie.seek(offset, skipping_forward);
+            let time = movie
+                .timeline
+                .get(*position)
+                .map_or(0.0, |entry| entry.time);
+            write.stream_time = time;
+        }
+
         if matches!(write.stream_type, Some(NetStreamType::Flv { .. })) {
             let slice = write.buffer.to_full_slice();
             let buffer = slice.data();
@@ -638,11 +688,22 @@ impl<'gc> NetStream<'gc> {
         slice: &Slice,
         audio_data: FlvAudioData<'_>,
     ) -> Result<(), NetstreamError> {
+        let is_aac_config = matches!(audio_data.data, FlvAudioDataType::AacSequenceHeader(_));
         let data = match audio_data.data {
             FlvAudioDataType::Raw(data)
             | FlvAudioDataType::AacSequenceHeader(data)
             | FlvAudioDataType::AacRaw(data) => slice.to_subslice(data),
         };
+
+        if is_aac_config {
+            // Keep the config around for any new stream we need to start
+            // after seeking. The current stream is already configured.
+            write.aac_config = Some(data.clone());
+            if write.audio_stream.is_some() {
+                return Ok(());
+            }
+        }
+
         let substream = match &mut write.audio_stream {
             Some((substream, _sound_stream_info)) => {
                 if substream
@@ -664,7 +725,13 @@ impl<'gc> NetStream<'gc> {
             }
             audio_stream => {
                 // None
-                let substream = Substream::new(slice.buffer().clone());
+                let mut substream = Substream::new(slice.buffer().clone());
+                if matches!(audio_data.format, FlvSoundFormat::Aac) && !is_aac_config {
+                    // AAC streams must start with the decoder configuration.
+                    let config = write.aac_config.clone();
+                    substream.append(config.ok_or(NetstreamError::MissingAacConfig)?)?;
+                }
+
                 let swf_format = SoundFormat {
                     compression: match audio_data.format {
                         FlvSoundFormat::LinearPCMPlatformEndian => {
@@ -678,7 +745,7 @@ impl<'gc> NetStream<'gc> {
                         FlvSoundFormat::Nellymoser => AudioCompression::Nellymoser,
                         FlvSoundFormat::G711ALawPCM => return Err(NetstreamError::UnknownCodec),
                         FlvSoundFormat::G711MuLawPCM => return Err(NetstreamError::UnknownCodec),
-                        FlvSoundFormat::Aac => return Err(NetstreamError::UnknownCodec),
+                        FlvSoundFormat::Aac => AudioCompression::Aac,
                         FlvSoundFormat::Speex => AudioCompression::Speex,
                         FlvSoundFormat::MP38kHz => AudioCompression::Mp3,
                         FlvSoundFormat::DeviceSpecific => return Err(NetstreamError::UnknownCodec),
@@ -716,6 +783,101 @@ impl<'gc> NetStream<'gc> {
         Ok(substream.append(data)?)
     }
 
+    /// Process an audio sample from an MP4 movie.
+    ///
+    /// The sample is repackaged as the FLV audio tag that would carry it, so
+    /// that it can share the FLV audio pipeline. `write` must be an active
+    /// borrow of the current `NetStream`. `slice` must reference the
+    /// underlying backing buffer, and `buffer` must be its data.
+    fn mp4_audio_sample(
+        self,
+        write: &mut NetStreamData<'gc>,
+        slice: &Slice,
+        buffer: &[u8],
+        track: &Mp4Track,
+        sample: &Mp4Sample,
+    ) -> Result<(), NetstreamError> {
+        let Mp4Media::Audio { config, .. } = &track.media else {
+            unreachable!()
+        };
+        let data = &buffer[sample.data.clone()];
+
+        match mp4_sound_format(&track.media) {
+            Some(FlvSoundFormat::Aac) => {
+                // New audio streams need the decoder configuration first.
+                if write.audio_stream.is_none() {
+                    if let Some(config) = config {
+                        let config = FlvAudioDataType::AacSequenceHeader(&buffer[config.clone()]);
+                        self.flv_audio_tag(write, slice, mp4_audio_data(&track.media, config))?;
+                    }
+                }
+
+                let data = FlvAudioDataType::AacRaw(data);
+                self.flv_audio_tag(write, slice, mp4_audio_data(&track.media, data))
+            }
+            Some(_) => {
+                let data = FlvAudioDataType::Raw(data);
+                self.flv_audio_tag(write, slice, mp4_audio_data(&track.media, data))
+            }
+            // Already reported when the stream type was sniffed.
+            None => Ok(()),
+        }
+    }
+
+    /// Process a video sample from an MP4 movie.
+    ///
+    /// The sample is repackaged as the FLV video tag that would carry it, so
+    /// that it can share the FLV video pipeline. `write` must be an active
+    /// borrow of the current `NetStream`. `slice` must reference the
+    /// underlying backing buffer, and `buffer` must be its data.
+    #[allow(clippy::too_many_arguments)]
+    fn mp4_video_sample(
+        self,
+        context: &mut UpdateContext<'_, 'gc>,
+        write: &mut NetStreamData<'gc>,
+        slice: &Slice,
+        buffer: &[u8],
+        track: &Mp4Track,
+        sample: &Mp4Sample,
+        sample_needs_preloading: bool,
+    ) {
+        if !is_supported_mp4_media(&track.media) {
+            return;
+        }
+        let Mp4Media::Video { config, .. } = &track.media else {
+            unreachable!()
+        };
+
+        let sent_video_config = match &mut write.stream_type {
+            Some(NetStreamType::Mp4 {
+                sent_video_config, ..
+            }) => std::mem::replace(sent_video_config, true),
+            _ => unreachable!(),
+        };
+        if let (false, Some(config)) = (sent_video_config, config) {
+            let video_data = FlvVideoData {
+                frame_type: FlvFrameType::Keyframe,
+                codec_id: FlvCodecId::Avc,
+                data: FlvVideoPacket::AvcSequenceHeader(&buffer[config.clone()]),
+            };
+            self.flv_video_tag(context, write, slice, video_data, sample_needs_preloading);
+        }
+
+        let video_data = FlvVideoData {
+            frame_type: if sample.is_sync {
+                FlvFrameType::Keyframe
+            } else {
+                FlvFrameType::Interframe
+            },
+            codec_id: FlvCodecId::Avc,
+            data: FlvVideoPacket::AvcNalu {
+                composition_time_offset: track.to_millis(sample.composition_offset.into()) as i32,
+                data: &buffer[sample.data.clone()],
+            },
+        };
+        self.flv_video_tag(context, write, slice, video_data, sample_needs_preloading);
+    }
+
     /// Determine if the given sound is currently playing.
     fn sound_currently_playing(
         context: &mut UpdateContext<'_, 'gc>,
@@ -822,6 +984,48 @@ impl<'gc> NetStream<'gc> {
                     }
                 }
             }
+            // MP4 signatures are in the first box header.
+            Some(_) if buffer.len() < 8 && write.expected_length.is_some() => false,
+            // Sample locations are checked against the length of the file, so
+            // wait for all of it if the server didn't tell us how long it is.
+            Some(_) if ruffle_mp4::is_mp4(&buffer) && write.expected_length == Some(0) => false,
+            Some(_) if ruffle_mp4::is_mp4(&buffer) => {
+                match Mp4Movie::parse(&buffer, write.expected_length.unwrap_or(buffer.len())) {
+                    Ok(movie) => {
+                        for track in &movie.tracks {
+                            if !is_supported_mp4_media(&track.media) {
+                                tracing::warn!(
+                                    "MP4 track {} has unsupported media {:?}",
+                                    track.id,
+                                    track.media
+                                );
+                            }
+                        }
+
+                        write.stream_type = Some(NetStreamType::Mp4 {
+                            movie: Arc::new(movie),
+                            position: 0,
+                            video_stream: None,
+                            frame_id: 0,
+                            sent_video_config: false,
+                            has_dispatched_metadata: false,
+                        });
+                        true
+                    }
+                    // The movie box may be at the end of the file, after the media data.
+                    Err(Mp4Error::EndOfData) if write.expected_length.is_some() => false,
+                    Err(e) => {
+                        let e = match e {
+                            Mp4Error::EndOfData => Mp4Error::MissingMovie,
+                            e => e,
+                        };
+                        //TODO: Fire an error event to AS & stop playing too
+                        tracing::error!("MP4 movie parsing failed: {}", e);
+                        write.preload_offset = 3;
+                        false
+                    }
+                }
+            }
             Some(magic) => {
                 //Unrecognized signature
                 //TODO: Fire an error event to AS & stop playing too
@@ -866,11 +1070,18 @@ impl<'gc> NetStream<'gc> {
         tag_needs_preloading: bool,
     ) {
         let (video_handle, frame_id) = match write.stream_type {
-            Some(NetStreamType::Flv {
-                video_stream,
-                frame_id,
-                ..
-            }) => (video_stream, frame_id),
+            Some(
+                NetStreamType::Flv {
+                    video_stream,
+                    frame_id,
+                    ..
+                }
+                | NetStreamType::Mp4 {
+                    video_stream,
+                    frame_id,
+                    ..
+                },
+            ) => (video_stream, frame_id),
             _ => unreachable!(),
         };
         let codec = VideoCodec::from_u8(video_data.codec_id as u8);
@@ -901,9 +1112,10 @@ impl<'gc> NetStream<'gc> {
                         ) {
                             Ok(new_handle) => {
                                 match &mut write.stream_type {
-                                    Some(NetStreamType::Flv { video_stream, .. }) => {
-                                        *video_stream = Some(new_handle)
-                                    }
+                                    Some(
+                                        NetStreamType::Flv { video_stream, .. }
+                                        | NetStreamType::Mp4 { video_stream, .. },
+                                    ) => *video_stream = Some(new_handle),
                                     _ => unreachable!(),
                                 }
 
@@ -991,9 +1203,14 @@ impl<'gc> NetStream<'gc> {
         }
 
         match &mut write.stream_type {
-            Some(NetStreamType::Flv {
-                ref mut frame_id, ..
-            }) => *frame_id += 1,
+            Some(
+                NetStreamType::Flv {
+                    ref mut frame_id, ..
+                }
+                | NetStreamType::Mp4 {
+                    ref mut frame_id, ..
+                },
+            ) => *frame_id += 1,
             _ => unreachable!(),
         };
     }
@@ -1013,7 +1230,9 @@ impl<'gc> NetStream<'gc> {
     ) {
         let mut write = self.0.write(context.gc_context);
         let has_stream_already = match write.stream_type {
-            Some(NetStreamType::Flv { video_stream, .. }) => video_stream.is_some(),
+            Some(
+                NetStreamType::Flv { video_stream, .. } | NetStreamType::Mp4 { video_stream, .. },
+            ) => video_stream.is_some(),
             _ => unreachable!(),
         };
 
@@ -1070,9 +1289,10 @@ impl<'gc> NetStream<'gc> {
                         VideoDeblocking::UseVideoPacketValue,
                     ) {
                         Ok(stream_handle) => match &mut write.stream_type {
-                            Some(NetStreamType::Flv { video_stream, .. }) => {
-                                *video_stream = Some(stream_handle)
-                            }
+                            Some(
+                                NetStreamType::Flv { video_stream, .. }
+                                | NetStreamType::Mp4 { video_stream, .. },
+                            ) => *video_stream = Some(stream_handle),
                             _ => unreachable!(),
                         },
                         Err(e) => {
@@ -1187,6 +1407,90 @@ impl<'gc> NetStream<'gc> {
                     write.preload_offset = max(write.offset, write.preload_offset);
                 }
             }
+        } else if let Some(NetStreamType::Mp4 {
+            movie,
+            position,
+            has_dispatched_metadata,
+            ..
+        }) = &mut write.stream_type
+        {
+            let movie = movie.clone();
+            let mut position = *position;
+
+            if !std::mem::replace(has_dispatched_metadata, true) {
+                let script_data = FlvScriptData(vec![FlvVariable {
+                    name: b"onMetaData",
+                    data: mp4_metadata(&movie, &buffer),
+                }]);
+
+                drop(write);
+                self.flv_script_tag(context, script_data, true);
+                write = self.0.write(context.gc_context);
+            }
+
+            loop {
+                let Some(entry) = movie.timeline.get(position) else {
+                    if !is_lookahead_tag {
+                        buffer_underrun = true;
+                    }
+
+                    break;
+                };
+
+                is_lookahead_tag = entry.time >= max_time;
+                if is_lookahead_tag && max_lookahead_audio_tags == 0 {
+                    break;
+                }
+
+                let (track, sample) = movie.sample(entry);
+                if sample.data.end > buffer.len() {
+                    // Still downloading.
+                    if !is_lookahead_tag {
+                        buffer_underrun = true;
+                    }
+
+                    break;
+                }
+
+                let sample_needs_preloading = sample.data.end > write.preload_offset;
+
+                match &track.media {
+                    Mp4Media::Audio { .. } => {
+                        if is_lookahead_tag {
+                            max_lookahead_audio_tags -= 1;
+                        }
+
+                        if let Err(e) =
+                            self.mp4_audio_sample(&mut write, &slice, &buffer, track, sample)
+                        {
+                            //TODO: Fire an error event at AS.
+                            tracing::error!("Error committing sound stream: {}", e);
+                        }
+                    }
+                    Mp4Media::Video { .. } if !is_lookahead_tag => self.mp4_video_sample(
+                        context,
+                        &mut write,
+                        &slice,
+                        &buffer,
+                        track,
+                        sample,
+                        sample_needs_preloading,
+                    ),
+                    Mp4Media::Video { .. } | Mp4Media::Other { .. } => {}
+                }
+
+                position += 1;
+                if !is_lookahead_tag {
+                    if let Some(NetStreamType::Mp4 {
+                        position: stream_position,
+                        ..
+                    }) = &mut write.stream_type
+                    {
+                        *stream_position = position;
+                    }
+                    write.preload_offset = max(sample.data.end, write.preload_offset);
+                }
+            }
         }
 
         write.stream_time = max_time;
@@ -1332,3 +1636,201 @@ impl<'gc> NetStream<'gc> {
         Ok(())
     }
 }
+
+/// Determine the FLV sound format that can carry the given MP4 audio.
+fn mp4_sound_format(media: &Mp4Media) -> Option<FlvSoundFormat> {
+    match media {
+        Mp4Media::Audio {
+            codec, object_type, ..
+        } => match (codec, object_type) {
+            // MPEG-4 and MPEG-2 AAC profiles.
+            (b"mp4a", Some(0x40 | 0x66..=0x68)) => Some(FlvSoundFormat::Aac),
+            // MPEG-1 and MPEG-2 audio.
+            (b"mp4a", Some(0x69 | 0x6B)) | (b".mp3", _) => Some(FlvSoundFormat::MP3),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Determine if MP4 media can either be played through the FLV pipeline, or
+/// safely ignored.
+fn is_supported_mp4_media(media: &Mp4Media) -> bool {
+    match media {
+        Mp4Media::Video { codec, .. } => matches!(codec, b"avc1" | b"avc3"),
+        Mp4Media::Audio { .. } => mp4_sound_format(media).is_some(),
+        Mp4Media::Other { .. } => true,
+    }
+}
+
+/// Build the FLV audio tag that would carry some MP4 audio data.
+///
+/// `media` must be supported audio media.
+fn mp4_audio_data<'a>(media: &Mp4Media, data: FlvAudioDataType<'a>) -> FlvAudioData<'a> {
+    let Mp4Media::Audio {
+        sample_rate,
+        channels,
+        sample_size,
+        ..
+    } = media
+    else {
+        unreachable!()
+    };
+    let format = mp4_sound_format(media).expect("supported audio media");
+
+    FlvAudioData {
+        rate: match (format, sample_rate) {
+            // FLV always signals 44kHz for AAC, the real rate is in the config.
+            (FlvSoundFormat::Aac, _) => FlvSoundRate::R44_000,
+            (_, 0..=8_250) => FlvSoundRate::R5_500,
+            (_, 8_251..=16_500) => FlvSoundRate::R11_000,
+            (_, 16_501..=33_000) => FlvSoundRate::R22_000,
+            _ => FlvSoundRate::R44_000,
+        },
+        size: if *sample_size == 8 {
+            FlvSoundSize::Bits8
+        } else {
+            FlvSoundSize::Bits16
+        },
+        sound_type: if *channels == 1 {
+            FlvSoundType::Mono
+        } else {
+            FlvSoundType::Stereo
+        },
+        format,
+        data,
+    }
+}
+
+/// Generate the `onMetaData` object of an MP4 movie from its movie box.
+///
+/// `buffer` must be the data the movie was parsed from.
+fn mp4_metadata<'a>(movie: &'a Mp4Movie, buffer: &'a [u8]) -> FlvValue<'a> {
+    let number = |name: &'a [u8], value: f64| FlvVariable {
+        name,
+        data: FlvValue::Number(value),
+    };
+    let mut variables = vec![
+        number(b"duration", movie.duration_millis() / 1000.0),
+        number(b"moovposition", movie.moov_position as f64),
+    ];
+
+    let video = movie.tracks.iter().find(|track| track.is_video());
+    if let Some(
+        track @ Mp4Track {
+            media:
+                Mp4Media::Video {
+                    codec,
+                    width,
+                    height,
+                    config,
+                },
+            ..
+        },
+    ) = video
+    {
+        variables.push(number(b"width", (*width).into()));
+        variables.push(number(b"height", (*height).into()));
+        variables.push(FlvVariable {
+            name: b"videocodecid",
+            data: FlvValue::String(codec),
+        });
+        if let Some(config) = config
+            .as_ref()
+            .and_then(|config| buffer.get(config.clone()))
+        {
+            if let (Some(profile), Some(level)) = (config.get(1), config.get(3)) {
+                variables.push(number(b"avcprofile", (*profile).into()));
+                variables.push(number(b"avclevel", (*level).into()));
+            }
+        }
+        let duration = track.to_millis(track.duration as i64) / 1000.0;
+        if duration > 0.0 {
+            variables.push(number(
+                b"videoframerate",
+                track.samples.len() as f64 / duration,
+            ));
+        }
+    }
+
+    let audio = movie
+        .tracks
+        .iter()
+        .find(|track| matches!(track.media, Mp4Media::Audio { .. }));
+    if let Some(Mp4Track {
+        media:
+            media @ Mp4Media::Audio {
+                codec,
+                sample_rate,
+                channels,
+                config,
+                ..
+            },
+        ..
+    }) = audio
+    {
+        variables.push(FlvVariable {
+            name: b"audiocodecid",
+            data: FlvValue::String(codec),
+        });
+        variables.push(number(b"audiosamplerate", (*sample_rate).into()));
+        variables.push(number(b"audiochannels", (*channels).into()));
+        if matches!(mp4_sound_format(media), Some(FlvSoundFormat::Aac)) {
+            // The audio object type is the top 5 bits of the AAC config.
+            if let Some(first_byte) = config.as_ref().and_then(|config| buffer.get(config.start)) {
+                variables.push(number(b"aacaot", (first_byte >> 3).into()));
+            }
+        }
+    }
+
+    let track_info = movie
+        .tracks
+        .iter()
+        .map(|track| {
+            let sample_type = match &track.media {
+                Mp4Media::Video { codec, .. } | Mp4Media::Audio { codec, .. } => codec,
+                Mp4Media::Other { handler } => handler,
+            };
+
+            FlvValue::Object(vec![
+                number(b"length", track.duration as f64),
+                number(b"timescale", track.timescale.into()),
+                FlvVariable {
+                    name: b"language",
+                    data: FlvValue::String(&track.language),
+                },
+                FlvVariable {
+                    name: b"sampledescription",
+                    data: FlvValue::StrictArray(vec![FlvValue::Object(vec![FlvVariable {
+                        name: b"sampletype",
+                        data: FlvValue::String(sample_type),
+                    }])]),
+                },
+            ])
+        })
+        .collect();
+    variables.push(FlvVariable {
+        name: b"trackinfo",
+        data: FlvValue::StrictArray(track_info),
+    });
+
+    let seek_points = movie
+        .timeline
+        .iter()
+        .filter_map(|entry| {
+            let (track, sample) = movie.sample(entry);
+            (track.is_video() && sample.is_sync).then(|| {
+                FlvValue::Object(vec![
+                    number(b"time", entry.time / 1000.0),
+                    number(b"offset", sample.data.start as f64),
+                ])
+            })
+        })
+        .collect();
+    variables.push(FlvVariable {
+        name: b"seekpoints",
+        data: FlvValue::StrictArray(seek_points),
+    });
+
+    FlvValue::EcmaArray(variables)
+}
@@ -0,0 +1,379 @@
+//! Shared implementation of `TextSnapshot` for both AVMs.
+
+use crate::display_object::{
+    DisplayObject, StaticTextGlyph, TDisplayObject, TDisplayObjectContainer, Text,
+};
+use crate::library::Library;
+use crate::prelude::*;
+use crate::string::{utils as string_utils, WStr, WString};
+use gc_arena::{Collect, Mutation};
+use std::ops::Range;
+
+/// A snapshot of the static text directly contained in a display object.
+///
+/// The characters of all texts are indexed as if they were one string, in
+/// the order in which the texts are rendered.
+#[derive(Clone, Debug, Default, Collect)]
+#[collect(no_drop)]
+pub struct TextSnapshot<'gc> {
+    texts: Vec<Text<'gc>>,
+}
+
+/// A glyph of a `TextSnapshot`, in the coordinate space of its container.
+pub struct SnapshotGlyph<'gc> {
+    pub glyph: StaticTextGlyph<'gc>,
+
+    /// The text that this glyph belongs to.
+    pub text: Text<'gc>,
+
+    /// The index of this glyph within its text.
+    pub index_in_text: usize,
+
+    /// Whether this glyph starts a new line of the snapshot.
+    pub is_new_line: bool,
+
+    /// The transform from the glyph's space into the space of the container.
+    pub matrix: Matrix,
+}
+
+impl<'gc> SnapshotGlyph<'gc> {
+    /// The corners of the glyph in the container's space, clockwise from the
+    /// top left corner.
+    pub fn corners(&self) -> [Point<Twips>; 4] {
+        let bounds = &self.glyph.bounds;
+        [
+            Point::new(bounds.x_min, bounds.y_min),
+            Point::new(bounds.x_max, bounds.y_min),
+            Point::new(bounds.x_max, bounds.y_max),
+            Point::new(bounds.x_min, bounds.y_max),
+        ]
+        .map(|corner| self.matrix * corner)
+    }
+
+    pub fn is_selected(&self) -> bool {
+        self.text.is_selected(self.index_in_text)
+    }
+}
+
+impl<'gc> TextSnapshot<'gc> {
+    pub fn new(container: DisplayObject<'gc>) -> Self {
+        let texts = container
+            .as_container()
+            .map(|container| {
+                container
+                    .iter_render_list()
+                    .filter_map(|child| match child {
+                        DisplayObject::Text(text) => Some(text),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self { texts }
+    }
+
+    pub fn glyphs(&self, library: &Library<'gc>) -> Vec<SnapshotGlyph<'gc>> {
+        let mut glyphs = vec![];
+        for &text in &self.texts {
+            let text_matrix = *text.base().matrix();
+            let mut line = None;
+            for (index_in_text, glyph) in text.glyphs(library).into_iter().enumerate() {
+                // Every text starts on a new line.
+                let is_new_line = !glyphs.is_empty() && line != Some(glyph.line);
+                line = Some(glyph.line);
+                glyphs.push(SnapshotGlyph {
+                    matrix: text_matrix * glyph.matrix,
+                    glyph,
+                    text,
+                    index_in_text,
+                    is_new_line,
+                });
+            }
+        }
+        glyphs
+    }
+
+    pub fn char_count(&self, library: &Library<'gc>) -> usize {
+        self.texts
+            .iter()
+            .map(|text| text.glyphs(library).len())
+            .sum()
+    }
+
+    /// Returns the text of the glyphs in the given range, optionally with
+    /// newlines between lines.
+    pub fn text(
+        &self,
+        library: &Library<'gc>,
+        range: Range<usize>,
+        include_line_endings: bool,
+    ) -> WString {
+        let glyphs = self.glyphs(library);
+        let range = clamp_range(range, glyphs.len());
+        collect_text(
+            glyphs[range]
+                .iter()
+                .map(|glyph| (glyph.glyph.character, glyph.is_new_line)),
+            include_line_endings,
+        )
+    }
+
+    /// Returns the text of all selected glyphs, optionally with newlines
+    /// between lines.
+    pub fn selected_text(&self, library: &Library<'gc>, include_line_endings: bool) -> WString {
+        collect_text(
+            self.glyphs(library)
+                .iter()
+                .filter(|glyph| glyph.is_selected())
+                .map(|glyph| (glyph.glyph.character, glyph.is_new_line)),
+            include_line_endings,
+        )
+    }
+
+    /// Returns the index of the first occurrence of `pattern` at or after `begin_index`.
+    pub fn find_text(
+        &self,
+        library: &Library<'gc>,
+        begin_index: usize,
+        pattern: &WStr,
+        case_sensitive: bool,
+    ) -> Option<usize> {
+        // Only the characters are needed, so don't bother with the glyphs' transforms.
+        let characters = self
+            .texts
+            .iter()
+            .flat_map(|text| text.glyphs(library))
+            .map(|glyph| glyph.character);
+        find_in_glyphs(characters, begin_index, pattern, case_sensitive)
+    }
+
+    /// Returns whether any glyph in the given range is selected.
+    pub fn is_selected(&self, library: &Library<'gc>, range: Range<usize>) -> bool {
+        let glyphs = self.glyphs(library);
+        let range = clamp_range(range, glyphs.len());
+        glyphs[range].iter().any(SnapshotGlyph::is_selected)
+    }
+
+    pub fn set_selected(
+        &self,
+        library: &Library<'gc>,
+        gc_context: &Mutation<'gc>,
+        range: Range<usize>,
+        selected: bool,
+    ) {
+        let glyphs = self.glyphs(library);
+        let range = clamp_range(range, glyphs.len());
+        for glyph in &glyphs[range] {
+            glyph.text.set_selected(
+                gc_context,
+                glyph.index_in_text..glyph.index_in_text + 1,
+                selected,
+            );
+        }
+    }
+
+    pub fn set_select_color(&self, gc_context: &Mutation<'gc>, color: Color) {
+        for text in &self.texts {
+            text.set_select_color(gc_context, color);
+        }
+    }
+
+    /// Returns the index of the glyph closest to the given point, in the
+    /// coordinate space of the container, if it is within `max_distance`.
+    pub fn hit_test_text_near_pos(
+        &self,
+        library: &Library<'gc>,
+        point: Point<Twips>,
+        max_distance: Twips,
+    ) -> Option<usize> {
+        let mut closest = None;
+        for (index, glyph) in self.glyphs(library).iter().enumerate() {
+            let bounds = glyph.matrix * glyph.glyph.bounds.clone();
+            let clamped = bounds.clamp(point);
+            let dx = (point.x - clamped.x).to_pixels();
+            let dy = (point.y - clamped.y).to_pixels();
+            let distance = dx.hypot(dy);
+            if distance <= max_distance.to_pixels()
+                && closest.map_or(true, |(_, closest)| distance < closest)
+            {
+                closest = Some((index, distance));
+            }
+        }
+        closest.map(|(index, _)| index)
+    }
+}
+
+fn clamp_range(range: Range<usize>, len: usize) -> Range<usize> {
+    let start = range.start.min(len);
+    let end = range.end.clamp(start, len);
+    start..end
+}
+
+/// Joins the characters of a sequence of glyphs, given as `(character, is_new_line)`.
+///
+/// With `include_line_endings`, a newline is written before each glyph that
+/// starts a new line, except at the very start of the text.
+fn collect_text(
+    glyphs: impl IntoIterator<Item = (char, bool)>,
+    include_line_endings: bool,
+) -> WString {
+    let mut text = WString::new();
+    for (character, is_new_line) in glyphs {
+        if include_line_endings && is_new_line && !text.is_empty() {
+            text.push_byte(b'\n');
+        }
+        text.push_char(character);
+    }
+    text
+}
+
+/// Finds `pattern` in the characters of a sequence of glyphs.
+///
+/// Characters outside the BMP take two units in a `WString`, so both
+/// `begin_index` and the result are glyph indices, and matches that don't
+/// start on a glyph are ignored.
+fn find_in_glyphs(
+    characters: impl IntoIterator<Item = char>,
+    begin_index: usize,
+    pattern: &WStr,
+    case_sensitive: bool,
+) -> Option<usize> {
+    let mut text = WString::new();
+    let mut glyph_starts = vec![];
+    for character in characters {
+        glyph_starts.push(text.len());
+        text.push_char(character);
+    }
+    glyph_starts.push(text.len());
+
+    let mut begin = glyph_starts.get(begin_index).copied().unwrap_or(text.len());
+    loop {
+        let found = find_in_text(&text, begin, pattern, case_sensitive)?;
+        match glyph_starts.binary_search(&found) {
+            Ok(glyph_index) => return Some(glyph_index),
+            Err(_) => begin = found + 1,
+        }
+    }
+}
+
+fn find_in_text(
+    text: &WStr,
+    begin_index: usize,
+    pattern: &WStr,
+    case_sensitive: bool,
+) -> Option<usize> {
+    let (text, pattern) = if case_sensitive {
+        (text.to_owned(), pattern.to_owned())
+    } else {
+        (
+            text.iter().map(string_utils::swf_to_lowercase).collect(),
+            pattern.iter().map(string_utils::swf_to_lowercase).collect(),
+        )
+    };
+
+    let begin_index = begin_index.min(text.len());
+    text[begin_index..]
+        .find(&pattern[..])
+        .map(|index| index + begin_index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(s: &str) -> WString {
+        WString::from_utf8(s)
+    }
+
+    /// Two lines, "Hello" and "World", as glyphs.
+    fn two_lines() -> Vec<(char, bool)> {
+        "Hello"
+            .chars()
+            .map(|c| (c, false))
+            .chain("World".chars().enumerate().map(|(i, c)| (c, i == 0)))
+            .collect()
+    }
+
+    #[test]
+    fn find_case_sensitive() {
+        let haystack = text("Hello World, hello world");
+        assert_eq!(find_in_text(&haystack, 0, &text("hello"), true), Some(13));
+        assert_eq!(find_in_text(&haystack, 0, &text("World"), true), Some(6));
+        assert_eq!(find_in_text(&haystack, 7, &text("World"), true), None);
+        assert_eq!(find_in_text(&haystack, 0, &text("HELLO"), true), None);
+    }
+
+    #[test]
+    fn find_case_insensitive() {
+        let haystack = text("Hello World, hello world");
+        assert_eq!(find_in_text(&haystack, 0, &text("HELLO"), false), Some(0));
+        assert_eq!(find_in_text(&haystack, 1, &text("HELLO"), false), Some(13));
+        assert_eq!(find_in_text(&haystack, 7, &text("wOrLd"), false), Some(19));
+        assert_eq!(find_in_text(&haystack, 20, &text("world"), false), None);
+    }
+
+    #[test]
+    fn find_begin_index() {
+        let haystack = text("abcabc");
+        assert_eq!(find_in_text(&haystack, 3, &text("abc"), true), Some(3));
+        assert_eq!(find_in_text(&haystack, 4, &text("abc"), true), None);
+        assert_eq!(find_in_text(&haystack, 100, &text("abc"), true), None);
+        assert_eq!(find_in_text(&haystack, 100, &text(""), true), Some(6));
+    }
+
+    #[test]
+    fn find_glyph_indices() {
+        let glyphs = || "a\u{1F600}b\u{1F600}c".chars();
+        assert_eq!(find_in_glyphs(glyphs(), 0, &text("b"), true), Some(2));
+        assert_eq!(find_in_glyphs(glyphs(), 0, &text("c"), true), Some(4));
+        assert_eq!(
+            find_in_glyphs(glyphs(), 2, &text("\u{1F600}"), true),
+            Some(3)
+        );
+        assert_eq!(find_in_glyphs(glyphs(), 4, &text("\u{1F600}"), true), None);
+        assert_eq!(find_in_glyphs(glyphs(), 100, &text(""), true), Some(5));
+    }
+
+    #[test]
+    fn text_with_line_endings() {
+        assert_eq!(collect_text(two_lines(), false), text("HelloWorld"));
+        assert_eq!(collect_text(two_lines(), true), text("Hello\nWorld"));
+
+        // A range that starts on a new line doesn't start with a newline.
+        let glyphs = two_lines();
+        assert_eq!(
+            collect_text(glyphs[5..].iter().copied(), true),
+            text("World")
+        );
+        assert_eq!(
+            collect_text(glyphs[3..7].iter().copied(), true),
+            text("lo\nWo")
+        );
+        assert_eq!(collect_text(glyphs[..0].iter().copied(), true), text(""));
+    }
+
+    #[test]
+    fn selected_text_with_line_endings() {
+        // "lo" and "Wo" are selected.
+        let selected = two_lines()
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| (3..7).contains(i))
+            .map(|(_, glyph)| glyph);
+        assert_eq!(collect_text(selected, true), text("lo\nWo"));
+
+        // Only the second line is selected.
+        let selected = two_lines().into_iter().skip(5);
+        assert_eq!(collect_text(selected, true), text("World"));
+    }
+
+    #[test]
+    fn selection_ranges() {
+        assert_eq!(clamp_range(2..5, 10), 2..5);
+        assert_eq!(clamp_range(2..50, 10), 2..10);
+        assert_eq!(clamp_range(20..50, 10), 10..10);
+        // An end before the start selects nothing.
+        assert_eq!(clamp_range(5..2, 10), 5..5);
+        assert_eq!(clamp_range(0..0, 0), 0..0);
+    }
+}
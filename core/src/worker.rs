@@ -0,0 +1,693 @@
+//! Background workers
+//!
+//! Every worker runs in a `Player` of its own, with its own GC arena. Since
+//! objects can't be shared between arenas, everything that workers share is
+//! kept in a `WorkerGroup`:
+//!
+//! * Shared properties and messages are stored as AMF values, and deserialized
+//!   again by the worker that reads them.
+//! * Workers, message channels, mutexes and conditions are referred to by ID.
+//! * Shareable `ByteArray`s are copied out of a worker after it ran, and into
+//!   a worker before it runs. Workers only hold them weakly, and their bytes
+//!   are dropped once no worker, shared property or message refers to them.
+//!
+//! Background workers are run cooperatively by the primordial worker: after
+//! each of its frames, every running background worker runs one frame, in the
+//! order in which the workers were created. This keeps the order in which
+//! messages are exchanged deterministic.
+
+use crate::avm2::object::{TObject, WeakObject};
+use crate::avm2::Object as Avm2Object;
+use crate::backend::log::LogBackend;
+use crate::context::UpdateContext;
+use crate::loader::LoadBehavior;
+use crate::player::{Player, PlayerBuilder, PlayerRuntime};
+use crate::tag_utils::SwfMovie;
+use flash_lso::types::Value as AmfValue;
+use gc_arena::{DynamicRoot, Rootable};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+/// The ID of a worker, which is its index in the `WorkerGroup`.
+pub type WorkerId = u32;
+
+/// The ID of a message channel, which is its index in the `WorkerGroup`.
+pub type ChannelId = u32;
+
+/// The worker that runs the movie that was loaded by the player.
+pub const PRIMORDIAL_WORKER: WorkerId = 0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WorkerState {
+    New,
+    Running,
+    Terminated,
+}
+
+impl WorkerState {
+    /// The name of this state, as used by `flash.system.WorkerState`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WorkerState::New => "new",
+            WorkerState::Running => "running",
+            WorkerState::Terminated => "terminated",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChannelState {
+    Open,
+    Closing,
+    Closed,
+}
+
+impl ChannelState {
+    /// The name of this state, as used by `flash.system.MessageChannelState`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ChannelState::Open => "open",
+            ChannelState::Closing => "closing",
+            ChannelState::Closed => "closed",
+        }
+    }
+}
+
+/// A value that is passed from one worker to another.
+#[derive(Clone, Debug)]
+pub enum SharedValue {
+    /// A value that is copied, serialized as AMF3.
+    Amf(AmfValue),
+    Worker(WorkerId),
+    Channel(ChannelId),
+    ByteArray(u32),
+    Mutex(u32),
+    Condition(u32),
+}
+
+/// An event that has to be dispatched in the worker that it was returned to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WorkerEvent {
+    /// The state of a worker changed.
+    WorkerState(WorkerId),
+
+    /// A message was sent to a channel that this worker receives from.
+    ChannelMessage(ChannelId),
+
+    /// The state of a channel changed.
+    ChannelState(ChannelId),
+}
+
+struct WorkerEntry {
+    state: WorkerState,
+
+    /// The movie of a worker that wasn't started yet.
+    movie: Option<SwfMovie>,
+
+    shared_properties: HashMap<String, SharedValue>,
+}
+
+struct Channel {
+    sender: WorkerId,
+    receiver: WorkerId,
+    state: ChannelState,
+    messages: VecDeque<SharedValue>,
+
+    /// The number of messages that the receiver wasn't notified about yet.
+    unannounced_messages: usize,
+}
+
+struct SharedByteArray {
+    bytes: Vec<u8>,
+
+    /// The workers that have an object for this `ByteArray`.
+    holders: BTreeSet<WorkerId>,
+}
+
+#[derive(Default)]
+struct MutexEntry {
+    owner: Option<WorkerId>,
+    lock_count: u32,
+}
+
+struct ConditionEntry {
+    mutex: u32,
+
+    /// Whether the condition was notified since it was last waited on.
+    notified: bool,
+}
+
+/// The state that is shared by all workers of a movie.
+struct WorkerGroup {
+    workers: Vec<WorkerEntry>,
+    channels: Vec<Channel>,
+    byte_arrays: BTreeMap<u32, SharedByteArray>,
+    next_byte_array_id: u32,
+    mutexes: Vec<MutexEntry>,
+    conditions: Vec<ConditionEntry>,
+
+    /// Messages traced by background workers, which are written to the log of
+    /// the primordial worker once they ran.
+    traces: Vec<String>,
+
+    player_version: u8,
+    player_runtime: PlayerRuntime,
+}
+
+impl WorkerGroup {
+    fn worker(&self, id: WorkerId) -> Option<&WorkerEntry> {
+        self.workers.get(id as usize)
+    }
+
+    fn worker_mut(&mut self, id: WorkerId) -> Option<&mut WorkerEntry> {
+        self.workers.get_mut(id as usize)
+    }
+
+    fn channel(&self, id: ChannelId) -> Option<&Channel> {
+        self.channels.get(id as usize)
+    }
+
+    fn channel_mut(&mut self, id: ChannelId) -> Option<&mut Channel> {
+        self.channels.get_mut(id as usize)
+    }
+
+    /// Drops the shared `ByteArray`s that no worker has an object for, and
+    /// that aren't in a shared property or a queued message either.
+    fn release_byte_arrays(&mut self) {
+        let values = self
+            .workers
+            .iter()
+            .flat_map(|worker| worker.shared_properties.values())
+            .chain(self.channels.iter().flat_map(|channel| &channel.messages));
+        let referenced: BTreeSet<u32> = values
+            .filter_map(|value| match value {
+                SharedValue::ByteArray(id) => Some(*id),
+                _ => None,
+            })
+            .collect();
+        self.byte_arrays
+            .retain(|id, byte_array| !byte_array.holders.is_empty() || referenced.contains(id));
+    }
+}
+
+/// The log backend of background workers, which keeps their traces in the group.
+struct WorkerLogBackend(Rc<RefCell<WorkerGroup>>);
+
+impl LogBackend for WorkerLogBackend {
+    fn avm_trace(&self, message: &str) {
+        self.0.borrow_mut().traces.push(message.to_string());
+    }
+}
+
+/// The workers of a movie, as seen by one of them.
+pub struct Workers {
+    group: Rc<RefCell<WorkerGroup>>,
+
+    /// The worker that this player runs.
+    current: WorkerId,
+
+    /// The players of the running background workers.
+    ///
+    /// This is only used by the primordial worker, which runs all others.
+    players: Vec<(WorkerId, Arc<Mutex<Player>>)>,
+
+    /// The states of workers and channels that this worker was last told about.
+    known_worker_states: BTreeMap<WorkerId, WorkerState>,
+    known_channel_states: BTreeMap<ChannelId, ChannelState>,
+
+    /// The shareable `ByteArray`s of this worker, by their ID in the group.
+    byte_arrays: Vec<(u32, DynamicRoot<Rootable![WeakObject<'_>]>)>,
+}
+
+impl Workers {
+    /// Creates the workers of a player that runs the primordial worker.
+    pub fn primordial(player_version: u8, player_runtime: PlayerRuntime) -> Self {
+        let group = WorkerGroup {
+            workers: vec![WorkerEntry {
+                state: WorkerState::Running,
+                movie: None,
+                shared_properties: HashMap::new(),
+            }],
+            channels: Vec::new(),
+            byte_arrays: BTreeMap::new(),
+            next_byte_array_id: 0,
+            mutexes: Vec::new(),
+            conditions: Vec::new(),
+            traces: Vec::new(),
+            player_version,
+            player_runtime,
+        };
+        Self::new(Rc::new(RefCell::new(group)), PRIMORDIAL_WORKER)
+    }
+
+    fn new(group: Rc<RefCell<WorkerGroup>>, current: WorkerId) -> Self {
+        Self {
+            group,
+            current,
+            players: Vec::new(),
+            known_worker_states: BTreeMap::from([(current, WorkerState::Running)]),
+            known_channel_states: BTreeMap::new(),
+            byte_arrays: Vec::new(),
+        }
+    }
+
+    /// The ID of the worker that this player runs.
+    pub fn current(&self) -> WorkerId {
+        self.current
+    }
+
+    pub fn is_primordial(&self) -> bool {
+        self.current == PRIMORDIAL_WORKER
+    }
+
+    /// Whether there's anything to synchronize between workers.
+    pub fn is_active(&self) -> bool {
+        let group = self.group.borrow();
+        group.workers.len() > 1 || !group.channels.is_empty()
+    }
+
+    /// Creates a new worker, which runs the given movie once it is started.
+    pub fn create_worker(&mut self, movie: SwfMovie) -> WorkerId {
+        let mut group = self.group.borrow_mut();
+        let id = group.workers.len() as WorkerId;
+        group.workers.push(WorkerEntry {
+            state: WorkerState::New,
+            movie: Some(movie),
+            shared_properties: HashMap::new(),
+        });
+        self.known_worker_states.insert(id, WorkerState::New);
+        id
+    }
+
+    /// Lists the workers that weren't terminated yet.
+    pub fn list_workers(&self) -> Vec<WorkerId> {
+        let group = self.group.borrow();
+        (0..group.workers.len() as WorkerId)
+            .filter(|&id| group.workers[id as usize].state != WorkerState::Terminated)
+            .collect()
+    }
+
+    /// Returns the state of a worker, and remembers that this worker knows
+    /// about it, so that it is told when it changes.
+    pub fn worker_state(&mut self, id: WorkerId) -> Option<WorkerState> {
+        let state = self.group.borrow().worker(id)?.state;
+        self.known_worker_states.entry(id).or_insert(state);
+        Some(state)
+    }
+
+    /// Starts a new worker, which will run after the next frame of the primordial worker.
+    pub fn start_worker(&mut self, id: WorkerId) {
+        if let Some(worker) = self.group.borrow_mut().worker_mut(id) {
+            if worker.state == WorkerState::New {
+                worker.state = WorkerState::Running;
+            }
+        }
+    }
+
+    /// Terminates a background worker, and closes all of its channels.
+    ///
+    /// Returns `false` if the worker is the primordial one or was already terminated.
+    pub fn terminate_worker(&mut self, id: WorkerId) -> bool {
+        let mut group = self.group.borrow_mut();
+        match group.worker_mut(id) {
+            Some(worker) if id != PRIMORDIAL_WORKER && worker.state != WorkerState::Terminated => {
+                worker.state = WorkerState::Terminated;
+                worker.movie = None;
+                worker.shared_properties.clear();
+            }
+            _ => return false,
+        }
+
+        for channel in &mut group.channels {
+            if channel.sender == id || channel.receiver == id {
+                channel.state = ChannelState::Closed;
+                channel.messages.clear();
+                channel.unannounced_messages = 0;
+            }
+        }
+        for byte_array in group.byte_arrays.values_mut() {
+            byte_array.holders.remove(&id);
+        }
+        group.release_byte_arrays();
+        true
+    }
+
+    pub fn shared_property(&self, id: WorkerId, key: &str) -> Option<SharedValue> {
+        let group = self.group.borrow();
+        group.worker(id)?.shared_properties.get(key).cloned()
+    }
+
+    pub fn set_shared_property(&mut self, id: WorkerId, key: String, value: SharedValue) {
+        if let Some(worker) = self.group.borrow_mut().worker_mut(id) {
+            if worker.state != WorkerState::Terminated {
+                worker.shared_properties.insert(key, value);
+            }
+        }
+    }
+
+    /// Creates a channel that sends messages from one worker to another.
+    pub fn create_channel(&mut self, sender: WorkerId, receiver: WorkerId) -> ChannelId {
+        let mut group = self.group.borrow_mut();
+        let id = group.channels.len() as ChannelId;
+        group.channels.push(Channel {
+            sender,
+            receiver,
+            state: ChannelState::Open,
+            messages: VecDeque::new(),
+            unannounced_messages: 0,
+        });
+        self.known_channel_states.insert(id, ChannelState::Open);
+        id
+    }
+
+    /// Returns the state of a channel, and remembers that this worker knows
+    /// about it, so that it is told when it changes.
+    pub fn channel_state(&mut self, id: ChannelId) -> Option<ChannelState> {
+        let state = self.group.borrow().channel(id)?.state;
+        self.known_channel_states.entry(id).or_insert(state);
+        Some(state)
+    }
+
+    /// Queues a message on an open channel.
+    ///
+    /// Returns `false` if the channel isn't open.
+    pub fn send_message(&mut self, id: ChannelId, message: SharedValue) -> bool {
+        match self.group.borrow_mut().channel_mut(id) {
+            Some(channel) if channel.state == ChannelState::Open => {
+                channel.messages.push_back(message);
+                channel.unannounced_messages += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Takes the next message of a channel that this worker receives from.
+    pub fn receive_message(&mut self, id: ChannelId) -> Option<SharedValue> {
+        let mut group = self.group.borrow_mut();
+        let channel = group.channel_mut(id)?;
+        if channel.receiver != self.current {
+            return None;
+        }
+        let message = channel.messages.pop_front();
+        if channel.messages.is_empty() && channel.state == ChannelState::Closing {
+            channel.state = ChannelState::Closed;
+        }
+        message
+    }
+
+    /// Whether a channel has messages for this worker.
+    pub fn message_available(&self, id: ChannelId) -> bool {
+        let group = self.group.borrow();
+        group
+            .channel(id)
+            .is_some_and(|channel| channel.receiver == self.current && !channel.messages.is_empty())
+    }
+
+    /// Stops a channel from accepting messages. It is closed once the
+    /// messages that were already sent are received.
+    pub fn close_channel(&mut self, id: ChannelId) {
+        if let Some(channel) = self.group.borrow_mut().channel_mut(id) {
+            if channel.state == ChannelState::Open {
+                channel.state = if channel.messages.is_empty() {
+                    ChannelState::Closed
+                } else {
+                    ChannelState::Closing
+                };
+            }
+        }
+    }
+
+    pub fn create_mutex(&mut self) -> u32 {
+        let mut group = self.group.borrow_mut();
+        group.mutexes.push(MutexEntry::default());
+        group.mutexes.len() as u32 - 1
+    }
+
+    /// Locks a mutex, unless it is owned by another worker.
+    pub fn try_lock_mutex(&mut self, id: u32) -> bool {
+        let mut group = self.group.borrow_mut();
+        let Some(mutex) = group.mutexes.get_mut(id as usize) else {
+            return false;
+        };
+        match mutex.owner {
+            Some(owner) if owner != self.current => false,
+            _ => {
+                mutex.owner = Some(self.current);
+                mutex.lock_count += 1;
+                true
+            }
+        }
+    }
+
+    /// Unlocks a mutex that is owned by this worker.
+    ///
+    /// Returns `false` if this worker doesn't own the mutex.
+    pub fn unlock_mutex(&mut self, id: u32) -> bool {
+        let mut group = self.group.borrow_mut();
+        let Some(mutex) = group.mutexes.get_mut(id as usize) else {
+            return false;
+        };
+        if mutex.owner != Some(self.current) {
+            return false;
+        }
+        mutex.lock_count -= 1;
+        if mutex.lock_count == 0 {
+            mutex.owner = None;
+        }
+        true
+    }
+
+    /// Whether this worker owns a mutex.
+    pub fn owns_mutex(&self, id: u32) -> bool {
+        let group = self.group.borrow();
+        group
+            .mutexes
+            .get(id as usize)
+            .is_some_and(|mutex| mutex.owner == Some(self.current))
+    }
+
+    pub fn create_condition(&mut self, mutex: u32) -> u32 {
+        let mut group = self.group.borrow_mut();
+        group.conditions.push(ConditionEntry {
+            mutex,
+            notified: false,
+        });
+        group.conditions.len() as u32 - 1
+    }
+
+    /// The mutex that a condition belongs to.
+    pub fn condition_mutex(&self, id: u32) -> Option<u32> {
+        let group = self.group.borrow();
+        group.conditions.get(id as usize).map(|c| c.mutex)
+    }
+
+    pub fn notify_condition(&mut self, id: u32) {
+        if let Some(condition) = self.group.borrow_mut().conditions.get_mut(id as usize) {
+            condition.notified = true;
+        }
+    }
+
+    /// Takes the notification of a condition, if it was notified since the last wait.
+    pub fn take_condition_notification(&mut self, id: u32) -> bool {
+        self.group
+            .borrow_mut()
+            .conditions
+            .get_mut(id as usize)
+            .is_some_and(|condition| std::mem::take(&mut condition.notified))
+    }
+
+    /// Returns the ID of a shareable `ByteArray` of this worker, sharing it if needed.
+    pub fn share_byte_array<'gc>(
+        context: &mut UpdateContext<'_, 'gc>,
+        object: Avm2Object<'gc>,
+    ) -> u32 {
+        for (id, root) in &context.workers.byte_arrays {
+            let shared = context.dynamic_root.fetch(root).upgrade(context.gc_context);
+            if shared.is_some_and(|shared| Avm2Object::ptr_eq(shared, object)) {
+                return *id;
+            }
+        }
+
+        let bytes = object
+            .as_bytearray()
+            .map(|bytearray| bytearray.bytes().to_vec())
+            .unwrap_or_default();
+        let mut group = context.workers.group.borrow_mut();
+        let id = group.next_byte_array_id;
+        group.next_byte_array_id += 1;
+        group.byte_arrays.insert(
+            id,
+            SharedByteArray {
+                bytes,
+                holders: BTreeSet::from([context.workers.current]),
+            },
+        );
+        drop(group);
+
+        let root = context
+            .dynamic_root
+            .stash(context.gc_context, object.downgrade());
+        context.workers.byte_arrays.push((id, root));
+        id
+    }
+
+    /// Returns the object of a shared `ByteArray` in this worker, if it has one yet.
+    pub fn shared_byte_array<'gc>(
+        context: &mut UpdateContext<'_, 'gc>,
+        id: u32,
+    ) -> Option<Avm2Object<'gc>> {
+        context
+            .workers
+            .byte_arrays
+            .iter()
+            .find(|(shared_id, _)| *shared_id == id)
+            .and_then(|(_, root)| context.dynamic_root.fetch(root).upgrade(context.gc_context))
+    }
+
+    /// The current contents of a shared `ByteArray`.
+    pub fn shared_byte_array_bytes(&self, id: u32) -> Vec<u8> {
+        let group = self.group.borrow();
+        group
+            .byte_arrays
+            .get(&id)
+            .map(|byte_array| byte_array.bytes.clone())
+            .unwrap_or_default()
+    }
+
+    /// Sets the object that represents a shared `ByteArray` in this worker.
+    pub fn register_shared_byte_array<'gc>(
+        context: &mut UpdateContext<'_, 'gc>,
+        id: u32,
+        object: Avm2Object<'gc>,
+    ) {
+        let current = context.workers.current;
+        if let Some(byte_array) = context.workers.group.borrow_mut().byte_arrays.get_mut(&id) {
+            byte_array.holders.insert(current);
+        }
+        let root = context
+            .dynamic_root
+            .stash(context.gc_context, object.downgrade());
+        context.workers.byte_arrays.push((id, root));
+    }
+
+    /// Copies the shared `ByteArray`s of this worker into the group after it
+    /// ran, and forgets those that it doesn't use any more.
+    pub fn store_shared_state(context: &mut UpdateContext<'_, '_>) {
+        let current = context.workers.current;
+        let mut group = context.workers.group.borrow_mut();
+        context.workers.byte_arrays.retain(|(id, root)| {
+            let Some(shared) = group.byte_arrays.get_mut(id) else {
+                return false;
+            };
+            match context.dynamic_root.fetch(root).upgrade(context.gc_context) {
+                Some(object) => {
+                    if let Some(bytearray) = object.as_bytearray() {
+                        shared.bytes.clear();
+                        shared.bytes.extend_from_slice(bytearray.bytes());
+                    }
+                    true
+                }
+                None => {
+                    shared.holders.remove(&current);
+                    false
+                }
+            }
+        });
+        group.release_byte_arrays();
+    }
+
+    /// Copies the shared `ByteArray`s of the group into this worker before it
+    /// runs, and returns the events that it has to be told about.
+    pub fn load_shared_state(context: &mut UpdateContext<'_, '_>) -> Vec<WorkerEvent> {
+        let group = context.workers.group.borrow();
+        for (id, root) in &context.workers.byte_arrays {
+            let (Some(object), Some(shared)) = (
+                context.dynamic_root.fetch(root).upgrade(context.gc_context),
+                group.byte_arrays.get(id),
+            ) else {
+                continue;
+            };
+            if let Some(mut bytearray) = object.as_bytearray_mut(context.gc_context) {
+                bytearray.set_bytes(shared.bytes.clone());
+            }
+        }
+        drop(group);
+
+        context.workers.take_events()
+    }
+
+    fn take_events(&mut self) -> Vec<WorkerEvent> {
+        let mut group = self.group.borrow_mut();
+        let mut events = Vec::new();
+
+        for (&id, known_state) in &mut self.known_worker_states {
+            let state = group.workers[id as usize].state;
+            if *known_state != state {
+                *known_state = state;
+                events.push(WorkerEvent::WorkerState(id));
+            }
+        }
+
+        for (id, channel) in group.channels.iter_mut().enumerate() {
+            let id = id as ChannelId;
+            if channel.receiver == self.current {
+                for _ in 0..std::mem::take(&mut channel.unannounced_messages) {
+                    events.push(WorkerEvent::ChannelMessage(id));
+                }
+            }
+            if let Some(known_state) = self.known_channel_states.get_mut(&id) {
+                if *known_state != channel.state {
+                    *known_state = channel.state;
+                    events.push(WorkerEvent::ChannelState(id));
+                }
+            }
+        }
+
+        events
+    }
+
+    /// Creates the players of the workers that were started, drops those of
+    /// the workers that were terminated, and returns the players to run.
+    pub fn update_players(&mut self) -> Vec<Arc<Mutex<Player>>> {
+        let mut group = self.group.borrow_mut();
+        self.players
+            .retain(|(id, _)| group.workers[*id as usize].state != WorkerState::Terminated);
+
+        let started: Vec<(WorkerId, SwfMovie)> = group
+            .workers
+            .iter_mut()
+            .enumerate()
+            .filter(|(_, worker)| worker.state == WorkerState::Running)
+            .filter_map(|(id, worker)| Some((id as WorkerId, worker.movie.take()?)))
+            .collect();
+        let player_version = group.player_version;
+        let player_runtime = group.player_runtime;
+        drop(group);
+
+        for (id, movie) in started {
+            let player = PlayerBuilder::new()
+                .with_movie(movie)
+                .with_log(WorkerLogBackend(self.group.clone()))
+                .with_autoplay(true)
+                .with_load_behavior(LoadBehavior::Blocking)
+                .with_player_version(Some(player_version))
+                .with_player_runtime(player_runtime)
+                .with_workers(Workers::new(self.group.clone(), id))
+                .build();
+            self.players.push((id, player));
+        }
+
+        self.players
+            .iter()
+            .map(|(_, player)| player.clone())
+            .collect()
+    }
+
+    /// Takes the messages traced by background workers since the last call.
+    pub fn take_traces(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.group.borrow_mut().traces)
+    }
+}
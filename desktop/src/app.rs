@@ -3,8 +3,8 @@ use crate::gui::{GuiController, MENU_HEIGHT};
 use crate::player::{LaunchOptions, PlayerController};
 use crate::preferences::GlobalPreferences;
 use crate::util::{
-    get_screen_size, gilrs_button_to_gamepad_button, parse_url, pick_file, plot_stats_in_tracy,
-    winit_to_ruffle_key_code, winit_to_ruffle_text_control,
+    get_screen_size, gilrs_axis_to_gamepad_axis, gilrs_button_to_gamepad_button, parse_url,
+    pick_file, plot_stats_in_tracy, winit_to_ruffle_key_code, winit_to_ruffle_text_control,
 };
 use anyhow::{Context, Error};
 use gilrs::{Event, EventType, Gilrs};
@@ -15,7 +15,7 @@ use std::rc::Rc;
 use std::time::{Duration, Instant};
 use url::Url;
 use winit::dpi::{LogicalSize, PhysicalPosition, PhysicalSize, Size};
-use winit::event::{ElementState, KeyEvent, Modifiers, WindowEvent};
+use winit::event::{ElementState, Ime, KeyEvent, Modifiers, WindowEvent};
 use winit::event_loop::{ControlFlow, EventLoop, EventLoopBuilder};
 use winit::keyboard::{Key, NamedKey};
 use winit::window::{Fullscreen, Icon, Window, WindowBuilder};
@@ -119,6 +119,7 @@ impl App {
         let mut minimized = false;
         let mut modifiers = Modifiers::default();
         let mut fullscreen_down = false;
+        let mut ime_composing = false;
 
         if self.initial_movie_url.is_none() {
             // No SWF provided on command line; show window with dummy movie immediately.
@@ -279,6 +280,20 @@ impl App {
                             self.player.handle_event(event);
                             check_redraw = true;
                         }
+                        WindowEvent::Touch(touch) => {
+                            use winit::event::TouchPhase;
+                            let id = touch.id;
+                            let x = touch.location.x;
+                            let y = touch.location.y - height_offset;
+                            let event = match touch.phase {
+                                TouchPhase::Started => PlayerEvent::TouchBegin { id, x, y },
+                                TouchPhase::Moved => PlayerEvent::TouchMove { id, x, y },
+                                TouchPhase::Ended => PlayerEvent::TouchEnd { id, x, y },
+                                TouchPhase::Cancelled => PlayerEvent::TouchCancel { id },
+                            };
+                            self.player.handle_event(event);
+                            check_redraw = true;
+                        }
                         WindowEvent::CursorEntered { .. } => {
                             if let Some(mut player) = self.player.get() {
                                 player.set_mouse_in_stage(true);
@@ -370,6 +385,39 @@ impl App {
                             };
                             check_redraw = true;
                         }
+                        WindowEvent::Ime(ime) => {
+                            use ruffle_core::events::ImeEvent;
+                            match ime {
+                                Ime::Preedit(text, cursor) => {
+                                    // winit has no event for the start of a composition,
+                                    // it's implied by the first non-empty pre-edit text.
+                                    if ime_composing || !text.is_empty() {
+                                        if !ime_composing {
+                                            self.player
+                                                .handle_event(PlayerEvent::Ime(ImeEvent::Start));
+                                        }
+                                        ime_composing = !text.is_empty();
+                                        self.player.handle_event(PlayerEvent::Ime(
+                                            ImeEvent::Update(text, cursor),
+                                        ));
+                                    }
+                                }
+                                Ime::Commit(text) => {
+                                    ime_composing = false;
+                                    self.player
+                                        .handle_event(PlayerEvent::Ime(ImeEvent::Commit(text)));
+                                }
+                                Ime::Disabled if ime_composing => {
+                                    ime_composing = false;
+                                    self.player.handle_event(PlayerEvent::Ime(ImeEvent::Update(
+                                        String::new(),
+                                        None,
+                                    )));
+                                }
+                                Ime::Enabled | Ime::Disabled => {}
+                            }
+                            check_redraw = true;
+                        }
                         _ => (),
                     }
                 }
@@ -456,6 +504,16 @@ impl App {
                             scale_factor: viewport_scale_factor,
                         });
                     }
+
+                    // Gamepads that are already connected aren't reported by gilrs as events.
+                    if let Some(gilrs) = &gilrs {
+                        for (id, gamepad) in gilrs.gamepads() {
+                            self.player.handle_event(PlayerEvent::GamepadConnected {
+                                device: id.into(),
+                                name: gamepad.name().to_string(),
+                            });
+                        }
+                    }
                 }
 
                 winit::event::Event::UserEvent(RuffleEvent::ContextMenuItemClicked(index)) => {
@@ -493,23 +551,49 @@ impl App {
                 _ => (),
             }
 
-            if let Some(Event { event, .. }) = gilrs.as_mut().and_then(|gilrs| gilrs.next_event()) {
-                match event {
-                    EventType::ButtonPressed(button, _) => {
-                        if let Some(button) = gilrs_button_to_gamepad_button(button) {
+            if let Some(gilrs) = &mut gilrs {
+                if let Some(Event { id, event, .. }) = gilrs.next_event() {
+                    let device = id.into();
+                    match event {
+                        EventType::Connected => {
+                            let name = gilrs.gamepad(id).name().to_string();
                             self.player
-                                .handle_event(PlayerEvent::GamepadButtonDown { button });
+                                .handle_event(PlayerEvent::GamepadConnected { device, name });
                             check_redraw = true;
                         }
-                    }
-                    EventType::ButtonReleased(button, _) => {
-                        if let Some(button) = gilrs_button_to_gamepad_button(button) {
+                        EventType::Disconnected => {
                             self.player
-                                .handle_event(PlayerEvent::GamepadButtonUp { button });
+                                .handle_event(PlayerEvent::GamepadDisconnected { device });
                             check_redraw = true;
                         }
+                        EventType::ButtonPressed(button, _) => {
+                            if let Some(button) = gilrs_button_to_gamepad_button(button) {
+                                self.player.handle_event(PlayerEvent::GamepadButtonDown {
+                                    device,
+                                    button,
+                                });
+                                check_redraw = true;
+                            }
+                        }
+                        EventType::ButtonReleased(button, _) => {
+                            if let Some(button) = gilrs_button_to_gamepad_button(button) {
+                                self.player
+                                    .handle_event(PlayerEvent::GamepadButtonUp { device, button });
+                                check_redraw = true;
+                            }
+                        }
+                        EventType::AxisChanged(axis, value, _) => {
+                            if let Some((axis, value)) = gilrs_axis_to_gamepad_axis(axis, value) {
+                                self.player.handle_event(PlayerEvent::GamepadAxisChanged {
+                                    device,
+                                    axis,
+                                    value,
+                                });
+                                check_redraw = true;
+                            }
+                        }
+                        _ => {}
                     }
-                    _ => {}
                 }
             }
 
@@ -1,11 +1,13 @@
 mod audio;
 mod external_interface;
+mod filesystem;
 mod fscommand;
 mod navigator;
 mod ui;
 
 pub use audio::CpalAudioBackend;
 pub use external_interface::DesktopExternalInterfaceProvider;
+pub use filesystem::DiskFilesystemBackend;
 pub use fscommand::DesktopFSCommandProvider;
 pub use navigator::RfdNavigatorInterface;
 pub use ui::DesktopUiBackend;
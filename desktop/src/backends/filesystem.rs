@@ -0,0 +1,155 @@
+use chrono::{DateTime, Utc};
+use ruffle_core::backend::filesystem::{FileMetadata, FilesystemBackend, FilesystemError};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+
+/// A filesystem backend that stores the files of an AIR application
+/// in a directory of its own.
+pub struct DiskFilesystemBackend {
+    root: PathBuf,
+}
+
+impl DiskFilesystemBackend {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    /// Converts a virtual path to a path inside of the root directory.
+    ///
+    /// Every component has to be a plain name, so that the path can't
+    /// escape the root or refer to another drive.
+    fn real_path(&self, path: &str) -> Result<PathBuf, FilesystemError> {
+        let mut real_path = self.root.clone();
+        for component in path.split('/').filter(|c| !c.is_empty()) {
+            let mut components = Path::new(component).components();
+            match (components.next(), components.next()) {
+                (Some(Component::Normal(name)), None) => real_path.push(name),
+                _ => return Err(FilesystemError::InvalidPath),
+            }
+        }
+        Ok(real_path)
+    }
+}
+
+fn filesystem_error(error: std::io::Error) -> FilesystemError {
+    match error.kind() {
+        ErrorKind::NotFound => FilesystemError::NotFound,
+        ErrorKind::AlreadyExists => FilesystemError::AlreadyExists,
+        _ => FilesystemError::Io(error.to_string()),
+    }
+}
+
+impl FilesystemBackend for DiskFilesystemBackend {
+    fn metadata(&self, path: &str) -> Option<FileMetadata> {
+        let real_path = self.real_path(path).ok()?;
+        if path == "/" {
+            // The root always exists, even if nothing was written yet.
+            return Some(FileMetadata {
+                is_directory: true,
+                size: 0,
+                creation_time: None,
+                modification_time: None,
+            });
+        }
+        let metadata = fs::metadata(real_path).ok()?;
+        Some(FileMetadata {
+            is_directory: metadata.is_dir(),
+            size: metadata.len(),
+            creation_time: metadata.created().ok().map(DateTime::<Utc>::from),
+            modification_time: metadata.modified().ok().map(DateTime::<Utc>::from),
+        })
+    }
+
+    fn read(&self, path: &str) -> Result<Vec<u8>, FilesystemError> {
+        let real_path = self.real_path(path)?;
+        if real_path.is_dir() {
+            return Err(FilesystemError::IsADirectory);
+        }
+        fs::read(real_path).map_err(filesystem_error)
+    }
+
+    fn write(&mut self, path: &str, data: &[u8]) -> Result<(), FilesystemError> {
+        let real_path = self.real_path(path)?;
+        if real_path.is_dir() {
+            return Err(FilesystemError::IsADirectory);
+        }
+        if let Some(parent) = real_path.parent() {
+            fs::create_dir_all(parent).map_err(filesystem_error)?;
+        }
+        fs::write(real_path, data).map_err(filesystem_error)
+    }
+
+    fn create_directory(&mut self, path: &str) -> Result<(), FilesystemError> {
+        let real_path = self.real_path(path)?;
+        if real_path.is_file() {
+            return Err(FilesystemError::AlreadyExists);
+        }
+        fs::create_dir_all(real_path).map_err(filesystem_error)
+    }
+
+    fn read_directory(&self, path: &str) -> Result<Vec<String>, FilesystemError> {
+        let real_path = self.real_path(path)?;
+        if path == "/" && !real_path.exists() {
+            return Ok(Vec::new());
+        }
+        if real_path.is_file() {
+            return Err(FilesystemError::NotADirectory);
+        }
+        let mut names = Vec::new();
+        for entry in fs::read_dir(real_path).map_err(filesystem_error)? {
+            let entry = entry.map_err(filesystem_error)?;
+            names.push(entry.file_name().to_string_lossy().into_owned());
+        }
+        names.sort();
+        Ok(names)
+    }
+
+    fn delete_file(&mut self, path: &str) -> Result<(), FilesystemError> {
+        let real_path = self.real_path(path)?;
+        if real_path.is_dir() {
+            return Err(FilesystemError::IsADirectory);
+        }
+        fs::remove_file(real_path).map_err(filesystem_error)
+    }
+
+    fn delete_directory(&mut self, path: &str, recursive: bool) -> Result<(), FilesystemError> {
+        if path == "/" {
+            return Err(FilesystemError::InvalidPath);
+        }
+        let real_path = self.real_path(path)?;
+        if real_path.is_file() {
+            return Err(FilesystemError::NotADirectory);
+        }
+        if recursive {
+            fs::remove_dir_all(real_path).map_err(filesystem_error)
+        } else {
+            let is_empty = fs::read_dir(&real_path)
+                .map_err(filesystem_error)?
+                .next()
+                .is_none();
+            if !is_empty {
+                return Err(FilesystemError::DirectoryNotEmpty);
+            }
+            fs::remove_dir(real_path).map_err(filesystem_error)
+        }
+    }
+
+    fn rename(&mut self, from: &str, to: &str) -> Result<(), FilesystemError> {
+        if from == "/" || to == "/" || to.starts_with(&format!("{from}/")) {
+            return Err(FilesystemError::InvalidPath);
+        }
+        let real_from = self.real_path(from)?;
+        let real_to = self.real_path(to)?;
+        if !real_from.exists() {
+            return Err(FilesystemError::NotFound);
+        }
+        if real_to.is_dir() {
+            return Err(FilesystemError::AlreadyExists);
+        }
+        if let Some(parent) = real_to.parent() {
+            fs::create_dir_all(parent).map_err(filesystem_error)?;
+        }
+        fs::rename(real_from, real_to).map_err(filesystem_error)
+    }
+}
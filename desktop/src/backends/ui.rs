@@ -8,9 +8,12 @@ use rfd::{
 };
 use ruffle_core::backend::navigator::OpenURLMode;
 use ruffle_core::backend::ui::{
-    DialogLoaderError, DialogResultFuture, FileDialogResult, FileFilter, FontDefinition,
-    FullscreenError, LanguageIdentifier, MouseCursor, UiBackend,
+    ClipboardData, ClipboardFormat, DialogLoaderError, DialogResultFuture, FileDialogResult,
+    FileFilter, FontDefinition, FullscreenError, ImeConversionMode, LanguageIdentifier,
+    MouseCursor, UiBackend,
 };
+use ruffle_render::bitmap::{Bitmap, BitmapFormat};
+use std::borrow::Cow;
 use std::rc::Rc;
 use tracing::error;
 use url::Url;
@@ -115,12 +118,23 @@ pub struct DesktopUiBackend {
     window: Rc<Window>,
     cursor_visible: bool,
     clipboard: Clipboard,
+    /// Used for the formats that `clipboard` can't handle, which are HTML and images.
+    /// This is `None` if the system clipboard couldn't be opened.
+    rich_clipboard: Option<arboard::Clipboard>,
+    /// The data that was put on the clipboard by the movie.
+    ///
+    /// The system clipboard can't give back HTML, RTF or URLs, so those are read from here
+    /// for as long as the system clipboard still holds the text from `clipboard_text`.
+    clipboard_items: Vec<ClipboardData>,
+    clipboard_text: String,
     preferences: GlobalPreferences,
     preferred_cursor: MouseCursor,
     open_url_mode: OpenURLMode,
     font_database: Rc<fontdb::Database>,
     /// Is a dialog currently open
     dialog_open: bool,
+    ime_enabled: bool,
+    ime_conversion_mode: ImeConversionMode,
 }
 
 impl DesktopUiBackend {
@@ -139,18 +153,85 @@ impl DesktopUiBackend {
                 .ok()
                 .map(|handle| handle.as_raw()),
         );
+        let rich_clipboard = arboard::Clipboard::new()
+            .inspect_err(|e| tracing::warn!("Couldn't open the system clipboard: {e}"))
+            .ok();
+        window.set_ime_allowed(true);
         Ok(Self {
             window,
             cursor_visible: true,
             clipboard,
+            rich_clipboard,
+            clipboard_items: Vec::new(),
+            clipboard_text: String::new(),
             preferences,
             preferred_cursor: MouseCursor::Arrow,
             open_url_mode,
             dialog_open: false,
             font_database,
+            ime_enabled: true,
+            ime_conversion_mode: ImeConversionMode::default(),
         })
     }
 
+    /// Forgets the data put on the clipboard by the movie if another
+    /// application has since replaced the text on the system clipboard.
+    fn update_clipboard_items(&mut self) {
+        let text = self.clipboard.get().unwrap_or_default();
+        if text != self.clipboard_text {
+            self.clipboard_items.clear();
+            if !text.is_empty() {
+                self.clipboard_items.push(ClipboardData::Text(text.clone()));
+            }
+            self.clipboard_text = text;
+        }
+    }
+
+    /// Puts the data from `clipboard_items` on the system clipboard.
+    ///
+    /// The system clipboard only takes one kind of data at a time, so an image
+    /// is only shared if the movie didn't put any text or HTML there.
+    fn write_clipboard_items(&mut self) {
+        let (mut text, mut html, mut bitmap) = (None, None, None);
+        for data in &self.clipboard_items {
+            match data {
+                ClipboardData::Text(data) => text = Some(data.as_str()),
+                ClipboardData::Html(data) => html = Some(data.as_str()),
+                ClipboardData::Bitmap(data) => bitmap = Some(data),
+                ClipboardData::RichText(_) | ClipboardData::Url(_) => {}
+            }
+        }
+
+        let result = match (self.rich_clipboard.as_mut(), html, bitmap) {
+            (Some(rich_clipboard), Some(html), _) => rich_clipboard.set().html(html, text),
+            (Some(rich_clipboard), None, Some(bitmap)) if text.is_none() => rich_clipboard
+                .set_image(arboard::ImageData {
+                    width: bitmap.width() as usize,
+                    height: bitmap.height() as usize,
+                    bytes: Cow::Owned(unmultiply_alpha(bitmap.data())),
+                }),
+            _ => {
+                self.clipboard.set(text.unwrap_or_default().to_owned());
+                Ok(())
+            }
+        };
+        if let Err(e) = result {
+            tracing::warn!("Couldn't write to the system clipboard: {e}");
+        }
+        self.clipboard_text = text.unwrap_or_default().to_owned();
+    }
+
+    /// Reads the image on the system clipboard, if there is one.
+    fn clipboard_image(&mut self) -> Option<Bitmap> {
+        let image = self.rich_clipboard.as_mut()?.get_image().ok()?;
+        Some(Bitmap::new(
+            image.width as u32,
+            image.height as u32,
+            BitmapFormat::Rgba,
+            premultiply_alpha(&image.bytes),
+        ))
+    }
+
     pub fn cursor(&self) -> egui::CursorIcon {
         if self.cursor_visible {
             match self.preferred_cursor {
@@ -165,6 +246,41 @@ impl DesktopUiBackend {
     }
 }
 
+/// Converts the premultiplied RGBA of a `Bitmap` to the straight RGBA used by the system clipboard.
+fn unmultiply_alpha(rgba: &[u8]) -> Vec<u8> {
+    rgba.chunks_exact(4)
+        .flat_map(|pixel| {
+            let a = u32::from(pixel[3]);
+            let unmultiply = |c: u8| match a {
+                0 => 0,
+                _ => ((u32::from(c) * 255 + a / 2) / a).min(255) as u8,
+            };
+            [
+                unmultiply(pixel[0]),
+                unmultiply(pixel[1]),
+                unmultiply(pixel[2]),
+                pixel[3],
+            ]
+        })
+        .collect()
+}
+
+/// Converts straight RGBA from the system clipboard to the premultiplied RGBA of a `Bitmap`.
+fn premultiply_alpha(rgba: &[u8]) -> Vec<u8> {
+    rgba.chunks_exact(4)
+        .flat_map(|pixel| {
+            let a = u32::from(pixel[3]);
+            let premultiply = |c: u8| ((u32::from(c) * a + 127) / 255) as u8;
+            [
+                premultiply(pixel[0]),
+                premultiply(pixel[1]),
+                premultiply(pixel[2]),
+                pixel[3],
+            ]
+        })
+        .collect()
+}
+
 const DOWNLOAD_FAILED_MESSAGE: &str = "Ruffle failed to open or download this file.";
 
 impl UiBackend for DesktopUiBackend {
@@ -185,7 +301,50 @@ impl UiBackend for DesktopUiBackend {
     }
 
     fn set_clipboard_content(&mut self, content: String) {
-        self.clipboard.set(content);
+        self.clipboard_items = vec![ClipboardData::Text(content)];
+        self.write_clipboard_items();
+    }
+
+    fn clipboard_formats(&mut self) -> Vec<ClipboardFormat> {
+        self.update_clipboard_items();
+        let mut formats: Vec<_> = self
+            .clipboard_items
+            .iter()
+            .map(ClipboardData::format)
+            .filter(|format| *format != ClipboardFormat::Bitmap)
+            .collect();
+        if self.clipboard_image().is_some() {
+            formats.push(ClipboardFormat::Bitmap);
+        }
+        formats
+    }
+
+    fn clipboard_data(&mut self, format: ClipboardFormat) -> Option<ClipboardData> {
+        self.update_clipboard_items();
+        match format {
+            ClipboardFormat::Text => (!self.clipboard_text.is_empty())
+                .then(|| ClipboardData::Text(self.clipboard_text.clone())),
+            ClipboardFormat::Bitmap => self.clipboard_image().map(ClipboardData::Bitmap),
+            _ => self
+                .clipboard_items
+                .iter()
+                .find(|data| data.format() == format)
+                .cloned(),
+        }
+    }
+
+    fn set_clipboard_data(&mut self, data: ClipboardData) {
+        self.update_clipboard_items();
+        self.clipboard_items.retain(|d| d.format() != data.format());
+        self.clipboard_items.push(data);
+        self.write_clipboard_items();
+    }
+
+    fn clear_clipboard_data(&mut self, format: Option<ClipboardFormat>) {
+        self.update_clipboard_items();
+        self.clipboard_items
+            .retain(|data| format.is_some_and(|format| data.format() != format));
+        self.write_clipboard_items();
     }
 
     fn set_fullscreen(&mut self, is_full: bool) -> Result<(), FullscreenError> {
@@ -375,4 +534,48 @@ impl UiBackend for DesktopUiBackend {
     fn close_file_dialog(&mut self) {
         self.dialog_open = false;
     }
+
+    fn ime_enabled(&self) -> bool {
+        self.ime_enabled
+    }
+
+    fn set_ime_enabled(&mut self, enabled: bool) {
+        self.ime_enabled = enabled;
+        self.window.set_ime_allowed(enabled);
+    }
+
+    fn ime_conversion_mode(&self) -> ImeConversionMode {
+        self.ime_conversion_mode
+    }
+
+    fn set_ime_conversion_mode(&mut self, mode: ImeConversionMode) {
+        // winit can't change the conversion mode of the system IME,
+        // so only remember what the movie asked for.
+        self.ime_conversion_mode = mode;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{premultiply_alpha, unmultiply_alpha};
+
+    #[test]
+    fn clipboard_alpha_round_trip() {
+        // Every premultiplied color of a few semi-transparent alphas survives a copy and paste.
+        for a in [1, 64, 128, 200, 254] {
+            let premultiplied: Vec<u8> = (0..=a).flat_map(|c| [c, a - c, c / 2, a]).collect();
+            let straight = unmultiply_alpha(&premultiplied);
+            assert_eq!(premultiply_alpha(&straight), premultiplied);
+        }
+    }
+
+    #[test]
+    fn clipboard_alpha_conversion() {
+        assert_eq!(unmultiply_alpha(&[64, 32, 0, 128]), [128, 64, 0, 128]);
+        assert_eq!(premultiply_alpha(&[255, 128, 0, 128]), [128, 64, 0, 128]);
+        // Opaque and fully transparent pixels.
+        assert_eq!(unmultiply_alpha(&[10, 20, 30, 255]), [10, 20, 30, 255]);
+        assert_eq!(unmultiply_alpha(&[0, 0, 0, 0]), [0, 0, 0, 0]);
+        assert_eq!(premultiply_alpha(&[10, 20, 30, 0]), [0, 0, 0, 0]);
+    }
 }
@@ -3,6 +3,7 @@ use crate::RUFFLE_VERSION;
 use anyhow::{anyhow, Error};
 use clap::{Parser, ValueEnum};
 use ruffle_core::backend::navigator::{OpenURLMode, SocketMode};
+use ruffle_core::backend::printing::PrintFileFormat;
 use ruffle_core::config::Letterbox;
 use ruffle_core::events::{GamepadButton, KeyCode};
 use ruffle_core::{LoadBehavior, PlayerRuntime, StageAlign, StageScaleMode};
@@ -19,6 +20,20 @@ fn get_default_save_directory() -> std::path::PathBuf {
         .join("SharedObjects")
 }
 
+fn get_default_filesystem_directory() -> std::path::PathBuf {
+    dirs::data_local_dir()
+        .expect("Couldn't find a valid data_local dir")
+        .join("ruffle")
+        .join("Filesystem")
+}
+
+fn get_default_print_directory() -> std::path::PathBuf {
+    dirs::data_local_dir()
+        .expect("Couldn't find a valid data_local dir")
+        .join("ruffle")
+        .join("Prints")
+}
+
 fn get_default_config_directory() -> std::path::PathBuf {
     dirs::config_local_dir()
         .expect("Couldn't find a valid config_local dir")
@@ -114,6 +129,21 @@ pub struct Opt {
     #[clap(long, default_value_os_t=get_default_save_directory())]
     pub save_directory: std::path::PathBuf,
 
+    /// Location to store the files of AIR applications.
+    ///
+    /// Every application gets a directory of its own inside of this one.
+    /// This option has no effect if `storage` is not `disk`.
+    #[clap(long, default_value_os_t=get_default_filesystem_directory())]
+    pub filesystem_directory: std::path::PathBuf,
+
+    /// Location to save the pages printed by movies.
+    #[clap(long, default_value_os_t=get_default_print_directory())]
+    pub print_directory: std::path::PathBuf,
+
+    /// The file format of printed pages.
+    #[clap(long, default_value = "pdf")]
+    pub print_format: PrintFileFormat,
+
     /// Location of a directory to store Ruffle configuration.
     #[clap(long, default_value_os_t=get_default_config_directory())]
     pub config: std::path::PathBuf,
@@ -157,6 +187,12 @@ pub struct Opt {
     #[clap(long)]
     pub player_runtime: Option<PlayerRuntime>,
 
+    /// Location of the application descriptor (`application.xml`) of an AIR application.
+    ///
+    /// Bundles can specify their descriptor in `ruffle-bundle.toml` instead.
+    #[clap(long)]
+    pub application_descriptor: Option<std::path::PathBuf>,
+
     /// Set and lock the player's frame rate, overriding the movie's frame rate.
     #[clap(long)]
     pub frame_rate: Option<f64>,
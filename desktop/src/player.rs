@@ -7,7 +7,9 @@ use crate::gui::MovieView;
 use crate::preferences::GlobalPreferences;
 use crate::{CALLSTACK, RENDER_INFO, SWF_INFO};
 use anyhow::anyhow;
+use ruffle_core::application_descriptor::ApplicationDescriptor;
 use ruffle_core::backend::navigator::{OpenURLMode, SocketMode};
+use ruffle_core::backend::printing::{FilePrintBackend, PrintFileFormat};
 use ruffle_core::config::Letterbox;
 use ruffle_core::events::{GamepadButton, KeyCode};
 use ruffle_core::{DefaultFont, LoadBehavior, Player, PlayerBuilder, PlayerEvent};
@@ -41,6 +43,10 @@ pub struct LaunchOptions {
     pub tcp_connections: Option<SocketMode>,
     pub fullscreen: bool,
     pub save_directory: PathBuf,
+    pub filesystem_directory: PathBuf,
+    pub print_directory: PathBuf,
+    pub print_format: PrintFileFormat,
+    pub application_descriptor: Option<PathBuf>,
     pub open_url_mode: OpenURLMode,
     pub gamepad_button_mapping: HashMap<GamepadButton, KeyCode>,
     pub avm2_optimizer_enabled: bool,
@@ -86,6 +92,10 @@ impl From<&GlobalPreferences> for LaunchOptions {
             proxy: value.cli.proxy.clone(),
             fullscreen: value.cli.fullscreen,
             save_directory: value.cli.save_directory.clone(),
+            filesystem_directory: value.cli.filesystem_directory.clone(),
+            print_directory: value.cli.print_directory.clone(),
+            print_format: value.cli.print_format,
+            application_descriptor: value.cli.application_descriptor.clone(),
             open_url_mode: value.cli.open_url_mode,
             socket_allowed: HashSet::from_iter(value.cli.socket_allow.iter().cloned()),
             tcp_connections: value.cli.tcp_connections,
@@ -164,6 +174,10 @@ impl ActivePlayer {
             }
         }
 
+        if let Some(descriptor) = load_application_descriptor(opt, &content) {
+            builder = builder.with_application_descriptor(descriptor);
+        }
+
         let (executor, future_spawner) = AsyncExecutor::new(WinitWaker(event_loop.clone()));
         let movie_url = content.initial_swf_url().clone();
         let readable_name = content.name();
@@ -206,6 +220,15 @@ impl ActivePlayer {
             .with_navigator(navigator)
             .with_renderer(renderer)
             .with_storage(preferences.storage_backend().create_backend(opt))
+            .with_filesystem(
+                preferences
+                    .storage_backend()
+                    .create_filesystem_backend(opt, &movie_url),
+            )
+            .with_print(Box::new(FilePrintBackend::new(
+                opt.print_directory.clone(),
+                opt.print_format,
+            )))
             .with_fs_commands(Box::new(DesktopFSCommandProvider {
                 event_loop: event_loop.clone(),
                 window: window.clone(),
@@ -320,6 +343,42 @@ impl ActivePlayer {
     }
 }
 
+/// Reads the AIR application descriptor, either from the bundle being played
+/// or from the file given on the command line.
+fn load_application_descriptor(
+    opt: &LaunchOptions,
+    content: &PlayingContent,
+) -> Option<ApplicationDescriptor> {
+    let bundle_descriptor = match content {
+        PlayingContent::Bundle(_, bundle) => bundle
+            .information()
+            .air_descriptor
+            .as_ref()
+            .map(|path| (bundle, path)),
+        PlayingContent::DirectFile(_) => None,
+    };
+    let data = if let Some((bundle, path)) = bundle_descriptor {
+        bundle.source().read_content(path).map_err(|e| {
+            tracing::error!("Couldn't read application descriptor {path:?} from bundle: {e}");
+        })
+    } else if let Some(path) = &opt.application_descriptor {
+        std::fs::read(path).map_err(|e| {
+            tracing::error!("Couldn't read application descriptor {path:?}: {e}");
+        })
+    } else {
+        return None;
+    }
+    .ok()?;
+
+    match ApplicationDescriptor::parse(String::from_utf8_lossy(&data).into_owned()) {
+        Ok(descriptor) => Some(descriptor),
+        Err(e) => {
+            tracing::error!("Invalid application descriptor: {e}");
+            None
+        }
+    }
+}
+
 /// Owner of a Ruffle Player (via ActivePlayer),
 /// responsible for either creating, destroying or communicating with that player.
 pub struct PlayerController {
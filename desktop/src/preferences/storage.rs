@@ -1,7 +1,11 @@
+use crate::backends::DiskFilesystemBackend;
 use crate::player::LaunchOptions;
+use ruffle_core::backend::filesystem::{FilesystemBackend, MemoryFilesystemBackend};
 use ruffle_core::backend::storage::MemoryStorageBackend;
 use ruffle_frontend_utils::backends::storage::DiskStorageBackend;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use url::Url;
 
 #[derive(clap::ValueEnum, Copy, Clone, PartialEq, Eq, Debug, Default)]
 pub enum StorageBackend {
@@ -39,4 +43,36 @@ impl StorageBackend {
             StorageBackend::Memory => Box::new(MemoryStorageBackend::new()),
         }
     }
+
+    pub fn create_filesystem_backend(
+        &self,
+        opt: &LaunchOptions,
+        movie_url: &Url,
+    ) -> Box<dyn FilesystemBackend> {
+        match self {
+            StorageBackend::Disk => Box::new(DiskFilesystemBackend::new(application_directory(
+                &opt.filesystem_directory,
+                movie_url,
+            ))),
+            StorageBackend::Memory => Box::new(MemoryFilesystemBackend::new()),
+        }
+    }
+}
+
+/// Returns the directory that holds the files of the movie at the given URL,
+/// laid out like the shared objects of the movie.
+fn application_directory(filesystem_directory: &Path, movie_url: &Url) -> PathBuf {
+    let host = match movie_url.host_str() {
+        Some(host) if !host.is_empty() => host,
+        _ => "localhost",
+    };
+    let mut path = filesystem_directory.join(host);
+    for segment in movie_url.path_segments().into_iter().flatten() {
+        if matches!(segment, "" | "." | "..") {
+            continue;
+        }
+        // Windows drive letters can't be used as directory names.
+        path.push(segment.replace(':', "_"));
+    }
+    path
 }
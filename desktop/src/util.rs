@@ -1,8 +1,8 @@
 use crate::custom_event::RuffleEvent;
 use anyhow::{anyhow, Error};
-use gilrs::Button;
+use gilrs::{Axis, Button};
 use rfd::FileDialog;
-use ruffle_core::events::{GamepadButton, KeyCode, TextControlCode};
+use ruffle_core::events::{GamepadAxis, GamepadButton, KeyCode, TextControlCode};
 use std::path::{Path, PathBuf};
 use url::Url;
 use winit::dpi::PhysicalSize;
@@ -195,6 +195,19 @@ pub fn gilrs_button_to_gamepad_button(button: Button) -> Option<GamepadButton> {
     }
 }
 
+/// Converts a gilrs axis and its value to a gamepad axis.
+/// The Y axes are flipped, as gilrs reports up as positive.
+pub fn gilrs_axis_to_gamepad_axis(axis: Axis, value: f32) -> Option<(GamepadAxis, f64)> {
+    let value = f64::from(value);
+    match axis {
+        Axis::LeftStickX => Some((GamepadAxis::LeftStickX, value)),
+        Axis::LeftStickY => Some((GamepadAxis::LeftStickY, -value)),
+        Axis::RightStickX => Some((GamepadAxis::RightStickX, value)),
+        Axis::RightStickY => Some((GamepadAxis::RightStickY, -value)),
+        _ => None,
+    }
+}
+
 pub fn get_screen_size(event_loop: &EventLoop<RuffleEvent>) -> PhysicalSize<u32> {
     let mut min_x = 0;
     let mut min_y = 0;
@@ -3,9 +3,12 @@ use clap::Parser;
 use image::RgbaImage;
 use indicatif::{ProgressBar, ProgressStyle};
 use rayon::prelude::*;
+use ruffle_core::backend::audio::OfflineAudioBackend;
+use ruffle_core::backend::printing::{FilePrintBackend, PrintFileFormat};
 use ruffle_core::limits::ExecutionLimit;
 use ruffle_core::tag_utils::SwfMovie;
 use ruffle_core::PlayerBuilder;
+use ruffle_render_software::backend::SoftwareRenderBackend;
 use ruffle_render_wgpu::backend::{request_adapter_and_device, WgpuRenderBackend};
 use ruffle_render_wgpu::clap::{GraphicsBackend, PowerPreference};
 use ruffle_render_wgpu::descriptors::Descriptors;
@@ -73,6 +76,11 @@ struct Opt {
     #[clap(long, short, default_value = "high")]
     power: PowerPreference,
 
+    /// Render on the CPU instead of using a graphics device.
+    /// This is slower, but works on machines without a GPU.
+    #[clap(long, action)]
+    software: bool,
+
     /// Location to store a wgpu trace output
     #[clap(long)]
     #[cfg(feature = "render_trace")]
@@ -81,18 +89,49 @@ struct Opt {
     /// Skip unsupported movie types (currently AVM 2)
     #[clap(long, action)]
     skip_unsupported: bool,
+
+    /// Also export the audio of each movie as a WAV file, named after its capture
+    #[clap(long, action)]
+    audio: bool,
+
+    /// Save the pages printed by movies in this directory. Without it, print jobs are cancelled.
+    #[clap(long)]
+    print_directory: Option<PathBuf>,
+
+    /// The file format of printed pages
+    #[clap(long, default_value = "pdf")]
+    print_format: PrintFileFormat,
+}
+
+/// The renderer used to capture frames.
+#[derive(Clone)]
+enum Renderer {
+    Wgpu(Arc<Descriptors>),
+    Software,
+}
+
+/// The frames captured from a movie, and the audio played during them.
+struct Capture {
+    frames: Vec<RgbaImage>,
+
+    /// The audio as a WAV file, if it was requested.
+    audio: Option<Vec<u8>>,
 }
 
 /// Captures a screenshot. The resulting image uses straight alpha
+#[allow(clippy::too_many_arguments)]
 fn take_screenshot(
-    descriptors: Arc<Descriptors>,
+    renderer: Renderer,
     swf_path: &Path,
     frames: u32,
     skipframes: u32,
     progress: &Option<ProgressBar>,
     size: SizeOpt,
     skip_unsupported: bool,
-) -> Result<Vec<RgbaImage>> {
+    audio: bool,
+    print_directory: Option<&Path>,
+    print_format: PrintFileFormat,
+) -> Result<Capture> {
     let movie = SwfMovie::from_path(swf_path, None).map_err(|e| anyhow!(e.to_string()))?;
 
     if movie.is_action_script_3() && skip_unsupported {
@@ -111,12 +150,32 @@ fn take_screenshot(
         .unwrap_or_else(|| movie.height().to_pixels());
     let height = (height * size.scale).round() as u32;
 
-    let target = TextureTarget::new(&descriptors.device, (width, height))
-        .map_err(|e| anyhow!(e.to_string()))?;
-    let player = PlayerBuilder::new()
-        .with_renderer(
-            WgpuRenderBackend::new(descriptors, target).map_err(|e| anyhow!(e.to_string()))?,
-        )
+    let builder = match renderer {
+        Renderer::Wgpu(descriptors) => {
+            let target = TextureTarget::new(&descriptors.device, (width, height))
+                .map_err(|e| anyhow!(e.to_string()))?;
+            PlayerBuilder::new().with_renderer(
+                WgpuRenderBackend::new(descriptors, target).map_err(|e| anyhow!(e.to_string()))?,
+            )
+        }
+        Renderer::Software => {
+            PlayerBuilder::new().with_renderer(SoftwareRenderBackend::new(width, height))
+        }
+    };
+    let builder = if audio {
+        builder.with_audio(OfflineAudioBackend::new())
+    } else {
+        builder
+    };
+    let builder = if let Some(print_directory) = print_directory {
+        builder.with_print(Box::new(FilePrintBackend::new(
+            print_directory.to_path_buf(),
+            print_format,
+        )))
+    } else {
+        builder
+    };
+    let player = builder
         .with_movie(movie)
         .with_viewport_dimensions(width, height, size.scale)
         .build();
@@ -136,15 +195,24 @@ fn take_screenshot(
         player.lock().unwrap().preload(&mut ExecutionLimit::none());
 
         player.lock().unwrap().run_frame();
+        if audio {
+            let mut player = player.lock().unwrap();
+            let frame_time = 1000.0 / player.frame_rate();
+            player.audio_mut().tick(frame_time);
+        }
         if i >= skipframes {
             let image = || {
                 player.lock().unwrap().render();
                 let mut player = player.lock().unwrap();
-                let renderer = player
-                    .renderer_mut()
-                    .downcast_mut::<WgpuRenderBackend<TextureTarget>>()
-                    .unwrap();
-                renderer.capture_frame()
+                let renderer = player.renderer_mut();
+                if let Some(renderer) = renderer.downcast_mut::<SoftwareRenderBackend>() {
+                    renderer.capture_frame()
+                } else {
+                    renderer
+                        .downcast_mut::<WgpuRenderBackend<TextureTarget>>()
+                        .unwrap()
+                        .capture_frame()
+                }
             };
             match catch_unwind(image) {
                 Ok(Some(image)) => result.push(image),
@@ -164,7 +232,24 @@ fn take_screenshot(
             progress.inc(1);
         }
     }
-    Ok(result)
+    let audio = if audio {
+        let mut wav = Vec::new();
+        player
+            .lock()
+            .unwrap()
+            .audio()
+            .downcast_ref::<OfflineAudioBackend>()
+            .unwrap()
+            .write_wav(&mut wav)?;
+        Some(wav)
+    } else {
+        None
+    };
+
+    Ok(Capture {
+        frames: result,
+        audio,
+    })
 }
 
 fn find_files(root: &Path, with_progress: bool) -> Vec<DirEntry> {
@@ -197,7 +282,7 @@ fn find_files(root: &Path, with_progress: bool) -> Vec<DirEntry> {
     results
 }
 
-fn capture_single_swf(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
+fn capture_single_swf(renderer: Renderer, opt: &Opt) -> Result<()> {
     let output = opt.output_path.clone().unwrap_or_else(|| {
         let mut result = PathBuf::new();
         result.set_file_name(opt.swf.file_stem().unwrap());
@@ -225,14 +310,17 @@ fn capture_single_swf(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
         None
     };
 
-    let frames = take_screenshot(
-        descriptors,
+    let Capture { frames, audio } = take_screenshot(
+        renderer,
         &opt.swf,
         opt.frames,
         opt.skipframes,
         &progress,
         opt.size,
         opt.skip_unsupported,
+        opt.audio,
+        opt.print_directory.as_deref(),
+        opt.print_format,
     )?;
 
     if let Some(progress) = &progress {
@@ -260,6 +348,18 @@ fn capture_single_swf(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
         }
     }
 
+    if let Some(audio) = audio {
+        let path = if frames.len() == 1 {
+            if opt.output_path == Some(PathBuf::from("-")) {
+                return Err(anyhow!("Can't write audio to stdout alongside an image"));
+            }
+            output.with_extension("wav")
+        } else {
+            output.join("audio.wav")
+        };
+        std::fs::write(path, audio)?;
+    }
+
     let message = if frames.len() == 1 {
         if !opt.silent {
             Some(format!(
@@ -291,7 +391,7 @@ fn capture_single_swf(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
 }
 
 #[allow(clippy::branches_sharing_code)]
-fn capture_multiple_swfs(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
+fn capture_multiple_swfs(renderer: Renderer, opt: &Opt) -> Result<()> {
     let output = opt.output_path.clone().unwrap();
     let files = find_files(&opt.swf, !opt.silent);
 
@@ -319,14 +419,17 @@ fn capture_multiple_swfs(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()>
                     .into_owned(),
             );
         }
-        if let Ok(frames) = take_screenshot(
-            descriptors.clone(),
+        if let Ok(Capture { frames, audio }) = take_screenshot(
+            renderer.clone(),
             file.path(),
             opt.frames,
             opt.skipframes,
             &progress,
             opt.size,
             opt.skip_unsupported,
+            opt.audio,
+            opt.print_directory.as_deref(),
+            opt.print_format,
         ) {
             let mut relative_path = file
                 .path()
@@ -342,6 +445,9 @@ fn capture_multiple_swfs(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()>
                     let _ = create_dir_all(parent);
                 }
                 frames.first().unwrap().save(&destination)?;
+                if let Some(audio) = audio {
+                    std::fs::write(destination.with_extension("wav"), audio)?;
+                }
             } else {
                 let mut parent: PathBuf = (&output).into();
                 relative_path.set_extension("");
@@ -352,6 +458,9 @@ fn capture_multiple_swfs(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()>
                     destination.push(format!("{frame}.png"));
                     image.save(&destination)?;
                 }
+                if let Some(audio) = audio {
+                    std::fs::write(parent.join("audio.wav"), audio)?;
+                }
             }
         }
 
@@ -399,27 +508,31 @@ fn trace_path(_opt: &Opt) -> Option<&Path> {
 
 fn main() -> Result<()> {
     let opt: Opt = Opt::parse();
-    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
-        backends: opt.graphics.into(),
-        ..Default::default()
-    });
-    let (adapter, device, queue) = futures::executor::block_on(request_adapter_and_device(
-        opt.graphics.into(),
-        &instance,
-        None,
-        opt.power.into(),
-        trace_path(&opt),
-    ))
-    .map_err(|e| anyhow!(e.to_string()))?;
+    let renderer = if opt.software {
+        Renderer::Software
+    } else {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+            backends: opt.graphics.into(),
+            ..Default::default()
+        });
+        let (adapter, device, queue) = futures::executor::block_on(request_adapter_and_device(
+            opt.graphics.into(),
+            &instance,
+            None,
+            opt.power.into(),
+            trace_path(&opt),
+        ))
+        .map_err(|e| anyhow!(e.to_string()))?;
 
-    let descriptors = Arc::new(Descriptors::new(instance, adapter, device, queue));
+        Renderer::Wgpu(Arc::new(Descriptors::new(instance, adapter, device, queue)))
+    };
 
     if opt.swf.is_file() {
-        capture_single_swf(descriptors, &opt)?;
+        capture_single_swf(renderer, &opt)?;
     } else if !opt.swf.is_dir() {
         return Err(anyhow!("Given path is not a file or directory."));
     } else if opt.output_path.is_some() {
-        capture_multiple_swfs(descriptors, &opt)?;
+        capture_multiple_swfs(renderer, &opt)?;
     } else {
         return Err(anyhow!(
             "Output directory is required when exporting multiple files."
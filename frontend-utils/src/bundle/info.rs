@@ -23,6 +23,9 @@ pub enum BundleInformationParseError {
 pub struct BundleInformation {
     pub name: String,
     pub url: Url,
+
+    /// The path of the AIR application descriptor, inside of the content directory.
+    pub air_descriptor: Option<String>,
 }
 
 impl BundleInformation {
@@ -33,7 +36,7 @@ impl BundleInformation {
 
         let mut cx = ParseContext::default();
 
-        let result = document
+        let (name, url) = document
             .get_table_like(&mut cx, "bundle", |cx, bundle| {
                 let Some(name) = bundle.parse_from_str(cx, "name") else {
                     return Err(BundleInformationParseError::InvalidName);
@@ -41,10 +44,22 @@ impl BundleInformation {
                 let Some(url) = bundle.parse_from_str(cx, "url") else {
                     return Err(BundleInformationParseError::InvalidUrl);
                 };
-                Ok(BundleInformation { name, url })
+                Ok((name, url))
             })
             .unwrap_or(Err(BundleInformationParseError::InvalidBundleSection))?;
 
+        let air_descriptor = document
+            .get_table_like(&mut cx, "air", |cx, air| {
+                air.parse_from_str(cx, "descriptor")
+            })
+            .flatten();
+
+        let result = BundleInformation {
+            name,
+            url,
+            air_descriptor,
+        };
+
         Ok(ParseDetails {
             warnings: cx.warnings,
             result: DocumentHolder::new(result, document),
@@ -157,9 +172,62 @@ mod test {
                 BundleInformation {
                     name: "Cool Game!".to_string(),
                     url: Url::parse("file:///game.swf").unwrap(),
+                    air_descriptor: None,
                 },
                 vec![]
             ))
         )
     }
+
+    #[test]
+    fn air_descriptor() {
+        assert_eq!(
+            read(
+                r#"
+                [bundle]
+                name = "Cool Game!"
+                url = "file:///game.swf"
+
+                [air]
+                descriptor = "META-INF/AIR/application.xml"
+                "#
+            ),
+            Ok((
+                BundleInformation {
+                    name: "Cool Game!".to_string(),
+                    url: Url::parse("file:///game.swf").unwrap(),
+                    air_descriptor: Some("META-INF/AIR/application.xml".to_string()),
+                },
+                vec![]
+            ))
+        )
+    }
+
+    #[test]
+    fn invalid_air_descriptor() {
+        assert_eq!(
+            read(
+                r#"
+                [bundle]
+                name = "Cool Game!"
+                url = "file:///game.swf"
+
+                [air]
+                descriptor = 1234
+                "#
+            ),
+            Ok((
+                BundleInformation {
+                    name: "Cool Game!".to_string(),
+                    url: Url::parse("file:///game.swf").unwrap(),
+                    air_descriptor: None,
+                },
+                vec![ParseWarning::UnexpectedType {
+                    expected: "string",
+                    actual: "integer",
+                    path: "air.descriptor".to_string(),
+                }]
+            ))
+        )
+    }
 }
@@ -0,0 +1,26 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum Error {
+    #[error("the MP4 parser ran out of data")]
+    EndOfData,
+
+    #[error("the MP4 cannot be read as its length exceeds the maximum memory size for this architecture")]
+    PointerTooBig,
+
+    #[error("the data stream does not start with an ISO base media file box")]
+    WrongMagic,
+
+    #[error("the MP4 does not contain a movie box")]
+    MissingMovie,
+
+    #[error("the MP4 contains a malformed {0} box")]
+    MalformedBox(String),
+}
+
+impl Error {
+    /// Build a `MalformedBox` error for the box with the given type.
+    pub fn malformed(box_type: [u8; 4]) -> Self {
+        Self::MalformedBox(String::from_utf8_lossy(&box_type).into_owned())
+    }
+}
@@ -0,0 +1,9 @@
+mod movie;
+
+mod reader;
+
+mod error;
+
+pub use error::Error;
+pub use movie::{is_mp4, Media, Movie, Sample, TimelineEntry, Track};
+pub use reader::{BoxHeader, Mp4Reader};
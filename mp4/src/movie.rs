@@ -0,0 +1,1034 @@
+use crate::error::Error;
+use crate::reader::Mp4Reader;
+use std::ops::Range;
+
+/// A single sample of a track, such as a video frame or a block of audio.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sample {
+    /// The location of the sample's data in the file.
+    pub data: Range<usize>,
+
+    /// When the sample should be decoded, in units of the track's timescale.
+    pub decode_time: u64,
+
+    /// How much later than its decode time the sample should be presented,
+    /// in units of the track's timescale.
+    pub composition_offset: i32,
+
+    /// True if this sample can be decoded without reference to any others.
+    pub is_sync: bool,
+}
+
+/// The kind of media stored in a track, and how it is encoded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Media {
+    Video {
+        /// The four character code of the sample entry, such as `avc1`.
+        codec: [u8; 4],
+        width: u16,
+        height: u16,
+
+        /// The location of the decoder configuration (i.e. the contents of
+        /// the `avcC` box) in the file.
+        config: Option<Range<usize>>,
+    },
+    Audio {
+        /// The four character code of the sample entry, such as `mp4a`.
+        codec: [u8; 4],
+
+        /// The MPEG-4 object type indication, for `mp4a` tracks.
+        object_type: Option<u8>,
+        sample_rate: u32,
+        channels: u16,
+        sample_size: u16,
+
+        /// The location of the decoder specific info (i.e. the AAC
+        /// `AudioSpecificConfig`) in the file.
+        config: Option<Range<usize>>,
+    },
+    Other {
+        /// The handler type of the track, such as `text`.
+        handler: [u8; 4],
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Track {
+    pub id: u32,
+
+    /// The number of time units that pass in one second.
+    pub timescale: u32,
+
+    /// The duration of the track, in units of its timescale.
+    pub duration: u64,
+
+    /// The ISO 639-2/T language code of the track.
+    pub language: [u8; 3],
+    pub media: Media,
+    pub samples: Vec<Sample>,
+}
+
+impl Track {
+    /// Convert a time in units of this track's timescale to milliseconds.
+    pub fn to_millis(&self, time: i64) -> f64 {
+        if self.timescale == 0 {
+            return 0.0;
+        }
+
+        time as f64 * 1000.0 / self.timescale as f64
+    }
+
+    pub fn is_video(&self) -> bool {
+        matches!(self.media, Media::Video { .. })
+    }
+}
+
+/// A reference to a sample, in the order that samples should be played.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimelineEntry {
+    /// The index of the track in `Movie::tracks`.
+    pub track: usize,
+
+    /// The index of the sample in `Track::samples`.
+    pub sample: usize,
+
+    /// The decode time of the sample, in milliseconds.
+    pub time: f64,
+}
+
+/// The contents of the movie box of an ISO base media file, which describes
+/// every track and where each of their samples are located.
+///
+/// Fragmented movies (using `moof` boxes) and edit lists are not supported.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Movie {
+    /// The major brand of the file, from the `ftyp` box.
+    pub brand: Option<[u8; 4]>,
+
+    /// The location of the movie box in the file.
+    pub moov_position: usize,
+
+    /// The number of time units that pass in one second.
+    pub timescale: u32,
+
+    /// The duration of the movie, in units of its timescale.
+    pub duration: u64,
+    pub tracks: Vec<Track>,
+
+    /// All samples of all audio and video tracks, sorted by decode time.
+    pub timeline: Vec<TimelineEntry>,
+}
+
+impl Movie {
+    /// Parse the movie box of an ISO base media file.
+    ///
+    /// `EndOfData` is returned if the movie box has not been loaded yet, which
+    /// may be because it is stored after the media data.
+    ///
+    /// `file_length` is the length of the whole file, which may not have been
+    /// entirely loaded into `data` yet. Samples that lie beyond it are rejected.
+    pub fn parse(data: &[u8], file_length: usize) -> Result<Self, Error> {
+        let file_length = file_length.max(data.len());
+        let mut reader = Mp4Reader::from_source(data);
+        let mut brand = None;
+
+        while !reader.is_empty() {
+            let header = reader.read_box_header()?;
+            match &header.box_type {
+                b"ftyp" => brand = Some(reader.box_content(&header)?.read_fourcc()?),
+                b"moov" => {
+                    let content = reader.box_content(&header)?;
+                    return parse_moov(content, header.start, brand, file_length).map_err(
+                        |e| match e {
+                            Error::EndOfData => Error::malformed(header.box_type),
+                            e => e,
+                        },
+                    );
+                }
+                _ => {}
+            }
+
+            if header.extends_to_end {
+                break;
+            }
+
+            reader = Mp4Reader::from_source(data);
+            reader.skip(header.end)?;
+        }
+
+        Err(Error::EndOfData)
+    }
+
+    /// The duration of the movie, in milliseconds.
+    pub fn duration_millis(&self) -> f64 {
+        if self.timescale == 0 {
+            return 0.0;
+        }
+
+        self.duration as f64 * 1000.0 / self.timescale as f64
+    }
+
+    /// Look up the track and sample referenced by a timeline entry.
+    pub fn sample(&self, entry: &TimelineEntry) -> (&Track, &Sample) {
+        let track = &self.tracks[entry.track];
+        (track, &track.samples[entry.sample])
+    }
+
+    /// Find the timeline entry to resume playback from after seeking to the
+    /// given time, in milliseconds.
+    ///
+    /// Movies with video are snapped to a video keyframe: the last one before
+    /// `time` when seeking backwards, and the first one after it when seeking
+    /// forwards.
+    pub fn seek(&self, time: f64, forward: bool) -> usize {
+        let is_keyframe = |entry: &TimelineEntry| {
+            let (track, sample) = self.sample(entry);
+            track.is_video() && sample.is_sync
+        };
+        let has_keyframes = self.timeline.iter().any(is_keyframe);
+        let is_candidate = |entry: &TimelineEntry| !has_keyframes || is_keyframe(entry);
+
+        let before = self
+            .timeline
+            .iter()
+            .rposition(|entry| is_candidate(entry) && entry.time <= time);
+        let after = self
+            .timeline
+            .iter()
+            .position(|entry| is_candidate(entry) && entry.time >= time);
+
+        let index = if forward {
+            after.or(before)
+        } else {
+            before.or(after)
+        };
+
+        index.unwrap_or(0)
+    }
+}
+
+/// Check if some data looks like the start of an ISO base media file, such
+/// as an MP4, F4V or QuickTime movie.
+pub fn is_mp4(data: &[u8]) -> bool {
+    matches!(
+        data.get(4..8),
+        Some(b"ftyp" | b"moov" | b"mdat" | b"free" | b"skip" | b"wide" | b"pnot")
+    )
+}
+
+fn parse_moov(
+    mut moov: Mp4Reader<'_>,
+    moov_position: usize,
+    brand: Option<[u8; 4]>,
+    file_length: usize,
+) -> Result<Movie, Error> {
+    let mut timescale = 0;
+    let mut duration = 0;
+    let mut tracks = Vec::new();
+
+    while let Some((header, mut content)) = moov.next_child()? {
+        match &header.box_type {
+            b"mvhd" => {
+                let (version, _flags) = content.read_full_box_header()?;
+                if version == 1 {
+                    content.skip(16)?;
+                    timescale = content.read_u32()?;
+                    duration = content.read_u64()?;
+                } else {
+                    content.skip(8)?;
+                    timescale = content.read_u32()?;
+                    duration = content.read_u32()?.into();
+                }
+            }
+            b"trak" => {
+                if let Some(track) = parse_trak(content, file_length)? {
+                    tracks.push(track);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut timeline: Vec<_> = tracks
+        .iter()
+        .enumerate()
+        .filter(|(_, track)| !matches!(track.media, Media::Other { .. }))
+        .flat_map(|(track_index, track)| {
+            track
+                .samples
+                .iter()
+                .enumerate()
+                .map(move |(sample_index, sample)| TimelineEntry {
+                    track: track_index,
+                    sample: sample_index,
+                    time: track.to_millis(sample.decode_time as i64),
+                })
+        })
+        .collect();
+    timeline.sort_by(|a, b| {
+        let a_start = tracks[a.track].samples[a.sample].data.start;
+        let b_start = tracks[b.track].samples[b.sample].data.start;
+        a.time.total_cmp(&b.time).then(a_start.cmp(&b_start))
+    });
+
+    Ok(Movie {
+        brand,
+        moov_position,
+        timescale,
+        duration,
+        tracks,
+        timeline,
+    })
+}
+
+fn parse_trak(mut trak: Mp4Reader<'_>, file_length: usize) -> Result<Option<Track>, Error> {
+    let mut id = 0;
+    let mut track_size = (0, 0);
+    let mut media = None;
+
+    while let Some((header, mut content)) = trak.next_child()? {
+        match &header.box_type {
+            b"tkhd" => {
+                let (version, _flags) = content.read_full_box_header()?;
+                if version == 1 {
+                    content.skip(16)?;
+                    id = content.read_u32()?;
+                    content.skip(12)?;
+                } else {
+                    content.skip(8)?;
+                    id = content.read_u32()?;
+                    content.skip(8)?;
+                }
+                // Reserved, layer, alternate group, volume, reserved and matrix.
+                content.skip(52)?;
+                // The track size is 16.16 fixed point.
+                track_size = (
+                    (content.read_u32()? >> 16) as u16,
+                    (content.read_u32()? >> 16) as u16,
+                );
+            }
+            b"mdia" => media = Some(parse_mdia(content, file_length)?),
+            _ => {}
+        }
+    }
+
+    let Some(mut track) = media else {
+        return Ok(None);
+    };
+    track.id = id;
+    if let Media::Video { width, height, .. } = &mut track.media {
+        if *width == 0 || *height == 0 {
+            (*width, *height) = track_size;
+        }
+    }
+
+    Ok(Some(track))
+}
+
+fn parse_mdia(mut mdia: Mp4Reader<'_>, file_length: usize) -> Result<Track, Error> {
+    let mut timescale = 0;
+    let mut duration = 0;
+    let mut language = *b"und";
+    let mut handler = [0; 4];
+    let mut sample_table = None;
+
+    while let Some((header, mut content)) = mdia.next_child()? {
+        match &header.box_type {
+            b"mdhd" => {
+                let (version, _flags) = content.read_full_box_header()?;
+                if version == 1 {
+                    content.skip(16)?;
+                    timescale = content.read_u32()?;
+                    duration = content.read_u64()?;
+                } else {
+                    content.skip(8)?;
+                    timescale = content.read_u32()?;
+                    duration = content.read_u32()?.into();
+                }
+                // Three lowercase letters, packed into 5 bits each.
+                let packed = content.read_u16()?;
+                if packed != 0 {
+                    language = [
+                        ((packed >> 10) & 0x1F) as u8 + 0x60,
+                        ((packed >> 5) & 0x1F) as u8 + 0x60,
+                        (packed & 0x1F) as u8 + 0x60,
+                    ];
+                }
+            }
+            b"hdlr" => {
+                content.read_full_box_header()?;
+                content.skip(4)?;
+                handler = content.read_fourcc()?;
+            }
+            b"minf" => {
+                while let Some((header, content)) = content.next_child()? {
+                    if header.box_type == *b"stbl" {
+                        sample_table = Some(content);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let (media, samples) = match sample_table {
+        Some(stbl) => parse_stbl(stbl, handler, file_length)?,
+        None => (Media::Other { handler }, Vec::new()),
+    };
+
+    Ok(Track {
+        id: 0,
+        timescale,
+        duration,
+        language,
+        media,
+        samples,
+    })
+}
+
+enum SampleSizes {
+    Constant { size: u32, count: u32 },
+    Variable(Vec<u32>),
+}
+
+impl SampleSizes {
+    fn len(&self) -> usize {
+        match self {
+            Self::Constant { count, .. } => *count as usize,
+            Self::Variable(sizes) => sizes.len(),
+        }
+    }
+
+    fn get(&self, index: usize) -> u32 {
+        match self {
+            Self::Constant { size, .. } => *size,
+            Self::Variable(sizes) => sizes[index],
+        }
+    }
+}
+
+fn parse_stbl(
+    mut stbl: Mp4Reader<'_>,
+    handler: [u8; 4],
+    file_length: usize,
+) -> Result<(Media, Vec<Sample>), Error> {
+    let mut media = Media::Other { handler };
+    let mut decode_deltas = Vec::new();
+    let mut composition_offsets = Vec::new();
+    let mut chunk_runs = Vec::new();
+    let mut sizes = SampleSizes::Variable(Vec::new());
+    let mut chunk_offsets = Vec::new();
+    let mut sync_samples = None;
+
+    while let Some((header, mut content)) = stbl.next_child()? {
+        match &header.box_type {
+            b"stsd" => {
+                content.read_full_box_header()?;
+                let count = content.read_u32()?;
+                if count > 0 {
+                    if let Some((entry_header, entry)) = content.next_child()? {
+                        media = parse_sample_entry(entry, entry_header.box_type, handler)?;
+                    }
+                }
+            }
+            b"stts" => {
+                content.read_full_box_header()?;
+                for _ in 0..content.read_u32()? {
+                    decode_deltas.push((content.read_u32()?, content.read_u32()?));
+                }
+            }
+            b"ctts" => {
+                content.read_full_box_header()?;
+                for _ in 0..content.read_u32()? {
+                    // Version 0 offsets are unsigned, but negative offsets
+                    // are common in practice anyway.
+                    composition_offsets.push((content.read_u32()?, content.read_i32()?));
+                }
+            }
+            b"stsc" => {
+                content.read_full_box_header()?;
+                for _ in 0..content.read_u32()? {
+                    let first_chunk = content.read_u32()?;
+                    let samples_per_chunk = content.read_u32()?;
+                    content.skip(4)?;
+                    chunk_runs.push((first_chunk, samples_per_chunk));
+                }
+            }
+            b"stsz" => {
+                content.read_full_box_header()?;
+                let size = content.read_u32()?;
+                let count = content.read_u32()?;
+                sizes = if size == 0 {
+                    let mut sizes = Vec::new();
+                    for _ in 0..count {
+                        sizes.push(content.read_u32()?);
+                    }
+                    SampleSizes::Variable(sizes)
+                } else if count as usize > file_length / size as usize {
+                    // Every sample takes up at least `size` bytes of the file.
+                    return Err(Error::malformed(header.box_type));
+                } else {
+                    SampleSizes::Constant { size, count }
+                };
+            }
+            b"stz2" => {
+                content.read_full_box_header()?;
+                content.skip(3)?;
+                let field_size = content.read_u8()?;
+                let count = content.read_u32()?;
+                let mut sizes_list = Vec::new();
+                let mut i = 0;
+                while i < count {
+                    match field_size {
+                        4 => {
+                            let byte = content.read_u8()?;
+                            sizes_list.push(u32::from(byte >> 4));
+                            if i + 1 < count {
+                                sizes_list.push(u32::from(byte & 0xF));
+                            }
+                            i += 2;
+                        }
+                        8 => {
+                            sizes_list.push(content.read_u8()?.into());
+                            i += 1;
+                        }
+                        16 => {
+                            sizes_list.push(content.read_u16()?.into());
+                            i += 1;
+                        }
+                        _ => return Err(Error::malformed(header.box_type)),
+                    }
+                }
+                sizes = SampleSizes::Variable(sizes_list);
+            }
+            b"stco" => {
+                content.read_full_box_header()?;
+                for _ in 0..content.read_u32()? {
+                    chunk_offsets.push(content.read_u32()?.into());
+                }
+            }
+            b"co64" => {
+                content.read_full_box_header()?;
+                for _ in 0..content.read_u32()? {
+                    chunk_offsets.push(content.read_u64()?);
+                }
+            }
+            b"stss" => {
+                content.read_full_box_header()?;
+                let mut samples = Vec::new();
+                for _ in 0..content.read_u32()? {
+                    samples.push(content.read_u32()?);
+                }
+                sync_samples = Some(samples);
+            }
+            _ => {}
+        }
+    }
+
+    // Lay out the samples, which are stored in runs of chunks.
+    let mut samples = Vec::new();
+    let mut run = 0;
+    for (chunk_index, chunk_offset) in chunk_offsets.into_iter().enumerate() {
+        let chunk_number = chunk_index as u64 + 1;
+        while chunk_runs
+            .get(run + 1)
+            .is_some_and(|(first_chunk, _)| u64::from(*first_chunk) <= chunk_number)
+        {
+            run += 1;
+        }
+        let samples_per_chunk = chunk_runs.get(run).map_or(0, |(_, count)| *count);
+
+        let mut offset = usize::try_from(chunk_offset).map_err(|_| Error::PointerTooBig)?;
+        for _ in 0..samples_per_chunk {
+            if samples.len() >= sizes.len() {
+                break;
+            }
+
+            let size = sizes.get(samples.len()) as usize;
+            let end = offset.checked_add(size).ok_or(Error::PointerTooBig)?;
+            if end > file_length {
+                return Err(Error::malformed(*b"stbl"));
+            }
+            samples.push(Sample {
+                data: offset..end,
+                decode_time: 0,
+                composition_offset: 0,
+                is_sync: sync_samples.is_none(),
+            });
+            offset = end;
+        }
+    }
+
+    let mut time = 0u64;
+    let mut remaining = samples.iter_mut();
+    'decode: for (count, delta) in decode_deltas {
+        for _ in 0..count {
+            let Some(sample) = remaining.next() else {
+                break 'decode;
+            };
+            sample.decode_time = time;
+            time = time.saturating_add(delta.into());
+        }
+    }
+
+    let mut remaining = samples.iter_mut();
+    'composition: for (count, offset) in composition_offsets {
+        for _ in 0..count {
+            let Some(sample) = remaining.next() else {
+                break 'composition;
+            };
+            sample.composition_offset = offset;
+        }
+    }
+
+    for number in sync_samples.into_iter().flatten() {
+        // Sample numbers start at 1.
+        if let Some(sample) = (number as usize)
+            .checked_sub(1)
+            .and_then(|index| samples.get_mut(index))
+        {
+            sample.is_sync = true;
+        }
+    }
+
+    Ok((media, samples))
+}
+
+fn parse_sample_entry(
+    mut entry: Mp4Reader<'_>,
+    codec: [u8; 4],
+    handler: [u8; 4],
+) -> Result<Media, Error> {
+    match &handler {
+        b"vide" => {
+            // Reserved, data reference index, predefined and reserved.
+            entry.skip(24)?;
+            let width = entry.read_u16()?;
+            let height = entry.read_u16()?;
+            // Resolution, reserved, frame count, compressor name, depth and predefined.
+            entry.skip(50)?;
+
+            let mut config = None;
+            while let Some((header, content)) = entry.next_child()? {
+                if header.box_type == *b"avcC" {
+                    config = Some(content.position()..header.end);
+                }
+            }
+
+            Ok(Media::Video {
+                codec,
+                width,
+                height,
+                config,
+            })
+        }
+        b"soun" => {
+            // Reserved and data reference index.
+            entry.skip(8)?;
+            // QuickTime sound descriptions are versioned; ISO ones are always 0.
+            let version = entry.read_u16()?;
+            entry.skip(6)?;
+            let mut channels = entry.read_u16()?;
+            let sample_size = entry.read_u16()?;
+            entry.skip(4)?;
+            // The sample rate is 16.16 fixed point.
+            let mut sample_rate = entry.read_u32()? >> 16;
+            match version {
+                1 => entry.skip(16)?,
+                2 => {
+                    entry.skip(4)?;
+                    sample_rate = f64::from_bits(entry.read_u64()?) as u32;
+                    channels = entry.read_u32()? as u16;
+                    entry.skip(20)?;
+                }
+                _ => {}
+            }
+
+            let (object_type, config) = find_decoder_config(entry)?;
+
+            Ok(Media::Audio {
+                codec,
+                object_type,
+                sample_rate,
+                channels,
+                sample_size,
+                config,
+            })
+        }
+        _ => Ok(Media::Other { handler }),
+    }
+}
+
+/// Find the object type and decoder specific info of an audio sample entry.
+///
+/// QuickTime files may wrap the `esds` box in a `wave` box.
+fn find_decoder_config(
+    mut entry: Mp4Reader<'_>,
+) -> Result<(Option<u8>, Option<Range<usize>>), Error> {
+    while let Some((header, content)) = entry.next_child()? {
+        match &header.box_type {
+            // Descriptors are a separate, quite flexible format, so don't
+            // fail the whole movie if we can't make sense of them.
+            b"esds" => return Ok(parse_esds(content).unwrap_or_default()),
+            b"wave" => {
+                let found = find_decoder_config(content)?;
+                if found.0.is_some() {
+                    return Ok(found);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok((None, None))
+}
+
+/// Read the tag and length of an MPEG-4 descriptor.
+fn read_descriptor_header(reader: &mut Mp4Reader<'_>) -> Result<(u8, usize), Error> {
+    let tag = reader.read_u8()?;
+    let mut length = 0;
+    for _ in 0..4 {
+        let byte = reader.read_u8()?;
+        length = (length << 7) | usize::from(byte & 0x7F);
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+
+    Ok((tag, length))
+}
+
+fn parse_esds(mut esds: Mp4Reader<'_>) -> Result<(Option<u8>, Option<Range<usize>>), Error> {
+    const ES_DESCRIPTOR: u8 = 0x03;
+    const DECODER_CONFIG_DESCRIPTOR: u8 = 0x04;
+    const DECODER_SPECIFIC_INFO: u8 = 0x05;
+
+    esds.read_full_box_header()?;
+
+    if read_descriptor_header(&mut esds)?.0 != ES_DESCRIPTOR {
+        return Ok((None, None));
+    }
+    // ES ID
+    esds.skip(2)?;
+    let flags = esds.read_u8()?;
+    if flags & 0x80 != 0 {
+        // Depends-on ES ID
+        esds.skip(2)?;
+    }
+    if flags & 0x40 != 0 {
+        let url_length = esds.read_u8()?;
+        esds.skip(url_length.into())?;
+    }
+    if flags & 0x20 != 0 {
+        // OCR ES ID
+        esds.skip(2)?;
+    }
+
+    if read_descriptor_header(&mut esds)?.0 != DECODER_CONFIG_DESCRIPTOR {
+        return Ok((None, None));
+    }
+    let object_type = esds.read_u8()?;
+    // Stream type, buffer size, max bitrate and average bitrate.
+    esds.skip(12)?;
+
+    let (tag, length) = read_descriptor_header(&mut esds)?;
+    if tag != DECODER_SPECIFIC_INFO {
+        return Ok((Some(object_type), None));
+    }
+    let start = esds.position();
+    esds.skip(length)?;
+
+    Ok((Some(object_type), Some(start..start + length)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_box(box_type: &[u8; 4], contents: &[u8]) -> Vec<u8> {
+        let mut data = ((contents.len() + 8) as u32).to_be_bytes().to_vec();
+        data.extend_from_slice(box_type);
+        data.extend_from_slice(contents);
+        data
+    }
+
+    fn make_full_box(box_type: &[u8; 4], contents: &[u8]) -> Vec<u8> {
+        make_box(box_type, &[&[0, 0, 0, 0], contents].concat())
+    }
+
+    fn make_table(entries: &[&[u32]]) -> Vec<u8> {
+        let mut data = (entries.len() as u32).to_be_bytes().to_vec();
+        for entry in entries {
+            for value in *entry {
+                data.extend_from_slice(&value.to_be_bytes());
+            }
+        }
+        data
+    }
+
+    fn make_trak(
+        handler: &[u8; 4],
+        timescale: u32,
+        sample_entry: Vec<u8>,
+        sample_tables: Vec<u8>,
+    ) -> Vec<u8> {
+        let mut mdhd = vec![0; 8];
+        mdhd.extend_from_slice(&timescale.to_be_bytes());
+        mdhd.extend_from_slice(&[0, 0, 0, 0]);
+        // "eng"
+        mdhd.extend_from_slice(&0x15C7u16.to_be_bytes());
+        mdhd.extend_from_slice(&[0, 0]);
+        let mut hdlr = vec![0; 4];
+        hdlr.extend_from_slice(handler);
+        hdlr.extend_from_slice(&[0; 13]);
+
+        let stsd = make_full_box(b"stsd", &[&1u32.to_be_bytes()[..], &sample_entry].concat());
+        let stbl = make_box(b"stbl", &[stsd, sample_tables].concat());
+        let minf = make_box(b"minf", &stbl);
+        let mdia = make_box(
+            b"mdia",
+            &[
+                make_full_box(b"mdhd", &mdhd),
+                make_full_box(b"hdlr", &hdlr),
+                minf,
+            ]
+            .concat(),
+        );
+        make_box(b"trak", &mdia)
+    }
+
+    /// Build a movie with two video frames and three blocks of audio, all
+    /// stored in a single chunk per track, with the movie box first.
+    fn make_movie(mdat_offset: u32) -> Vec<u8> {
+        let mut avc1 = vec![0; 24];
+        avc1.extend_from_slice(&320u16.to_be_bytes());
+        avc1.extend_from_slice(&240u16.to_be_bytes());
+        avc1.extend_from_slice(&[0; 50]);
+        avc1.extend(make_box(b"avcC", &[1, 66, 0, 30]));
+        let video = make_trak(
+            b"vide",
+            1000,
+            make_box(b"avc1", &avc1),
+            [
+                make_full_box(b"stts", &make_table(&[&[2, 100]])),
+                make_full_box(b"stss", &make_table(&[&[1]])),
+                make_full_box(b"stsc", &make_table(&[&[1, 2, 1]])),
+                make_full_box(b"stsz", &[0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 10, 0, 0, 0, 5]),
+                make_full_box(b"stco", &make_table(&[&[mdat_offset]])),
+            ]
+            .concat(),
+        );
+
+        let mut mp4a = vec![0; 8];
+        mp4a.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 16, 0, 0, 0, 0]);
+        mp4a.extend_from_slice(&(44100u32 << 16).to_be_bytes());
+        mp4a.extend(make_full_box(
+            b"esds",
+            &[
+                3, 25, 0, 1, 0, // ES descriptor
+                4, 17, 0x40, 0x15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // Decoder config
+                5, 2, 0x12, 0x10, // Decoder specific info
+            ],
+        ));
+        let audio = make_trak(
+            b"soun",
+            44100,
+            make_box(b"mp4a", &mp4a),
+            [
+                make_full_box(b"stts", &make_table(&[&[3, 1024]])),
+                make_full_box(b"stsc", &make_table(&[&[1, 3, 1]])),
+                make_full_box(b"stsz", &[0, 0, 0, 4, 0, 0, 0, 3]),
+                make_full_box(b"stco", &make_table(&[&[mdat_offset + 15]])),
+            ]
+            .concat(),
+        );
+
+        let mut mvhd = vec![0; 8];
+        mvhd.extend_from_slice(&1000u32.to_be_bytes());
+        mvhd.extend_from_slice(&200u32.to_be_bytes());
+        mvhd.extend_from_slice(&[0; 80]);
+
+        [
+            make_box(b"ftyp", b"f4v \0\0\0\0isommp42"),
+            make_box(
+                b"moov",
+                &[make_full_box(b"mvhd", &mvhd), video, audio].concat(),
+            ),
+        ]
+        .concat()
+    }
+
+    fn movie_with_mdat() -> Vec<u8> {
+        let header_len = make_movie(0).len() as u32;
+        let mut data = make_movie(header_len + 8);
+        data.extend(make_box(b"mdat", &[0xAA; 27]));
+        data
+    }
+
+    #[test]
+    fn sniff() {
+        assert!(is_mp4(&movie_with_mdat()));
+        assert!(!is_mp4(b"FLV\x01\x05\0\0\0\x09"));
+        assert!(!is_mp4(b"\0\0\0"));
+    }
+
+    #[test]
+    fn parse_movie() {
+        let data = movie_with_mdat();
+        let movie = Movie::parse(&data, data.len()).unwrap();
+        let mdat = data.len() - 27;
+
+        assert_eq!(movie.brand, Some(*b"f4v "));
+        assert_eq!(movie.moov_position, 24);
+        assert_eq!(movie.duration_millis(), 200.0);
+        assert_eq!(movie.tracks.len(), 2);
+
+        let video = &movie.tracks[0];
+        assert_eq!(video.language, *b"eng");
+        let Media::Video {
+            codec,
+            width,
+            height,
+            config: Some(config),
+        } = &video.media
+        else {
+            panic!("expected a video track, got {:?}", video.media);
+        };
+        assert_eq!((codec, *width, *height), (b"avc1", 320, 240));
+        assert_eq!(&data[config.clone()], &[1, 66, 0, 30]);
+        assert_eq!(
+            video.samples,
+            vec![
+                Sample {
+                    data: mdat..mdat + 10,
+                    decode_time: 0,
+                    composition_offset: 0,
+                    is_sync: true,
+                },
+                Sample {
+                    data: mdat + 10..mdat + 15,
+                    decode_time: 100,
+                    composition_offset: 0,
+                    is_sync: false,
+                },
+            ]
+        );
+
+        let audio = &movie.tracks[1];
+        let Media::Audio {
+            codec,
+            object_type,
+            sample_rate,
+            channels,
+            config: Some(config),
+            ..
+        } = &audio.media
+        else {
+            panic!("expected an audio track, got {:?}", audio.media);
+        };
+        assert_eq!(
+            (codec, *object_type, *sample_rate, *channels),
+            (b"mp4a", Some(0x40), 44100, 2)
+        );
+        assert_eq!(&data[config.clone()], &[0x12, 0x10]);
+        assert_eq!(audio.samples.len(), 3);
+        assert_eq!(audio.samples[2].data, mdat + 23..mdat + 27);
+
+        let order: Vec<_> = movie
+            .timeline
+            .iter()
+            .map(|entry| (entry.track, entry.sample))
+            .collect();
+        assert_eq!(order, vec![(0, 0), (1, 0), (1, 1), (1, 2), (0, 1)]);
+    }
+
+    #[test]
+    fn incomplete_movie() {
+        let data = movie_with_mdat();
+        assert_eq!(Movie::parse(&data[..4], data.len()), Err(Error::EndOfData));
+        assert_eq!(
+            Movie::parse(&data[..100], data.len()),
+            Err(Error::EndOfData)
+        );
+
+        // The movie box may come after the media data.
+        let mdat = make_box(b"mdat", &[0; 16]);
+        let data = [mdat.clone(), make_movie(8)].concat();
+        assert_eq!(Movie::parse(&mdat, data.len()), Err(Error::EndOfData));
+        assert_eq!(Movie::parse(&data, data.len()).unwrap().tracks.len(), 2);
+
+        // The media data doesn't need to be loaded yet, as long as the file is long enough.
+        let data = movie_with_mdat();
+        let movie_len = data.len() - 35;
+        assert!(Movie::parse(&data[..movie_len], data.len()).is_ok());
+    }
+
+    #[test]
+    fn samples_outside_file() {
+        // A file length shorter than the loaded data is ignored.
+        let data = movie_with_mdat();
+        assert_eq!(
+            Movie::parse(&data, data.len() - 1),
+            Ok(Movie::parse(&data, data.len()).unwrap())
+        );
+
+        let data = make_movie(u16::MAX.into());
+        assert_eq!(
+            Movie::parse(&data, data.len()),
+            Err(Error::MalformedBox("stbl".to_string()))
+        );
+    }
+
+    #[test]
+    fn huge_sample_counts() {
+        // A constant sample size with a count that can't fit in the file.
+        let stsz = make_full_box(b"stsz", &[0, 0, 0, 1, 0xFF, 0xFF, 0xFF, 0xFF]);
+        let trak = make_trak(
+            b"soun",
+            44100,
+            make_box(b"mp4a", &[0; 28]),
+            [
+                make_full_box(b"stsc", &make_table(&[&[1, u32::MAX, 1]])),
+                stsz,
+                make_full_box(b"stco", &make_table(&[&[0]])),
+            ]
+            .concat(),
+        );
+        let data = make_box(b"moov", &trak);
+        assert_eq!(
+            Movie::parse(&data, data.len()),
+            Err(Error::MalformedBox("stsz".to_string()))
+        );
+
+        // A huge number of samples per chunk is limited by the sample sizes.
+        let trak = make_trak(
+            b"soun",
+            44100,
+            make_box(b"mp4a", &[0; 28]),
+            [
+                make_full_box(b"stsc", &make_table(&[&[1, u32::MAX, 1]])),
+                make_full_box(b"stsz", &[0, 0, 0, 1, 0, 0, 0, 4]),
+                make_full_box(b"stco", &make_table(&[&[0]])),
+            ]
+            .concat(),
+        );
+        let data = make_box(b"moov", &trak);
+        let movie = Movie::parse(&data, data.len()).unwrap();
+        assert_eq!(movie.tracks[0].samples.len(), 4);
+    }
+
+    #[test]
+    fn seek() {
+        let data = movie_with_mdat();
+        let movie = Movie::parse(&data, data.len()).unwrap();
+
+        assert_eq!(movie.seek(150.0, false), 0);
+        assert_eq!(movie.seek(150.0, true), 0);
+        assert_eq!(movie.seek(0.0, true), 0);
+
+        let mut audio_only = movie.clone();
+        audio_only.timeline.retain(|entry| entry.track == 1);
+        assert_eq!(audio_only.seek(30.0, true), 2);
+        assert_eq!(audio_only.seek(30.0, false), 1);
+    }
+}
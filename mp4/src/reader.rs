@@ -0,0 +1,236 @@
+use crate::error::Error;
+
+/// The header of an ISO base media file box.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoxHeader {
+    /// The four character code identifying the kind of box.
+    pub box_type: [u8; 4],
+
+    /// The position of the start of the box, including its header.
+    pub start: usize,
+
+    /// The position of the start of the box's contents.
+    pub content_start: usize,
+
+    /// The position of the end of the box.
+    ///
+    /// Boxes that extend to the end of the file report the end of the data
+    /// that was available when the header was read.
+    pub end: usize,
+
+    /// True if the box extends to the end of the file.
+    pub extends_to_end: bool,
+}
+
+/// A reader over (part of) an ISO base media file.
+///
+/// All positions are absolute offsets into the file, even for readers that
+/// are limited to the contents of a single box.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mp4Reader<'a> {
+    source: &'a [u8],
+
+    position: usize,
+}
+
+impl<'a> Mp4Reader<'a> {
+    pub fn from_source(source: &'a [u8]) -> Self {
+        Mp4Reader {
+            source,
+            position: 0,
+        }
+    }
+
+    /// The current position of the reader.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns true if there is no more data left to read.
+    pub fn is_empty(&self) -> bool {
+        self.position >= self.source.len()
+    }
+
+    /// Read a certain number of bytes from the buffer.
+    ///
+    /// If the requested number of bytes are not available, `EndOfData` is
+    /// returned and the position of the reader is unchanged.
+    pub fn read(&mut self, count: usize) -> Result<&'a [u8], Error> {
+        let start = self.position;
+        let end = start.checked_add(count).ok_or(Error::PointerTooBig)?;
+        let data = self.source.get(start..end).ok_or(Error::EndOfData)?;
+
+        self.position = end;
+
+        Ok(data)
+    }
+
+    /// Skip over a certain number of bytes.
+    pub fn skip(&mut self, count: usize) -> Result<(), Error> {
+        self.read(count).map(|_| ())
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, Error> {
+        Ok(self.read(1)?[0])
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16, Error> {
+        Ok(u16::from_be_bytes(
+            self.read(2)?.try_into().expect("two bytes"),
+        ))
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, Error> {
+        Ok(u32::from_be_bytes(
+            self.read(4)?.try_into().expect("four bytes"),
+        ))
+    }
+
+    pub fn read_i32(&mut self) -> Result<i32, Error> {
+        Ok(i32::from_be_bytes(
+            self.read(4)?.try_into().expect("four bytes"),
+        ))
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, Error> {
+        Ok(u64::from_be_bytes(
+            self.read(8)?.try_into().expect("eight bytes"),
+        ))
+    }
+
+    pub fn read_fourcc(&mut self) -> Result<[u8; 4], Error> {
+        Ok(self.read(4)?.try_into().expect("four bytes"))
+    }
+
+    /// Read the version and flags of a full box.
+    pub fn read_full_box_header(&mut self) -> Result<(u8, u32), Error> {
+        let version_and_flags = self.read_u32()?;
+
+        Ok((
+            (version_and_flags >> 24) as u8,
+            version_and_flags & 0xFFFFFF,
+        ))
+    }
+
+    /// Read the header of the box at the current position.
+    ///
+    /// The reader is left at the start of the box's contents. The contents
+    /// themselves may not have been loaded yet.
+    pub fn read_box_header(&mut self) -> Result<BoxHeader, Error> {
+        let start = self.position;
+        let result = self.read_box_header_inner(start);
+        if result.is_err() {
+            self.position = start;
+        }
+
+        result
+    }
+
+    fn read_box_header_inner(&mut self, start: usize) -> Result<BoxHeader, Error> {
+        let size = self.read_u32()?;
+        let box_type = self.read_fourcc()?;
+        let (end, extends_to_end) = match size {
+            0 => (self.source.len(), true),
+            1 => {
+                let size = usize::try_from(self.read_u64()?).map_err(|_| Error::PointerTooBig)?;
+                (start.checked_add(size).ok_or(Error::PointerTooBig)?, false)
+            }
+            size => (
+                start
+                    .checked_add(size as usize)
+                    .ok_or(Error::PointerTooBig)?,
+                false,
+            ),
+        };
+
+        if end < self.position {
+            return Err(Error::malformed(box_type));
+        }
+
+        Ok(BoxHeader {
+            box_type,
+            start,
+            content_start: self.position,
+            end,
+            extends_to_end,
+        })
+    }
+
+    /// Get a reader limited to the contents of a box.
+    ///
+    /// Returns `EndOfData` if the box has not been entirely loaded yet.
+    pub fn box_content(&self, header: &BoxHeader) -> Result<Mp4Reader<'a>, Error> {
+        Ok(Mp4Reader {
+            source: self.source.get(..header.end).ok_or(Error::EndOfData)?,
+            position: header.content_start,
+        })
+    }
+
+    /// Read the next child box, if there is one.
+    ///
+    /// This yields the header of the child and a reader limited to its
+    /// contents, and moves this reader past it.
+    pub fn next_child(&mut self) -> Result<Option<(BoxHeader, Mp4Reader<'a>)>, Error> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+
+        let start = self.position;
+        let header = self.read_box_header()?;
+        let content = match self.box_content(&header) {
+            Ok(content) => content,
+            Err(e) => {
+                self.position = start;
+                return Err(e);
+            }
+        };
+        self.position = header.end;
+
+        Ok(Some((header, content)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_box_headers() {
+        let data = [
+            0, 0, 0, 12, b'f', b'r', b'e', b'e', 1, 2, 3, 4, //
+            0, 0, 0, 1, b'm', b'd', b'a', b't', 0, 0, 0, 0, 0, 0, 0, 17, 5,
+        ];
+        let mut reader = Mp4Reader::from_source(&data);
+
+        let (header, mut content) = reader.next_child().unwrap().unwrap();
+        assert_eq!(header.box_type, *b"free");
+        assert_eq!(content.read_u32(), Ok(0x01020304));
+        assert!(content.is_empty());
+
+        let header = reader.read_box_header().unwrap();
+        assert_eq!(
+            header,
+            BoxHeader {
+                box_type: *b"mdat",
+                start: 12,
+                content_start: 28,
+                end: 29,
+                extends_to_end: false,
+            }
+        );
+        assert_eq!(reader.box_content(&header).unwrap().read_u8(), Ok(5));
+    }
+
+    #[test]
+    fn partial_box() {
+        let data = [0, 0, 0, 16, b'f', b'r', b'e', b'e', 1, 2];
+        let mut reader = Mp4Reader::from_source(&data);
+
+        assert_eq!(reader.next_child(), Err(Error::EndOfData));
+        assert_eq!(reader.position(), 0);
+
+        let mut reader = Mp4Reader::from_source(&data[..6]);
+        assert_eq!(reader.read_box_header(), Err(Error::EndOfData));
+        assert_eq!(reader.position(), 0);
+    }
+}
@@ -0,0 +1,524 @@
+use crate::bitmap::{as_bitmap, SoftwareBitmap};
+use crate::mesh::{Draw, GradientRamp, Mesh, Paint};
+use crate::target::Target;
+use ruffle_render::backend::{
+    BitmapCacheEntry, Context3D, Context3DProfile, PixelBenderOutput, PixelBenderTarget,
+    RenderBackend, ShapeHandle, ViewportDimensions,
+};
+use ruffle_render::bitmap::{
+    Bitmap, BitmapHandle, BitmapSource, PixelRegion, RgbaBufRead, SyncHandle,
+};
+use ruffle_render::commands::CommandList;
+use ruffle_render::error::Error;
+use ruffle_render::filters::Filter;
+use ruffle_render::pixel_bender::{
+    PixelBenderParam, PixelBenderParamQualifier, PixelBenderShader, PixelBenderShaderArgument,
+    PixelBenderShaderHandle, PixelBenderShaderImpl,
+};
+use ruffle_render::quality::StageQuality;
+use ruffle_render::shape_utils::DistilledShape;
+use ruffle_render::tessellator::{DrawType, ShapeTessellator};
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::sync::Arc;
+use swf::Color;
+
+/// A render backend that draws everything on the CPU.
+pub struct SoftwareRenderBackend {
+    viewport_dimensions: ViewportDimensions,
+    quality: StageQuality,
+    shape_tessellator: ShapeTessellator,
+
+    /// The premultiplied RGBA pixels of the last submitted frame.
+    frame: Option<Vec<u8>>,
+
+    /// Whether any filter or shader was left out, since they aren't supported.
+    skipped_filters: Cell<bool>,
+}
+
+/// A pending `render_offscreen` result.
+/// Rendering happens synchronously, so this only remembers where to read the pixels from.
+#[derive(Debug)]
+struct SoftwareSyncHandle {
+    handle: BitmapHandle,
+    bounds: PixelRegion,
+}
+
+impl SyncHandle for SoftwareSyncHandle {}
+
+/// A shader that can't be run, only kept for the parameters of its `ShaderData`.
+#[derive(Debug)]
+struct SoftwareShader(PixelBenderShader);
+
+impl PixelBenderShaderImpl for SoftwareShader {
+    fn parsed_shader(&self) -> &PixelBenderShader {
+        &self.0
+    }
+}
+
+impl SoftwareRenderBackend {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            viewport_dimensions: ViewportDimensions {
+                width,
+                height,
+                scale_factor: 1.0,
+            },
+            quality: StageQuality::default(),
+            shape_tessellator: ShapeTessellator::new(),
+            frame: None,
+            skipped_filters: Cell::new(false),
+        }
+    }
+
+    /// Whether anything was drawn without its filters or shaders,
+    /// in which case frames don't look like they would elsewhere.
+    pub fn skipped_filters(&self) -> bool {
+        self.skipped_filters.get()
+    }
+
+    fn skip_filters(&self) {
+        if !self.skipped_filters.replace(true) {
+            tracing::warn!("Filters and shaders are not supported by the software renderer");
+        }
+    }
+
+    /// Captures the last submitted frame. The resulting image uses straight alpha.
+    pub fn capture_frame(&self) -> Option<image::RgbaImage> {
+        let mut pixels = self.frame.clone()?;
+        ruffle_render::utils::unmultiply_alpha_rgba(&mut pixels);
+        image::RgbaImage::from_raw(
+            self.viewport_dimensions.width,
+            self.viewport_dimensions.height,
+            pixels,
+        )
+    }
+
+    fn draw_to_bitmap(&self, handle: &BitmapHandle, target: Target, commands: CommandList) {
+        let mut target = target;
+        self.draw_commands(&mut target, commands);
+        *as_bitmap(handle).pixels_mut() = target.resolve();
+    }
+
+    fn draw_commands(&self, target: &mut Target, commands: CommandList) {
+        target.draw_commands(commands);
+        if target.skipped_shaders() {
+            self.skip_filters();
+        }
+    }
+}
+
+/// Converts a straight alpha color into the premultiplied form used by `Target`.
+fn premultiplied_color(color: Color) -> [f32; 4] {
+    let a = f32::from(color.a) / 255.0;
+    [
+        f32::from(color.r) / 255.0 * a,
+        f32::from(color.g) / 255.0 * a,
+        f32::from(color.b) / 255.0 * a,
+        a,
+    ]
+}
+
+impl RenderBackend for SoftwareRenderBackend {
+    fn viewport_dimensions(&self) -> ViewportDimensions {
+        self.viewport_dimensions
+    }
+
+    fn set_viewport_dimensions(&mut self, dimensions: ViewportDimensions) {
+        self.viewport_dimensions = dimensions;
+        self.frame = None;
+    }
+
+    fn register_shape(
+        &mut self,
+        shape: DistilledShape,
+        bitmap_source: &dyn BitmapSource,
+    ) -> ShapeHandle {
+        let lyon_mesh = self
+            .shape_tessellator
+            .tessellate_shape(shape, bitmap_source);
+        let gradients: Vec<_> = lyon_mesh
+            .gradients
+            .into_iter()
+            .map(|gradient| Arc::new(GradientRamp::new(gradient)))
+            .collect();
+
+        let mut draws = Vec::with_capacity(lyon_mesh.draws.len());
+        for draw in lyon_mesh.draws {
+            let paint = match draw.draw_type {
+                DrawType::Color => Paint::Color,
+                DrawType::Gradient { matrix, gradient } => Paint::Gradient {
+                    matrix,
+                    gradient: gradients[gradient].clone(),
+                },
+                DrawType::Bitmap(bitmap) => {
+                    let Some(handle) = bitmap_source.bitmap_handle(bitmap.bitmap_id, self) else {
+                        continue;
+                    };
+                    Paint::Bitmap {
+                        matrix: bitmap.matrix,
+                        bitmap: handle,
+                        is_smoothed: bitmap.is_smoothed,
+                        is_repeating: bitmap.is_repeating,
+                    }
+                }
+            };
+            draws.push(Draw {
+                paint,
+                vertices: draw.vertices,
+                indices: draw.indices,
+                num_mask_indices: draw.mask_index_count as usize,
+            });
+        }
+
+        ShapeHandle(Arc::new(Mesh { draws }))
+    }
+
+    fn render_offscreen(
+        &mut self,
+        handle: BitmapHandle,
+        commands: CommandList,
+        quality: StageQuality,
+        bounds: PixelRegion,
+    ) -> Option<Box<dyn SyncHandle>> {
+        let bitmap = as_bitmap(&handle);
+        let target =
+            Target::from_pixels(bitmap.width(), bitmap.height(), quality, &bitmap.pixels());
+        self.draw_to_bitmap(&handle, target, commands);
+        Some(Box::new(SoftwareSyncHandle { handle, bounds }))
+    }
+
+    fn is_filter_supported(&self, _filter: &Filter) -> bool {
+        // Asked before applying a filter to a `BitmapData`, which then becomes a copy.
+        self.skip_filters();
+        false
+    }
+
+    fn is_offscreen_supported(&self) -> bool {
+        true
+    }
+
+    fn submit_frame(
+        &mut self,
+        clear: Color,
+        commands: CommandList,
+        cache_entries: Vec<BitmapCacheEntry>,
+    ) {
+        for entry in cache_entries {
+            if !entry.filters.is_empty() {
+                self.skip_filters();
+            }
+            let bitmap = as_bitmap(&entry.handle);
+            let target = Target::new(
+                bitmap.width(),
+                bitmap.height(),
+                self.quality,
+                premultiplied_color(entry.clear),
+            );
+            self.draw_to_bitmap(&entry.handle, target, entry.commands);
+        }
+
+        let clear = premultiplied_color(clear);
+        let mut target = Target::new(
+            self.viewport_dimensions.width,
+            self.viewport_dimensions.height,
+            self.quality,
+            clear,
+        );
+        self.draw_commands(&mut target, commands);
+        self.frame = Some(target.resolve());
+    }
+
+    fn create_empty_texture(&mut self, width: u32, height: u32) -> Result<BitmapHandle, Error> {
+        if width == 0 || height == 0 {
+            return Err(Error::InvalidSize);
+        }
+        Ok(BitmapHandle(Arc::new(SoftwareBitmap::new(
+            width,
+            height,
+            vec![0; width as usize * height as usize * 4],
+        ))))
+    }
+
+    fn register_bitmap(&mut self, bitmap: Bitmap) -> Result<BitmapHandle, Error> {
+        let bitmap = bitmap.to_rgba();
+        Ok(BitmapHandle(Arc::new(SoftwareBitmap::new(
+            bitmap.width(),
+            bitmap.height(),
+            bitmap.data().to_vec(),
+        ))))
+    }
+
+    fn update_texture(
+        &mut self,
+        handle: &BitmapHandle,
+        bitmap: Bitmap,
+        region: PixelRegion,
+    ) -> Result<(), Error> {
+        let texture = as_bitmap(handle);
+        let bitmap = bitmap.to_rgba();
+        if bitmap.width() != texture.width() || bitmap.height() != texture.height() {
+            return Err(Error::InvalidSize);
+        }
+
+        let row_len = texture.width() as usize * 4;
+        let mut pixels = texture.pixels_mut();
+        for y in region.y_min..region.y_max {
+            let start = y as usize * row_len + region.x_min as usize * 4;
+            let end = y as usize * row_len + region.x_max as usize * 4;
+            pixels[start..end].copy_from_slice(&bitmap.data()[start..end]);
+        }
+        Ok(())
+    }
+
+    fn create_context3d(
+        &mut self,
+        _profile: Context3DProfile,
+    ) -> Result<Box<dyn Context3D>, Error> {
+        Err(Error::Unimplemented("createContext3D".into()))
+    }
+
+    fn context3d_present(&mut self, _context: &mut dyn Context3D) -> Result<(), Error> {
+        Err(Error::Unimplemented("Context3D.present".into()))
+    }
+
+    fn debug_info(&self) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "Renderer: Software\nSample count: {}",
+            crate::rasterizer::sample_positions(self.quality.sample_count()).len()
+        ))
+    }
+
+    fn name(&self) -> &'static str {
+        "software"
+    }
+
+    fn set_quality(&mut self, quality: StageQuality) {
+        self.quality = quality;
+    }
+
+    fn compile_pixelbender_shader(
+        &mut self,
+        shader: PixelBenderShader,
+    ) -> Result<PixelBenderShaderHandle, Error> {
+        Ok(PixelBenderShaderHandle(Arc::new(SoftwareShader(shader))))
+    }
+
+    fn run_pixelbender_shader(
+        &mut self,
+        handle: PixelBenderShaderHandle,
+        _arguments: &[PixelBenderShaderArgument],
+        target: &PixelBenderTarget,
+    ) -> Result<PixelBenderOutput, Error> {
+        // The target is left as it was, like a shader that draws nothing.
+        self.skip_filters();
+        Ok(match target {
+            PixelBenderTarget::Bitmap(handle) => {
+                let bitmap = as_bitmap(handle);
+                PixelBenderOutput::Bitmap(Box::new(SoftwareSyncHandle {
+                    handle: handle.clone(),
+                    bounds: PixelRegion::for_whole_size(bitmap.width(), bitmap.height()),
+                }))
+            }
+            PixelBenderTarget::Bytes { width, height } => {
+                let channels = handle
+                    .0
+                    .parsed_shader()
+                    .params
+                    .iter()
+                    .find_map(|param| match param {
+                        PixelBenderParam::Normal {
+                            qualifier: PixelBenderParamQualifier::Output,
+                            reg,
+                            ..
+                        } => Some(reg.channels.len()),
+                        _ => None,
+                    })
+                    .unwrap_or(4);
+                let len = *width as usize * *height as usize * channels;
+                PixelBenderOutput::Bytes(vec![0; len * std::mem::size_of::<f32>()])
+            }
+        })
+    }
+
+    fn resolve_sync_handle(
+        &mut self,
+        handle: Box<dyn SyncHandle>,
+        with_rgba: RgbaBufRead,
+    ) -> Result<(), Error> {
+        let handle = handle
+            .downcast::<SoftwareSyncHandle>()
+            .map_err(|_| Error::Unimplemented("Foreign sync handle".into()))?;
+        let bitmap = as_bitmap(&handle.handle);
+        let pixels = bitmap.pixels();
+        let bounds = handle.bounds;
+
+        let row_len = bitmap.width() as usize * 4;
+        let mut buffer = Vec::with_capacity(bounds.width() as usize * bounds.height() as usize * 4);
+        for y in bounds.y_min..bounds.y_max {
+            let start = y as usize * row_len + bounds.x_min as usize * 4;
+            let end = y as usize * row_len + bounds.x_max as usize * 4;
+            buffer.extend_from_slice(&pixels[start..end]);
+        }
+        with_rgba(&buffer, bounds.width() * 4);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ruffle_render::backend::null::NullBitmapSource;
+    use ruffle_render::commands::{CommandHandler, RenderBlendMode};
+    use ruffle_render::matrix::Matrix;
+    use ruffle_render::shape_utils::{DrawCommand, DrawPath, FillRule};
+    use swf::{BlendMode, FillStyle, Point, Rectangle, Twips};
+
+    fn rect_matrix(x: i32, y: i32, width: f32, height: f32) -> Matrix {
+        Matrix {
+            tx: Twips::from_pixels_i32(x),
+            ty: Twips::from_pixels_i32(y),
+            ..Matrix::scale(width, height)
+        }
+    }
+
+    fn pixel(image: &image::RgbaImage, x: u32, y: u32) -> [u8; 4] {
+        image.get_pixel(x, y).0
+    }
+
+    #[test]
+    fn draws_shapes() {
+        let mut renderer = SoftwareRenderBackend::new(4, 4);
+        let fill = FillStyle::Color(Color::RED);
+        let shape = DistilledShape {
+            paths: vec![DrawPath::Fill {
+                style: &fill,
+                commands: vec![
+                    DrawCommand::MoveTo(Point::new(Twips::ZERO, Twips::ZERO)),
+                    DrawCommand::LineTo(Point::new(Twips::from_pixels(2.0), Twips::ZERO)),
+                    DrawCommand::LineTo(Point::new(
+                        Twips::from_pixels(2.0),
+                        Twips::from_pixels(4.0),
+                    )),
+                    DrawCommand::LineTo(Point::new(Twips::ZERO, Twips::from_pixels(4.0))),
+                    DrawCommand::LineTo(Point::new(Twips::ZERO, Twips::ZERO)),
+                ],
+                winding_rule: FillRule::EvenOdd,
+            }],
+            shape_bounds: Rectangle::default(),
+            edge_bounds: Rectangle::default(),
+            id: 1,
+        };
+        let handle = renderer.register_shape(shape, &NullBitmapSource);
+
+        let mut commands = CommandList::new();
+        commands.render_shape(handle, Default::default());
+        renderer.submit_frame(Color::WHITE, commands, vec![]);
+
+        let image = renderer.capture_frame().unwrap();
+        assert_eq!(pixel(&image, 0, 0), [255, 0, 0, 255]);
+        assert_eq!(pixel(&image, 1, 3), [255, 0, 0, 255]);
+        assert_eq!(pixel(&image, 2, 0), [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn masks_clip_content() {
+        let mut renderer = SoftwareRenderBackend::new(4, 4);
+        let mut commands = CommandList::new();
+        commands.push_mask();
+        commands.draw_rect(Color::BLACK, rect_matrix(0, 0, 2.0, 4.0));
+        commands.activate_mask();
+        commands.draw_rect(Color::BLUE, rect_matrix(0, 0, 4.0, 4.0));
+        commands.deactivate_mask();
+        commands.draw_rect(Color::BLACK, rect_matrix(0, 0, 2.0, 4.0));
+        commands.pop_mask();
+        commands.draw_rect(Color::GREEN, rect_matrix(0, 3, 4.0, 1.0));
+        renderer.submit_frame(Color::WHITE, commands, vec![]);
+
+        let image = renderer.capture_frame().unwrap();
+        assert_eq!(pixel(&image, 1, 1), [0, 0, 255, 255]);
+        assert_eq!(pixel(&image, 3, 1), [255, 255, 255, 255]);
+        assert_eq!(pixel(&image, 3, 3), [0, 255, 0, 255]);
+    }
+
+    #[test]
+    fn blend_modes() {
+        let mut renderer = SoftwareRenderBackend::new(2, 1);
+        let mut layer = CommandList::new();
+        layer.draw_rect(Color::from_rgba(0xFF808080), rect_matrix(0, 0, 2.0, 1.0));
+        let mut commands = CommandList::new();
+        commands.draw_rect(Color::from_rgba(0xFF00FF40), rect_matrix(0, 0, 1.0, 1.0));
+        commands.blend(layer, RenderBlendMode::Builtin(BlendMode::Multiply));
+        renderer.submit_frame(Color::WHITE, commands, vec![]);
+
+        let image = renderer.capture_frame().unwrap();
+        assert_eq!(pixel(&image, 0, 0), [0, 128, 32, 255]);
+        assert_eq!(pixel(&image, 1, 0), [128, 128, 128, 255]);
+    }
+
+    #[test]
+    fn translucent_cache_clear_is_premultiplied() {
+        let mut renderer = SoftwareRenderBackend::new(1, 1);
+        let handle = renderer.create_empty_texture(1, 1).unwrap();
+        renderer.submit_frame(
+            Color::WHITE,
+            CommandList::new(),
+            vec![BitmapCacheEntry {
+                handle: handle.clone(),
+                commands: CommandList::new(),
+                clear: Color::from_rgba(0x80FF0000),
+                filters: vec![],
+            }],
+        );
+        assert_eq!(*as_bitmap(&handle).pixels(), [128, 0, 0, 128]);
+    }
+
+    #[test]
+    fn cache_filters_are_skipped() {
+        let mut renderer = SoftwareRenderBackend::new(1, 1);
+        let handle = renderer.create_empty_texture(1, 1).unwrap();
+        assert!(!renderer.skipped_filters());
+        renderer.submit_frame(
+            Color::WHITE,
+            CommandList::new(),
+            vec![BitmapCacheEntry {
+                handle,
+                commands: CommandList::new(),
+                clear: Color::from_rgba(0),
+                filters: vec![Filter::default()],
+            }],
+        );
+        assert!(renderer.skipped_filters());
+    }
+
+    #[test]
+    fn render_offscreen_updates_bitmap() {
+        let mut renderer = SoftwareRenderBackend::new(1, 1);
+        let handle = renderer.create_empty_texture(2, 2).unwrap();
+        let mut commands = CommandList::new();
+        commands.draw_rect(Color::RED, rect_matrix(1, 0, 1.0, 2.0));
+        let sync = renderer
+            .render_offscreen(
+                handle,
+                commands,
+                StageQuality::High,
+                PixelRegion::for_whole_size(2, 2),
+            )
+            .unwrap();
+
+        let mut result = vec![];
+        renderer
+            .resolve_sync_handle(
+                sync,
+                Box::new(|buffer, stride| {
+                    assert_eq!(stride, 8);
+                    result.extend_from_slice(buffer);
+                }),
+            )
+            .unwrap();
+        assert_eq!(
+            result,
+            [0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 255]
+        );
+    }
+}
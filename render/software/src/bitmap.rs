@@ -0,0 +1,108 @@
+use ruffle_render::bitmap::{BitmapHandle, BitmapHandleImpl};
+use std::cell::{Ref, RefCell, RefMut};
+
+/// A bitmap stored in main memory.
+///
+/// Pixels are stored as premultiplied RGBA, matching the layout that the other
+/// backends use for their textures.
+#[derive(Debug)]
+pub struct SoftwareBitmap {
+    width: u32,
+    height: u32,
+    pixels: RefCell<Vec<u8>>,
+}
+
+impl BitmapHandleImpl for SoftwareBitmap {}
+
+pub fn as_bitmap(handle: &BitmapHandle) -> &SoftwareBitmap {
+    <dyn BitmapHandleImpl>::downcast_ref(&*handle.0)
+        .expect("Bitmap handle must be a software bitmap")
+}
+
+impl SoftwareBitmap {
+    pub fn new(width: u32, height: u32, pixels: Vec<u8>) -> Self {
+        debug_assert_eq!(pixels.len(), (width * height * 4) as usize);
+        Self {
+            width,
+            height,
+            pixels: RefCell::new(pixels),
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn pixels(&self) -> Ref<'_, Vec<u8>> {
+        self.pixels.borrow()
+    }
+
+    pub fn pixels_mut(&self) -> RefMut<'_, Vec<u8>> {
+        self.pixels.borrow_mut()
+    }
+}
+
+/// Samples a premultiplied RGBA pixel buffer at the given normalized texture coordinates.
+///
+/// This mirrors the behaviour of a GPU sampler: `smoothed` selects bilinear filtering,
+/// and `repeating` selects between repeat and clamp-to-edge addressing.
+pub fn sample(
+    pixels: &[u8],
+    width: u32,
+    height: u32,
+    uv: [f32; 2],
+    smoothed: bool,
+    repeating: bool,
+) -> [f32; 4] {
+    if width == 0 || height == 0 {
+        return [0.0; 4];
+    }
+
+    let fetch = |x: i64, y: i64| -> [f32; 4] {
+        let (x, y) = if repeating {
+            (x.rem_euclid(width as i64), y.rem_euclid(height as i64))
+        } else {
+            (x.clamp(0, width as i64 - 1), y.clamp(0, height as i64 - 1))
+        };
+        let i = ((y as usize) * (width as usize) + (x as usize)) * 4;
+        [
+            f32::from(pixels[i]) / 255.0,
+            f32::from(pixels[i + 1]) / 255.0,
+            f32::from(pixels[i + 2]) / 255.0,
+            f32::from(pixels[i + 3]) / 255.0,
+        ]
+    };
+
+    let x = uv[0] * width as f32;
+    let y = uv[1] * height as f32;
+    if !x.is_finite() || !y.is_finite() {
+        return [0.0; 4];
+    }
+
+    if smoothed {
+        let x = x - 0.5;
+        let y = y - 0.5;
+        let x0 = x.floor();
+        let y0 = y.floor();
+        let fx = x - x0;
+        let fy = y - y0;
+        let (x0, y0) = (x0 as i64, y0 as i64);
+        let c00 = fetch(x0, y0);
+        let c10 = fetch(x0 + 1, y0);
+        let c01 = fetch(x0, y0 + 1);
+        let c11 = fetch(x0 + 1, y0 + 1);
+        let mut out = [0.0; 4];
+        for i in 0..4 {
+            let top = c00[i] + (c10[i] - c00[i]) * fx;
+            let bottom = c01[i] + (c11[i] - c01[i]) * fx;
+            out[i] = top + (bottom - top) * fy;
+        }
+        out
+    } else {
+        fetch(x.floor() as i64, y.floor() as i64)
+    }
+}
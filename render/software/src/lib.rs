@@ -0,0 +1,17 @@
+//! A CPU-only implementation of Ruffle's `RenderBackend`.
+//!
+//! This renderer doesn't need a graphics device, which makes it suitable for
+//! headless environments such as batch exports and CI machines without a GPU.
+//! It trades speed for portability: shapes are tessellated with the shared
+//! `ShapeTessellator` and the resulting triangles are rasterized one sample at a time.
+
+// Bitmaps and shapes are only ever used from the thread that owns the renderer.
+#![allow(clippy::arc_with_non_send_sync)]
+
+pub mod backend;
+mod bitmap;
+mod mesh;
+mod rasterizer;
+mod target;
+
+pub use rasterizer::MAX_SAMPLE_COUNT;
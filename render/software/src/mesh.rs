@@ -0,0 +1,189 @@
+use ruffle_render::backend::ShapeHandleImpl;
+use ruffle_render::bitmap::BitmapHandle;
+use ruffle_render::shape_utils::GradientType;
+use ruffle_render::tessellator::{Gradient, Vertex};
+use std::sync::Arc;
+use swf::{GradientInterpolation, GradientSpread};
+
+const GRADIENT_SIZE: usize = 256;
+
+#[derive(Debug)]
+pub struct Mesh {
+    pub draws: Vec<Draw>,
+}
+
+impl ShapeHandleImpl for Mesh {}
+
+#[derive(Debug)]
+pub struct Draw {
+    pub paint: Paint,
+    pub vertices: Vec<Vertex>,
+    pub indices: Vec<u32>,
+    pub num_mask_indices: usize,
+}
+
+#[derive(Debug)]
+pub enum Paint {
+    Color,
+    Gradient {
+        matrix: [[f32; 3]; 3],
+        gradient: Arc<GradientRamp>,
+    },
+    Bitmap {
+        matrix: [[f32; 3]; 3],
+        bitmap: BitmapHandle,
+        is_smoothed: bool,
+        is_repeating: bool,
+    },
+}
+
+/// Transforms an object space point into texture space with a column-major 3x3 matrix,
+/// as produced by the tessellator.
+pub fn texture_coords(matrix: &[[f32; 3]; 3], x: f32, y: f32) -> [f32; 2] {
+    [
+        matrix[0][0] * x + matrix[1][0] * y + matrix[2][0],
+        matrix[0][1] * x + matrix[1][1] * y + matrix[2][1],
+    ]
+}
+
+/// A gradient baked into a lookup table of straight alpha colors.
+#[derive(Debug)]
+pub struct GradientRamp {
+    colors: Vec<[f32; 4]>,
+    gradient_type: GradientType,
+    repeat_mode: GradientSpread,
+    focal_point: f32,
+    linear_rgb: bool,
+}
+
+/// Converts a color channel from sRGB space to linear color space.
+fn srgb_to_linear(color: f32) -> f32 {
+    if color <= 0.04045 {
+        color / 12.92
+    } else {
+        f32::powf((color + 0.055) / 1.055, 2.4)
+    }
+}
+
+/// Converts a color channel from linear color space to sRGB space.
+fn linear_to_srgb(color: f32) -> f32 {
+    if color <= 0.0031308 {
+        color * 12.92
+    } else {
+        1.055 * f32::powf(color, 1.0 / 2.4) - 0.055
+    }
+}
+
+impl GradientRamp {
+    pub fn new(gradient: Gradient) -> Self {
+        let linear_rgb = gradient.interpolation == GradientInterpolation::LinearRgb;
+        let convert = |c: u8| {
+            let c = f32::from(c) / 255.0;
+            if linear_rgb {
+                srgb_to_linear(c)
+            } else {
+                c
+            }
+        };
+
+        let mut colors = vec![[0.0; 4]; GRADIENT_SIZE];
+        if !gradient.records.is_empty() {
+            for (t, color) in colors.iter_mut().enumerate() {
+                let mut last = 0;
+                let mut next = 0;
+                for (i, record) in gradient.records.iter().enumerate().rev() {
+                    if (record.ratio as usize) < t {
+                        last = i;
+                        next = (i + 1).min(gradient.records.len() - 1);
+                        break;
+                    }
+                }
+
+                let last_record = &gradient.records[last];
+                let next_record = &gradient.records[next];
+                let a = if next == last {
+                    // This can happen if we are before the first gradient record, or after the last one.
+                    0.0
+                } else {
+                    (t as f32 - f32::from(last_record.ratio))
+                        / (f32::from(next_record.ratio) - f32::from(last_record.ratio))
+                };
+                let lerp = |from: f32, to: f32| from + (to - from) * a;
+                *color = [
+                    lerp(convert(last_record.color.r), convert(next_record.color.r)),
+                    lerp(convert(last_record.color.g), convert(next_record.color.g)),
+                    lerp(convert(last_record.color.b), convert(next_record.color.b)),
+                    lerp(
+                        f32::from(last_record.color.a) / 255.0,
+                        f32::from(next_record.color.a) / 255.0,
+                    ),
+                ];
+            }
+        }
+
+        Self {
+            colors,
+            gradient_type: gradient.gradient_type,
+            repeat_mode: gradient.repeat_mode,
+            focal_point: gradient.focal_point.to_f32().clamp(-0.98, 0.98),
+            linear_rgb,
+        }
+    }
+
+    /// Returns the straight alpha color of the gradient at the given gradient space coordinates.
+    pub fn color_at(&self, uv: [f32; 2]) -> [f32; 4] {
+        let t = match self.gradient_type {
+            GradientType::Linear => uv[0],
+            GradientType::Radial => {
+                let (x, y) = (uv[0] * 2.0 - 1.0, uv[1] * 2.0 - 1.0);
+                (x * x + y * y).sqrt()
+            }
+            GradientType::Focal => {
+                let (x, y) = (uv[0] * 2.0 - 1.0, uv[1] * 2.0 - 1.0);
+                let focal = self.focal_point;
+                let (dx, dy) = (focal - x, -y);
+                let l = (dx * dx + dy * dy).sqrt();
+                if l > 0.0 {
+                    let (dx, dy) = (dx / l, dy / l);
+                    l / ((1.0 - focal * focal * dy * dy).sqrt() + focal * dx)
+                } else {
+                    0.0
+                }
+            }
+        };
+
+        let t = match self.repeat_mode {
+            GradientSpread::Pad => t.clamp(0.0, 1.0),
+            GradientSpread::Reflect => {
+                let t = t.abs();
+                if (t as i32) & 1 == 0 {
+                    t.fract()
+                } else {
+                    1.0 - t.fract()
+                }
+            }
+            GradientSpread::Repeat => t.rem_euclid(1.0),
+        };
+        if !t.is_finite() {
+            return [0.0; 4];
+        }
+
+        // Linearly sample the ramp, like a texture sampler would.
+        let x = (t * GRADIENT_SIZE as f32 - 0.5).clamp(0.0, (GRADIENT_SIZE - 1) as f32);
+        let i = x as usize;
+        let j = (i + 1).min(GRADIENT_SIZE - 1);
+        let f = x - i as f32;
+        let (a, b) = (self.colors[i], self.colors[j]);
+        let mut color = [0.0; 4];
+        for c in 0..4 {
+            color[c] = a[c] + (b[c] - a[c]) * f;
+        }
+
+        if self.linear_rgb {
+            for c in &mut color[..3] {
+                *c = linear_to_srgb(*c);
+            }
+        }
+        color
+    }
+}
@@ -0,0 +1,123 @@
+/// The largest number of samples per pixel the software renderer will use.
+///
+/// Higher stage qualities are clamped to this, as every sample costs memory and time on the CPU.
+pub const MAX_SAMPLE_COUNT: u32 = 4;
+
+/// Standard multisample positions, in fractions of a pixel.
+const SAMPLES_1X: [(f32, f32); 1] = [(0.5, 0.5)];
+const SAMPLES_2X: [(f32, f32); 2] = [(0.25, 0.25), (0.75, 0.75)];
+const SAMPLES_4X: [(f32, f32); 4] = [
+    (0.375, 0.125),
+    (0.875, 0.375),
+    (0.125, 0.625),
+    (0.625, 0.875),
+];
+
+/// Returns the sample positions used for the given sample count.
+pub fn sample_positions(sample_count: u32) -> &'static [(f32, f32)] {
+    match sample_count.min(MAX_SAMPLE_COUNT) {
+        0 | 1 => &SAMPLES_1X,
+        2 | 3 => &SAMPLES_2X,
+        _ => &SAMPLES_4X,
+    }
+}
+
+/// Whether an edge (of a triangle with positive area) owns the samples lying exactly on it.
+///
+/// This is the usual "top-left" fill rule, which guarantees that triangles sharing an edge
+/// never cover the same sample twice.
+fn is_top_left(a: [f64; 2], b: [f64; 2]) -> bool {
+    let dx = b[0] - a[0];
+    let dy = b[1] - a[1];
+    (dy == 0.0 && dx > 0.0) || dy < 0.0
+}
+
+/// Rasterizes a triangle in pixel space.
+///
+/// `emit` is called once for every pixel touched by the triangle,
+/// with a bitmask of the covered sample positions.
+pub fn rasterize_triangle(
+    width: u32,
+    height: u32,
+    samples: &[(f32, f32)],
+    points: [[f32; 2]; 3],
+    mut emit: impl FnMut(u32, u32, u32),
+) {
+    let mut p = points.map(|p| [f64::from(p[0]), f64::from(p[1])]);
+    if p.iter().flatten().any(|c| !c.is_finite()) {
+        return;
+    }
+
+    let area =
+        (p[1][0] - p[0][0]) * (p[2][1] - p[0][1]) - (p[1][1] - p[0][1]) * (p[2][0] - p[0][0]);
+    if area == 0.0 {
+        return;
+    }
+    if area < 0.0 {
+        p.swap(1, 2);
+    }
+
+    let edges = [(p[0], p[1]), (p[1], p[2]), (p[2], p[0])];
+    let biases = edges.map(|(a, b)| is_top_left(a, b));
+
+    let min_x = p.iter().map(|p| p[0]).fold(f64::INFINITY, f64::min);
+    let max_x = p.iter().map(|p| p[0]).fold(f64::NEG_INFINITY, f64::max);
+    let min_y = p.iter().map(|p| p[1]).fold(f64::INFINITY, f64::min);
+    let max_y = p.iter().map(|p| p[1]).fold(f64::NEG_INFINITY, f64::max);
+
+    let x_start = min_x.floor().max(0.0) as u32;
+    let x_end = (max_x.ceil().max(0.0) as u32).min(width);
+    let y_start = min_y.floor().max(0.0) as u32;
+    let y_end = (max_y.ceil().max(0.0) as u32).min(height);
+
+    for y in y_start..y_end {
+        for x in x_start..x_end {
+            let mut coverage = 0;
+            for (i, (sx, sy)) in samples.iter().enumerate() {
+                let px = f64::from(x) + f64::from(*sx);
+                let py = f64::from(y) + f64::from(*sy);
+                let inside = edges.iter().zip(biases).all(|((a, b), owns_edge)| {
+                    let e = (b[0] - a[0]) * (py - a[1]) - (b[1] - a[1]) * (px - a[0]);
+                    e > 0.0 || (e == 0.0 && owns_edge)
+                });
+                if inside {
+                    coverage |= 1 << i;
+                }
+            }
+            if coverage != 0 {
+                emit(x, y, coverage);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shared_edges_are_covered_once() {
+        let mut hits = [0u32; 16];
+        let quad = [[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]];
+        for triangle in [[quad[0], quad[1], quad[2]], [quad[0], quad[2], quad[3]]] {
+            rasterize_triangle(4, 4, &SAMPLES_1X, triangle, |x, y, coverage| {
+                assert_eq!(coverage, 1);
+                hits[(y * 4 + x) as usize] += 1;
+            });
+        }
+        assert!(hits.iter().all(|&hit| hit == 1));
+    }
+
+    #[test]
+    fn partial_coverage() {
+        let mut covered = vec![];
+        rasterize_triangle(
+            2,
+            1,
+            &SAMPLES_4X,
+            [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]],
+            |x, y, coverage| covered.push((x, y, coverage.count_ones())),
+        );
+        assert_eq!(covered, vec![(0, 0, 2)]);
+    }
+}
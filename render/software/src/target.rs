@@ -0,0 +1,469 @@
+use crate::bitmap::{as_bitmap, sample};
+use crate::mesh::{texture_coords, Mesh, Paint};
+use crate::rasterizer::{rasterize_triangle, sample_positions};
+use ruffle_render::backend::ShapeHandle;
+use ruffle_render::bitmap::BitmapHandle;
+use ruffle_render::commands::{Command, CommandList, RenderBlendMode};
+use ruffle_render::matrix::Matrix;
+use ruffle_render::quality::StageQuality;
+use ruffle_render::transform::Transform;
+use swf::{BlendMode, Color, ColorTransform};
+
+/// How draws currently interact with the stencil buffer.
+/// This follows the same scheme as the wgpu backend.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum MaskState {
+    NoMask,
+    DrawMaskStencil,
+    DrawMaskedContent,
+    ClearMaskStencil,
+}
+
+/// A multisampled render target living in main memory.
+pub struct Target {
+    width: u32,
+    height: u32,
+    quality: StageQuality,
+    samples: &'static [(f32, f32)],
+
+    /// Premultiplied RGBA colors, one entry per sample.
+    color: Vec<[f32; 4]>,
+    stencil: Vec<u8>,
+
+    num_masks: u8,
+    mask_state: MaskState,
+
+    /// Whether a shader blend mode was drawn as a normal blend instead.
+    skipped_shaders: bool,
+}
+
+/// A 2D affine transform in pixel units, stored as `[a, b, c, d, tx, ty]`.
+type PixelMatrix = [f32; 6];
+
+fn pixel_matrix(matrix: &Matrix) -> PixelMatrix {
+    [
+        matrix.a,
+        matrix.b,
+        matrix.c,
+        matrix.d,
+        matrix.tx.to_pixels() as f32,
+        matrix.ty.to_pixels() as f32,
+    ]
+}
+
+fn transform_point(m: &PixelMatrix, x: f32, y: f32) -> [f32; 2] {
+    [m[0] * x + m[2] * y + m[4], m[1] * x + m[3] * y + m[5]]
+}
+
+fn invert(m: &PixelMatrix) -> Option<PixelMatrix> {
+    let det = m[0] * m[3] - m[1] * m[2];
+    if det == 0.0 || !det.is_finite() {
+        return None;
+    }
+    let a = m[3] / det;
+    let b = -m[1] / det;
+    let c = -m[2] / det;
+    let d = m[0] / det;
+    Some([a, b, c, d, -(a * m[4] + c * m[5]), -(b * m[4] + d * m[5])])
+}
+
+/// Applies a color transform to a straight alpha color, returning a premultiplied color.
+fn transform_color(color: [f32; 4], color_transform: &ColorTransform) -> [f32; 4] {
+    let mult = color_transform.mult_rgba_normalized();
+    let add = color_transform.add_rgba_normalized();
+    let mut out = [0.0; 4];
+    for i in 0..4 {
+        out[i] = (color[i] * mult[i] + add[i]).clamp(0.0, 1.0);
+    }
+    [out[0] * out[3], out[1] * out[3], out[2] * out[3], out[3]]
+}
+
+/// Applies a color transform to a premultiplied color.
+fn transform_premultiplied(color: [f32; 4], color_transform: &ColorTransform) -> [f32; 4] {
+    if color[3] <= 0.0 {
+        return color;
+    }
+    let a = color[3];
+    transform_color(
+        [color[0] / a, color[1] / a, color[2] / a, a],
+        color_transform,
+    )
+}
+
+fn straight_color(color: Color) -> [f32; 4] {
+    [
+        f32::from(color.r) / 255.0,
+        f32::from(color.g) / 255.0,
+        f32::from(color.b) / 255.0,
+        f32::from(color.a) / 255.0,
+    ]
+}
+
+fn over(src: [f32; 4], dst: [f32; 4]) -> [f32; 4] {
+    let inv = 1.0 - src[3];
+    [
+        src[0] + dst[0] * inv,
+        src[1] + dst[1] * inv,
+        src[2] + dst[2] * inv,
+        src[3] + dst[3] * inv,
+    ]
+}
+
+/// Blends `src` onto `dst` with a separable blend function, using the same formula as the
+/// complex blend shaders of the wgpu backend.
+fn separable(src: [f32; 4], dst: [f32; 4], f: impl Fn(f32, f32) -> f32) -> [f32; 4] {
+    let (sa, da) = (src[3], dst[3]);
+    let mut out = [0.0, 0.0, 0.0, sa + da * (1.0 - sa)];
+    for i in 0..3 {
+        let s = src[i] / sa;
+        let d = if da > 0.0 { dst[i] / da } else { 0.0 };
+        out[i] = src[i] * (1.0 - da) + dst[i] * (1.0 - sa) + sa * da * f(s, d);
+    }
+    out
+}
+
+fn blend(mode: BlendMode, src: [f32; 4], dst: [f32; 4]) -> [f32; 4] {
+    // Fully transparent source pixels never modify the destination.
+    if src[3] <= 0.0 {
+        return dst;
+    }
+    let out = match mode {
+        BlendMode::Normal | BlendMode::Layer => over(src, dst),
+        BlendMode::Add => [
+            src[0] + dst[0],
+            src[1] + dst[1],
+            src[2] + dst[2],
+            src[3] + dst[3] * (1.0 - src[3]),
+        ],
+        BlendMode::Subtract => [
+            dst[0] - src[0],
+            dst[1] - src[1],
+            dst[2] - src[2],
+            src[3] + dst[3] * (1.0 - src[3]),
+        ],
+        BlendMode::Screen => [
+            src[0] + dst[0] * (1.0 - src[0]),
+            src[1] + dst[1] * (1.0 - src[1]),
+            src[2] + dst[2] * (1.0 - src[2]),
+            src[3] + dst[3] * (1.0 - src[3]),
+        ],
+        // Flash only multiplies onto pixels that already have some coverage.
+        BlendMode::Multiply if dst[3] <= 0.0 => src,
+        BlendMode::Multiply => separable(src, dst, |s, d| s * d),
+        BlendMode::Lighten => separable(src, dst, f32::max),
+        BlendMode::Darken => separable(src, dst, f32::min),
+        BlendMode::Difference => separable(src, dst, |s, d| (d - s).abs()),
+        BlendMode::Invert => separable(src, dst, |_, d| 1.0 - d),
+        BlendMode::Overlay => separable(src, dst, |s, d| {
+            if d <= 0.5 {
+                2.0 * s * d
+            } else {
+                1.0 - 2.0 * (1.0 - d) * (1.0 - s)
+            }
+        }),
+        BlendMode::HardLight => separable(src, dst, |s, d| {
+            if s <= 0.5 {
+                2.0 * s * d
+            } else {
+                1.0 - 2.0 * (1.0 - d) * (1.0 - s)
+            }
+        }),
+        BlendMode::Alpha => [
+            dst[0] * src[3],
+            dst[1] * src[3],
+            dst[2] * src[3],
+            dst[3] * src[3],
+        ],
+        BlendMode::Erase => {
+            let inv = 1.0 - src[3];
+            [dst[0] * inv, dst[1] * inv, dst[2] * inv, dst[3] * inv]
+        }
+    };
+    out.map(|c| c.clamp(0.0, 1.0))
+}
+
+impl Target {
+    /// Creates a target filled with the given premultiplied color.
+    pub fn new(width: u32, height: u32, quality: StageQuality, clear: [f32; 4]) -> Self {
+        let samples = sample_positions(quality.sample_count());
+        let len = width as usize * height as usize * samples.len();
+        Self {
+            width,
+            height,
+            quality,
+            samples,
+            color: vec![clear; len],
+            stencil: vec![0; len],
+            num_masks: 0,
+            mask_state: MaskState::NoMask,
+            skipped_shaders: false,
+        }
+    }
+
+    /// Creates a target from existing premultiplied RGBA pixels.
+    pub fn from_pixels(width: u32, height: u32, quality: StageQuality, pixels: &[u8]) -> Self {
+        let mut target = Self::new(width, height, quality, [0.0; 4]);
+        let samples = target.samples.len();
+        for (pixel, colors) in pixels
+            .chunks_exact(4)
+            .zip(target.color.chunks_exact_mut(samples))
+        {
+            colors.fill([
+                f32::from(pixel[0]) / 255.0,
+                f32::from(pixel[1]) / 255.0,
+                f32::from(pixel[2]) / 255.0,
+                f32::from(pixel[3]) / 255.0,
+            ]);
+        }
+        target
+    }
+
+    /// Whether anything was drawn without the shader of its blend mode.
+    pub fn skipped_shaders(&self) -> bool {
+        self.skipped_shaders
+    }
+
+    /// Averages the samples of every pixel, producing premultiplied RGBA pixels.
+    pub fn resolve(&self) -> Vec<u8> {
+        let samples = self.samples.len();
+        let mut out = Vec::with_capacity(self.width as usize * self.height as usize * 4);
+        for colors in self.color.chunks_exact(samples) {
+            let mut sum = [0.0; 4];
+            for color in colors {
+                for i in 0..4 {
+                    sum[i] += color[i];
+                }
+            }
+            out.extend(sum.map(|c| ((c / samples as f32).clamp(0.0, 1.0) * 255.0).round() as u8));
+        }
+        out
+    }
+
+    pub fn draw_commands(&mut self, commands: CommandList) {
+        for command in commands.commands {
+            match command {
+                Command::RenderBitmap {
+                    bitmap,
+                    transform,
+                    smoothing,
+                    pixel_snapping,
+                } => {
+                    let mut matrix = transform.matrix;
+                    pixel_snapping.apply(&mut matrix);
+                    self.draw_bitmap(&bitmap, matrix, &transform.color_transform, smoothing);
+                }
+                Command::RenderStage3D { bitmap, transform } => {
+                    self.draw_bitmap(&bitmap, transform.matrix, &transform.color_transform, false);
+                }
+                Command::RenderShape { shape, transform } => self.draw_shape(&shape, &transform),
+                Command::DrawRect { color, matrix } => self.draw_rect(color, &matrix),
+                Command::PushMask => {
+                    self.num_masks += 1;
+                    self.mask_state = MaskState::DrawMaskStencil;
+                }
+                Command::ActivateMask => self.mask_state = MaskState::DrawMaskedContent,
+                Command::DeactivateMask => self.mask_state = MaskState::ClearMaskStencil,
+                Command::PopMask => {
+                    self.num_masks -= 1;
+                    self.mask_state = if self.num_masks == 0 {
+                        MaskState::NoMask
+                    } else {
+                        MaskState::DrawMaskedContent
+                    };
+                }
+                Command::Blend(commands, blend_mode) => self.draw_blend(commands, blend_mode),
+            }
+        }
+    }
+
+    fn writes_color(&self) -> bool {
+        matches!(
+            self.mask_state,
+            MaskState::NoMask | MaskState::DrawMaskedContent
+        )
+    }
+
+    /// Updates a single sample, honoring the current mask state.
+    /// `color` is only evaluated if the sample passes the stencil test and color is written.
+    fn write_sample(&mut self, i: usize, mode: BlendMode, color: impl FnOnce() -> [f32; 4]) {
+        match self.mask_state {
+            MaskState::NoMask => self.color[i] = blend(mode, color(), self.color[i]),
+            MaskState::DrawMaskedContent => {
+                if self.stencil[i] == self.num_masks {
+                    self.color[i] = blend(mode, color(), self.color[i]);
+                }
+            }
+            MaskState::DrawMaskStencil => {
+                if self.stencil[i] == self.num_masks - 1 {
+                    self.stencil[i] = self.stencil[i].saturating_add(1);
+                }
+            }
+            MaskState::ClearMaskStencil => {
+                if self.stencil[i] == self.num_masks {
+                    self.stencil[i] = self.stencil[i].saturating_sub(1);
+                }
+            }
+        }
+    }
+
+    /// Fills a triangle given in pixel space, evaluating `shade` at the center of every covered pixel.
+    fn fill_triangle(&mut self, points: [[f32; 2]; 3], shade: impl Fn(f32, f32) -> [f32; 4]) {
+        let (width, height, samples) = (self.width, self.height, self.samples);
+        let writes_color = self.writes_color();
+        rasterize_triangle(width, height, samples, points, |x, y, coverage| {
+            let color = if writes_color {
+                shade(x as f32 + 0.5, y as f32 + 0.5)
+            } else {
+                [0.0; 4]
+            };
+            let base = (y as usize * width as usize + x as usize) * samples.len();
+            for s in 0..samples.len() {
+                if coverage & (1 << s) != 0 {
+                    self.write_sample(base + s, BlendMode::Normal, || color);
+                }
+            }
+        });
+    }
+
+    fn draw_shape(&mut self, shape: &ShapeHandle, transform: &Transform) {
+        let Some(mesh) = shape.0.downcast_ref::<Mesh>() else {
+            return;
+        };
+        let matrix = pixel_matrix(&transform.matrix);
+        let inverse = invert(&matrix);
+        let color_transform = &transform.color_transform;
+
+        for draw in &mesh.draws {
+            let num_indices = if self.writes_color() {
+                draw.indices.len()
+            } else {
+                // Strokes are omitted when drawing a mask.
+                draw.num_mask_indices
+            };
+            let positions: Vec<[f32; 2]> = draw
+                .vertices
+                .iter()
+                .map(|v| transform_point(&matrix, v.x, v.y))
+                .collect();
+            let triangles = draw.indices[..num_indices]
+                .chunks_exact(3)
+                .map(|t| t.iter().map(|i| *i as usize).collect::<Vec<_>>());
+
+            match &draw.paint {
+                Paint::Color => {
+                    for t in triangles {
+                        let color = transform_color(
+                            straight_color(draw.vertices[t[0]].color),
+                            color_transform,
+                        );
+                        self.fill_triangle(
+                            [positions[t[0]], positions[t[1]], positions[t[2]]],
+                            |_, _| color,
+                        );
+                    }
+                }
+                Paint::Gradient { matrix, gradient } => {
+                    let Some(inverse) = inverse else { continue };
+                    for t in triangles {
+                        self.fill_triangle(
+                            [positions[t[0]], positions[t[1]], positions[t[2]]],
+                            |x, y| {
+                                let [ox, oy] = transform_point(&inverse, x, y);
+                                let uv = texture_coords(matrix, ox, oy);
+                                transform_color(gradient.color_at(uv), color_transform)
+                            },
+                        );
+                    }
+                }
+                Paint::Bitmap {
+                    matrix,
+                    bitmap,
+                    is_smoothed,
+                    is_repeating,
+                } => {
+                    let Some(inverse) = inverse else { continue };
+                    let bitmap = as_bitmap(bitmap);
+                    let pixels = bitmap.pixels();
+                    let smoothed = *is_smoothed && self.quality != StageQuality::Low;
+                    for t in triangles {
+                        self.fill_triangle(
+                            [positions[t[0]], positions[t[1]], positions[t[2]]],
+                            |x, y| {
+                                let [ox, oy] = transform_point(&inverse, x, y);
+                                let uv = texture_coords(matrix, ox, oy);
+                                let color = sample(
+                                    &pixels,
+                                    bitmap.width(),
+                                    bitmap.height(),
+                                    uv,
+                                    smoothed,
+                                    *is_repeating,
+                                );
+                                transform_premultiplied(color, color_transform)
+                            },
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Fills the quad spanning the unit square transformed by `matrix`.
+    fn fill_quad(&mut self, matrix: &PixelMatrix, shade: impl Fn(f32, f32) -> [f32; 4]) {
+        let corners = [
+            transform_point(matrix, 0.0, 0.0),
+            transform_point(matrix, 1.0, 0.0),
+            transform_point(matrix, 1.0, 1.0),
+            transform_point(matrix, 0.0, 1.0),
+        ];
+        self.fill_triangle([corners[0], corners[1], corners[2]], &shade);
+        self.fill_triangle([corners[0], corners[2], corners[3]], &shade);
+    }
+
+    fn draw_rect(&mut self, color: Color, matrix: &Matrix) {
+        let color = transform_color(straight_color(color), &ColorTransform::IDENTITY);
+        self.fill_quad(&pixel_matrix(matrix), |_, _| color);
+    }
+
+    fn draw_bitmap(
+        &mut self,
+        bitmap: &BitmapHandle,
+        matrix: Matrix,
+        color_transform: &ColorTransform,
+        smoothing: bool,
+    ) {
+        let bitmap = as_bitmap(bitmap);
+        let (width, height) = (bitmap.width(), bitmap.height());
+        let matrix = pixel_matrix(&(matrix * Matrix::scale(width as f32, height as f32)));
+        let Some(inverse) = invert(&matrix) else {
+            return;
+        };
+        let pixels = bitmap.pixels();
+        let smoothing = smoothing && self.quality != StageQuality::Low;
+        self.fill_quad(&matrix, |x, y| {
+            let uv = transform_point(&inverse, x, y);
+            let color = sample(&pixels, width, height, uv, smoothing, false);
+            transform_premultiplied(color, color_transform)
+        });
+    }
+
+    fn draw_blend(&mut self, commands: CommandList, blend_mode: RenderBlendMode) {
+        let mode = match blend_mode {
+            RenderBlendMode::Builtin(mode) => mode,
+            RenderBlendMode::Shader(_) => {
+                self.skipped_shaders = true;
+                BlendMode::Normal
+            }
+        };
+
+        let mut layer = Self::new(self.width, self.height, self.quality, [0.0; 4]);
+        layer.draw_commands(commands);
+        self.skipped_shaders |= layer.skipped_shaders;
+
+        for (i, src) in layer.color.into_iter().enumerate() {
+            if self.writes_color() || src[3] > 0.0 {
+                self.write_sample(i, mode, || src);
+            }
+        }
+    }
+}
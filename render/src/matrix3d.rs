@@ -0,0 +1,464 @@
+//! The 3D transforms of display objects, introduced by Flash Player 10.
+//!
+//! Display objects with a 3D transform are still drawn in 2D: they are
+//! projected onto the plane of their parent with a perspective projection,
+//! and their content is drawn as a bitmap that's split into tiles, which are
+//! each close enough to an affine transform.
+
+use crate::matrix::Matrix;
+use swf::Twips;
+
+/// A 3D transformation matrix, in the same column-major order as
+/// `flash.geom.Matrix3D.rawData`. Translations are in pixels.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Matrix3D {
+    pub raw_data: [f64; 16],
+}
+
+/// The components of a `Matrix3D`, in the order they are applied:
+/// scale, then rotation around the X, Y and Z axes, then translation.
+///
+/// Rotations are in degrees.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Components3D {
+    pub translation: [f64; 3],
+    pub rotation: [f64; 3],
+    pub scale: [f64; 3],
+}
+
+impl Matrix3D {
+    pub const IDENTITY: Self = Self {
+        raw_data: [
+            1.0, 0.0, 0.0, 0.0, //
+            0.0, 1.0, 0.0, 0.0, //
+            0.0, 0.0, 1.0, 0.0, //
+            0.0, 0.0, 0.0, 1.0, //
+        ],
+    };
+
+    /// Creates a 3D matrix which transforms the plane `z = 0` like the given 2D matrix.
+    pub fn from_2d(matrix: &Matrix) -> Self {
+        let mut raw_data = Self::IDENTITY.raw_data;
+        raw_data[0] = matrix.a.into();
+        raw_data[1] = matrix.b.into();
+        raw_data[4] = matrix.c.into();
+        raw_data[5] = matrix.d.into();
+        raw_data[12] = matrix.tx.to_pixels();
+        raw_data[13] = matrix.ty.to_pixels();
+        Self { raw_data }
+    }
+
+    /// The 2D part of this matrix, which is how it transforms the plane `z = 0`
+    /// when the depth is ignored.
+    pub fn to_2d(&self) -> Matrix {
+        Matrix {
+            a: self.raw_data[0] as f32,
+            b: self.raw_data[1] as f32,
+            c: self.raw_data[4] as f32,
+            d: self.raw_data[5] as f32,
+            tx: Twips::from_pixels(self.raw_data[12]),
+            ty: Twips::from_pixels(self.raw_data[13]),
+        }
+    }
+
+    pub fn from_components(components: &Components3D) -> Self {
+        let [rx, ry, rz] = components.rotation.map(f64::to_radians);
+        let [sx, sy, sz] = components.scale;
+        let [tx, ty, tz] = components.translation;
+        let (sin_x, cos_x) = rx.sin_cos();
+        let (sin_y, cos_y) = ry.sin_cos();
+        let (sin_z, cos_z) = rz.sin_cos();
+
+        // The columns of Rz * Ry * Rx, scaled.
+        Self {
+            raw_data: [
+                cos_z * cos_y * sx,
+                sin_z * cos_y * sx,
+                -sin_y * sx,
+                0.0,
+                (cos_z * sin_y * sin_x - sin_z * cos_x) * sy,
+                (sin_z * sin_y * sin_x + cos_z * cos_x) * sy,
+                cos_y * sin_x * sy,
+                0.0,
+                (cos_z * sin_y * cos_x + sin_z * sin_x) * sz,
+                (sin_z * sin_y * cos_x - cos_z * sin_x) * sz,
+                cos_y * cos_x * sz,
+                0.0,
+                tx,
+                ty,
+                tz,
+                1.0,
+            ],
+        }
+    }
+
+    /// Splits this matrix into its components, like `Matrix3D.decompose`.
+    pub fn components(&self) -> Components3D {
+        let m = &self.raw_data;
+        let column = |i: usize| [m[i * 4], m[i * 4 + 1], m[i * 4 + 2]];
+        let length = |c: [f64; 3]| (c[0] * c[0] + c[1] * c[1] + c[2] * c[2]).sqrt();
+        let normalize = |c: [f64; 3], length: f64| {
+            if length != 0.0 {
+                c.map(|v| v / length)
+            } else {
+                c
+            }
+        };
+
+        let (x, y, z) = (column(0), column(1), column(2));
+        let (sx, sy, mut sz) = (length(x), length(y), length(z));
+        let determinant = x[0] * (y[1] * z[2] - y[2] * z[1]) - y[0] * (x[1] * z[2] - x[2] * z[1])
+            + z[0] * (x[1] * y[2] - x[2] * y[1]);
+        if determinant < 0.0 {
+            sz = -sz;
+        }
+        let (x, y, z) = (normalize(x, sx), normalize(y, sy), normalize(z, sz));
+
+        let ry = (-x[2]).clamp(-1.0, 1.0).asin();
+        let (rx, rz) = if ry.cos().abs() > 1e-6 {
+            (y[2].atan2(z[2]), x[1].atan2(x[0]))
+        } else {
+            // Gimbal lock: only the difference of the X and Z rotations matters.
+            (0.0, (-y[0]).atan2(y[1]))
+        };
+
+        Components3D {
+            translation: [m[12], m[13], m[14]],
+            rotation: [rx, ry, rz].map(f64::to_degrees),
+            scale: [sx, sy, sz],
+        }
+    }
+
+    /// The inverse of this matrix, ignoring its projective row like display objects do.
+    pub fn inverse(&self) -> Option<Self> {
+        let m = &self.raw_data;
+        let (a, b, c) = (m[0], m[4], m[8]);
+        let (d, e, f) = (m[1], m[5], m[9]);
+        let (g, h, i) = (m[2], m[6], m[10]);
+        let determinant = a * (e * i - f * h) - b * (d * i - f * g) + c * (d * h - e * g);
+        if determinant.abs() < f64::EPSILON {
+            return None;
+        }
+
+        // The adjugate, in column-major order.
+        let inverse = [
+            (e * i - f * h) / determinant,
+            (f * g - d * i) / determinant,
+            (d * h - e * g) / determinant,
+            (c * h - b * i) / determinant,
+            (a * i - c * g) / determinant,
+            (b * g - a * h) / determinant,
+            (b * f - c * e) / determinant,
+            (c * d - a * f) / determinant,
+            (a * e - b * d) / determinant,
+        ];
+        let mut raw_data = Self::IDENTITY.raw_data;
+        for column in 0..3 {
+            for row in 0..3 {
+                raw_data[column * 4 + row] = inverse[column * 3 + row];
+            }
+        }
+        let [x, y, z] = [m[12], m[13], m[14]];
+        for row in 0..3 {
+            raw_data[12 + row] =
+                -(raw_data[row] * x + raw_data[4 + row] * y + raw_data[8 + row] * z);
+        }
+        Some(Self { raw_data })
+    }
+
+    /// Transforms a point, ignoring the projective row like display objects do.
+    pub fn transform_point(&self, [x, y, z]: [f64; 3]) -> [f64; 3] {
+        let m = &self.raw_data;
+        [
+            m[0] * x + m[4] * y + m[8] * z + m[12],
+            m[1] * x + m[5] * y + m[9] * z + m[13],
+            m[2] * x + m[6] * y + m[10] * z + m[14],
+        ]
+    }
+}
+
+impl Default for Matrix3D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl std::ops::Mul for Matrix3D {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (&self.raw_data, &rhs.raw_data);
+        let mut raw_data = [0.0; 16];
+        for column in 0..4 {
+            for row in 0..4 {
+                raw_data[column * 4 + row] =
+                    (0..4).map(|i| lhs[i * 4 + row] * rhs[column * 4 + i]).sum();
+            }
+        }
+        Self { raw_data }
+    }
+}
+
+/// The `perspectiveProjection` of a display object, which is used to
+/// display its 3D children.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PerspectiveProjection {
+    /// The field of view, in degrees.
+    pub field_of_view: f64,
+
+    /// The vanishing point, in the coordinates of the display object.
+    pub projection_center: (f64, f64),
+}
+
+impl PerspectiveProjection {
+    pub const DEFAULT_FIELD_OF_VIEW: f64 = 55.0;
+
+    /// The default projection of a stage of the given size.
+    pub fn for_stage(width: f64, height: f64) -> Self {
+        Self {
+            field_of_view: Self::DEFAULT_FIELD_OF_VIEW,
+            projection_center: (width / 2.0, height / 2.0),
+        }
+    }
+
+    /// The distance from the viewer to the plane `z = 0`, for a stage of the given width.
+    pub fn focal_length(&self, stage_width: f64) -> f64 {
+        let field_of_view = self.field_of_view.clamp(0.1, 179.9);
+        stage_width / 2.0 / (field_of_view.to_radians() / 2.0).tan()
+    }
+}
+
+/// A perspective projection onto the plane `z = 0`, in the coordinates of that plane.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Projection {
+    pub focal_length: f64,
+    pub center: (f64, f64),
+}
+
+impl Projection {
+    /// The points closer to the viewer than this aren't displayed.
+    const NEAR_PLANE: f64 = 0.01;
+
+    /// Projects a point onto the plane, or returns `None` if it's behind the viewer.
+    pub fn project(&self, [x, y, z]: [f64; 3]) -> Option<(f64, f64)> {
+        let depth = self.focal_length + z;
+        if depth < Self::NEAR_PLANE {
+            return None;
+        }
+        let scale = self.focal_length / depth;
+        Some((
+            self.center.0 + (x - self.center.0) * scale,
+            self.center.1 + (y - self.center.1) * scale,
+        ))
+    }
+
+    /// Finds the point of the plane `z = 0` of `matrix` which is projected to `point`,
+    /// or returns `None` if there's none in front of the viewer.
+    pub fn unproject(&self, matrix: &Matrix3D, (x, y): (f64, f64)) -> Option<(f64, f64)> {
+        let m = &matrix.raw_data;
+        let f = self.focal_length;
+        let (dx, dy) = (x - self.center.0, y - self.center.1);
+
+        // Solve `project(matrix * (u, v, 0)) = (x, y)`, which is linear in `u` and `v`.
+        let (a, b) = (f * m[0] - dx * m[2], f * m[4] - dx * m[6]);
+        let (c, d) = (f * m[1] - dy * m[2], f * m[5] - dy * m[6]);
+        let e = dx * (f + m[14]) - f * (m[12] - self.center.0);
+        let g = dy * (f + m[14]) - f * (m[13] - self.center.1);
+        let determinant = a * d - b * c;
+        if determinant.abs() < f64::EPSILON {
+            return None;
+        }
+        let (u, v) = ((e * d - b * g) / determinant, (a * g - e * c) / determinant);
+
+        let [_, _, z] = matrix.transform_point([u, v, 0.0]);
+        (f + z >= Self::NEAR_PLANE).then_some((u, v))
+    }
+}
+
+/// A part of a projected bitmap, which is drawn with an affine transform.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ProjectedTile {
+    /// The transform of the whole bitmap, which is exact for this tile.
+    pub matrix: Matrix,
+
+    /// The transform of a unit square to the area of this tile, which the
+    /// bitmap must be clipped to. `None` if the bitmap doesn't need to be clipped.
+    pub clip: Option<Matrix>,
+}
+
+/// The most tiles in each direction that a projected bitmap is split into.
+const MAX_TILES: usize = 16;
+
+/// Splits a bitmap of the given size into tiles, so that it looks projected when each
+/// tile is drawn with an affine transform.
+///
+/// `project` maps a pixel of the bitmap to its position on screen, in pixels, or returns
+/// `None` if it isn't visible. Returns `None` if any corner of the tiles isn't visible.
+pub fn projected_tiles(
+    width: f64,
+    height: f64,
+    project: impl Fn(f64, f64) -> Option<(f64, f64)>,
+) -> Option<Vec<ProjectedTile>> {
+    let affine = |p00: (f64, f64), p10: (f64, f64), p01: (f64, f64), u, v, du, dv| {
+        let (a, b) = ((p10.0 - p00.0) / du, (p10.1 - p00.1) / du);
+        let (c, d) = ((p01.0 - p00.0) / dv, (p01.1 - p00.1) / dv);
+        Matrix {
+            a: a as f32,
+            b: b as f32,
+            c: c as f32,
+            d: d as f32,
+            tx: Twips::from_pixels(p00.0 - a * u - c * v),
+            ty: Twips::from_pixels(p00.1 - b * u - d * v),
+        }
+    };
+
+    let p00 = project(0.0, 0.0)?;
+    let p10 = project(width, 0.0)?;
+    let p01 = project(0.0, height)?;
+    let p11 = project(width, height)?;
+
+    // How far the last corner is from where an affine transform would put it.
+    let error = (p11.0 - (p10.0 + p01.0 - p00.0)).hypot(p11.1 - (p10.1 + p01.1 - p00.1));
+    if error < 0.5 {
+        return Some(vec![ProjectedTile {
+            matrix: affine(p00, p10, p01, 0.0, 0.0, width, height),
+            clip: None,
+        }]);
+    }
+
+    // The error of each tile shrinks with the square of the number of tiles.
+    let tiles = ((error * 4.0).sqrt().ceil() as usize).clamp(2, MAX_TILES);
+    let (du, dv) = (width / tiles as f64, height / tiles as f64);
+    let mut corners = Vec::with_capacity((tiles + 1) * (tiles + 1));
+    for row in 0..=tiles {
+        for column in 0..=tiles {
+            corners.push(project(column as f64 * du, row as f64 * dv)?);
+        }
+    }
+
+    let corner = |column: usize, row: usize| corners[row * (tiles + 1) + column];
+    let mut result = Vec::with_capacity(tiles * tiles);
+    for row in 0..tiles {
+        for column in 0..tiles {
+            let (p00, p10, p01) = (
+                corner(column, row),
+                corner(column + 1, row),
+                corner(column, row + 1),
+            );
+            let (u, v) = (column as f64 * du, row as f64 * dv);
+            let matrix = affine(p00, p10, p01, u, v, du, dv);
+            let clip = Matrix {
+                a: (p10.0 - p00.0) as f32,
+                b: (p10.1 - p00.1) as f32,
+                c: (p01.0 - p00.0) as f32,
+                d: (p01.1 - p00.1) as f32,
+                tx: Twips::from_pixels(p00.0),
+                ty: Twips::from_pixels(p00.1),
+            };
+            result.push(ProjectedTile {
+                matrix,
+                clip: Some(clip),
+            });
+        }
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).abs() < 1e-9, "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn compose_and_decompose() {
+        let components = Components3D {
+            translation: [10.0, -20.0, 30.0],
+            rotation: [15.0, -40.0, 70.0],
+            scale: [2.0, 0.5, 3.0],
+        };
+        let matrix = Matrix3D::from_components(&components);
+        let decomposed = matrix.components();
+        assert_close(&decomposed.translation, &components.translation);
+        assert_close(&decomposed.rotation, &components.rotation);
+        assert_close(&decomposed.scale, &components.scale);
+
+        let rotated = Matrix3D::from_components(&Components3D {
+            translation: [0.0; 3],
+            rotation: [0.0, 0.0, 90.0],
+            scale: [1.0; 3],
+        });
+        assert_close(&rotated.transform_point([1.0, 0.0, 0.0]), &[0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn inverse_and_multiply() {
+        let matrix = Matrix3D::from_components(&Components3D {
+            translation: [5.0, 6.0, 7.0],
+            rotation: [30.0, 45.0, 60.0],
+            scale: [1.5, 2.0, 0.5],
+        });
+        let inverse = matrix.inverse().expect("Matrix should be invertible");
+        assert_close(&(matrix * inverse).raw_data, &Matrix3D::IDENTITY.raw_data);
+        let point = matrix.transform_point([1.0, 2.0, 3.0]);
+        assert_close(&inverse.transform_point(point), &[1.0, 2.0, 3.0]);
+
+        let matrix_2d = Matrix::rotate(0.5);
+        assert_eq!(Matrix3D::from_2d(&matrix_2d).to_2d(), matrix_2d);
+    }
+
+    #[test]
+    fn project_and_unproject() {
+        let projection = Projection {
+            focal_length: PerspectiveProjection::for_stage(500.0, 400.0).focal_length(500.0),
+            center: (250.0, 200.0),
+        };
+        assert!((projection.focal_length - 480.25).abs() < 0.01);
+
+        // Points further away are closer to the center.
+        let (x, y) = projection
+            .project([350.0, 200.0, projection.focal_length])
+            .expect("Point should be visible");
+        assert!((x - 300.0).abs() < 1e-9 && (y - 200.0).abs() < 1e-9);
+        assert_eq!(projection.project([0.0, 0.0, -500.0]), None);
+
+        let matrix = Matrix3D::from_components(&Components3D {
+            translation: [100.0, 50.0, 20.0],
+            rotation: [0.0, 60.0, 10.0],
+            scale: [1.0; 3],
+        });
+        let projected = projection
+            .project(matrix.transform_point([30.0, 40.0, 0.0]))
+            .expect("Point should be visible");
+        let (u, v) = projection
+            .unproject(&matrix, projected)
+            .expect("Point should be visible");
+        assert_close(&[u, v], &[30.0, 40.0]);
+    }
+
+    #[test]
+    fn tiles() {
+        let affine = projected_tiles(100.0, 50.0, |x, y| Some((x * 2.0 + 10.0, y)))
+            .expect("Bitmap should be visible");
+        assert_eq!(affine.len(), 1);
+        assert_eq!(affine[0].clip, None);
+        assert_eq!(affine[0].matrix.a, 2.0);
+        assert_eq!(affine[0].matrix.tx, Twips::from_pixels(10.0));
+
+        let projection = Projection {
+            focal_length: 480.0,
+            center: (0.0, 0.0),
+        };
+        let tilted = projected_tiles(100.0, 100.0, |x, y| projection.project([x, y, x]))
+            .expect("Bitmap should be visible");
+        assert!(tilted.len() > 1);
+        assert!(tilted.iter().all(|tile| tile.clip.is_some()));
+
+        let behind = projected_tiles(100.0, 100.0, |x, y| projection.project([x, y, -x * 10.0]));
+        assert_eq!(behind, None);
+    }
+}
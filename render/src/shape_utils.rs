@@ -1246,6 +1246,223 @@ impl<'a> LineScales<'a> {
     }
 }
 
+/// A 9-slice scaling grid (`scale9Grid`), resolved for an object's bounds and scale.
+///
+/// Flash keeps the corners of a 9-slice scaled object at their original size, stretches the
+/// edges along one axis only, and lets the center absorb the rest of the scaling.
+/// This is done by moving every point of the object's vector content, in its local space,
+/// so that the object's own scale cancels out in the corners.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScalingGrid {
+    x: ScalingGridAxis,
+    y: ScalingGridAxis,
+}
+
+/// The piecewise linear mapping of a single axis of a [`ScalingGrid`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ScalingGridAxis {
+    /// The original start, grid start, grid end and end coordinates.
+    from: [f64; 4],
+    /// Where each of `from` ends up after scaling.
+    to: [f64; 4],
+}
+
+impl ScalingGridAxis {
+    fn new(
+        bounds_min: Twips,
+        bounds_max: Twips,
+        grid_min: Twips,
+        grid_max: Twips,
+        scale: f64,
+    ) -> Self {
+        let bounds_min = bounds_min.get() as f64;
+        let bounds_max = bounds_max.get() as f64;
+        let grid_min = (grid_min.get() as f64).clamp(bounds_min, bounds_max);
+        let grid_max = (grid_max.get() as f64).clamp(grid_min, bounds_max);
+
+        // The corners are drawn unscaled, unless the object has become too small to fit them.
+        // In that case they're shrunk proportionally and the center disappears entirely.
+        let corners = (grid_min - bounds_min) + (bounds_max - grid_max);
+        let scaled_size = (bounds_max - bounds_min) * scale;
+        let corner_scale = if corners > scaled_size {
+            scaled_size / corners
+        } else {
+            1.0
+        };
+
+        Self {
+            from: [bounds_min, grid_min, grid_max, bounds_max],
+            to: [
+                bounds_min,
+                bounds_min + (grid_min - bounds_min) * corner_scale / scale,
+                bounds_max - (bounds_max - grid_max) * corner_scale / scale,
+                bounds_max,
+            ],
+        }
+    }
+
+    /// Maps a coordinate between two sets of slice boundaries.
+    fn map(value: f64, from: &[f64; 4], to: &[f64; 4]) -> f64 {
+        // Content outside of the bounds extends the outermost slices.
+        let slice = if value < from[1] {
+            0
+        } else if value <= from[2] {
+            1
+        } else {
+            2
+        };
+        let (from_start, from_end) = (from[slice], from[slice + 1]);
+        let (to_start, to_end) = (to[slice], to[slice + 1]);
+        if from_end == from_start {
+            to_start
+        } else {
+            to_start + (value - from_start) * (to_end - to_start) / (from_end - from_start)
+        }
+    }
+
+    fn transform(&self, value: Twips) -> Twips {
+        Twips::new(Self::map(value.get() as f64, &self.from, &self.to).round() as i32)
+    }
+
+    fn inverse_transform(&self, value: Twips) -> Twips {
+        Twips::new(Self::map(value.get() as f64, &self.to, &self.from).round() as i32)
+    }
+}
+
+impl ScalingGrid {
+    /// Resolves the scaling grid `grid` of an object with the given (unscaled) bounds and scale.
+    ///
+    /// Returns `None` if the grid doesn't affect the object, such as when it's empty or unscaled.
+    pub fn new(
+        bounds: &Rectangle<Twips>,
+        grid: &Rectangle<Twips>,
+        scale_x: f64,
+        scale_y: f64,
+    ) -> Option<Self> {
+        if !bounds.is_valid()
+            || !grid.is_valid()
+            || grid.width() <= Twips::ZERO
+            || grid.height() <= Twips::ZERO
+            || !scale_x.is_finite()
+            || !scale_y.is_finite()
+            || scale_x <= 0.0
+            || scale_y <= 0.0
+            || (scale_x == 1.0 && scale_y == 1.0)
+        {
+            return None;
+        }
+
+        Some(Self {
+            x: ScalingGridAxis::new(bounds.x_min, bounds.x_max, grid.x_min, grid.x_max, scale_x),
+            y: ScalingGridAxis::new(bounds.y_min, bounds.y_max, grid.y_min, grid.y_max, scale_y),
+        })
+    }
+
+    /// Moves a point in the object's local space to its 9-slice scaled position.
+    pub fn transform_point(&self, point: swf::Point<Twips>) -> swf::Point<Twips> {
+        swf::Point::new(self.x.transform(point.x), self.y.transform(point.y))
+    }
+
+    /// Moves a 9-slice scaled point back to its original position. Used for hit testing.
+    pub fn inverse_transform_point(&self, point: swf::Point<Twips>) -> swf::Point<Twips> {
+        swf::Point::new(
+            self.x.inverse_transform(point.x),
+            self.y.inverse_transform(point.y),
+        )
+    }
+
+    /// Transforms the bounds of some content in the object's local space.
+    pub fn transform_bounds(&self, bounds: &Rectangle<Twips>) -> Rectangle<Twips> {
+        if !bounds.is_valid() {
+            return bounds.clone();
+        }
+        Rectangle {
+            x_min: self.x.transform(bounds.x_min),
+            x_max: self.x.transform(bounds.x_max),
+            y_min: self.y.transform(bounds.y_min),
+            y_max: self.y.transform(bounds.y_max),
+        }
+    }
+
+    /// Applies this grid to a shape.
+    ///
+    /// `matrix` transforms the shape into the local space of the object owning the grid
+    /// (for example, the matrix of a `Shape` child within a 9-slice scaled `Sprite`).
+    /// Returns `None` if the matrix isn't invertible.
+    pub fn transform_shape<'a>(
+        &self,
+        shape: DistilledShape<'a>,
+        matrix: &Matrix,
+    ) -> Option<DistilledShape<'a>> {
+        let inverse = matrix.inverse()?;
+        let transform = |point: swf::Point<Twips>| inverse * self.transform_point(*matrix * point);
+        let transform_bounds = |bounds: &Rectangle<Twips>| {
+            if bounds.is_valid() {
+                inverse * self.transform_bounds(&(*matrix * bounds.clone()))
+            } else {
+                bounds.clone()
+            }
+        };
+
+        let transform_commands = |commands: Vec<DrawCommand>| -> Vec<DrawCommand> {
+            commands
+                .into_iter()
+                .map(|command| match command {
+                    DrawCommand::MoveTo(point) => DrawCommand::MoveTo(transform(point)),
+                    DrawCommand::LineTo(point) => DrawCommand::LineTo(transform(point)),
+                    DrawCommand::QuadraticCurveTo { control, anchor } => {
+                        DrawCommand::QuadraticCurveTo {
+                            control: transform(control),
+                            anchor: transform(anchor),
+                        }
+                    }
+                    DrawCommand::CubicCurveTo {
+                        control_a,
+                        control_b,
+                        anchor,
+                    } => DrawCommand::CubicCurveTo {
+                        control_a: transform(control_a),
+                        control_b: transform(control_b),
+                        anchor: transform(anchor),
+                    },
+                })
+                .collect()
+        };
+
+        let paths = shape
+            .paths
+            .into_iter()
+            .map(|path| match path {
+                DrawPath::Stroke {
+                    style,
+                    is_closed,
+                    commands,
+                } => DrawPath::Stroke {
+                    style,
+                    is_closed,
+                    commands: transform_commands(commands),
+                },
+                DrawPath::Fill {
+                    style,
+                    commands,
+                    winding_rule,
+                } => DrawPath::Fill {
+                    style,
+                    commands: transform_commands(commands),
+                    winding_rule,
+                },
+            })
+            .collect();
+
+        Some(DistilledShape {
+            paths,
+            shape_bounds: transform_bounds(&shape.shape_bounds),
+            edge_bounds: transform_bounds(&shape.edge_bounds),
+            id: shape.id,
+        })
+    }
+}
+
 pub fn quadratic_curve_bounds(
     start: swf::Point<Twips>,
     stroke_width: Twips,
@@ -1462,4 +1679,51 @@ mod tests {
             1,
         );
     }
+
+    #[test]
+    fn scaling_grid() {
+        let bounds = Rectangle {
+            x_min: Twips::from_pixels(0.0),
+            x_max: Twips::from_pixels(100.0),
+            y_min: Twips::from_pixels(0.0),
+            y_max: Twips::from_pixels(100.0),
+        };
+        let grid = Rectangle {
+            x_min: Twips::from_pixels(10.0),
+            x_max: Twips::from_pixels(90.0),
+            y_min: Twips::from_pixels(20.0),
+            y_max: Twips::from_pixels(80.0),
+        };
+
+        assert_eq!(ScalingGrid::new(&bounds, &grid, 1.0, 1.0), None);
+        assert_eq!(
+            ScalingGrid::new(&bounds, &Rectangle::default(), 2.0, 2.0),
+            None
+        );
+
+        // Doubling the size halves the corners in local space, so that they're unscaled on screen.
+        let scaling_grid = ScalingGrid::new(&bounds, &grid, 2.0, 4.0).expect("Grid should apply");
+        let test = |from: (f64, f64), to: (f64, f64)| {
+            let from = swf::Point::from_pixels(from.0, from.1);
+            let to = swf::Point::from_pixels(to.0, to.1);
+            assert_eq!(scaling_grid.transform_point(from), to);
+            assert_eq!(scaling_grid.inverse_transform_point(to), from);
+        };
+        test((0.0, 0.0), (0.0, 0.0));
+        test((10.0, 20.0), (5.0, 5.0));
+        test((50.0, 50.0), (50.0, 50.0));
+        test((90.0, 80.0), (95.0, 95.0));
+        test((100.0, 100.0), (100.0, 100.0));
+
+        // When shrunk below the size of the corners, the corners shrink too.
+        let scaling_grid = ScalingGrid::new(&bounds, &grid, 0.1, 0.1).expect("Grid should apply");
+        assert_eq!(
+            scaling_grid.transform_point(swf::Point::from_pixels(10.0, 20.0)),
+            swf::Point::from_pixels(50.0, 50.0)
+        );
+        assert_eq!(
+            scaling_grid.transform_point(swf::Point::from_pixels(95.0, 90.0)),
+            swf::Point::from_pixels(75.0, 75.0)
+        );
+    }
 }
@@ -0,0 +1,71 @@
+#import filter
+
+struct Filter {
+    /// The color used for pixels outside of the source, unless `clamp` is set. Premultiplied.
+    default_color: vec4<f32>,
+
+    /// The area of the texture that we're filtering, in pixels (max is exclusive).
+    source_min: vec2<i32>,
+    source_max: vec2<i32>,
+
+    matrix_x: u32,
+    matrix_y: u32,
+    divisor: f32,
+    bias: f32,
+    clamp: u32,
+    preserve_alpha: u32,
+
+    /// The kernel, row by row, packed into vec4s to satisfy uniform array alignment.
+    matrix: array<vec4<f32>, 64>,
+}
+
+@group(0) @binding(0) var texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> filter_args: Filter;
+
+@vertex
+fn main_vertex(in: filter::VertexInput) -> filter::VertexOutput {
+    return filter::main_vertex(in);
+}
+
+fn unmultiply(color: vec4<f32>) -> vec4<f32> {
+    if (color.a == 0.0) {
+        return vec4<f32>(0.0);
+    }
+    return vec4<f32>(color.rgb / color.a, color.a);
+}
+
+fn source_pixel(position: vec2<i32>) -> vec4<f32> {
+    var pos = position;
+    if (any(pos < filter_args.source_min) || any(pos >= filter_args.source_max)) {
+        if (filter_args.clamp == 0u) {
+            return unmultiply(filter_args.default_color);
+        }
+        pos = clamp(pos, filter_args.source_min, filter_args.source_max - vec2<i32>(1));
+    }
+    return unmultiply(textureLoad(texture, pos, 0));
+}
+
+@fragment
+fn main_fragment(in: filter::VertexOutput) -> @location(0) vec4<f32> {
+    let center = vec2<i32>(floor(in.uv * vec2<f32>(textureDimensions(texture))));
+    let offset = vec2<i32>(i32(filter_args.matrix_x / 2u), i32(filter_args.matrix_y / 2u));
+
+    var sum = vec4<f32>(0.0);
+    for (var y = 0u; y < filter_args.matrix_y; y++) {
+        for (var x = 0u; x < filter_args.matrix_x; x++) {
+            let index = y * filter_args.matrix_x + x;
+            let weight = filter_args.matrix[index / 4u][index % 4u];
+            if (weight != 0.0) {
+                let position = center + vec2<i32>(i32(x), i32(y)) - offset;
+                sum += source_pixel(position) * weight;
+            }
+        }
+    }
+
+    var color = saturate(sum / filter_args.divisor + vec4<f32>(filter_args.bias / 255.0));
+    if (filter_args.preserve_alpha > 0u) {
+        color.a = source_pixel(center).a;
+    }
+    return vec4<f32>(color.rgb * color.a, color.a);
+}
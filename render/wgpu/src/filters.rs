@@ -1,26 +1,28 @@
 mod bevel;
 mod blur;
 mod color_matrix;
+mod convolution;
 mod displacement_map;
 mod drop_shadow;
 mod glow;
+mod gradient;
 mod shader;
 
-use std::collections::HashSet;
-use std::sync::{Mutex, OnceLock};
-
 use crate::buffer_pool::TexturePool;
 use crate::descriptors::Descriptors;
 use crate::filters::bevel::BevelFilter;
 use crate::filters::blur::BlurFilter;
 use crate::filters::color_matrix::ColorMatrixFilter;
+use crate::filters::convolution::ConvolutionFilter;
 use crate::filters::displacement_map::DisplacementMapFilter;
 use crate::filters::drop_shadow::DropShadowFilter;
 use crate::filters::glow::GlowFilter;
+use crate::filters::gradient::{GradientFilter, GradientFilterKind};
 use crate::filters::shader::ShaderFilter;
 use crate::surface::target::CommandTarget;
 use bytemuck::{Pod, Zeroable};
 use ruffle_render::filters::Filter;
+use swf::GradientRecord;
 use wgpu::util::StagingBelt;
 use wgpu::vertex_attr_array;
 
@@ -192,6 +194,9 @@ pub struct Filters {
     pub glow: GlowFilter,
     pub bevel: BevelFilter,
     pub displacement_map: DisplacementMapFilter,
+    pub gradient_glow: GradientFilter,
+    pub gradient_bevel: GradientFilter,
+    pub convolution: ConvolutionFilter,
 }
 
 impl Filters {
@@ -203,6 +208,9 @@ impl Filters {
             glow: GlowFilter::new(device),
             bevel: BevelFilter::new(device),
             displacement_map: DisplacementMapFilter::new(device),
+            gradient_glow: GradientFilter::new(device, GradientFilterKind::Glow),
+            gradient_bevel: GradientFilter::new(device, GradientFilterKind::Bevel),
+            convolution: ConvolutionFilter::new(device),
         }
     }
 
@@ -276,31 +284,32 @@ impl Filters {
                 &source,
                 &filter,
             ),
-            filter => {
-                static WARNED_FILTERS: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
-                let name = match filter {
-                    Filter::GradientGlowFilter(_) => "GradientGlowFilter",
-                    Filter::GradientBevelFilter(_) => "GradientBevelFilter",
-                    Filter::ConvolutionFilter(_) => "ConvolutionFilter",
-                    Filter::ColorMatrixFilter(_)
-                    | Filter::BlurFilter(_)
-                    | Filter::GlowFilter(_)
-                    | Filter::DropShadowFilter(_)
-                    | Filter::BevelFilter(_)
-                    | Filter::DisplacementMapFilter(_)
-                    | Filter::ShaderFilter(_) => unreachable!(),
-                };
-                // Only warn once per filter type
-                if WARNED_FILTERS
-                    .get_or_init(Default::default)
-                    .lock()
-                    .unwrap()
-                    .insert(name)
-                {
-                    tracing::warn!("Unsupported filter {filter:?}");
-                }
-                None
-            }
+            Filter::GradientGlowFilter(filter) => Some(descriptors.filters.gradient_glow.apply(
+                descriptors,
+                texture_pool,
+                draw_encoder,
+                staging_belt,
+                &source,
+                &filter,
+                &self.blur,
+            )),
+            Filter::GradientBevelFilter(filter) => Some(descriptors.filters.gradient_bevel.apply(
+                descriptors,
+                texture_pool,
+                draw_encoder,
+                staging_belt,
+                &source,
+                &filter,
+                &self.blur,
+            )),
+            Filter::ConvolutionFilter(filter) => Some(descriptors.filters.convolution.apply(
+                descriptors,
+                texture_pool,
+                draw_encoder,
+                staging_belt,
+                &source,
+                &filter,
+            )),
         };
 
         let target = target.unwrap_or_else(|| {
@@ -324,6 +333,45 @@ impl Filters {
     }
 }
 
+/// The number of entries in the color lookup table of a gradient glow or gradient bevel.
+pub const GRADIENT_FILTER_SIZE: usize = 256;
+
+/// Builds the premultiplied color lookup table used by gradient glows and gradient bevels.
+pub fn gradient_filter_colors(records: &[GradientRecord]) -> [[f32; 4]; GRADIENT_FILTER_SIZE] {
+    let mut colors = [[0.0; 4]; GRADIENT_FILTER_SIZE];
+    let (Some(first), Some(last)) = (records.first(), records.last()) else {
+        return colors;
+    };
+    for (t, color) in colors.iter_mut().enumerate() {
+        let (from, to) = if t <= first.ratio as usize {
+            (first, first)
+        } else if t >= last.ratio as usize {
+            (last, last)
+        } else {
+            let next = records
+                .iter()
+                .position(|record| record.ratio as usize >= t)
+                .unwrap_or(records.len() - 1);
+            (&records[next.saturating_sub(1)], &records[next])
+        };
+        let a = if to.ratio > from.ratio {
+            (t as f32 - from.ratio as f32) / (to.ratio as f32 - from.ratio as f32)
+        } else {
+            0.0
+        };
+        let lerp =
+            |from: u8, to: u8| (f32::from(from) + (f32::from(to) - f32::from(from)) * a) / 255.0;
+        let alpha = lerp(from.color.a, to.color.a);
+        *color = [
+            lerp(from.color.r, to.color.r) * alpha,
+            lerp(from.color.g, to.color.g) * alpha,
+            lerp(from.color.b, to.color.b) * alpha,
+            alpha,
+        ];
+    }
+    colors
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Pod, Zeroable)]
 pub struct FilterVertex {
@@ -380,3 +428,49 @@ pub const VERTEX_BUFFERS_DESCRIPTION_FILTERS_WITH_DOUBLE_BLUR: [wgpu::VertexBuff
             3 => Float32x2,
         ],
     }];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swf::Color;
+
+    fn record(ratio: u8, r: u8, g: u8, b: u8, a: u8) -> GradientRecord {
+        GradientRecord {
+            ratio,
+            color: Color { r, g, b, a },
+        }
+    }
+
+    #[test]
+    fn gradient_colors_ratio_boundaries() {
+        let colors =
+            gradient_filter_colors(&[record(64, 255, 0, 0, 255), record(192, 0, 0, 255, 255)]);
+        // Before the first and after the last ratio, the gradient is clamped.
+        assert_eq!(colors[0], [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(colors[64], [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(colors[192], [0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(colors[255], [0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(colors[128], [0.5, 0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn gradient_colors_single_record() {
+        let colors = gradient_filter_colors(&[record(100, 255, 255, 0, 255)]);
+        assert!(colors.iter().all(|color| *color == [1.0, 1.0, 0.0, 1.0]));
+        assert!(gradient_filter_colors(&[])
+            .iter()
+            .all(|color| *color == [0.0; 4]));
+    }
+
+    #[test]
+    fn gradient_colors_alpha_interpolation() {
+        let colors =
+            gradient_filter_colors(&[record(0, 255, 255, 255, 0), record(255, 255, 255, 255, 255)]);
+        // Colors are premultiplied by their interpolated alpha.
+        assert_eq!(colors[0], [0.0; 4]);
+        let [r, g, b, a] = colors[51];
+        assert!((a - 0.2).abs() < 1e-6);
+        assert_eq!([r, g, b], [a; 3]);
+        assert_eq!(colors[255], [1.0; 4]);
+    }
+}
@@ -2199,7 +2199,9 @@ impl<'a> Reader<'a> {
 
     pub fn read_sound_format(&mut self) -> Result<SoundFormat> {
         let flags = self.read_u8()?;
+        // AAC is only valid in FLV and MP4 files, Flash rejects it in SWF sound tags.
         let compression = AudioCompression::from_u8(flags >> 4)
+            .filter(|&compression| compression != AudioCompression::Aac)
             .ok_or_else(|| Error::invalid_data("Invalid audio format."))?;
         let sample_rate = match (flags & 0b11_00) >> 2 {
             0 => 5512,
@@ -3033,6 +3035,26 @@ pub mod tests {
         }
     }
 
+    /// AAC is an FLV sound format, which SWF sound tags can't use.
+    #[test]
+    fn read_aac_sound_format() {
+        assert!(reader(&[0b1010_1110]).read_sound_format().is_err());
+        assert_eq!(
+            reader(&[0b0010_1110]).read_sound_format().unwrap(),
+            SoundFormat {
+                compression: AudioCompression::Mp3,
+                sample_rate: 44100,
+                is_16_bit: true,
+                is_stereo: false,
+            }
+        );
+
+        let define_sound = [0x01, 0x00, 0b1010_1110, 0x00, 0x00, 0x00, 0x00];
+        assert!(reader(&define_sound).read_define_sound().is_err());
+        let sound_stream_head = [0b0010_1110, 0b1010_1110, 0x00, 0x00];
+        assert!(reader(&sound_stream_head).read_sound_stream_head().is_err());
+    }
+
     /// Ensure that we can read a PlaceObject3 tag that
     /// inccorrectly omits the 'is_bitmap_cached' u8
     /// Extracted from #7098
@@ -1026,6 +1026,9 @@ pub enum AudioCompression {
     Nellymoser16Khz = 4,
     Nellymoser8Khz = 5,
     Nellymoser = 6,
+
+    /// AAC is only used in FLV and MP4 files; SWF sound tags can't contain it.
+    Aac = 10,
     Speex = 11,
 }
 
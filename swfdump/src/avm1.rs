@@ -0,0 +1,115 @@
+//! AVM1 action listings.
+
+use serde::Serialize;
+use swf::avm1::read::Reader;
+use swf::avm1::types::{Action, CatchVar};
+use swf::error::Error;
+
+#[derive(Serialize, Debug)]
+pub struct ActionDump {
+    /// The offset of the action, relative to the start of the action block.
+    pub offset: usize,
+    /// How deeply this action is nested inside of function, `try` or `with` bodies.
+    pub depth: usize,
+    pub action: String,
+}
+
+/// Disassembles a block of AVM1 bytecode, including the bodies of any functions,
+/// `try` and `with` blocks that it contains.
+///
+/// If an action fails to parse, the listing up to that point is returned along
+/// with the error.
+pub fn disassemble(data: &[u8], version: u8) -> (Vec<ActionDump>, Option<Error>) {
+    let mut actions = vec![];
+    let error = disassemble_body(data, data, 0, version, &mut actions).err();
+    (actions, error)
+}
+
+fn disassemble_body(
+    block: &[u8],
+    body: &[u8],
+    depth: usize,
+    version: u8,
+    actions: &mut Vec<ActionDump>,
+) -> Result<(), Error> {
+    let offset_of = |data: &[u8]| data.as_ptr() as usize - block.as_ptr() as usize;
+
+    let mut reader = Reader::new(body, version);
+    while !reader.get_ref().is_empty() {
+        let offset = offset_of(reader.get_ref());
+        let action = reader.read_action()?;
+
+        let mut bodies = vec![];
+        let text = match &action {
+            Action::DefineFunction(function) => {
+                bodies.push((None, function.actions));
+                let params: Vec<_> = function
+                    .params
+                    .iter()
+                    .map(|param| format!("{param:?}"))
+                    .collect();
+                format!("DefineFunction {:?}({})", function.name, params.join(", "))
+            }
+            Action::DefineFunction2(function) => {
+                bodies.push((None, function.actions));
+                let params: Vec<_> = function
+                    .params
+                    .iter()
+                    .map(|param| match param.register_index {
+                        Some(register) => format!("{:?} in r{register}", param.name),
+                        None => format!("{:?}", param.name),
+                    })
+                    .collect();
+                format!(
+                    "DefineFunction2 {:?}({}) registers: {}, flags: {:?}",
+                    function.name,
+                    params.join(", "),
+                    function.register_count,
+                    function.flags
+                )
+            }
+            Action::Try(try_block) => {
+                bodies.push((Some("try".to_string()), try_block.try_body));
+                if let Some((catch_var, catch_body)) = &try_block.catch_body {
+                    let label = match catch_var {
+                        CatchVar::Var(name) => format!("catch {name:?}"),
+                        CatchVar::Register(register) => format!("catch r{register}"),
+                    };
+                    bodies.push((Some(label), *catch_body));
+                }
+                if let Some(finally_body) = try_block.finally_body {
+                    bodies.push((Some("finally".to_string()), finally_body));
+                }
+                "Try".to_string()
+            }
+            Action::With(with) => {
+                bodies.push((None, with.actions));
+                "With".to_string()
+            }
+            action => format!("{action:?}"),
+        };
+        actions.push(ActionDump {
+            offset,
+            depth,
+            action: text,
+        });
+
+        for (label, body) in bodies {
+            if let Some(label) = label {
+                actions.push(ActionDump {
+                    // Bogus `try` actions may have bodies that aren't part of the block.
+                    offset: if body.is_empty() {
+                        offset
+                    } else {
+                        offset_of(body)
+                    },
+                    depth,
+                    action: format!("{label}:"),
+                });
+            }
+            disassemble_body(block, body, depth + 1, version, actions)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,613 @@
+//! AVM2 class, script and method dumps.
+
+use serde::Serialize;
+use swf::avm2::read::Reader;
+use swf::avm2::types::{
+    AbcFile, DefaultValue, Index, Method, MethodBody, Multiname, Namespace, Op, Trait, TraitKind,
+};
+use swf::extensions::ReadSwfExt;
+
+#[derive(Serialize, Debug)]
+pub struct AbcDump {
+    /// The name given to the ABC file by a `DoAbc2` tag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub lazy_initialize: bool,
+    pub major_version: u16,
+    pub minor_version: u16,
+    pub classes: Vec<ClassDump>,
+    pub scripts: Vec<ScriptDump>,
+    pub methods: Vec<MethodDump>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ClassDump {
+    pub index: usize,
+    pub name: String,
+    pub super_name: Option<String>,
+    pub interfaces: Vec<String>,
+    pub is_sealed: bool,
+    pub is_final: bool,
+    pub is_interface: bool,
+    /// The method index of the instance initializer (the constructor).
+    pub instance_init: u32,
+    /// The method index of the class initializer.
+    pub class_init: u32,
+    pub instance_traits: Vec<TraitDump>,
+    pub class_traits: Vec<TraitDump>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ScriptDump {
+    pub index: usize,
+    /// The method index of the script initializer.
+    pub init: u32,
+    pub traits: Vec<TraitDump>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct TraitDump {
+    pub name: String,
+    pub kind: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// The method index of a method, getter, setter or function trait.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<u32>,
+    /// The class index of a class trait.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class: Option<u32>,
+    pub is_final: bool,
+    pub is_override: bool,
+}
+
+#[derive(Serialize, Debug)]
+pub struct MethodDump {
+    pub index: usize,
+    /// A descriptive name for the method, based on the trait or class that refers to it.
+    pub name: String,
+    pub params: Vec<ParamDump>,
+    pub return_type: String,
+    pub flags: Vec<String>,
+    pub body: Option<BodyDump>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ParamDump {
+    pub name: Option<String>,
+    pub type_name: String,
+    pub default_value: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct BodyDump {
+    pub max_stack: u32,
+    pub num_locals: u32,
+    pub init_scope_depth: u32,
+    pub max_scope_depth: u32,
+    pub exceptions: Vec<ExceptionDump>,
+    pub code: Vec<InstructionDump>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ExceptionDump {
+    pub from: u32,
+    pub to: u32,
+    pub target: u32,
+    pub variable_name: String,
+    pub type_name: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct InstructionDump {
+    /// The offset of the instruction, relative to the start of the method body.
+    pub offset: usize,
+    pub op: String,
+}
+
+/// The maximum nesting of `TypeName` multinames that we'll follow.
+const MAX_MULTINAME_DEPTH: usize = 8;
+
+/// Parses and dumps an ABC file.
+pub fn dump_abc(name: Option<String>, lazy_initialize: bool, data: &[u8]) -> AbcDump {
+    let abc = match Reader::new(data).read() {
+        Ok(abc) => abc,
+        Err(e) => {
+            return AbcDump {
+                name,
+                lazy_initialize,
+                major_version: 0,
+                minor_version: 0,
+                classes: vec![],
+                scripts: vec![],
+                methods: vec![],
+                error: Some(e.to_string()),
+            }
+        }
+    };
+
+    let pool = Pool { abc: &abc };
+    let mut method_names = vec![None; abc.methods.len()];
+
+    let classes = abc
+        .instances
+        .iter()
+        .zip(&abc.classes)
+        .enumerate()
+        .map(|(index, (instance, class))| {
+            let name = pool.multiname(&instance.name);
+            name_method(&mut method_names, &instance.init_method, || name.clone());
+            name_method(&mut method_names, &class.init_method, || {
+                format!("{name}$cinit")
+            });
+            let instance_traits = pool.traits(&instance.traits, &name, &mut method_names);
+            let class_traits = pool.traits(&class.traits, &format!("{name}$"), &mut method_names);
+
+            ClassDump {
+                index,
+                super_name: (instance.super_name.0 != 0)
+                    .then(|| pool.multiname(&instance.super_name)),
+                interfaces: instance
+                    .interfaces
+                    .iter()
+                    .map(|interface| pool.multiname(interface))
+                    .collect(),
+                is_sealed: instance.is_sealed,
+                is_final: instance.is_final,
+                is_interface: instance.is_interface,
+                instance_init: instance.init_method.0,
+                class_init: class.init_method.0,
+                instance_traits,
+                class_traits,
+                name,
+            }
+        })
+        .collect();
+
+    let scripts = abc
+        .scripts
+        .iter()
+        .enumerate()
+        .map(|(index, script)| {
+            let name = format!("script{index}");
+            name_method(&mut method_names, &script.init_method, || {
+                format!("{name}$init")
+            });
+
+            ScriptDump {
+                index,
+                init: script.init_method.0,
+                traits: pool.traits(&script.traits, &name, &mut method_names),
+            }
+        })
+        .collect();
+
+    let mut bodies = vec![None; abc.methods.len()];
+    for body in &abc.method_bodies {
+        if let Some(slot) = bodies.get_mut(body.method.0 as usize) {
+            *slot = Some(body);
+        }
+    }
+
+    let methods = abc
+        .methods
+        .iter()
+        .zip(method_names)
+        .zip(bodies)
+        .enumerate()
+        .map(|(index, ((method, name), body))| {
+            let name = name.unwrap_or_else(|| match pool.string(&method.name) {
+                name if name.is_empty() => format!("method{index}"),
+                name => name,
+            });
+            pool.method(index, name, method, body)
+        })
+        .collect();
+
+    AbcDump {
+        name,
+        lazy_initialize,
+        major_version: abc.major_version,
+        minor_version: abc.minor_version,
+        classes,
+        scripts,
+        methods,
+        error: None,
+    }
+}
+
+/// Gives a method a descriptive name, unless it has already been named by
+/// something else.
+fn name_method(
+    names: &mut [Option<String>],
+    method: &Index<Method>,
+    name: impl FnOnce() -> String,
+) {
+    if let Some(slot @ None) = names.get_mut(method.0 as usize) {
+        *slot = Some(name());
+    }
+}
+
+/// Resolves references into the constant pool of an ABC file.
+struct Pool<'a> {
+    abc: &'a AbcFile,
+}
+
+impl<'a> Pool<'a> {
+    fn string(&self, index: &Index<String>) -> String {
+        if index.0 == 0 {
+            return String::new();
+        }
+        match self.abc.constant_pool.strings.get(index.0 as usize - 1) {
+            Some(string) => String::from_utf8_lossy(string).into_owned(),
+            None => format!("<invalid string {}>", index.0),
+        }
+    }
+
+    fn namespace(&self, index: &Index<Namespace>) -> String {
+        if index.0 == 0 {
+            return "*".to_string();
+        }
+        let Some(namespace) = self.abc.constant_pool.namespaces.get(index.0 as usize - 1) else {
+            return format!("<invalid namespace {}>", index.0);
+        };
+        match namespace {
+            Namespace::Namespace(name) => format!("namespace {:?}", self.string(name)),
+            Namespace::Package(name) => format!("package {:?}", self.string(name)),
+            Namespace::PackageInternal(name) => format!("internal {:?}", self.string(name)),
+            Namespace::Protected(name) => format!("protected {:?}", self.string(name)),
+            Namespace::Explicit(name) => format!("explicit {:?}", self.string(name)),
+            Namespace::StaticProtected(name) => {
+                format!("static protected {:?}", self.string(name))
+            }
+            Namespace::Private(name) => format!("private {:?}", self.string(name)),
+        }
+    }
+
+    /// Formats a name qualified by a namespace the way it would be written in
+    /// ActionScript, such as `flash.display::Sprite` or `private::foo`.
+    fn qualified_name(&self, namespace: &Index<Namespace>, name: &Index<String>) -> String {
+        let name = self.string(name);
+        if namespace.0 == 0 {
+            return format!("*::{name}");
+        }
+        let prefix = match self
+            .abc
+            .constant_pool
+            .namespaces
+            .get(namespace.0 as usize - 1)
+        {
+            Some(Namespace::Namespace(uri) | Namespace::Package(uri)) => self.string(uri),
+            Some(Namespace::Explicit(uri)) => self.string(uri),
+            Some(Namespace::PackageInternal(_)) => "internal".to_string(),
+            Some(Namespace::Protected(_) | Namespace::StaticProtected(_)) => {
+                "protected".to_string()
+            }
+            Some(Namespace::Private(_)) => "private".to_string(),
+            None => format!("<invalid namespace {}>", namespace.0),
+        };
+        if prefix.is_empty() {
+            name
+        } else {
+            format!("{prefix}::{name}")
+        }
+    }
+
+    fn multiname(&self, index: &Index<Multiname>) -> String {
+        self.multiname_with_depth(index, 0)
+    }
+
+    fn multiname_with_depth(&self, index: &Index<Multiname>, depth: usize) -> String {
+        if index.0 == 0 {
+            return "*".to_string();
+        }
+        if depth > MAX_MULTINAME_DEPTH {
+            return "<recursive multiname>".to_string();
+        }
+        let Some(multiname) = self.abc.constant_pool.multinames.get(index.0 as usize - 1) else {
+            return format!("<invalid multiname {}>", index.0);
+        };
+        match multiname {
+            Multiname::QName { namespace, name } => self.qualified_name(namespace, name),
+            Multiname::QNameA { namespace, name } => {
+                format!("@{}", self.qualified_name(namespace, name))
+            }
+            Multiname::RTQName { name } => format!("<rt>::{}", self.string(name)),
+            Multiname::RTQNameA { name } => format!("@<rt>::{}", self.string(name)),
+            Multiname::RTQNameL => "<rt>::<rt>".to_string(),
+            Multiname::RTQNameLA => "@<rt>::<rt>".to_string(),
+            // Namespace sets are rarely interesting, so leave them out.
+            Multiname::Multiname { name, .. } => self.string(name),
+            Multiname::MultinameA { name, .. } => format!("@{}", self.string(name)),
+            Multiname::MultinameL { .. } => "<rt>".to_string(),
+            Multiname::MultinameLA { .. } => "@<rt>".to_string(),
+            Multiname::TypeName {
+                base_type,
+                parameters,
+            } => {
+                let parameters: Vec<_> = parameters
+                    .iter()
+                    .map(|parameter| self.multiname_with_depth(parameter, depth + 1))
+                    .collect();
+                format!(
+                    "{}.<{}>",
+                    self.multiname_with_depth(base_type, depth + 1),
+                    parameters.join(", ")
+                )
+            }
+        }
+    }
+
+    fn default_value(&self, value: &DefaultValue) -> String {
+        fn constant<T: ToString>(values: &[T], index: u32) -> String {
+            index
+                .checked_sub(1)
+                .and_then(|i| values.get(i as usize))
+                .map_or_else(|| format!("<invalid constant {index}>"), T::to_string)
+        }
+
+        let pool = &self.abc.constant_pool;
+        match value {
+            DefaultValue::Int(index) => constant(&pool.ints, index.0),
+            DefaultValue::Uint(index) => constant(&pool.uints, index.0),
+            DefaultValue::Double(index) => constant(&pool.doubles, index.0),
+            DefaultValue::String(index) => format!("{:?}", self.string(index)),
+            DefaultValue::True => "true".to_string(),
+            DefaultValue::False => "false".to_string(),
+            DefaultValue::Null => "null".to_string(),
+            DefaultValue::Undefined => "undefined".to_string(),
+            DefaultValue::Namespace(index)
+            | DefaultValue::Package(index)
+            | DefaultValue::PackageInternal(index)
+            | DefaultValue::Protected(index)
+            | DefaultValue::Explicit(index)
+            | DefaultValue::StaticProtected(index)
+            | DefaultValue::Private(index) => self.namespace(index),
+        }
+    }
+
+    /// Dumps a list of traits, naming the methods they refer to after `owner`.
+    fn traits(
+        &self,
+        traits: &[Trait],
+        owner: &str,
+        method_names: &mut [Option<String>],
+    ) -> Vec<TraitDump> {
+        traits
+            .iter()
+            .map(|t| {
+                let name = self.multiname(&t.name);
+                let mut dump = TraitDump {
+                    name: name.clone(),
+                    kind: "",
+                    type_name: None,
+                    value: None,
+                    method: None,
+                    class: None,
+                    is_final: t.is_final,
+                    is_override: t.is_override,
+                };
+                match &t.kind {
+                    TraitKind::Slot {
+                        type_name, value, ..
+                    }
+                    | TraitKind::Const {
+                        type_name, value, ..
+                    } => {
+                        dump.kind = if matches!(t.kind, TraitKind::Slot { .. }) {
+                            "slot"
+                        } else {
+                            "const"
+                        };
+                        dump.type_name = Some(self.multiname(type_name));
+                        dump.value = value.as_ref().map(|value| self.default_value(value));
+                    }
+                    TraitKind::Method { method, .. } => {
+                        dump.kind = "method";
+                        dump.method = Some(method.0);
+                        name_method(method_names, method, || format!("{owner}/{name}"));
+                    }
+                    TraitKind::Getter { method, .. } => {
+                        dump.kind = "getter";
+                        dump.method = Some(method.0);
+                        name_method(method_names, method, || format!("{owner}/get {name}"));
+                    }
+                    TraitKind::Setter { method, .. } => {
+                        dump.kind = "setter";
+                        dump.method = Some(method.0);
+                        name_method(method_names, method, || format!("{owner}/set {name}"));
+                    }
+                    TraitKind::Class { class, .. } => {
+                        dump.kind = "class";
+                        dump.class = Some(class.0);
+                    }
+                    TraitKind::Function { function, .. } => {
+                        dump.kind = "function";
+                        dump.method = Some(function.0);
+                        name_method(method_names, function, || format!("{owner}/{name}"));
+                    }
+                }
+                dump
+            })
+            .collect()
+    }
+
+    fn method(
+        &self,
+        index: usize,
+        name: String,
+        method: &Method,
+        body: Option<&MethodBody>,
+    ) -> MethodDump {
+        let params = method
+            .params
+            .iter()
+            .map(|param| ParamDump {
+                name: param.name.as_ref().map(|name| self.string(name)),
+                type_name: self.multiname(&param.kind),
+                default_value: param
+                    .default_value
+                    .as_ref()
+                    .map(|value| self.default_value(value)),
+            })
+            .collect();
+
+        MethodDump {
+            index,
+            name,
+            params,
+            return_type: self.multiname(&method.return_type),
+            flags: method
+                .flags
+                .iter_names()
+                .map(|(name, _)| name.to_string())
+                .collect(),
+            body: body.map(|body| self.body(body)),
+        }
+    }
+
+    fn body(&self, body: &MethodBody) -> BodyDump {
+        let mut code = vec![];
+        let mut error = None;
+        let mut reader = Reader::new(&body.code);
+        while !reader.as_slice().is_empty() {
+            let offset = body.code.len() - reader.as_slice().len();
+            match reader.read_op() {
+                Ok(op) => code.push(InstructionDump {
+                    offset,
+                    op: self.op(&op),
+                }),
+                Err(e) => {
+                    error = Some(format!("At offset {offset}: {e}"));
+                    break;
+                }
+            }
+        }
+
+        BodyDump {
+            max_stack: body.max_stack,
+            num_locals: body.num_locals,
+            init_scope_depth: body.init_scope_depth,
+            max_scope_depth: body.max_scope_depth,
+            exceptions: body
+                .exceptions
+                .iter()
+                .map(|exception| ExceptionDump {
+                    from: exception.from_offset,
+                    to: exception.to_offset,
+                    target: exception.target_offset,
+                    variable_name: self.multiname(&exception.variable_name),
+                    type_name: self.multiname(&exception.type_name),
+                })
+                .collect(),
+            code,
+            error,
+        }
+    }
+
+    /// Formats an instruction, resolving any references into the constant pool.
+    ///
+    /// Instructions without any such references are printed as-is.
+    fn op(&self, op: &Op) -> String {
+        match op {
+            Op::AsType { type_name } => {
+                format!("AsType {{ type_name: {} }}", self.multiname(type_name))
+            }
+            Op::CallProperty { index, num_args } => format!(
+                "CallProperty {{ index: {}, num_args: {num_args} }}",
+                self.multiname(index)
+            ),
+            Op::CallPropLex { index, num_args } => format!(
+                "CallPropLex {{ index: {}, num_args: {num_args} }}",
+                self.multiname(index)
+            ),
+            Op::CallPropVoid { index, num_args } => format!(
+                "CallPropVoid {{ index: {}, num_args: {num_args} }}",
+                self.multiname(index)
+            ),
+            Op::CallStatic { index, num_args } => format!(
+                "CallStatic {{ index: method{}, num_args: {num_args} }}",
+                index.0
+            ),
+            Op::CallSuper { index, num_args } => format!(
+                "CallSuper {{ index: {}, num_args: {num_args} }}",
+                self.multiname(index)
+            ),
+            Op::CallSuperVoid { index, num_args } => format!(
+                "CallSuperVoid {{ index: {}, num_args: {num_args} }}",
+                self.multiname(index)
+            ),
+            Op::Coerce { index } => format!("Coerce {{ index: {} }}", self.multiname(index)),
+            Op::ConstructProp { index, num_args } => format!(
+                "ConstructProp {{ index: {}, num_args: {num_args} }}",
+                self.multiname(index)
+            ),
+            Op::Debug {
+                is_local_register,
+                register_name,
+                register,
+            } => format!(
+                "Debug {{ is_local_register: {is_local_register}, register_name: {:?}, register: {register} }}",
+                self.string(register_name)
+            ),
+            Op::DebugFile { file_name } => {
+                format!("DebugFile {{ file_name: {:?} }}", self.string(file_name))
+            }
+            Op::DeleteProperty { index } => {
+                format!("DeleteProperty {{ index: {} }}", self.multiname(index))
+            }
+            Op::Dxns { index } => format!("Dxns {{ index: {:?} }}", self.string(index)),
+            Op::FindDef { index } => format!("FindDef {{ index: {} }}", self.multiname(index)),
+            Op::FindProperty { index } => {
+                format!("FindProperty {{ index: {} }}", self.multiname(index))
+            }
+            Op::FindPropStrict { index } => {
+                format!("FindPropStrict {{ index: {} }}", self.multiname(index))
+            }
+            Op::GetDescendants { index } => {
+                format!("GetDescendants {{ index: {} }}", self.multiname(index))
+            }
+            Op::GetLex { index } => format!("GetLex {{ index: {} }}", self.multiname(index)),
+            Op::GetProperty { index } => {
+                format!("GetProperty {{ index: {} }}", self.multiname(index))
+            }
+            Op::GetSuper { index } => format!("GetSuper {{ index: {} }}", self.multiname(index)),
+            Op::InitProperty { index } => {
+                format!("InitProperty {{ index: {} }}", self.multiname(index))
+            }
+            Op::IsType { index } => format!("IsType {{ index: {} }}", self.multiname(index)),
+            Op::NewCatch { index } => format!("NewCatch {{ index: {} }}", index.0),
+            Op::NewClass { index } => format!("NewClass {{ index: class{} }}", index.0),
+            Op::NewFunction { index } => format!("NewFunction {{ index: method{} }}", index.0),
+            Op::PushDouble { value } => format!(
+                "PushDouble {{ value: {} }}",
+                self.default_value(&DefaultValue::Double(*value))
+            ),
+            Op::PushInt { value } => format!(
+                "PushInt {{ value: {} }}",
+                self.default_value(&DefaultValue::Int(*value))
+            ),
+            Op::PushNamespace { value } => {
+                format!("PushNamespace {{ value: {} }}", self.namespace(value))
+            }
+            Op::PushString { value } => {
+                format!("PushString {{ value: {:?} }}", self.string(value))
+            }
+            Op::PushUint { value } => format!(
+                "PushUint {{ value: {} }}",
+                self.default_value(&DefaultValue::Uint(*value))
+            ),
+            Op::SetProperty { index } => {
+                format!("SetProperty {{ index: {} }}", self.multiname(index))
+            }
+            Op::SetSuper { index } => format!("SetSuper {{ index: {} }}", self.multiname(index)),
+            op => format!("{op:?}"),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! CLI Options
+
+use clap::{Parser, ValueEnum};
+use std::path::PathBuf;
+
+#[derive(Parser, Debug)]
+#[clap(
+    name = "swfdump",
+    version,
+    about = "Dumps the contents of an SWF or ABC file"
+)]
+pub struct Opt {
+    /// The SWF or ABC file to inspect
+    #[clap(name = "file")]
+    pub input_path: PathBuf,
+
+    /// Output machine-readable JSON instead of text
+    #[clap(long)]
+    pub json: bool,
+
+    /// Only dump the given sections. Can be specified multiple times.
+    /// If omitted, every section is dumped.
+    #[clap(short, long = "section", value_enum, action = clap::ArgAction::Append)]
+    pub sections: Vec<Section>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Section {
+    /// The SWF header
+    Header,
+
+    /// Every tag in the file, including tags nested in sprites
+    Tags,
+
+    /// The character dictionary, with export names and symbol classes
+    Characters,
+
+    /// AVM1 action listings for frames, init actions, buttons and clip events
+    Avm1,
+
+    /// AVM2 classes, scripts and method bodies
+    Avm2,
+}
+
+impl Opt {
+    pub fn wants(&self, section: Section) -> bool {
+        self.sections.is_empty() || self.sections.contains(&section)
+    }
+}
@@ -0,0 +1,367 @@
+//! The contents of a dumped file, and the code to collect them from an SWF.
+
+use crate::avm1::{self, ActionDump};
+use crate::avm2::{self, AbcDump};
+use serde::Serialize;
+use swf::read::Reader;
+use swf::{CharacterId, Encoding, SwfBuf, SwfStr, Tag, TagCode};
+
+#[derive(Serialize, Debug, Default)]
+pub struct FileDump {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<HeaderDump>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<TagDump>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub characters: Option<Vec<CharacterDump>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avm1: Option<Vec<ActionBlockDump>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avm2: Option<Vec<AbcDump>>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct HeaderDump {
+    pub version: u8,
+    pub compression: String,
+    pub uncompressed_length: i32,
+    pub stage_width: f64,
+    pub stage_height: f64,
+    pub frame_rate: f32,
+    pub num_frames: u16,
+    pub background_color: Option<String>,
+    pub is_action_script_3: bool,
+    pub use_gpu: bool,
+    pub use_direct_blit: bool,
+    pub use_network_sandbox: bool,
+    /// The class linked to the root timeline by `SymbolClass`.
+    pub document_class: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct TagDump {
+    /// The offset of the tag, relative to the start of the (decompressed) tag stream.
+    pub offset: usize,
+    pub code: u16,
+    pub name: String,
+    /// The length of the tag body, excluding the tag header.
+    pub length: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The tags of a `DefineSprite`'s timeline.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<TagDump>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct CharacterDump {
+    pub id: CharacterId,
+    /// The name of the tag that defined this character.
+    pub kind: String,
+    pub export_name: Option<String>,
+    pub class_name: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ActionBlockDump {
+    /// A description of where this code lives, such as `frame 3 of sprite 12`.
+    pub location: String,
+    pub actions: Vec<ActionDump>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Collects everything that we know how to dump from an SWF.
+pub fn dump_swf(swf_buf: &SwfBuf) -> FileDump {
+    let mut builder = Builder {
+        version: swf_buf.header.version(),
+        encoding: SwfStr::encoding_for_version(swf_buf.header.version()),
+        characters: vec![],
+        avm1: vec![],
+        avm2: vec![],
+        document_class: None,
+    };
+    let tags = builder.walk_tags(&swf_buf.data, &swf_buf.data, None);
+
+    let header = &swf_buf.header;
+    let stage_size = header.stage_size();
+    let header = HeaderDump {
+        version: header.version(),
+        compression: format!("{:?}", header.compression()),
+        uncompressed_length: header.uncompressed_len(),
+        stage_width: stage_size.width().to_pixels(),
+        stage_height: stage_size.height().to_pixels(),
+        frame_rate: header.frame_rate().to_f32(),
+        num_frames: header.num_frames(),
+        background_color: header
+            .background_color()
+            .map(|color| format!("#{:02X}{:02X}{:02X}", color.r, color.g, color.b)),
+        is_action_script_3: header.is_action_script_3(),
+        use_gpu: header.use_gpu(),
+        use_direct_blit: header.use_direct_blit(),
+        use_network_sandbox: header.use_network_sandbox(),
+        document_class: builder.document_class,
+    };
+
+    FileDump {
+        header: Some(header),
+        tags: Some(tags),
+        characters: Some(builder.characters),
+        avm1: Some(builder.avm1),
+        avm2: Some(builder.avm2),
+    }
+}
+
+/// Collects everything that we know how to dump from a standalone ABC file.
+pub fn dump_abc(data: &[u8]) -> FileDump {
+    FileDump {
+        avm2: Some(vec![avm2::dump_abc(None, false, data)]),
+        ..Default::default()
+    }
+}
+
+struct Builder {
+    version: u8,
+    encoding: &'static Encoding,
+    characters: Vec<CharacterDump>,
+    avm1: Vec<ActionBlockDump>,
+    avm2: Vec<AbcDump>,
+    document_class: Option<String>,
+}
+
+impl Builder {
+    /// Walks the tag stream in `data`, which is a subslice of the whole
+    /// tag stream `stream`.
+    ///
+    /// `sprite` is the ID of the sprite whose timeline is being walked,
+    /// or `None` for the root timeline.
+    fn walk_tags(
+        &mut self,
+        stream: &[u8],
+        data: &[u8],
+        sprite: Option<CharacterId>,
+    ) -> Vec<TagDump> {
+        let mut tags = vec![];
+        let mut position = 0;
+        let mut frame = 1;
+        while position < data.len() {
+            let offset = data[position..].as_ptr() as usize - stream.as_ptr() as usize;
+            let mut reader = Reader::new(&data[position..], self.version);
+            let (code, length) = match reader.read_tag_code_and_length() {
+                Ok(header) => header,
+                Err(e) => {
+                    tags.push(TagDump {
+                        offset,
+                        code: 0,
+                        name: "<invalid tag header>".to_string(),
+                        length: 0,
+                        summary: None,
+                        error: Some(e.to_string()),
+                        children: vec![],
+                    });
+                    break;
+                }
+            };
+            let header_length = data.len() - position - reader.get_ref().len();
+            let end = position + header_length + length;
+            let mut dump = TagDump {
+                offset,
+                code,
+                name: TagCode::format(code),
+                length,
+                summary: None,
+                error: None,
+                children: vec![],
+            };
+            if end > data.len() {
+                dump.error = Some(format!(
+                    "Tag is truncated ({} of {length} bytes available)",
+                    data.len() - position - header_length
+                ));
+                tags.push(dump);
+                break;
+            }
+
+            match Reader::new(&data[position..end], self.version).read_tag() {
+                Ok(tag) => {
+                    dump.summary = self.summarize(&tag);
+                    self.collect(&tag, code, sprite, frame);
+                    if let Tag::ShowFrame = tag {
+                        frame += 1;
+                    }
+                }
+                Err(e) => dump.error = Some(e.to_string()),
+            }
+
+            let body = &data[position + header_length..end];
+            if code == TagCode::DefineSprite as u16 && body.len() >= 4 {
+                // Walk the nested timeline ourselves, so that we can report tag offsets
+                // and keep going even if one of the nested tags fails to parse.
+                let id = u16::from_le_bytes([body[0], body[1]]);
+                dump.children = self.walk_tags(stream, &body[4..], Some(id));
+            }
+            tags.push(dump);
+            position = end;
+
+            if code == TagCode::End as u16 {
+                break;
+            }
+        }
+
+        tags
+    }
+
+    fn string(&self, s: &SwfStr) -> String {
+        s.to_string_lossy(self.encoding)
+    }
+
+    fn summarize(&self, tag: &Tag) -> Option<String> {
+        let summary = match tag {
+            Tag::DefineSprite(sprite) => {
+                format!("id {}, {} frames", sprite.id, sprite.num_frames)
+            }
+            Tag::DoAbc2(do_abc) => format!("\"{}\"", self.string(do_abc.name)),
+            Tag::DoInitAction { id, .. } => format!("sprite {id}"),
+            Tag::ExportAssets(exports)
+            | Tag::ImportAssets {
+                imports: exports, ..
+            } => exports
+                .iter()
+                .map(|export| format!("{} as \"{}\"", export.id, self.string(export.name)))
+                .collect::<Vec<_>>()
+                .join(", "),
+            Tag::FrameLabel(label) => format!("\"{}\"", self.string(label.label)),
+            Tag::PlaceObject(place) => {
+                let mut summary = format!("depth {}", place.depth);
+                if let Some(name) = place.name {
+                    summary += &format!(", name \"{}\"", self.string(name));
+                }
+                summary
+            }
+            Tag::RemoveObject(remove) => format!("depth {}", remove.depth),
+            Tag::SetBackgroundColor(color) => {
+                format!("#{:02X}{:02X}{:02X}", color.r, color.g, color.b)
+            }
+            Tag::SymbolClass(links) => links
+                .iter()
+                .map(|link| format!("{} as {}", link.id, self.string(link.class_name)))
+                .collect::<Vec<_>>()
+                .join(", "),
+            _ => format!("id {}", character_id(tag)?),
+        };
+        Some(summary)
+    }
+
+    /// Records any characters or code defined by a tag.
+    fn collect(&mut self, tag: &Tag, code: u16, sprite: Option<CharacterId>, frame: u16) {
+        let timeline = match sprite {
+            Some(id) => format!("frame {frame} of sprite {id}"),
+            None => format!("frame {frame}"),
+        };
+
+        match tag {
+            Tag::ExportAssets(exports) => {
+                for export in exports {
+                    let name = self.string(export.name);
+                    if let Some(character) = self.character_mut(export.id) {
+                        character.export_name = Some(name);
+                    }
+                }
+            }
+            Tag::SymbolClass(links) => {
+                for link in links {
+                    let class_name = self.string(link.class_name);
+                    if link.id == 0 {
+                        self.document_class = Some(class_name);
+                    } else if let Some(character) = self.character_mut(link.id) {
+                        character.class_name = Some(class_name);
+                    }
+                }
+            }
+            Tag::DoAction(action_data) => self.add_actions(timeline, action_data),
+            Tag::DoInitAction { id, action_data } => {
+                self.add_actions(format!("init actions of sprite {id}"), action_data)
+            }
+            Tag::DefineButton(button) | Tag::DefineButton2(button) => {
+                for action in &button.actions {
+                    self.add_actions(
+                        format!("button {} ({:?})", button.id, action.conditions),
+                        action.action_data,
+                    );
+                }
+            }
+            Tag::PlaceObject(place) => {
+                for clip_action in place.clip_actions.iter().flatten() {
+                    self.add_actions(
+                        format!(
+                            "depth {} on {timeline} ({:?})",
+                            place.depth, clip_action.events
+                        ),
+                        clip_action.action_data,
+                    );
+                }
+            }
+            Tag::DoAbc(data) => self.avm2.push(avm2::dump_abc(None, false, data)),
+            Tag::DoAbc2(do_abc) => self.avm2.push(avm2::dump_abc(
+                Some(self.string(do_abc.name)),
+                do_abc.flags.contains(swf::DoAbc2Flag::LAZY_INITIALIZE),
+                do_abc.data,
+            )),
+            _ => {}
+        }
+
+        if let Some(id) = character_id(tag) {
+            self.characters.push(CharacterDump {
+                id,
+                kind: TagCode::format(code),
+                export_name: None,
+                class_name: None,
+            });
+        }
+    }
+
+    fn character_mut(&mut self, id: CharacterId) -> Option<&mut CharacterDump> {
+        self.characters
+            .iter_mut()
+            .rev()
+            .find(|character| character.id == id)
+    }
+
+    fn add_actions(&mut self, location: String, data: &[u8]) {
+        let (actions, error) = avm1::disassemble(data, self.version);
+        self.avm1.push(ActionBlockDump {
+            location,
+            actions,
+            error: error.map(|e| e.to_string()),
+        });
+    }
+}
+
+/// The ID of the character defined by this tag, if it defines one.
+fn character_id(tag: &Tag) -> Option<CharacterId> {
+    let id = match tag {
+        Tag::DefineBinaryData(data) => data.id,
+        Tag::DefineBits { id, .. } | Tag::DefineBitsJpeg2 { id, .. } => *id,
+        Tag::DefineBitsJpeg3(jpeg) => jpeg.id,
+        Tag::DefineBitsLossless(bitmap) => bitmap.id,
+        Tag::DefineButton(button) | Tag::DefineButton2(button) => button.id,
+        Tag::DefineEditText(text) => text.id(),
+        Tag::DefineFont(font) => font.id,
+        Tag::DefineFont2(font) => font.id,
+        Tag::DefineFont4(font) => font.id,
+        Tag::DefineMorphShape(morph_shape) => morph_shape.id,
+        Tag::DefineShape(shape) => shape.id,
+        Tag::DefineSound(sound) => sound.id,
+        Tag::DefineSprite(sprite) => sprite.id,
+        Tag::DefineText(text) | Tag::DefineText2(text) => text.id,
+        Tag::DefineVideoStream(video) => video.id,
+        _ => return None,
+    };
+    Some(id)
+}
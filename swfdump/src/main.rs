@@ -0,0 +1,56 @@
+use crate::cli_options::{Opt, Section};
+use crate::dump::{dump_abc, dump_swf};
+use crate::text::write_text;
+use anyhow::{anyhow, Context, Result};
+use clap::Parser;
+use std::io::{BufWriter, Write};
+
+mod avm1;
+mod avm2;
+mod cli_options;
+mod dump;
+mod text;
+
+fn main() -> Result<()> {
+    let opt = Opt::parse();
+
+    let data = std::fs::read(&opt.input_path)
+        .with_context(|| format!("Couldn't read {}", opt.input_path.display()))?;
+
+    // Anything that doesn't look like an SWF is assumed to be a standalone ABC file.
+    let mut dump = if matches!(data.get(..3), Some(b"FWS" | b"CWS" | b"ZWS")) {
+        // `swf::error::Error` isn't `Send + Sync`, so it can't be wrapped with `context`.
+        let swf_buf =
+            swf::decompress_swf(&data[..]).map_err(|e| anyhow!("Couldn't decompress SWF: {e}"))?;
+        dump_swf(&swf_buf)
+    } else {
+        dump_abc(&data)
+    };
+
+    if !opt.wants(Section::Header) {
+        dump.header = None;
+    }
+    if !opt.wants(Section::Tags) {
+        dump.tags = None;
+    }
+    if !opt.wants(Section::Characters) {
+        dump.characters = None;
+    }
+    if !opt.wants(Section::Avm1) {
+        dump.avm1 = None;
+    }
+    if !opt.wants(Section::Avm2) {
+        dump.avm2 = None;
+    }
+
+    let mut out = BufWriter::new(std::io::stdout().lock());
+    if opt.json {
+        serde_json::to_writer_pretty(&mut out, &dump)?;
+        writeln!(out)?;
+    } else {
+        write_text(&dump, &mut out)?;
+    }
+    out.flush()?;
+
+    Ok(())
+}
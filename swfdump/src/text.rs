@@ -0,0 +1,267 @@
+//! Human-readable output.
+
+use crate::avm2::{AbcDump, MethodDump, TraitDump};
+use crate::dump::{FileDump, TagDump};
+use std::io::{self, Write};
+
+pub fn write_text(dump: &FileDump, out: &mut impl Write) -> io::Result<()> {
+    if let Some(header) = &dump.header {
+        writeln!(out, "Header:")?;
+        writeln!(
+            out,
+            "  SWF version {}, {} compression, {} bytes uncompressed",
+            header.version, header.compression, header.uncompressed_length
+        )?;
+        writeln!(
+            out,
+            "  Stage {}x{}, {} fps, {} frames",
+            header.stage_width, header.stage_height, header.frame_rate, header.num_frames
+        )?;
+        if let Some(color) = &header.background_color {
+            writeln!(out, "  Background color {color}")?;
+        }
+        writeln!(
+            out,
+            "  ActionScript {}",
+            if header.is_action_script_3 { 3 } else { 2 }
+        )?;
+        if let Some(class) = &header.document_class {
+            writeln!(out, "  Document class {class}")?;
+        }
+        writeln!(out)?;
+    }
+
+    if let Some(tags) = &dump.tags {
+        writeln!(out, "Tags:")?;
+        write_tags(tags, 1, out)?;
+        writeln!(out)?;
+    }
+
+    if let Some(characters) = &dump.characters {
+        writeln!(out, "Characters:")?;
+        if characters.is_empty() {
+            writeln!(out, "  none")?;
+        }
+        for character in characters {
+            write!(out, "  {:5} {}", character.id, character.kind)?;
+            if let Some(name) = &character.export_name {
+                write!(out, " exported as \"{name}\"")?;
+            }
+            if let Some(class) = &character.class_name {
+                write!(out, " class {class}")?;
+            }
+            writeln!(out)?;
+        }
+        writeln!(out)?;
+    }
+
+    if let Some(blocks) = &dump.avm1 {
+        writeln!(out, "AVM1:")?;
+        if blocks.is_empty() {
+            writeln!(out, "  none")?;
+            writeln!(out)?;
+        }
+        for block in blocks {
+            writeln!(out, "  {}:", block.location)?;
+            for action in &block.actions {
+                writeln!(
+                    out,
+                    "    {:05}  {:indent$}{}",
+                    action.offset,
+                    "",
+                    action.action,
+                    indent = action.depth * 2
+                )?;
+            }
+            if let Some(error) = &block.error {
+                writeln!(out, "    error: {error}")?;
+            }
+            writeln!(out)?;
+        }
+    }
+
+    if let Some(abcs) = &dump.avm2 {
+        writeln!(out, "AVM2:")?;
+        if abcs.is_empty() {
+            writeln!(out, "  none")?;
+        }
+        for abc in abcs {
+            write_abc(abc, out)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_tags(tags: &[TagDump], depth: usize, out: &mut impl Write) -> io::Result<()> {
+    for tag in tags {
+        write!(
+            out,
+            "{:indent$}{:08X} {} ({} bytes)",
+            "",
+            tag.offset,
+            tag.name,
+            tag.length,
+            indent = depth * 2
+        )?;
+        if let Some(summary) = &tag.summary {
+            write!(out, ": {summary}")?;
+        }
+        if let Some(error) = &tag.error {
+            write!(out, " [error: {error}]")?;
+        }
+        writeln!(out)?;
+        write_tags(&tag.children, depth + 1, out)?;
+    }
+    Ok(())
+}
+
+fn write_abc(abc: &AbcDump, out: &mut impl Write) -> io::Result<()> {
+    write!(out, "  ABC")?;
+    if let Some(name) = &abc.name {
+        write!(out, " \"{name}\"")?;
+    }
+    write!(out, " version {}.{}", abc.major_version, abc.minor_version)?;
+    if abc.lazy_initialize {
+        write!(out, ", lazy initialize")?;
+    }
+    writeln!(out)?;
+    if let Some(error) = &abc.error {
+        writeln!(out, "    error: {error}")?;
+        return writeln!(out);
+    }
+    writeln!(out)?;
+
+    for class in &abc.classes {
+        write!(out, "    ")?;
+        if class.is_final {
+            write!(out, "final ")?;
+        }
+        if !class.is_sealed {
+            write!(out, "dynamic ")?;
+        }
+        let keyword = if class.is_interface {
+            "interface"
+        } else {
+            "class"
+        };
+        write!(out, "{keyword} {}", class.name)?;
+        if let Some(super_name) = &class.super_name {
+            write!(out, " extends {super_name}")?;
+        }
+        if !class.interfaces.is_empty() {
+            write!(out, " implements {}", class.interfaces.join(", "))?;
+        }
+        writeln!(out, " (class{})", class.index)?;
+        writeln!(
+            out,
+            "      instance init method{}, class init method{}",
+            class.instance_init, class.class_init
+        )?;
+        for t in &class.class_traits {
+            write_trait(t, "static ", out)?;
+        }
+        for t in &class.instance_traits {
+            write_trait(t, "", out)?;
+        }
+        writeln!(out)?;
+    }
+
+    for script in &abc.scripts {
+        writeln!(
+            out,
+            "    script{} (init method{})",
+            script.index, script.init
+        )?;
+        for t in &script.traits {
+            write_trait(t, "", out)?;
+        }
+        writeln!(out)?;
+    }
+
+    for method in &abc.methods {
+        write_method(method, out)?;
+    }
+
+    Ok(())
+}
+
+fn write_trait(t: &TraitDump, prefix: &str, out: &mut impl Write) -> io::Result<()> {
+    write!(out, "      ")?;
+    if t.is_override {
+        write!(out, "override ")?;
+    }
+    if t.is_final {
+        write!(out, "final ")?;
+    }
+    write!(out, "{prefix}{} {}", t.kind, t.name)?;
+    if let Some(type_name) = &t.type_name {
+        write!(out, ":{type_name}")?;
+    }
+    if let Some(value) = &t.value {
+        write!(out, " = {value}")?;
+    }
+    if let Some(method) = t.method {
+        write!(out, " (method{method})")?;
+    }
+    if let Some(class) = t.class {
+        write!(out, " (class{class})")?;
+    }
+    writeln!(out)
+}
+
+fn write_method(method: &MethodDump, out: &mut impl Write) -> io::Result<()> {
+    let params: Vec<_> = method
+        .params
+        .iter()
+        .enumerate()
+        .map(|(i, param)| {
+            let mut param_text = match &param.name {
+                Some(name) => format!("{name}:{}", param.type_name),
+                None => format!("arg{}:{}", i + 1, param.type_name),
+            };
+            if let Some(value) = &param.default_value {
+                param_text += &format!(" = {value}");
+            }
+            param_text
+        })
+        .collect();
+    write!(
+        out,
+        "    method{} {}({}):{}",
+        method.index,
+        method.name,
+        params.join(", "),
+        method.return_type
+    )?;
+    if !method.flags.is_empty() {
+        write!(out, " [{}]", method.flags.join(", "))?;
+    }
+    writeln!(out)?;
+
+    if let Some(body) = &method.body {
+        writeln!(
+            out,
+            "      max stack {}, locals {}, scope depth {}..{}",
+            body.max_stack, body.num_locals, body.init_scope_depth, body.max_scope_depth
+        )?;
+        for exception in &body.exceptions {
+            writeln!(
+                out,
+                "      try {:05}..{:05} catch {}:{} -> {:05}",
+                exception.from,
+                exception.to,
+                exception.variable_name,
+                exception.type_name,
+                exception.target
+            )?;
+        }
+        for instruction in &body.code {
+            writeln!(out, "      {:05}  {}", instruction.offset, instruction.op)?;
+        }
+        if let Some(error) = &body.error {
+            writeln!(out, "      error: {error}")?;
+        }
+    }
+    writeln!(out)
+}
@@ -0,0 +1,165 @@
+//! Runs `swfdump` on the files in `tests/fixtures`.
+//!
+//! The text output is compared against the `.txt` file next to each fixture;
+//! the JSON output is checked field by field.
+
+use serde_json::{json, Value};
+use std::path::PathBuf;
+use std::process::Command;
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name)
+}
+
+fn swfdump(args: &[&str], file: &str) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_swfdump"))
+        .args(args)
+        .arg(fixture(file))
+        .output()
+        .expect("Failed to run swfdump");
+    assert!(
+        output.status.success(),
+        "swfdump {args:?} {file} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).expect("swfdump output should be UTF-8")
+}
+
+fn swfdump_json(args: &[&str], file: &str) -> Value {
+    let args = [&["--json"], args].concat();
+    serde_json::from_str(&swfdump(&args, file)).expect("swfdump should output valid JSON")
+}
+
+fn expected_text(file: &str) -> String {
+    std::fs::read_to_string(fixture(&format!("{file}.txt")))
+        .expect("Failed to read expected output")
+        .replace("\r\n", "\n")
+}
+
+#[test]
+fn swf_text() {
+    assert_eq!(swfdump(&[], "avm2.swf"), expected_text("avm2.swf"));
+}
+
+#[test]
+fn abc_text() {
+    assert_eq!(swfdump(&[], "avm2.abc"), expected_text("avm2.abc"));
+}
+
+#[test]
+fn swf_json() {
+    let dump = swfdump_json(&[], "avm2.swf");
+
+    let header = &dump["header"];
+    assert_eq!(header["version"], 34);
+    assert_eq!(header["compression"], "None");
+    assert_eq!(header["stage_width"], 550.0);
+    assert_eq!(header["stage_height"], 400.0);
+    assert_eq!(header["num_frames"], 1);
+    assert_eq!(header["is_action_script_3"], true);
+    assert_eq!(header["document_class"], Value::Null);
+
+    let names: Vec<_> = dump["tags"]
+        .as_array()
+        .expect("tags should be an array")
+        .iter()
+        .map(|tag| tag["name"].as_str().expect("tag should have a name"))
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "FileAttributes",
+            "SetBackgroundColor",
+            "DefineSceneAndFrameLabelData",
+            "DefineSprite",
+            "DoAbc2",
+            "SymbolClass",
+            "ShowFrame",
+            "End"
+        ]
+    );
+    assert_eq!(
+        dump["tags"][3]["children"].as_array().map(Vec::len),
+        Some(2)
+    );
+
+    assert_eq!(
+        dump["characters"],
+        json!([{
+            "id": 1,
+            "kind": "DefineSprite",
+            "export_name": null,
+            "class_name": "Avm2Test"
+        }])
+    );
+    assert_eq!(dump["avm1"], json!([]));
+
+    let abc = &dump["avm2"][0];
+    assert_eq!(abc["name"], "");
+    assert_eq!(abc["lazy_initialize"], true);
+    assert_eq!(abc["methods"][0]["name"], "script0/Avm2Test");
+}
+
+#[test]
+fn abc_json() {
+    let dump = swfdump_json(&[], "avm2.abc");
+
+    // A standalone ABC file only has the AVM2 section.
+    let sections: Vec<_> = dump
+        .as_object()
+        .expect("dump should be an object")
+        .keys()
+        .collect();
+    assert_eq!(sections, ["avm2"]);
+
+    let abc = &dump["avm2"][0];
+    assert_eq!(abc["lazy_initialize"], false);
+    assert_eq!(
+        (abc["major_version"].clone(), abc["minor_version"].clone()),
+        (json!(46), json!(16))
+    );
+    assert_eq!(abc["scripts"][0]["init"], 1);
+    assert_eq!(abc["scripts"][0]["traits"][0]["name"], "Avm2Test");
+
+    let method = &abc["methods"][0];
+    assert_eq!(method["return_type"], "void");
+    let ops: Vec<_> = method["body"]["code"]
+        .as_array()
+        .expect("method should have code")
+        .iter()
+        .map(|op| op["op"].as_str().expect("op should be a string"))
+        .collect();
+    assert_eq!(
+        ops,
+        [
+            "GetLocal { index: 0 }",
+            "PushScope",
+            "FindPropStrict { index: trace }",
+            "PushString { value: \"Test\" }",
+            "CallPropVoid { index: trace, num_args: 1 }",
+            "ReturnVoid"
+        ]
+    );
+
+    // The ABC is the same one that the SWF fixture contains.
+    let swf = swfdump_json(&["--section", "avm2"], "avm2.swf");
+    assert_eq!(swf["avm2"][0]["methods"], abc["methods"]);
+}
+
+#[test]
+fn sections() {
+    let dump = swfdump_json(&["-s", "header", "-s", "characters"], "avm2.swf");
+    let sections: Vec<_> = dump
+        .as_object()
+        .expect("dump should be an object")
+        .keys()
+        .collect();
+    assert_eq!(sections, ["characters", "header"]);
+
+    let text = swfdump(&["--section", "tags"], "avm2.swf");
+    assert!(text.starts_with("Tags:\n"));
+    assert!(!text.contains("AVM2:"));
+}
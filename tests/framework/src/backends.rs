@@ -1,9 +1,9 @@
-mod audio;
 mod log;
 mod navigator;
+mod print;
 mod ui;
 
-pub use audio::TestAudioBackend;
 pub use log::TestLogBackend;
 pub use navigator::TestNavigatorBackend;
+pub use print::TestPrintBackend;
 pub use ui::TestUiBackend;
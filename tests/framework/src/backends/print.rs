@@ -0,0 +1,73 @@
+use crate::backends::TestLogBackend;
+use image::RgbImage;
+use ruffle_core::backend::log::LogBackend;
+use ruffle_core::backend::printing::{
+    FilePrintBackend, PaperFormat, PrintBackend, PrintError, PrintFileFormat, PrintPage,
+};
+use tempfile::TempDir;
+
+/// A `PrintBackend` used by tests that logs the pages it's given.
+///
+/// Every job is printed on a small paper to PNG files in a temporary
+/// directory, and each printed page is summarised by its size and the colors
+/// of its top-left corner and center.
+pub struct TestPrintBackend {
+    log: TestLogBackend,
+    files: FilePrintBackend,
+
+    // Removes the printed files once the test is done.
+    _directory: TempDir,
+}
+
+impl TestPrintBackend {
+    pub const PAPER: PaperFormat = PaperFormat::new(100, 80, 72);
+
+    pub fn new(log: TestLogBackend) -> std::io::Result<Self> {
+        let directory = tempfile::tempdir()?;
+        let files = FilePrintBackend::new(directory.path().to_path_buf(), PrintFileFormat::Png)
+            .with_paper(Self::PAPER);
+        Ok(Self {
+            log,
+            files,
+            _directory: directory,
+        })
+    }
+}
+
+fn pixel_color(page: &RgbImage, x: u32, y: u32) -> String {
+    let [r, g, b] = page.get_pixel(x, y).0;
+    format!("#{r:02X}{g:02X}{b:02X}")
+}
+
+impl PrintBackend for TestPrintBackend {
+    fn start_job(&mut self) -> Option<PaperFormat> {
+        self.log.avm_trace("PrintBackend::start_job");
+        self.files.start_job()
+    }
+
+    fn print(&mut self, paper: &PaperFormat, pages: Vec<PrintPage>) -> Result<(), PrintError> {
+        self.log.avm_trace("PrintBackend::print:");
+        self.log.avm_trace(&format!(
+            "  Paper: {}x{} {}",
+            paper.paper_width,
+            paper.paper_height,
+            paper.orientation.as_str()
+        ));
+
+        self.files.print(paper, pages)?;
+        for (index, path) in self.files.printed_files().iter().enumerate() {
+            let page = image::open(path)
+                .map_err(|e| PrintError::Encoding(e.to_string()))?
+                .to_rgb8();
+            self.log.avm_trace(&format!(
+                "  Page {}: {}x{}, top left {}, center {}",
+                index + 1,
+                page.width(),
+                page.height(),
+                pixel_color(&page, 0, 0),
+                pixel_color(&page, page.width() / 2, page.height() / 2)
+            ));
+        }
+        Ok(())
+    }
+}
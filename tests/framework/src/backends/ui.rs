@@ -1,8 +1,9 @@
 use crate::test::Font;
 use chrono::{DateTime, Utc};
 use ruffle_core::backend::ui::{
-    DialogLoaderError, DialogResultFuture, FileDialogResult, FileFilter, FontDefinition,
-    FullscreenError, LanguageIdentifier, MouseCursor, UiBackend, US_ENGLISH,
+    ClipboardData, ClipboardFormat, DialogLoaderError, DialogResultFuture, FileDialogResult,
+    FileFilter, FontDefinition, FullscreenError, ImeConversionMode, LanguageIdentifier,
+    MouseCursor, UiBackend, US_ENGLISH,
 };
 use url::Url;
 
@@ -76,16 +77,21 @@ impl FileDialogResult for TestFileDialogResult {
 /// * Attempting to display a file save dialog with a file name hint of "debug-success.txt" will simulate successfully selecting a destination
 ///   otherwise a user cancellation will be simulated
 /// * Simulated in-memory clipboard
+/// * Simulated IME state
 pub struct TestUiBackend {
     fonts: Vec<Font>,
-    clipboard: String,
+    clipboard: Vec<ClipboardData>,
+    ime_enabled: bool,
+    ime_conversion_mode: ImeConversionMode,
 }
 
 impl TestUiBackend {
     pub fn new(fonts: Vec<Font>) -> Self {
         Self {
             fonts,
-            clipboard: "".to_string(),
+            clipboard: Vec::new(),
+            ime_enabled: false,
+            ime_conversion_mode: ImeConversionMode::default(),
         }
     }
 }
@@ -100,11 +106,35 @@ impl UiBackend for TestUiBackend {
     fn set_mouse_cursor(&mut self, _cursor: MouseCursor) {}
 
     fn clipboard_content(&mut self) -> String {
-        self.clipboard.clone()
+        match self.clipboard_data(ClipboardFormat::Text) {
+            Some(ClipboardData::Text(text)) => text,
+            _ => "".to_string(),
+        }
     }
 
     fn set_clipboard_content(&mut self, content: String) {
-        self.clipboard = content;
+        self.clipboard = vec![ClipboardData::Text(content)];
+    }
+
+    fn clipboard_formats(&mut self) -> Vec<ClipboardFormat> {
+        self.clipboard.iter().map(ClipboardData::format).collect()
+    }
+
+    fn clipboard_data(&mut self, format: ClipboardFormat) -> Option<ClipboardData> {
+        self.clipboard
+            .iter()
+            .find(|data| data.format() == format)
+            .cloned()
+    }
+
+    fn set_clipboard_data(&mut self, data: ClipboardData) {
+        self.clipboard.retain(|d| d.format() != data.format());
+        self.clipboard.push(data);
+    }
+
+    fn clear_clipboard_data(&mut self, format: Option<ClipboardFormat>) {
+        self.clipboard
+            .retain(|data| format.is_some_and(|format| data.format() != format));
     }
 
     fn set_fullscreen(&mut self, _is_full: bool) -> Result<(), FullscreenError> {
@@ -185,4 +215,20 @@ impl UiBackend for TestUiBackend {
     }
 
     fn close_file_dialog(&mut self) {}
+
+    fn ime_enabled(&self) -> bool {
+        self.ime_enabled
+    }
+
+    fn set_ime_enabled(&mut self, enabled: bool) {
+        self.ime_enabled = enabled;
+    }
+
+    fn ime_conversion_mode(&self) -> ImeConversionMode {
+        self.ime_conversion_mode
+    }
+
+    fn set_ime_conversion_mode(&mut self, mode: ImeConversionMode) {
+        self.ime_conversion_mode = mode;
+    }
 }
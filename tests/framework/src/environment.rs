@@ -43,4 +43,12 @@ pub trait RenderInterface {
     ///
     /// The provided backend is guaranteed to be the same one paired with this interface.
     fn capture(&self, renderer: &mut Box<dyn RenderBackend>) -> image::RgbaImage;
+
+    /// Checks if the given render backend drew everything it was asked to,
+    /// so that its captures can be compared with the expected images.
+    ///
+    /// The provided backend is guaranteed to be the same one paired with this interface.
+    fn is_capture_supported(&self, _renderer: &mut Box<dyn RenderBackend>) -> bool {
+        true
+    }
 }
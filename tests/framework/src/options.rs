@@ -1,11 +1,11 @@
-use crate::backends::TestAudioBackend;
 use crate::environment::{Environment, RenderInterface};
 use crate::image_trigger::ImageTrigger;
-use crate::util::write_image;
+use crate::util::{write_image, write_wav_file};
 use anyhow::{anyhow, Result};
 use approx::relative_eq;
 use image::ImageFormat;
 use regex::Regex;
+use ruffle_core::backend::audio::OfflineAudioBackend;
 use ruffle_core::tag_utils::SwfMovie;
 use ruffle_core::{PlayerBuilder, PlayerRuntime, ViewportDimensions};
 use ruffle_render::backend::RenderBackend;
@@ -24,11 +24,13 @@ pub struct TestOptions {
     pub output_path: String,
     pub sleep_to_meet_frame_rate: bool,
     pub image_comparisons: HashMap<String, ImageComparison>,
+    pub audio_comparison: Option<AudioComparison>,
     pub ignore: bool,
     pub known_failure: bool,
     pub approximations: Option<Approximations>,
     pub player_options: PlayerOptions,
     pub log_fetch: bool,
+    pub log_print: bool,
     pub required_features: RequiredFeatures,
     pub fonts: HashMap<String, FontOptions>,
 }
@@ -42,11 +44,13 @@ impl Default for TestOptions {
             output_path: "output.txt".to_string(),
             sleep_to_meet_frame_rate: false,
             image_comparisons: Default::default(),
+            audio_comparison: None,
             ignore: false,
             known_failure: false,
             approximations: None,
             player_options: PlayerOptions::default(),
             log_fetch: false,
+            log_print: false,
             required_features: RequiredFeatures::default(),
             fonts: Default::default(),
         }
@@ -75,6 +79,12 @@ impl TestOptions {
             }
         }
 
+        if self.audio_comparison.is_some() && !self.player_options.with_audio {
+            return Err(anyhow!(
+                "An audio comparison is set up, but with_audio isn't enabled in player_options"
+            ));
+        }
+
         Ok(())
     }
 
@@ -169,7 +179,7 @@ impl PlayerOptions {
         }
 
         if self.with_audio {
-            player_builder = player_builder.with_audio(TestAudioBackend::default());
+            player_builder = player_builder.with_audio(OfflineAudioBackend::new());
         }
 
         player_builder = player_builder.with_player_runtime(self.runtime);
@@ -368,6 +378,79 @@ impl ImageComparison {
     }
 }
 
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct AudioComparison {
+    path: String,
+    tolerance: u16,
+    max_outliers: usize,
+}
+
+impl Default for AudioComparison {
+    fn default() -> Self {
+        Self {
+            path: "output.wav".to_string(),
+            tolerance: 0,
+            max_outliers: 0,
+        }
+    }
+}
+
+impl AudioComparison {
+    pub fn expected_path(&self, test_path: &VfsPath) -> Result<VfsPath> {
+        Ok(test_path.join(&self.path)?)
+    }
+
+    pub fn test(
+        &self,
+        actual_samples: &[i16],
+        expected_samples: &[i16],
+        test_path: &VfsPath,
+        known_failure: bool,
+    ) -> Result<()> {
+        // Samples missing from either recording count as outliers.
+        let outliers = expected_samples
+            .iter()
+            .zip(actual_samples)
+            .filter(|(expected, actual)| expected.abs_diff(**actual) > self.tolerance)
+            .count()
+            + expected_samples.len().abs_diff(actual_samples.len());
+
+        let max_difference = expected_samples
+            .iter()
+            .zip(actual_samples)
+            .map(|(expected, actual)| expected.abs_diff(*actual))
+            .max()
+            .unwrap_or_default();
+
+        if outliers > self.max_outliers {
+            if !known_failure {
+                // If we're expecting failure, spamming files isn't productive.
+                let name = self.path.trim_end_matches(".wav");
+                write_wav_file(
+                    &test_path.join(format!("{name}.actual.wav"))?,
+                    actual_samples,
+                )?;
+            }
+
+            return Err(anyhow!(
+                "Audio '{}' failed: Number of outliers ({}) is bigger than allowed limit of {}. Max difference is {}",
+                self.path,
+                outliers,
+                self.max_outliers,
+                max_difference
+            ));
+        } else {
+            println!(
+                "Audio '{}' succeeded: {outliers} outliers found, max difference {max_difference}",
+                self.path
+            );
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Clone, Deserialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct RenderOptions {
@@ -394,3 +477,79 @@ pub struct FontOptions {
     pub bold: bool,
     pub italic: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::{read_bytes, read_wav};
+    use vfs::MemoryFS;
+
+    fn comparison(tolerance: u16, max_outliers: usize) -> AudioComparison {
+        AudioComparison {
+            tolerance,
+            max_outliers,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn audio_comparison_tolerance() {
+        let root = VfsPath::new(MemoryFS::new());
+        let expected = [0, 100, -100, 1000];
+
+        assert!(comparison(0, 0)
+            .test(&expected, &expected, &root, false)
+            .is_ok());
+        assert!(comparison(0, 0)
+            .test(&[0, 101, -100, 1000], &expected, &root, false)
+            .is_err());
+        assert!(comparison(2, 0)
+            .test(&[2, 98, -101, 1000], &expected, &root, false)
+            .is_ok());
+        assert!(comparison(2, 0)
+            .test(&[3, 100, -100, 1000], &expected, &root, false)
+            .is_err());
+    }
+
+    #[test]
+    fn audio_comparison_outliers() {
+        let root = VfsPath::new(MemoryFS::new());
+        let expected = [0, 100, -100, 1000];
+
+        assert!(comparison(2, 1)
+            .test(&[3, 100, -100, 1000], &expected, &root, false)
+            .is_ok());
+        assert!(comparison(2, 1)
+            .test(&[3, 100, -103, 1000], &expected, &root, false)
+            .is_err());
+
+        // Missing and extra samples are outliers too.
+        assert!(comparison(0, 1)
+            .test(&[0, 100, -100], &expected, &root, false)
+            .is_ok());
+        assert!(comparison(0, 1)
+            .test(&[0, 100], &expected, &root, false)
+            .is_err());
+        assert!(comparison(0, 1)
+            .test(&[0, 100, -100, 1000, 0, 0], &expected, &root, false)
+            .is_err());
+    }
+
+    #[test]
+    fn audio_comparison_writes_actual_recording() {
+        let root = VfsPath::new(MemoryFS::new());
+        let actual_path = root.join("output.actual.wav").unwrap();
+
+        // Expected failures don't write the actual recording.
+        assert!(comparison(0, 0)
+            .test(&[1, 2], &[0, 0], &root, true)
+            .is_err());
+        assert!(!actual_path.exists().unwrap());
+
+        assert!(comparison(0, 0)
+            .test(&[1, 2], &[0, 0], &root, false)
+            .is_err());
+        let actual = read_wav(&read_bytes(&actual_path).unwrap()).unwrap();
+        assert_eq!(actual, [1, 2]);
+    }
+}
@@ -1,22 +1,25 @@
-use crate::backends::{TestLogBackend, TestNavigatorBackend, TestUiBackend};
+use crate::backends::{TestLogBackend, TestNavigatorBackend, TestPrintBackend, TestUiBackend};
 use crate::environment::RenderInterface;
 use crate::fs_commands::{FsCommand, TestFsCommandProvider};
 use crate::image_trigger::ImageTrigger;
-use crate::options::{ImageComparison, TestOptions};
+use crate::options::{AudioComparison, ImageComparison, TestOptions};
 use crate::test::Test;
-use crate::util::{read_bytes, write_image};
+use crate::util::{read_bytes, read_wav, write_image, write_wav_file};
 use anyhow::{anyhow, Result};
 use image::ImageFormat;
 use pretty_assertions::Comparison;
+use ruffle_core::backend::audio::OfflineAudioBackend;
 use ruffle_core::backend::navigator::NullExecutor;
 use ruffle_core::events::MouseButton as RuffleMouseButton;
-use ruffle_core::events::{KeyCode, TextControlCode as RuffleTextControlCode};
+use ruffle_core::events::{
+    GamepadAxis as RuffleGamepadAxis, ImeEvent, KeyCode, TextControlCode as RuffleTextControlCode,
+};
 use ruffle_core::limits::ExecutionLimit;
 use ruffle_core::tag_utils::SwfMovie;
 use ruffle_core::{Player, PlayerBuilder, PlayerEvent};
 use ruffle_input_format::{
-    AutomatedEvent, InputInjector, MouseButton as InputMouseButton,
-    TextControlCode as InputTextControlCode,
+    AutomatedEvent, GamepadAxis as InputGamepadAxis, InputInjector,
+    MouseButton as InputMouseButton, TextControlCode as InputTextControlCode,
 };
 use ruffle_render::backend::{RenderBackend, ViewportDimensions};
 use ruffle_socket_format::SocketEvent;
@@ -94,6 +97,10 @@ impl TestRunner {
                 viewport_dimensions.scale_factor,
             );
 
+        if test.options.log_print {
+            builder = builder.with_print(Box::new(TestPrintBackend::new(log.clone())?));
+        }
+
         let render_interface = if let Some((interface, backend)) = renderer {
             builder = builder.with_boxed_renderer(backend);
             Some(interface)
@@ -162,7 +169,11 @@ impl TestRunner {
         } else {
             self.player.lock().unwrap().run_frame();
             self.player.lock().unwrap().update_timers(self.frame_time);
-            self.player.lock().unwrap().audio_mut().tick();
+            self.player
+                .lock()
+                .unwrap()
+                .audio_mut()
+                .tick(self.frame_time);
         }
         self.remaining_iterations -= 1;
         self.current_iteration += 1;
@@ -280,6 +291,52 @@ impl TestRunner {
                         InputTextControlCode::Delete => RuffleTextControlCode::Delete,
                     },
                 },
+                AutomatedEvent::ImeStart => PlayerEvent::Ime(ImeEvent::Start),
+                AutomatedEvent::ImePreedit { text, cursor } => {
+                    PlayerEvent::Ime(ImeEvent::Update(text.to_owned(), *cursor))
+                }
+                AutomatedEvent::ImeCommit { text } => {
+                    PlayerEvent::Ime(ImeEvent::Commit(text.to_owned()))
+                }
+                AutomatedEvent::TouchBegin { id, pos } => PlayerEvent::TouchBegin {
+                    id: *id,
+                    x: pos.0,
+                    y: pos.1,
+                },
+                AutomatedEvent::TouchMove { id, pos } => PlayerEvent::TouchMove {
+                    id: *id,
+                    x: pos.0,
+                    y: pos.1,
+                },
+                AutomatedEvent::TouchEnd { id, pos } => PlayerEvent::TouchEnd {
+                    id: *id,
+                    x: pos.0,
+                    y: pos.1,
+                },
+                AutomatedEvent::TouchCancel { id } => PlayerEvent::TouchCancel { id: *id },
+                AutomatedEvent::GamepadConnected { device, name } => {
+                    PlayerEvent::GamepadConnected {
+                        device: *device,
+                        name: name.to_owned(),
+                    }
+                }
+                AutomatedEvent::GamepadDisconnected { device } => {
+                    PlayerEvent::GamepadDisconnected { device: *device }
+                }
+                AutomatedEvent::GamepadAxisChanged {
+                    device,
+                    axis,
+                    value,
+                } => PlayerEvent::GamepadAxisChanged {
+                    device: *device,
+                    axis: match axis {
+                        InputGamepadAxis::LeftStickX => RuffleGamepadAxis::LeftStickX,
+                        InputGamepadAxis::LeftStickY => RuffleGamepadAxis::LeftStickY,
+                        InputGamepadAxis::RightStickX => RuffleGamepadAxis::RightStickX,
+                        InputGamepadAxis::RightStickY => RuffleGamepadAxis::RightStickY,
+                    },
+                    value: *value,
+                },
                 AutomatedEvent::Wait | AutomatedEvent::SetClipboardText { .. } => unreachable!(),
             });
         });
@@ -337,6 +394,15 @@ impl TestRunner {
                 ));
             }
 
+            if let Some(audio_comparison) = &self.options.audio_comparison {
+                compare_audio(
+                    &self.root_path,
+                    &self.player,
+                    audio_comparison,
+                    self.options.known_failure,
+                )?;
+            }
+
             self.executor.run();
 
             let trace = self.log.trace_output();
@@ -465,6 +531,15 @@ fn capture_and_compare_image(
         let mut player_lock = player.lock().unwrap();
         player_lock.render();
 
+        if !render_interface.is_capture_supported(player_lock.renderer_mut()) {
+            if known_failure {
+                return Err(anyhow!(
+                    "Renderer can't draw this image, pretending this failed since we don't know if it worked."
+                ));
+            }
+            return Ok(());
+        }
+
         let actual_image = render_interface.capture(player_lock.renderer_mut());
 
         let expected_image_path = base_path.join(format!("{name}.expected.png"))?;
@@ -500,6 +575,35 @@ fn capture_and_compare_image(
     Ok(())
 }
 
+fn compare_audio(
+    base_path: &VfsPath,
+    player: &Arc<Mutex<Player>>,
+    audio_comparison: &AudioComparison,
+    known_failure: bool,
+) -> Result<()> {
+    let player_lock = player.lock().unwrap();
+    let actual_samples = player_lock
+        .audio()
+        .downcast_ref::<OfflineAudioBackend>()
+        .ok_or_else(|| anyhow!("Audio comparisons require the offline audio backend"))?
+        .recording();
+
+    let expected_path = audio_comparison.expected_path(base_path)?;
+    if expected_path.is_file()? {
+        let expected_samples = read_wav(&read_bytes(&expected_path)?)?;
+        audio_comparison.test(actual_samples, &expected_samples, base_path, known_failure)?;
+    } else if known_failure {
+        return Err(anyhow!(
+            "No audio to compare to, pretending this failed since we don't know if it worked."
+        ));
+    } else {
+        // If we're expecting this to be wrong, don't save likely wrong audio
+        write_wav_file(&expected_path, actual_samples)?;
+    }
+
+    Ok(())
+}
+
 /// Wrapper around string slice that makes debug output `{:?}` to print string same way as `{}`.
 /// Used in different `assert*!` macros in combination with `pretty_assertions` crate to make
 /// test failures to show nice diffs.
@@ -1,4 +1,6 @@
+use anyhow::anyhow;
 use image::{EncodableLayout, ImageBuffer, ImageFormat, Pixel, PixelWithColorType};
+use ruffle_core::backend::audio::{write_wav, OfflineAudioBackend};
 use std::io::{Cursor, Read, Write};
 use std::ops::Deref;
 use vfs::{VfsError, VfsPath};
@@ -29,3 +31,108 @@ where
     write_bytes(path, &buffer)?;
     Ok(())
 }
+
+/// Writes interleaved stereo samples as a WAV file, in the format recorded by `OfflineAudioBackend`.
+pub fn write_wav_file(path: &VfsPath, samples: &[i16]) -> anyhow::Result<()> {
+    let mut buffer = vec![];
+    write_wav(
+        &mut buffer,
+        samples,
+        OfflineAudioBackend::NUM_CHANNELS.into(),
+        OfflineAudioBackend::SAMPLE_RATE,
+    )?;
+    write_bytes(path, &buffer)?;
+    Ok(())
+}
+
+/// Reads the samples of a WAV file, which must be in the format recorded by `OfflineAudioBackend`.
+pub fn read_wav(data: &[u8]) -> anyhow::Result<Vec<i16>> {
+    if data.get(..4) != Some(b"RIFF") || data.get(8..12) != Some(b"WAVE") {
+        return Err(anyhow!("Not a WAV file"));
+    }
+
+    let mut format = None;
+    let mut chunks = &data[12..];
+    while chunks.len() >= 8 {
+        let size = u32::from_le_bytes(chunks[4..8].try_into().unwrap()) as usize;
+        let chunk = chunks
+            .get(8..8 + size)
+            .ok_or_else(|| anyhow!("Truncated WAV chunk"))?;
+        match &chunks[..4] {
+            b"fmt " if chunk.len() >= 16 => {
+                let read_u16 = |i: usize| u16::from_le_bytes([chunk[i], chunk[i + 1]]);
+                format = Some((
+                    read_u16(0),
+                    read_u16(2),
+                    u32::from_le_bytes(chunk[4..8].try_into().unwrap()),
+                    read_u16(14),
+                ));
+            }
+            b"data" => {
+                let expected_format = (
+                    1,
+                    OfflineAudioBackend::NUM_CHANNELS.into(),
+                    OfflineAudioBackend::SAMPLE_RATE,
+                    16,
+                );
+                if format != Some(expected_format) {
+                    return Err(anyhow!(
+                        "WAV files must contain 16-bit stereo PCM at 44.1kHz"
+                    ));
+                }
+                return Ok(chunk
+                    .chunks_exact(2)
+                    .map(|sample| i16::from_le_bytes([sample[0], sample[1]]))
+                    .collect());
+            }
+            _ => {}
+        }
+        // Chunks are padded to an even size.
+        chunks = chunks.get(8 + size + (size & 1)..).unwrap_or_default();
+    }
+
+    Err(anyhow!("WAV file has no data"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vfs::MemoryFS;
+
+    #[test]
+    fn wav_round_trip() {
+        let root = VfsPath::new(MemoryFS::new());
+        let path = root.join("output.wav").unwrap();
+        let samples = [0, 1, -1, i16::MAX, i16::MIN, 1234];
+        write_wav_file(&path, &samples).unwrap();
+        assert_eq!(read_wav(&read_bytes(&path).unwrap()).unwrap(), samples);
+    }
+
+    #[test]
+    fn wav_skips_unknown_chunks() {
+        let mut wav = vec![];
+        write_wav(&mut wav, &[5, -5], 2, 44100).unwrap();
+        // An odd-sized chunk is followed by a padding byte.
+        let chunk = [b"LIST".as_slice(), &3u32.to_le_bytes(), &[1, 2, 3, 0]].concat();
+        wav.splice(36..36, chunk);
+        assert_eq!(read_wav(&wav).unwrap(), [5, -5]);
+    }
+
+    #[test]
+    fn wav_rejects_other_formats() {
+        assert!(read_wav(b"not a wav file").is_err());
+
+        let mut mono = vec![];
+        write_wav(&mut mono, &[1, 2], 1, 44100).unwrap();
+        assert!(read_wav(&mono).is_err());
+
+        let mut slow = vec![];
+        write_wav(&mut slow, &[1, 2], 2, 22050).unwrap();
+        assert!(read_wav(&slow).is_err());
+
+        let mut truncated = vec![];
+        write_wav(&mut truncated, &[1, 2], 2, 44100).unwrap();
+        truncated.truncate(46);
+        assert!(read_wav(&truncated).is_err());
+    }
+}
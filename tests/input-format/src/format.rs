@@ -19,6 +19,15 @@ pub enum MouseButton {
     Right,
 }
 
+/// An analog axis of a gamepad.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub enum GamepadAxis {
+    LeftStickX,
+    LeftStickY,
+    RightStickX,
+    RightStickY,
+}
+
 /// Control inputs to a text field
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TextControlCode {
@@ -87,4 +96,43 @@ pub enum AutomatedEvent {
 
     /// Populate clipboard with the given text
     SetClipboardText { text: String },
+
+    /// Start an IME composition
+    ImeStart,
+
+    /// Replace the pre-edit text of the IME composition in progress
+    ///
+    /// The cursor is a range of byte indices into the pre-edit text.
+    ImePreedit {
+        text: String,
+        cursor: Option<(usize, usize)>,
+    },
+
+    /// Finish the IME composition in progress with the given text
+    ImeCommit { text: String },
+
+    /// Put a finger on the screen
+    TouchBegin { id: u64, pos: MousePosition },
+
+    /// Move a finger across the screen
+    TouchMove { id: u64, pos: MousePosition },
+
+    /// Lift a finger off the screen
+    TouchEnd { id: u64, pos: MousePosition },
+
+    /// Abort a touch without lifting the finger
+    TouchCancel { id: u64 },
+
+    /// Plug in a gamepad
+    GamepadConnected { device: usize, name: String },
+
+    /// Unplug a gamepad
+    GamepadDisconnected { device: usize },
+
+    /// Move an analog axis of a gamepad, from -1.0 to 1.0
+    GamepadAxisChanged {
+        device: usize,
+        axis: GamepadAxis,
+        value: f64,
+    },
 }
@@ -99,7 +99,17 @@ impl InputInjector {
                     | AutomatedEvent::KeyUp { .. }
                     | AutomatedEvent::TextInput { .. }
                     | AutomatedEvent::TextControl { .. }
-                    | AutomatedEvent::SetClipboardText { .. } => {}
+                    | AutomatedEvent::SetClipboardText { .. }
+                    | AutomatedEvent::ImeStart
+                    | AutomatedEvent::ImePreedit { .. }
+                    | AutomatedEvent::ImeCommit { .. }
+                    | AutomatedEvent::TouchBegin { .. }
+                    | AutomatedEvent::TouchMove { .. }
+                    | AutomatedEvent::TouchEnd { .. }
+                    | AutomatedEvent::TouchCancel { .. }
+                    | AutomatedEvent::GamepadConnected { .. }
+                    | AutomatedEvent::GamepadDisconnected { .. }
+                    | AutomatedEvent::GamepadAxisChanged { .. } => {}
                     AutomatedEvent::MouseDown { btn, .. } => {
                         self.buttons |= (*btn).into();
                     }
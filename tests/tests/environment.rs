@@ -2,16 +2,36 @@ use ruffle_test_framework::environment::Environment;
 
 pub struct NativeEnvironment;
 
+/// Whether image tests should be rendered on the CPU rather than through wgpu.
+///
+/// The software renderer is used when it's the only one compiled in,
+/// or when explicitly requested with `RUFFLE_TEST_RENDERER=software`.
+#[cfg(feature = "imgtests_software")]
+fn use_software_renderer() -> bool {
+    !cfg!(feature = "imgtests")
+        || std::env::var("RUFFLE_TEST_RENDERER").is_ok_and(|renderer| renderer == "software")
+}
+
 impl Environment for NativeEnvironment {
-    #[cfg(feature = "imgtests")]
+    #[cfg(any(feature = "imgtests", feature = "imgtests_software"))]
     fn is_render_supported(
         &self,
-        requirements: &ruffle_test_framework::options::RenderOptions,
+        _requirements: &ruffle_test_framework::options::RenderOptions,
     ) -> bool {
-        renderer::is_supported(requirements)
+        #[cfg(feature = "imgtests_software")]
+        if use_software_renderer() {
+            return software_renderer::is_supported(_requirements);
+        }
+
+        #[cfg(feature = "imgtests")]
+        if renderer::is_supported(_requirements) {
+            return true;
+        }
+
+        false
     }
 
-    #[cfg(feature = "imgtests")]
+    #[cfg(any(feature = "imgtests", feature = "imgtests_software"))]
     fn create_renderer(
         &self,
         width: u32,
@@ -20,7 +40,65 @@ impl Environment for NativeEnvironment {
         Box<dyn ruffle_test_framework::environment::RenderInterface>,
         Box<dyn ruffle_test_framework::environment::RenderBackend>,
     )> {
-        renderer::NativeRenderInterface::create_pair(width, height)
+        #[cfg(feature = "imgtests_software")]
+        if use_software_renderer() {
+            return Some(software_renderer::SoftwareRenderInterface::create_pair(
+                width, height,
+            ));
+        }
+
+        #[cfg(feature = "imgtests")]
+        if let Some(pair) = renderer::NativeRenderInterface::create_pair(width, height) {
+            return Some(pair);
+        }
+
+        None
+    }
+}
+
+#[cfg(feature = "imgtests_software")]
+mod software_renderer {
+    use image::RgbaImage;
+    use ruffle_render_software::backend::SoftwareRenderBackend;
+    use ruffle_render_software::MAX_SAMPLE_COUNT;
+    use ruffle_test_framework::environment::{RenderBackend, RenderInterface};
+    use ruffle_test_framework::options::RenderOptions;
+
+    pub struct SoftwareRenderInterface;
+
+    impl SoftwareRenderInterface {
+        pub fn create_pair(
+            width: u32,
+            height: u32,
+        ) -> (Box<dyn RenderInterface>, Box<dyn RenderBackend>) {
+            (
+                Box::new(Self),
+                Box::new(SoftwareRenderBackend::new(width, height)),
+            )
+        }
+    }
+
+    impl RenderInterface for SoftwareRenderInterface {
+        fn name(&self) -> String {
+            "software".to_string()
+        }
+
+        fn capture(&self, backend: &mut Box<dyn RenderBackend>) -> RgbaImage {
+            let renderer = backend.downcast_mut::<SoftwareRenderBackend>().unwrap();
+
+            renderer.capture_frame().expect("Failed to capture image")
+        }
+
+        fn is_capture_supported(&self, backend: &mut Box<dyn RenderBackend>) -> bool {
+            // Filters and shaders are left out by the software renderer,
+            // so images that needed them can't match.
+            let renderer = backend.downcast_mut::<SoftwareRenderBackend>().unwrap();
+            !renderer.skipped_filters()
+        }
+    }
+
+    pub fn is_supported(requirements: &RenderOptions) -> bool {
+        requirements.sample_count <= MAX_SAMPLE_COUNT
     }
 }
 
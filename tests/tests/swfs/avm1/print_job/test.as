@@ -0,0 +1,22 @@
+var job = new PrintJob();
+trace("start: " + job.start());
+trace("paper: " + job.paperWidth + "x" + job.paperHeight);
+trace("page: " + job.pageWidth + "x" + job.pageHeight);
+trace("orientation: " + job.orientation);
+
+var mc = _root.createEmptyMovieClip("mc", 1);
+mc.beginFill(0xFF0000);
+mc.moveTo(0, 0);
+mc.lineTo(50, 0);
+mc.lineTo(50, 40);
+mc.lineTo(0, 40);
+mc.lineTo(0, 0);
+mc.endFill();
+
+trace("addPage(mc): " + job.addPage(mc));
+trace("addPage(0): " + job.addPage(0));
+trace("addPage(\"mc\", area): " + job.addPage("mc", {xMin: 0, xMax: 50, yMin: 0, yMax: 40}));
+trace("addPage(\"missing\"): " + job.addPage("missing"));
+job.send();
+trace("sent");
+trace("addPage after send: " + job.addPage(mc));
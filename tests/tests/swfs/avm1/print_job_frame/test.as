@@ -0,0 +1,13 @@
+// Frame 1: a red rectangle covers the stage.
+stop();
+var job = new PrintJob();
+trace("start: " + job.start());
+trace("addPage(0, frame 2): " + job.addPage(0, null, null, 2));
+trace("addPage(0): " + job.addPage(0));
+job.send();
+trace("_currentframe: " + _root._currentframe);
+
+// Frame 2: the red rectangle is replaced by a clip holding a blue rectangle,
+// whose frame script is:
+// trace("sprite script");
+trace("frame 2 script");
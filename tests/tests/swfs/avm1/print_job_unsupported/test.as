@@ -0,0 +1,6 @@
+var job = new PrintJob();
+trace("start: " + job.start());
+trace("paper: " + job.paperWidth + "x" + job.paperHeight);
+trace("addPage(_root): " + job.addPage(_root));
+job.send();
+trace("done");
@@ -0,0 +1,50 @@
+var css = new TextField.StyleSheet();
+trace(css.parseCSS("p { font-weight: bold; text-align: center; } .red { color: #FF0000; font-size: 20px; }"));
+var names = css.getStyleNames();
+names.sort();
+trace(names);
+
+var style = css.getStyle(".red");
+trace(style.color);
+trace(style.fontSize);
+trace(css.getStyle("missing"));
+
+// getStyle returns a copy.
+style.color = "#00FF00";
+trace(css.getStyle(".red").color);
+
+css.setStyle("em", {color: "#0000FF"});
+names = css.getStyleNames();
+names.sort();
+trace(names);
+css.setStyle("em", null);
+names = css.getStyleNames();
+names.sort();
+trace(names);
+
+var fmt = css.transform(css.getStyle(".red"));
+trace(fmt instanceof TextFormat);
+trace(fmt.color);
+trace(fmt.size);
+
+_root.createTextField("tf", 1, 0, 0, 300, 100);
+var tf = _root.tf;
+tf.html = true;
+tf.styleSheet = css;
+trace(tf.styleSheet == css);
+tf.htmlText = "<p>Hello <span class='red'>World</span></p>";
+
+// "Hello " only has the style of the p tag.
+var f = tf.getTextFormat(0, 1);
+trace(f.bold);
+trace(f.align);
+trace(f.color);
+
+// "World" also has the style of its class.
+f = tf.getTextFormat(6, 7);
+trace(f.bold);
+trace(f.color);
+trace(f.size);
+
+tf.styleSheet = undefined;
+trace(tf.styleSheet);
@@ -0,0 +1,46 @@
+// The stage contains two static texts in a font whose glyphs are all
+// 20px wide, with an ascent of 15px and a descent of 5px:
+// "The first line" and "and the second" at (10, 10), and "Last LINE" at (10, 60).
+var s = _root.getTextSnapshot();
+
+trace("// getCount");
+trace(s.getCount());
+trace(_root.createEmptyMovieClip("empty", 10).getTextSnapshot().getCount());
+
+trace("// getText");
+trace(s.getText(0, s.getCount()));
+trace(s.getText(0, s.getCount(), true));
+trace(s.getText(10, 17, true));
+trace(s.getText(30, 100));
+trace("[" + s.getText(5, 2) + "]");
+
+trace("// findText");
+trace(s.findText(0, "the", true));
+trace(s.findText(0, "the", false));
+trace(s.findText(1, "THE", false));
+trace(s.findText(0, "line", true));
+trace(s.findText(11, "line", false));
+trace(s.findText(11, "line", true));
+trace(s.findText(0, "xyz", true));
+
+trace("// setSelected");
+trace(s.getSelected(0, s.getCount()));
+s.setSelected(4, 9, true);
+trace(s.getSelected(0, 4));
+trace(s.getSelected(0, 5));
+trace(s.getSelected(8, 9));
+trace(s.getSelectedText());
+s.setSelected(14, 30, true);
+trace(s.getSelected(9, 14));
+trace(s.getSelectedText(false));
+trace(s.getSelectedText(true));
+s.setSelected(0, s.getCount(), false);
+trace("[" + s.getSelectedText() + "]");
+
+trace("// hitTestTextNearPos");
+trace(s.hitTestTextNearPos(15, 20));
+trace(s.hitTestTextNearPos(75, 40));
+trace(s.hitTestTextNearPos(195, 70));
+trace(s.hitTestTextNearPos(195, 70, 10));
+trace(s.hitTestTextNearPos(5, 5, 10));
+trace(s.hitTestTextNearPos(500, 500, 10));
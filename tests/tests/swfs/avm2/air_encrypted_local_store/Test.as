@@ -0,0 +1,81 @@
+package {
+    import flash.data.EncryptedLocalStore;
+    import flash.desktop.NativeApplication;
+    import flash.display.Sprite;
+    import flash.utils.ByteArray;
+
+    public class Test extends Sprite {
+        public function Test() {
+            trace("// NativeApplication");
+            var app:NativeApplication = NativeApplication.nativeApplication;
+            trace("applicationID: " + app.applicationID);
+            var descriptor:XML = app.applicationDescriptor;
+            var ns:* = descriptor.namespace();
+            trace("namespace: " + ns.uri);
+            trace("root: " + descriptor.localName());
+            trace("id: " + descriptor.ns::id);
+            trace("filename: " + descriptor.ns::filename);
+            trace("versionNumber: " + descriptor.ns::versionNumber);
+            trace("content: " + descriptor.ns::initialWindow.ns::content);
+            trace("same descriptor: " + (app.applicationDescriptor == descriptor));
+
+            trace("// EncryptedLocalStore");
+            trace("isSupported: " + EncryptedLocalStore.isSupported);
+            trace("missing: " + EncryptedLocalStore.getItem("token"));
+
+            var data:ByteArray = bytes("secret");
+            EncryptedLocalStore.setItem("token", data);
+            data.writeUTFBytes("!");
+            var item:ByteArray = EncryptedLocalStore.getItem("token");
+            trace("same ByteArray: " + (item === data));
+            trace("position: " + item.position);
+            trace("token: " + item.readUTFBytes(item.length));
+
+            EncryptedLocalStore.setItem("other", bytes("abc"), true);
+            EncryptedLocalStore.setItem("token", bytes("replaced"));
+            trace("token: " + text("token"));
+            trace("other: " + text("other"));
+
+            trace("// removeItem");
+            EncryptedLocalStore.removeItem("token");
+            EncryptedLocalStore.removeItem("missing");
+            trace("token: " + text("token"));
+            trace("other: " + text("other"));
+
+            trace("// reset");
+            EncryptedLocalStore.reset();
+            trace("other: " + text("other"));
+            EncryptedLocalStore.setItem("token", bytes("after reset"));
+            trace("token: " + text("token"));
+
+            trace("// invalid arguments");
+            try {
+                EncryptedLocalStore.setItem("", bytes("empty"));
+            } catch (e:Error) {
+                trace("empty name: " + e.errorID + " " + Object(e).constructor);
+            }
+            try {
+                EncryptedLocalStore.getItem(null);
+            } catch (e:Error) {
+                trace("null name: " + e.errorID + " " + Object(e).constructor);
+            }
+            try {
+                EncryptedLocalStore.setItem("token", null);
+            } catch (e:Error) {
+                trace("null data: " + e.errorID + " " + Object(e).constructor);
+            }
+            trace("token: " + text("token"));
+        }
+
+        private function bytes(value:String):ByteArray {
+            var data:ByteArray = new ByteArray();
+            data.writeUTFBytes(value);
+            return data;
+        }
+
+        private function text(name:String):String {
+            var item:ByteArray = EncryptedLocalStore.getItem(name);
+            return item == null ? "null" : item.readUTFBytes(item.length);
+        }
+    }
+}
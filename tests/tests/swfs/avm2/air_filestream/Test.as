@@ -0,0 +1,135 @@
+package {
+    import flash.display.Sprite;
+    import flash.filesystem.File;
+    import flash.filesystem.FileMode;
+    import flash.filesystem.FileStream;
+
+    public class Test extends Sprite {
+        public function Test() {
+            trace("// applicationStorageDirectory");
+            var dir:File = File.applicationStorageDirectory;
+            trace("nativePath: " + dir.nativePath);
+            trace("url: " + dir.url);
+            trace("exists: " + dir.exists);
+            trace("isDirectory: " + dir.isDirectory);
+
+            trace("// resolvePath");
+            var file:File = dir.resolvePath("data/test.txt");
+            trace("nativePath: " + file.nativePath);
+            trace("url: " + file.url);
+            trace("name: " + file.name);
+            trace("type: " + file.type);
+            trace("parent: " + file.parent.nativePath);
+            trace("exists: " + file.exists);
+            trace("dotted: " + dir.resolvePath("a/../b/./c.txt").nativePath);
+            trace("absolute: " + dir.resolvePath("/other/file.txt").nativePath);
+            trace("url string: " + new File("app-storage:/data/test.txt").nativePath);
+
+            var stream:FileStream = new FileStream();
+
+            trace("// FileMode.WRITE");
+            stream.open(file, FileMode.WRITE);
+            trace("position: " + stream.position);
+            stream.writeUTFBytes("Hello");
+            trace("position: " + stream.position);
+            stream.close();
+            trace("exists: " + file.exists);
+            trace("parent exists: " + file.parent.exists);
+            trace("size: " + file.size);
+
+            trace("// FileMode.READ");
+            stream.open(file, FileMode.READ);
+            trace("position: " + stream.position);
+            trace("bytesAvailable: " + stream.bytesAvailable);
+            trace("read: " + stream.readUTFBytes(3));
+            trace("position: " + stream.position);
+            stream.position = 1;
+            trace("read: " + stream.readUTFBytes(stream.bytesAvailable));
+            try {
+                stream.writeUTFBytes("!");
+            } catch (e:Error) {
+                trace("write: " + e.errorID + " " + Object(e).constructor);
+            }
+            stream.close();
+            trace("bytesAvailable after close: " + stream.bytesAvailable);
+            try {
+                stream.readByte();
+            } catch (e:Error) {
+                trace("read after close: " + e.errorID + " " + Object(e).constructor);
+            }
+
+            trace("// FileMode.APPEND");
+            stream.open(file, FileMode.APPEND);
+            trace("position: " + stream.position);
+            stream.position = 0;
+            trace("position after seek: " + stream.position);
+            stream.writeUTFBytes(" world");
+            try {
+                stream.readByte();
+            } catch (e:Error) {
+                trace("read: " + e.errorID + " " + Object(e).constructor);
+            }
+            stream.close();
+            trace("contents: " + readText(file));
+
+            trace("// FileMode.UPDATE");
+            stream.open(file, FileMode.UPDATE);
+            trace("position: " + stream.position);
+            stream.writeUTFBytes("J");
+            trace("position: " + stream.position);
+            trace("read: " + stream.readUTFBytes(4));
+            stream.truncate();
+            stream.close();
+            trace("contents: " + readText(file));
+
+            trace("// FileMode.WRITE on an existing file");
+            stream.open(file, FileMode.WRITE);
+            trace("bytesAvailable: " + stream.bytesAvailable);
+            trace("size: " + file.size);
+            stream.writeUTFBytes("Bye");
+            stream.close();
+            trace("contents: " + readText(file));
+
+            trace("// FileMode.UPDATE on a missing file");
+            var created:File = dir.resolvePath("created.txt");
+            stream.open(created, FileMode.UPDATE);
+            trace("exists: " + created.exists);
+            trace("bytesAvailable: " + stream.bytesAvailable);
+            stream.close();
+            trace("size: " + created.size);
+
+            trace("// missing files");
+            var missing:File = dir.resolvePath("missing.txt");
+            trace("exists: " + missing.exists);
+            try {
+                stream.open(missing, FileMode.READ);
+            } catch (e:Error) {
+                trace("open: " + e.errorID + " " + Object(e).constructor);
+            }
+            trace("exists after open: " + missing.exists);
+            try {
+                trace(missing.size);
+            } catch (e:Error) {
+                trace("size: " + e.errorID + " " + Object(e).constructor);
+            }
+            try {
+                missing.deleteFile();
+            } catch (e:Error) {
+                trace("deleteFile: " + e.errorID + " " + Object(e).constructor);
+            }
+            try {
+                stream.open(dir, FileMode.READ);
+            } catch (e:Error) {
+                trace("open directory: " + e.errorID + " " + Object(e).constructor);
+            }
+        }
+
+        private function readText(file:File):String {
+            var stream:FileStream = new FileStream();
+            stream.open(file, FileMode.READ);
+            var text:String = stream.readUTFBytes(stream.bytesAvailable);
+            stream.close();
+            return text;
+        }
+    }
+}
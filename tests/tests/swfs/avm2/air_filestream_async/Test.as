@@ -0,0 +1,79 @@
+package {
+    import flash.display.Sprite;
+    import flash.events.Event;
+    import flash.events.IOErrorEvent;
+    import flash.events.ProgressEvent;
+    import flash.filesystem.File;
+    import flash.filesystem.FileMode;
+    import flash.filesystem.FileStream;
+
+    public class Test extends Sprite {
+        private var file:File = File.applicationStorageDirectory.resolvePath("async.txt");
+
+        public function Test() {
+            var stream:FileStream = new FileStream();
+            stream.open(file, FileMode.WRITE);
+            stream.writeUTFBytes("Hello");
+            stream.close();
+
+            trace("// openAsync with FileMode.READ");
+            var reader:FileStream = new FileStream();
+            reader.addEventListener(ProgressEvent.PROGRESS, function(e:ProgressEvent):void {
+                trace("progress: " + e.bytesLoaded + "/" + e.bytesTotal);
+            });
+            reader.addEventListener(Event.COMPLETE, function(e:Event):void {
+                trace("complete, bytesAvailable: " + reader.bytesAvailable);
+                trace("read: " + reader.readUTFBytes(reader.bytesAvailable));
+                reader.close();
+                trace("closed");
+            });
+            reader.addEventListener(Event.CLOSE, function(e:Event):void {
+                trace("close event");
+                testAppend();
+            });
+            reader.openAsync(file, FileMode.READ);
+            trace("openAsync returned");
+        }
+
+        private function testAppend():void {
+            trace("// openAsync with FileMode.APPEND");
+            var writer:FileStream = new FileStream();
+            writer.addEventListener(Event.COMPLETE, function(e:Event):void {
+                trace("complete");
+            });
+            writer.addEventListener(Event.CLOSE, function(e:Event):void {
+                trace("close event");
+                trace("contents: " + readText(file));
+                testMissing();
+            });
+            writer.openAsync(file, FileMode.APPEND);
+            trace("position: " + writer.position);
+            writer.writeUTFBytes(" world");
+            writer.close();
+            trace("closed");
+        }
+
+        private function testMissing():void {
+            trace("// openAsync with a missing file");
+            var missing:File = File.applicationStorageDirectory.resolvePath("missing.txt");
+            var reader:FileStream = new FileStream();
+            reader.addEventListener(Event.COMPLETE, function(e:Event):void {
+                trace("complete");
+            });
+            reader.addEventListener(IOErrorEvent.IO_ERROR, function(e:IOErrorEvent):void {
+                trace("ioError: " + e.errorID + " " + e.text);
+                trace("exists: " + missing.exists);
+            });
+            reader.openAsync(missing, FileMode.READ);
+            trace("openAsync returned");
+        }
+
+        private function readText(file:File):String {
+            var stream:FileStream = new FileStream();
+            stream.open(file, FileMode.READ);
+            var text:String = stream.readUTFBytes(stream.bytesAvailable);
+            stream.close();
+            return text;
+        }
+    }
+}
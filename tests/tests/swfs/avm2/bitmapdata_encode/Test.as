@@ -0,0 +1,56 @@
+package {
+    import flash.display.BitmapData;
+    import flash.display.JPEGEncoderOptions;
+    import flash.display.JPEGXREncoderOptions;
+    import flash.display.PNGEncoderOptions;
+    import flash.display.Sprite;
+    import flash.geom.Rectangle;
+    import flash.utils.ByteArray;
+
+    public class Test extends Sprite {
+        public function Test() {
+            var bitmap:BitmapData = new BitmapData(4, 3, true, 0x80FF0000);
+
+            trace("// PNGEncoderOptions");
+            var png:ByteArray = bitmap.encode(bitmap.rect, new PNGEncoderOptions());
+            trace("header: " + bytes(png, 0, 8));
+
+            trace("// JPEGEncoderOptions");
+            var jpeg:ByteArray = bitmap.encode(bitmap.rect, new JPEGEncoderOptions(50));
+            trace("start: " + bytes(jpeg, 0, 2));
+            trace("end: " + bytes(jpeg, jpeg.length - 2, 2));
+
+            trace("// PNGEncoderOptions into an existing ByteArray");
+            var existing:ByteArray = new ByteArray();
+            existing.writeUTFBytes("abc");
+            var result:ByteArray = bitmap.encode(new Rectangle(1, 1, 2, 2), new PNGEncoderOptions(true), existing);
+            trace("same ByteArray: " + (result === existing));
+            trace("start: " + bytes(existing, 0, 4));
+
+            trace("// JPEGXREncoderOptions");
+            trace(bitmap.encode(bitmap.rect, new JPEGXREncoderOptions()));
+
+            trace("// unknown compressor");
+            try {
+                bitmap.encode(bitmap.rect, {});
+            } catch (e:Error) {
+                trace(e.errorID + " " + Object(e).constructor);
+            }
+
+            trace("// empty rect");
+            try {
+                bitmap.encode(new Rectangle(10, 10, 5, 5), new PNGEncoderOptions());
+            } catch (e:Error) {
+                trace(e.errorID + " " + Object(e).constructor);
+            }
+        }
+
+        private function bytes(data:ByteArray, start:int, count:int):String {
+            var values:Array = [];
+            for (var i:int = start; i < start + count; i++) {
+                values.push(data[i]);
+            }
+            return values.join(",");
+        }
+    }
+}
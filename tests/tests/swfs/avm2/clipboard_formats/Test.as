@@ -0,0 +1,111 @@
+package {
+    import flash.desktop.Clipboard;
+    import flash.desktop.ClipboardFormats;
+    import flash.display.BitmapData;
+    import flash.display.Sprite;
+    import flash.events.Event;
+    import flash.utils.ByteArray;
+
+    public class Test extends Sprite {
+        private var cb:Clipboard = Clipboard.generalClipboard;
+
+        public function Test() {
+            trace("initial formats: [" + cb.formats + "]");
+            addEventListener(Event.ENTER_FRAME, onEnterFrame);
+        }
+
+        // Waits for the text put on the clipboard by the system.
+        private function onEnterFrame(e:Event):void {
+            if (!cb.hasFormat(ClipboardFormats.TEXT_FORMAT)) {
+                return;
+            }
+            removeEventListener(Event.ENTER_FRAME, onEnterFrame);
+
+            trace("formats: [" + cb.formats + "]");
+            trace("text: " + cb.getData(ClipboardFormats.TEXT_FORMAT));
+
+            testStandardFormats();
+            testCustomFormats();
+            testClear();
+            testOtherClipboard();
+        }
+
+        private function testStandardFormats():void {
+            var rtf:ByteArray = new ByteArray();
+            rtf.writeUTFBytes("{\\rtf1 hi}");
+            var bmd:BitmapData = new BitmapData(2, 1, true, 0x80FF0000);
+            bmd.setPixel32(1, 0, 0xFF00FF00);
+
+            trace("setData text: " + cb.setData(ClipboardFormats.TEXT_FORMAT, "plain"));
+            trace("setData html: " + cb.setData(ClipboardFormats.HTML_FORMAT, "<b>bold</b>"));
+            trace("setData url: " + cb.setData(ClipboardFormats.URL_FORMAT, "https://example.org/"));
+            trace("setData rtf: " + cb.setData(ClipboardFormats.RICH_TEXT_FORMAT, rtf));
+            trace("setData bitmap: " + cb.setData(ClipboardFormats.BITMAP_FORMAT, bmd));
+            trace("formats: [" + cb.formats + "]");
+
+            trace("text: " + cb.getData(ClipboardFormats.TEXT_FORMAT));
+            trace("html: " + cb.getData(ClipboardFormats.HTML_FORMAT));
+            trace("url: " + cb.getData(ClipboardFormats.URL_FORMAT));
+            var rtfCopy:ByteArray = cb.getData(ClipboardFormats.RICH_TEXT_FORMAT) as ByteArray;
+            trace("rtf: " + rtfCopy.readUTFBytes(rtfCopy.length));
+            var bmdCopy:BitmapData = cb.getData(ClipboardFormats.BITMAP_FORMAT) as BitmapData;
+            trace("bitmap: " + bmdCopy.width + "x" + bmdCopy.height);
+            trace("bitmap is a copy: " + (bmdCopy != bmd));
+            trace("bitmap pixels match: " + (bmdCopy.getPixel32(0, 0) == bmd.getPixel32(0, 0)) + " " +
+                (bmdCopy.getPixel32(1, 0) == bmd.getPixel32(1, 0)));
+            trace("bitmap pixel 1: " + Object(bmdCopy.getPixel32(1, 0)).toString(16));
+
+            trace("setData bitmap with string: " + cb.setData(ClipboardFormats.BITMAP_FORMAT, "not a bitmap"));
+            trace("setData rtf with string: " + cb.setData(ClipboardFormats.RICH_TEXT_FORMAT, "not bytes"));
+        }
+
+        private function testCustomFormats():void {
+            var custom:Object = {a: 1};
+            trace("setData custom: " + cb.setData("com.example:custom", custom));
+            trace("formats: [" + cb.formats + "]");
+            trace("custom is same object: " + (cb.getData("com.example:custom") === custom));
+            cb.clearData("com.example:custom");
+            trace("hasFormat custom after clearData: " + cb.hasFormat("com.example:custom"));
+
+            trace("setDataHandler lazy: " + cb.setDataHandler("com.example:lazy", function():String {
+                trace("lazy handler called");
+                return "lazy";
+            }));
+            trace("hasFormat lazy: " + cb.hasFormat("com.example:lazy"));
+            trace("lazy: " + cb.getData("com.example:lazy"));
+            trace("lazy again: " + cb.getData("com.example:lazy"));
+
+            // Standard formats can't wait for the data on the system clipboard.
+            trace("setDataHandler text: " + cb.setDataHandler(ClipboardFormats.TEXT_FORMAT, function():String {
+                trace("text handler called");
+                return "eager";
+            }));
+            trace("text: " + cb.getData(ClipboardFormats.TEXT_FORMAT));
+
+            cb.clearData(ClipboardFormats.HTML_FORMAT);
+            trace("formats after clearData html: [" + cb.formats + "]");
+            trace("html: " + cb.getData(ClipboardFormats.HTML_FORMAT));
+
+            try {
+                cb.setDataHandler("com.example:null", null);
+            } catch (e:TypeError) {
+                trace("setDataHandler null: " + e.errorID);
+            }
+        }
+
+        private function testClear():void {
+            cb.clear();
+            trace("formats after clear: [" + cb.formats + "]");
+            trace("text after clear: " + cb.getData(ClipboardFormats.TEXT_FORMAT));
+            trace("lazy after clear: " + cb.getData("com.example:lazy"));
+        }
+
+        private function testOtherClipboard():void {
+            var other:Clipboard = new Clipboard();
+            trace("other setData text: " + other.setData(ClipboardFormats.TEXT_FORMAT, "private"));
+            trace("other formats: [" + other.formats + "]");
+            trace("other text: " + other.getData(ClipboardFormats.TEXT_FORMAT));
+            trace("general formats: [" + cb.formats + "]");
+        }
+    }
+}
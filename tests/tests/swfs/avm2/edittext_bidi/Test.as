@@ -0,0 +1,63 @@
+package {
+    import flash.display.Sprite;
+    import flash.events.Event;
+    import flash.geom.Rectangle;
+    import flash.text.TextField;
+    import flash.text.TextFieldType;
+    import flash.text.TextFormat;
+
+    public class Test extends Sprite {
+        private var field:TextField = new TextField();
+        private var lastCaretIndex:int = -1;
+
+        public function Test() {
+            field.type = TextFieldType.INPUT;
+            field.defaultTextFormat = new TextFormat("DejaVu Sans", 20);
+            field.width = 300;
+            field.height = 40;
+            // "abc ", three Hebrew letters (alef, bet, gimel), " def"
+            field.text = "abc אבג def";
+            addChild(field);
+
+            // Characters sorted by their position from left to right.
+            var visualOrder:Array = [];
+            for (var i:int = 0; i < field.length; i++) {
+                visualOrder.push(i);
+            }
+            visualOrder.sort(function(a:int, b:int):int {
+                return field.getCharBoundaries(a).x - field.getCharBoundaries(b).x;
+            });
+            trace("visual order: " + visualOrder);
+
+            var contiguous:Boolean = true;
+            var indicesAtPoints:Array = [];
+            for (var n:int = 0; n < visualOrder.length; n++) {
+                var bounds:Rectangle = field.getCharBoundaries(visualOrder[n]);
+                if (n > 0) {
+                    var previous:Rectangle = field.getCharBoundaries(visualOrder[n - 1]);
+                    contiguous = contiguous && Math.abs(previous.right - bounds.left) < 0.01;
+                }
+                indicesAtPoints.push(field.getCharIndexAtPoint(bounds.x + bounds.width / 2, bounds.y + bounds.height / 2));
+            }
+            trace("contiguous: " + contiguous);
+            trace("getCharIndexAtPoint from left to right: " + indicesAtPoints);
+
+            stage.focus = field;
+            field.setSelection(0, 0);
+            printCaret();
+            addEventListener(Event.ENTER_FRAME, onEnterFrame);
+        }
+
+        // Each injected arrow key moves the caret once.
+        private function onEnterFrame(e:Event):void {
+            printCaret();
+        }
+
+        private function printCaret():void {
+            if (field.caretIndex != lastCaretIndex) {
+                lastCaretIndex = field.caretIndex;
+                trace("caretIndex: " + field.caretIndex);
+            }
+        }
+    }
+}
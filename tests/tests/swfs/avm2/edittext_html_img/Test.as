@@ -0,0 +1,60 @@
+package {
+import flash.display.Bitmap;
+import flash.display.DisplayObject;
+import flash.display.Loader;
+import flash.display.Sprite;
+import flash.events.Event;
+import flash.text.TextField;
+
+public class Test extends Sprite {
+    private var tf:TextField;
+
+    function Test() {
+        tf = new TextField();
+        tf.width = 300;
+        tf.height = 200;
+        tf.multiline = true;
+        tf.wordWrap = true;
+        tf.htmlText = "<img src='RedBox' id='box' width='40' height='20'>Hello" +
+            "<img src='image.png' id='pic'>World";
+        addChild(tf);
+
+        // Library classes are instantiated right away, and scaled to the given size.
+        var box:DisplayObject = tf.getImageReference("box");
+        trace("box is RedBox: " + (box is RedBox));
+        trace("box size: " + box.width + "x" + box.height);
+
+        // Anything else is loaded.
+        var pic:DisplayObject = tf.getImageReference("pic");
+        trace("pic is Loader: " + (pic is Loader));
+        trace("missing: " + tf.getImageReference("missing"));
+
+        Loader(pic).contentLoaderInfo.addEventListener(Event.COMPLETE, onComplete);
+    }
+
+    private function onComplete(event:Event):void {
+        var pic:Loader = Loader(tf.getImageReference("pic"));
+        trace("pic loaded");
+        trace("pic content is Bitmap: " + (pic.content is Bitmap));
+        // Without a width and height, the image keeps its natural size.
+        trace("pic size: " + pic.width + "x" + pic.height);
+
+        // Replacing the text removes the old images.
+        tf.htmlText = "No images";
+        trace("box after replacing text: " + tf.getImageReference("box"));
+        trace("pic after replacing text: " + tf.getImageReference("pic"));
+    }
+}
+}
+
+package {
+import flash.display.Sprite;
+
+public class RedBox extends Sprite {
+    public function RedBox() {
+        graphics.beginFill(0xFF0000);
+        graphics.drawRect(0, 0, 10, 10);
+        graphics.endFill();
+    }
+}
+}
@@ -0,0 +1,65 @@
+package {
+    import flash.display.Sprite;
+    import flash.events.Event;
+    import flash.events.IMEEvent;
+    import flash.events.TextEvent;
+    import flash.system.System;
+    import flash.text.TextField;
+    import flash.text.TextFieldType;
+
+    public class Test extends Sprite {
+        private var tf:TextField;
+        private var last:String = null;
+        private var compositions:int = 0;
+
+        public function Test() {
+            tf = new TextField();
+            tf.type = TextFieldType.INPUT;
+            tf.text = "Hello world!";
+            addChild(tf);
+
+            tf.addEventListener(IMEEvent.IME_START_COMPOSITION, function(e:IMEEvent):void {
+                compositions++;
+                trace("imeStartComposition, target is tf: " + (e.target === tf));
+            });
+            tf.addEventListener(TextEvent.TEXT_INPUT, function(e:TextEvent):void {
+                trace("textInput: " + e.text);
+            });
+            tf.addEventListener(Event.CHANGE, function(e:Event):void {
+                trace("change: " + tf.text);
+            });
+            System.ime.addEventListener(IMEEvent.IME_COMPOSITION, function(e:IMEEvent):void {
+                trace("imeComposition: " + e.text);
+            });
+
+            stage.focus = tf;
+            tf.setSelection(6, 11);
+            snapshot();
+
+            addEventListener(Event.ENTER_FRAME, onEnterFrame);
+        }
+
+        private function onEnterFrame(e:Event):void {
+            snapshot();
+            if (compositions == 2 && stage.focus === tf) {
+                trace("Removing focus");
+                stage.focus = null;
+                snapshot(true);
+            }
+        }
+
+        // Traces the text, the selection and which characters are underlined,
+        // whenever any of them changed.
+        private function snapshot(always:Boolean = false):void {
+            var underline:String = "";
+            for (var i:int = 0; i < tf.length; i++) {
+                underline += tf.getTextFormat(i, i + 1).underline == true ? "_" : ".";
+            }
+            var state:String = tf.text + " [" + tf.selectionBeginIndex + "," + tf.selectionEndIndex + "] " + underline;
+            if (always || state != last) {
+                trace(state);
+                last = state;
+            }
+        }
+    }
+}
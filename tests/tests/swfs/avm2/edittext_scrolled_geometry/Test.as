@@ -0,0 +1,64 @@
+package {
+import flash.display.Sprite;
+import flash.geom.Rectangle;
+import flash.text.TextField;
+import flash.text.TextFormat;
+
+public class Test extends Sprite {
+    function Test() {
+        var tf:TextField = new TextField();
+        tf.defaultTextFormat = new TextFormat("_sans", 12);
+        tf.multiline = true;
+        tf.wordWrap = false;
+        tf.width = 200;
+        // Line 2 is empty.
+        tf.text = "line0\nline1\n\nline3\nline4\nline5\nline6";
+        addChild(tf);
+
+        // Make exactly three lines fully visible, whatever the font metrics.
+        var lineHeight:Number = tf.getLineMetrics(0).height;
+        tf.height = 4 + lineHeight * 3.5;
+
+        trace("length: " + tf.length);
+        trace("numLines: " + tf.numLines);
+        for (var line:int = 0; line < tf.numLines; line++) {
+            trace("line " + line + ": offset " + tf.getLineOffset(line) +
+                ", length " + tf.getLineLength(line));
+        }
+
+        var chars:Array = [0, 5, 6, 11, 12, 13, 35, 36];
+        for each (var i:int in chars) {
+            trace("getLineIndexOfChar(" + i + "): " + tf.getLineIndexOfChar(i));
+        }
+        trace("getFirstCharInParagraph(15): " + tf.getFirstCharInParagraph(15));
+        trace("getParagraphLength(15): " + tf.getParagraphLength(15));
+        trace("getParagraphLength(12): " + tf.getParagraphLength(12));
+
+        tf.scrollV = 3;
+        trace("scrollV: " + tf.scrollV);
+        trace("bottomScrollV: " + tf.bottomScrollV);
+        trace("maxScrollV: " + tf.maxScrollV);
+
+        // Points are relative to the field, so the first visible line is at the top.
+        for (var row:int = 0; row < 3; row++) {
+            var y:Number = 2 + lineHeight * (row + 0.5);
+            trace("getLineIndexAtPoint at visible row " + row + ": " + tf.getLineIndexAtPoint(10, y));
+        }
+        trace("getLineIndexAtPoint above the field: " + tf.getLineIndexAtPoint(10, -5));
+        trace("getLineIndexAtPoint right of the field: " + tf.getLineIndexAtPoint(1000, 2 + lineHeight * 0.5));
+
+        // Characters scrolled out of view have no boundaries.
+        trace("getCharBoundaries(0): " + tf.getCharBoundaries(0));
+        trace("getCharBoundaries(31): " + tf.getCharBoundaries(31));
+
+        var bounds:Rectangle = tf.getCharBoundaries(14);
+        trace("getCharBoundaries(14) is visible: " + (bounds != null));
+        trace("getCharBoundaries(14) is on the second visible row: " +
+            (bounds.y >= 2 + lineHeight * 0.5 && bounds.y < 2 + lineHeight * 1.5));
+        var centerX:Number = bounds.x + bounds.width / 2;
+        var centerY:Number = bounds.y + bounds.height / 2;
+        trace("getCharIndexAtPoint at char 14: " + tf.getCharIndexAtPoint(centerX, centerY));
+        trace("getLineIndexAtPoint at char 14: " + tf.getLineIndexAtPoint(centerX, centerY));
+    }
+}
+}
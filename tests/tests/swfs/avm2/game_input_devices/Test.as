@@ -0,0 +1,59 @@
+package {
+    import flash.display.Sprite;
+    import flash.events.Event;
+    import flash.events.GameInputEvent;
+    import flash.ui.GameInput;
+    import flash.ui.GameInputControl;
+    import flash.ui.GameInputDevice;
+
+    public class Test extends Sprite {
+        private var gameInput:GameInput;
+
+        public function Test() {
+            trace("isSupported: " + GameInput.isSupported);
+            trace("numDevices: " + GameInput.numDevices);
+            try {
+                GameInput.getDeviceAt(0);
+            } catch (e:RangeError) {
+                trace("getDeviceAt(0): " + e.errorID);
+            }
+
+            gameInput = new GameInput();
+            gameInput.addEventListener(GameInputEvent.DEVICE_ADDED, onDeviceAdded);
+            gameInput.addEventListener(GameInputEvent.DEVICE_REMOVED, onDeviceRemoved);
+        }
+
+        private function onDeviceAdded(e:GameInputEvent):void {
+            var device:GameInputDevice = e.device;
+            trace("deviceAdded: " + describe(device));
+            trace("  numControls: " + device.numControls);
+            trace("  enabled: " + device.enabled);
+            for each (var i:int in [0, 3, 4, 17]) {
+                var control:GameInputControl = device.getControlAt(i);
+                trace("  control " + i + ": " + control.id + " " + control.minValue + ".." + control.maxValue +
+                    " = " + control.value);
+            }
+
+            // Only the first device reports its values.
+            if (device.id == "0") {
+                device.enabled = true;
+            }
+            device.getControlAt(0).addEventListener(Event.CHANGE, onChange);
+        }
+
+        private function onDeviceRemoved(e:GameInputEvent):void {
+            trace("deviceRemoved: " + describe(e.device));
+            trace("  control 0 = " + e.device.getControlAt(0).value);
+            trace("  getDeviceAt(0): " + GameInput.getDeviceAt(0).name);
+        }
+
+        private function onChange(e:Event):void {
+            var control:GameInputControl = e.target as GameInputControl;
+            trace("change: " + control.device.name + " " + control.id + " = " + control.value);
+        }
+
+        private function describe(device:GameInputDevice):String {
+            return device.id + " " + device.name + ", numDevices: " + GameInput.numDevices;
+        }
+    }
+}
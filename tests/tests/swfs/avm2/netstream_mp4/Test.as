@@ -0,0 +1,43 @@
+package {
+    import flash.display.Sprite;
+    import flash.events.NetStatusEvent;
+    import flash.net.NetConnection;
+    import flash.net.NetStream;
+
+    public class Test extends Sprite {
+        private var ns:NetStream;
+
+        public function Test() {
+            var nc:NetConnection = new NetConnection();
+            nc.connect(null);
+
+            ns = new NetStream(nc);
+            ns.client = {onMetaData: onMetaData};
+            ns.addEventListener(NetStatusEvent.NET_STATUS, onNetStatus);
+            ns.play("test.mp4");
+        }
+
+        private function onMetaData(info:Object):void {
+            trace("onMetaData");
+            trace("duration: " + info.duration);
+            trace("moovposition: " + info.moovposition);
+            trace("audiocodecid: " + info.audiocodecid);
+            trace("audiosamplerate: " + info.audiosamplerate);
+            trace("audiochannels: " + info.audiochannels);
+            trace("trackinfo: " + info.trackinfo.length);
+            trace("bytesLoaded: " + ns.bytesLoaded);
+            trace("bytesTotal: " + ns.bytesTotal);
+
+            // Audio has no keyframes, so this snaps to the next MP3 frame.
+            ns.seek(0.5);
+        }
+
+        private function onNetStatus(event:NetStatusEvent):void {
+            trace("netStatus: " + event.info.code);
+            if (event.info.code == "NetStream.Seek.Complete") {
+                trace("time: " + Math.round(ns.time * 1000));
+                ns.pause();
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.Rectangle;
+	import flash.printing.PrintJob;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			trace("isSupported: " + PrintJob.isSupported);
+
+			var job:PrintJob = new PrintJob();
+			trace("start: " + job.start());
+			trace("paper: " + job.paperWidth + "x" + job.paperHeight);
+			trace("page: " + job.pageWidth + "x" + job.pageHeight);
+			trace("orientation: " + job.orientation);
+
+			var sprite:Sprite = new Sprite();
+			sprite.graphics.beginFill(0xFF0000);
+			sprite.graphics.drawRect(0, 0, 50, 40);
+			sprite.graphics.beginFill(0x0000FF);
+			sprite.graphics.drawRect(50, 40, 50, 40);
+			sprite.graphics.endFill();
+
+			job.addPage(sprite);
+			job.addPage(sprite, new Rectangle(50, 40, 50, 40));
+			try {
+				job.addPage(null);
+			} catch (e:Error) {
+				trace("addPage(null): " + e.errorID);
+			}
+			job.send();
+			trace("sent");
+
+			try {
+				job.addPage(sprite);
+			} catch (e:Error) {
+				trace("addPage after send: " + e.errorID);
+			}
+			job.send();
+
+			// A job without pages isn't printed.
+			var emptyJob:PrintJob = new PrintJob();
+			trace("start: " + emptyJob.start());
+			emptyJob.send();
+			trace("done");
+		}
+	}
+}
@@ -0,0 +1,25 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.Rectangle;
+	import flash.printing.PrintJob;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var sprite:Sprite = new Sprite();
+			sprite.graphics.beginFill(0xFF0000);
+			sprite.graphics.drawRect(0, 0, 50, 40);
+			sprite.graphics.beginFill(0x0000FF);
+			sprite.graphics.drawRect(50, 40, 50, 40);
+			sprite.graphics.endFill();
+
+			var job:PrintJob = new PrintJob();
+			if (job.start()) {
+				job.addPage(sprite);
+				// Only the area is printed, from the top-left corner of the page.
+				job.addPage(sprite, new Rectangle(50, 40, 50, 40));
+				job.send();
+			}
+		}
+	}
+}
@@ -0,0 +1,24 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.printing.PrintJob;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			trace("isSupported: " + PrintJob.isSupported);
+
+			var job:PrintJob = new PrintJob();
+			trace("start: " + job.start());
+			trace("paper: " + job.paperWidth + "x" + job.paperHeight);
+			trace("orientation: " + job.orientation);
+
+			try {
+				job.addPage(new Sprite());
+			} catch (e:Error) {
+				trace("addPage: " + e.errorID);
+			}
+			job.send();
+			trace("done");
+		}
+	}
+}
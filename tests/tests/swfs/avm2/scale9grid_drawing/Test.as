@@ -0,0 +1,65 @@
+package {
+    import flash.display.Graphics;
+    import flash.display.Shape;
+    import flash.display.Sprite;
+    import flash.geom.Rectangle;
+
+    public class Test extends Sprite {
+        public function Test() {
+            // A drawing scaled by its own scale9Grid.
+            var drawing:Sprite = new Sprite();
+            drawFrame(drawing.graphics);
+            drawing.scale9Grid = new Rectangle(10, 10, 20, 20);
+            drawing.x = 10;
+            drawing.y = 10;
+            drawing.scaleX = 4;
+            drawing.scaleY = 2;
+            addChild(drawing);
+
+            // A shape scaled by the scale9Grid of its parent.
+            var parent:Sprite = new Sprite();
+            var shape:Shape = new Shape();
+            drawFrame(shape.graphics);
+            parent.addChild(shape);
+            parent.scale9Grid = new Rectangle(10, 10, 20, 20);
+            parent.x = 10;
+            parent.y = 100;
+            parent.scaleX = 2.5;
+            parent.scaleY = 2;
+            addChild(parent);
+
+            trace("// drawing");
+            printHits(drawing, [[15, 50], [40, 50], [90, 50], [30, 15], [90, 25]]);
+            trace("// drawing without scale9Grid");
+            drawing.scale9Grid = null;
+            printHits(drawing, [[15, 50], [40, 50], [90, 50], [30, 15], [90, 25]]);
+            drawing.scale9Grid = new Rectangle(10, 10, 20, 20);
+
+            trace("// shape");
+            printHits(parent, [[15, 140], [30, 140], [60, 140], [60, 105], [60, 115]]);
+            trace("// shape without scale9Grid");
+            parent.scale9Grid = null;
+            printHits(parent, [[15, 140], [30, 140], [60, 140], [60, 105], [60, 115]]);
+            parent.scale9Grid = new Rectangle(10, 10, 20, 20);
+        }
+
+        // A 10 pixel wide black frame around a 40x40 square, with a red square in its middle.
+        private function drawFrame(graphics:Graphics):void {
+            graphics.beginFill(0x000000);
+            graphics.drawRect(0, 0, 40, 10);
+            graphics.drawRect(0, 30, 40, 10);
+            graphics.drawRect(0, 10, 10, 20);
+            graphics.drawRect(30, 10, 10, 20);
+            graphics.endFill();
+            graphics.beginFill(0xFF0000);
+            graphics.drawRect(15, 15, 10, 10);
+            graphics.endFill();
+        }
+
+        private function printHits(target:Sprite, points:Array):void {
+            for each (var point:Array in points) {
+                trace("hitTestPoint(" + point + "): " + target.hitTestPoint(point[0], point[1], true));
+            }
+        }
+    }
+}
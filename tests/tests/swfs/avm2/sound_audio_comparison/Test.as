@@ -0,0 +1,24 @@
+package {
+    import flash.display.Sprite;
+    import flash.media.Sound;
+    import flash.utils.ByteArray;
+
+    public class Test extends Sprite {
+        public function Test() {
+            // A quarter second of a positive level, then a quarter second of
+            // a negative one, followed by silence for the rest of the recording.
+            var bytes:ByteArray = new ByteArray();
+            for (var i:int = 0; i < 22050; i++) {
+                var level:Number = i < 11025 ? 0.25 : -0.25;
+                bytes.writeFloat(level);
+                bytes.writeFloat(level);
+            }
+            bytes.position = 0;
+
+            var sound:Sound = new Sound();
+            sound.loadPCMFromByteArray(bytes, 22050, "float", true, 44100);
+            sound.play();
+            trace("Playing " + sound.length + "ms");
+        }
+    }
+}
@@ -0,0 +1,80 @@
+package {
+    import flash.display.MovieClip;
+    import flash.media.Sound;
+    import flash.utils.ByteArray;
+
+    public class Test extends MovieClip {
+        public function Test() {
+            testShortMono();
+            testFloatStereo();
+            testInvalidFormat();
+        }
+
+        private function load(samples:ByteArray, count:uint, format:String, stereo:Boolean):Sound {
+            var sound:Sound = new Sound();
+            samples.position = 0;
+            sound.loadPCMFromByteArray(samples, count, format, stereo, 44100);
+            return sound;
+        }
+
+        private function traceFrames(target:ByteArray, count:uint):void {
+            target.position = 0;
+            for (var i:uint = 0; i < count; i++) {
+                trace(target.readFloat());
+                trace(target.readFloat());
+            }
+        }
+
+        private function testShortMono():void {
+            trace("// short, mono");
+            var samples:ByteArray = new ByteArray();
+            for (var i:int = 0; i < 441; i++) {
+                samples.writeShort(i * 64);
+            }
+            var sound:Sound = load(samples, 441, "short", false);
+            trace("length: " + sound.length);
+
+            // Extracting past the end only returns the remaining samples.
+            var target:ByteArray = new ByteArray();
+            trace("extract(1000, 400): " + sound.extract(target, 1000, 400));
+            trace("bytes: " + target.length);
+            traceFrames(target, 2);
+
+            // Without a start position, extraction continues where it left off.
+            target = new ByteArray();
+            trace("extract(10): " + sound.extract(target, 10));
+            trace("bytes: " + target.length);
+
+            target = new ByteArray();
+            trace("extract(3, 0): " + sound.extract(target, 3, 0));
+            traceFrames(target, 3);
+            trace("extract(2): " + sound.extract(target, 2));
+            traceFrames(target, 5);
+        }
+
+        private function testFloatStereo():void {
+            trace("// float, stereo");
+            var samples:ByteArray = new ByteArray();
+            for (var i:int = 0; i < 882; i++) {
+                samples.writeFloat(0.25);
+                samples.writeFloat(-0.5);
+            }
+            var sound:Sound = load(samples, 882, "float", true);
+            trace("length: " + sound.length);
+
+            var target:ByteArray = new ByteArray();
+            trace("extract(4, 880): " + sound.extract(target, 4, 880));
+            trace("bytes: " + target.length);
+            traceFrames(target, 2);
+        }
+
+        private function testInvalidFormat():void {
+            trace("// invalid format");
+            try {
+                load(new ByteArray(), 1, "double", false);
+            } catch (e:Error) {
+                trace(Object(e).constructor + ": " + e.errorID);
+            }
+        }
+    }
+}
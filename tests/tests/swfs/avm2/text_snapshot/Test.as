@@ -0,0 +1,56 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.text.TextSnapshot;
+
+	// The stage contains two static texts in a font whose glyphs are all
+	// 20px wide, with an ascent of 15px and a descent of 5px:
+	// "The first line" and "and the second" at (10, 10), and "Last LINE" at (10, 60).
+	public class Test extends MovieClip {
+		public function Test() {
+			var snapshot:TextSnapshot = this.textSnapshot;
+
+			trace("/// charCount");
+			trace(snapshot.charCount);
+			trace(new Sprite().textSnapshot.charCount);
+
+			trace("/// getText");
+			trace(snapshot.getText(0, snapshot.charCount));
+			trace(snapshot.getText(0, snapshot.charCount, true));
+			trace(snapshot.getText(10, 17, true));
+			trace(snapshot.getText(30, 100));
+			trace("[" + snapshot.getText(5, 2) + "]");
+
+			trace("/// findText");
+			trace(snapshot.findText(0, "the", true));
+			trace(snapshot.findText(0, "the", false));
+			trace(snapshot.findText(1, "THE", false));
+			trace(snapshot.findText(0, "line", true));
+			trace(snapshot.findText(11, "line", false));
+			trace(snapshot.findText(11, "line", true));
+			trace(snapshot.findText(0, "xyz", true));
+
+			trace("/// setSelected");
+			trace(snapshot.getSelected(0, snapshot.charCount));
+			snapshot.setSelected(4, 9, true);
+			trace(snapshot.getSelected(0, 4));
+			trace(snapshot.getSelected(0, 5));
+			trace(snapshot.getSelected(8, 9));
+			trace(snapshot.getSelectedText());
+			snapshot.setSelected(14, 30, true);
+			trace(snapshot.getSelected(9, 14));
+			trace(snapshot.getSelectedText(false));
+			trace(snapshot.getSelectedText(true));
+			snapshot.setSelected(0, snapshot.charCount, false);
+			trace("[" + snapshot.getSelectedText() + "]");
+
+			trace("/// hitTestTextNearPos");
+			trace(snapshot.hitTestTextNearPos(15, 20));
+			trace(snapshot.hitTestTextNearPos(75, 40));
+			trace(snapshot.hitTestTextNearPos(195, 70));
+			trace(snapshot.hitTestTextNearPos(195, 70, 10));
+			trace(snapshot.hitTestTextNearPos(5, 5, 10));
+			trace(snapshot.hitTestTextNearPos(500, 500, 10));
+		}
+	}
+}
@@ -0,0 +1,110 @@
+package {
+import flash.display.Sprite;
+
+public class Test extends Sprite {
+    function Test() {
+
+    }
+}
+}
+
+import flash.text.engine.ElementFormat;
+import flash.text.engine.TextBlock;
+import flash.text.engine.TextElement;
+import flash.text.engine.TextLine;
+
+var text:String = "aaaaaa bbbbbb cccccc";
+var format:ElementFormat = new ElementFormat();
+format.fontSize = 20;
+
+function describe(line:TextLine):void {
+    if (line == null) {
+        trace("line: null");
+        return;
+    }
+    var begin:int = line.textBlockBeginIndex;
+    trace("line: begin " + begin + ", length " + line.rawTextLength + ", text \"" +
+        Object(text).substr(begin, line.rawTextLength) + "\"");
+    trace("  atomCount: " + line.atomCount);
+    trace("  specifiedWidth: " + line.specifiedWidth);
+    trace("  textWidth <= specifiedWidth: " + (line.textWidth <= line.specifiedWidth));
+}
+
+// Each word fits on a line of its own, but two words never fit together.
+var block:TextBlock = new TextBlock(new TextElement(text, format));
+var lines:Array = [];
+var line:TextLine = block.createTextLine(null, 100);
+while (line != null) {
+    describe(line);
+    trace("  textLineCreationResult: " + block.textLineCreationResult);
+    lines.push(line);
+    line = block.createTextLine(line, 100);
+}
+describe(line);
+trace("textLineCreationResult: " + block.textLineCreationResult);
+trace("lines: " + lines.length);
+
+trace("firstLine is lines[0]: " + (block.firstLine == lines[0]));
+trace("lastLine is lines[2]: " + (block.lastLine == lines[2]));
+for (var i:int = 0; i < lines.length; i++) {
+    trace("lines[" + i + "]: previousLine is " + lines.indexOf(lines[i].previousLine) +
+        ", nextLine is " + lines.indexOf(lines[i].nextLine) +
+        ", textBlock is block: " + (lines[i].textBlock == block));
+}
+
+// A wider line fits all of the words.
+var wideBlock:TextBlock = new TextBlock(new TextElement(text, format));
+var wideLine:TextLine = wideBlock.createTextLine(null, 1000);
+describe(wideLine);
+describe(wideBlock.createTextLine(wideLine, 1000));
+trace("wide: textLineCreationResult: " + wideBlock.textLineCreationResult);
+
+// A word that doesn't fit has to be broken in the middle.
+var longBlock:TextBlock = new TextBlock(new TextElement("abcdefghijklmnopqrstuvwxyz", format));
+var longLine:TextLine = longBlock.createTextLine(null, 100, 0, true);
+trace("long: begin " + longLine.textBlockBeginIndex +
+    ", broken: " + (longLine.rawTextLength > 0 && longLine.rawTextLength < 26));
+trace("long: textLineCreationResult: " + longBlock.textLineCreationResult);
+var rest:TextLine = longBlock.createTextLine(longLine, 1000);
+trace("rest: begins after long: " + (rest.textBlockBeginIndex == longLine.rawTextLength) +
+    ", ends at end: " + (rest.textBlockBeginIndex + rest.rawTextLength == 26));
+trace("rest: textLineCreationResult: " + longBlock.textLineCreationResult);
+
+// Without fitSomething, nothing at all is returned if not even an atom fits.
+trace("narrow: " + longBlock.createTextLine(null, 1));
+trace("narrow: textLineCreationResult: " + longBlock.textLineCreationResult);
+
+// The previous line has to belong to the same block.
+try {
+    block.createTextLine(longLine, 100);
+} catch (e:Error) {
+    trace("other block's line: " + Object(e).constructor + ": " + e.errorID);
+}
+try {
+    block.createTextLine(null, -1);
+} catch (e:Error) {
+    trace("negative width: " + Object(e).constructor + ": " + e.errorID);
+}
+
+// Lines of long blocks are found without laying out all of the text that follows.
+var words:String = "";
+for (var w:int = 0; w < 100; w++) {
+    words += "word" + (w % 10) + " ";
+}
+var wordsBlock:TextBlock = new TextBlock(new TextElement(words, format));
+var wordsLine:TextLine = wordsBlock.createTextLine(null, 1000000);
+trace("words, wide: begin " + wordsLine.textBlockBeginIndex + ", length " + wordsLine.rawTextLength +
+    " of " + words.length);
+trace("words, wide: ascent > descent > 0: " + (wordsLine.ascent > wordsLine.descent && wordsLine.descent > 0));
+
+var wordLines:int = 0;
+var oneWordEach:Boolean = true;
+var wordLine:TextLine = wordsBlock.createTextLine(null, 100);
+while (wordLine != null) {
+    var expected:String = "word" + (wordLines % 10) + " ";
+    oneWordEach = oneWordEach && wordLine.textBlockBeginIndex == wordLines * 6 &&
+        Object(words).substr(wordLine.textBlockBeginIndex, wordLine.rawTextLength) == expected;
+    wordLines++;
+    wordLine = wordsBlock.createTextLine(wordLine, 100);
+}
+trace("words, narrow: " + wordLines + " lines, one word each: " + oneWordEach);
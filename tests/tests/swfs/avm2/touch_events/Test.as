@@ -0,0 +1,65 @@
+package {
+    import flash.display.Sprite;
+    import flash.events.MouseEvent;
+    import flash.events.TouchEvent;
+    import flash.ui.Multitouch;
+    import flash.ui.MultitouchInputMode;
+
+    public class Test extends Sprite {
+        private var clicks:int = 0;
+
+        public function Test() {
+            var box:Sprite = new Sprite();
+            box.name = "box";
+            box.graphics.beginFill(0xFF0000);
+            box.graphics.drawRect(0, 0, 100, 100);
+            box.x = 100;
+            box.y = 100;
+            addChild(box);
+
+            trace("inputMode: " + Multitouch.inputMode);
+            Multitouch.inputMode = MultitouchInputMode.TOUCH_POINT;
+            trace("inputMode: " + Multitouch.inputMode);
+            try {
+                Multitouch.inputMode = "bogus";
+            } catch (e:ArgumentError) {
+                trace("invalid inputMode: " + e.errorID);
+            }
+            trace("inputMode: " + Multitouch.inputMode);
+            trace("mapTouchToMouse: " + Multitouch.mapTouchToMouse);
+
+            for each (var type:String in [TouchEvent.TOUCH_BEGIN, TouchEvent.TOUCH_MOVE, TouchEvent.TOUCH_END,
+                    TouchEvent.TOUCH_TAP, TouchEvent.TOUCH_OVER, TouchEvent.TOUCH_OUT]) {
+                stage.addEventListener(type, onTouch);
+            }
+            for each (type in [MouseEvent.MOUSE_DOWN, MouseEvent.MOUSE_UP, MouseEvent.CLICK]) {
+                stage.addEventListener(type, onMouse);
+            }
+        }
+
+        private function targetName(e:Object):String {
+            return e.target === stage ? "stage" : e.target.name;
+        }
+
+        private function onTouch(e:TouchEvent):void {
+            trace(e.type + " " + targetName(e) + " id=" + e.touchPointID + " primary=" + e.isPrimaryTouchPoint +
+                " local=" + e.localX + "," + e.localY);
+        }
+
+        private function onMouse(e:MouseEvent):void {
+            trace(e.type + " " + targetName(e) + " local=" + e.localX + "," + e.localY);
+            if (e.type != MouseEvent.CLICK) {
+                return;
+            }
+            clicks++;
+            if (clicks == 1) {
+                Multitouch.inputMode = MultitouchInputMode.NONE;
+                trace("inputMode: " + Multitouch.inputMode);
+            } else if (clicks == 2) {
+                Multitouch.mapTouchToMouse = false;
+                Multitouch.inputMode = MultitouchInputMode.TOUCH_POINT;
+                trace("mapTouchToMouse: " + Multitouch.mapTouchToMouse + ", inputMode: " + Multitouch.inputMode);
+            }
+        }
+    }
+}
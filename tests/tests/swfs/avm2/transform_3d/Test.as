@@ -0,0 +1,176 @@
+package {
+    import flash.display.Sprite;
+    import flash.geom.Matrix;
+    import flash.geom.Matrix3D;
+    import flash.geom.PerspectiveProjection;
+    import flash.geom.Vector3D;
+
+    public class Test extends Sprite {
+        public function Test() {
+            var clip:Sprite = new Sprite();
+            clip.graphics.beginFill(0xFF0000);
+            clip.graphics.drawRect(-50, -50, 100, 100);
+            clip.graphics.endFill();
+            clip.x = 275;
+            clip.y = 200;
+            addChild(clip);
+
+            trace("// new clip");
+            printTransform(clip);
+            printHits(clip);
+
+            trace("// clip.z = 100");
+            clip.z = 100;
+            printTransform(clip);
+
+            trace("// clip.transform.matrix = new Matrix(1, 0, 0, 1, 275, 200)");
+            clip.transform.matrix = new Matrix(1, 0, 0, 1, 275, 200);
+            printTransform(clip);
+
+            trace("// clip.rotationY = 60");
+            clip.rotationY = 60;
+            printTransform(clip);
+            printHits(clip);
+
+            trace("// clip.transform.matrix = new Matrix(1, 0, 0, 1, 275, 200)");
+            clip.transform.matrix = new Matrix(1, 0, 0, 1, 275, 200);
+            printTransform(clip);
+            printHits(clip);
+
+            trace("// root.transform.perspectiveProjection");
+            var projection:PerspectiveProjection = this.transform.perspectiveProjection;
+            printProjection(projection);
+            trace("// clip.transform.perspectiveProjection");
+            trace(clip.transform.perspectiveProjection);
+
+            trace("// new PerspectiveProjection()");
+            projection = new PerspectiveProjection();
+            printProjection(projection);
+            trace("// projection.focalLength = 250");
+            projection.focalLength = 250;
+            printProjection(projection);
+            trace("// projection.focalLength = 500");
+            projection.focalLength = 500;
+            printProjection(projection);
+            trace("// projection.fieldOfView = 90");
+            projection.fieldOfView = 90;
+            printProjection(projection);
+
+            trace("// root.transform.perspectiveProjection = projection");
+            this.transform.perspectiveProjection = projection;
+            printProjection(this.transform.perspectiveProjection);
+
+            trace("// Matrix3D.pointAt");
+            var matrix:Matrix3D = new Matrix3D();
+            matrix.appendTranslation(10, 20, 30);
+            matrix.pointAt(new Vector3D(10, 20, 130));
+            trace("pointAt(10,20,130) from (10,20,30): " + matrixString(matrix));
+            matrix = new Matrix3D();
+            matrix.pointAt(new Vector3D(100, 0, 0));
+            trace("pointAt(100,0,0): " + matrixString(matrix));
+            matrix = new Matrix3D();
+            matrix.appendScale(2, 3, 4);
+            matrix.pointAt(new Vector3D(0, 0, 100));
+            trace("pointAt(0,0,100) with scale (2,3,4): " + matrixString(matrix));
+            matrix = new Matrix3D();
+            matrix.pointAt(new Vector3D(0, 0, 100), Vector3D.X_AXIS, Vector3D.Y_AXIS);
+            trace("pointAt(0,0,100), at X, up Y: " + matrixString(matrix));
+            matrix = new Matrix3D();
+            matrix.pointAt(new Vector3D(100, 100, 0), Vector3D.X_AXIS, Vector3D.Z_AXIS);
+            trace("pointAt(100,100,0), at X, up Z: " + matrixString(matrix));
+
+            // The direction is parallel to the default `up` of (0, -1, 0).
+            matrix = new Matrix3D();
+            matrix.pointAt(new Vector3D(0, 100, 0));
+            trace("pointAt(0,100,0): " + matrixString(matrix));
+            matrix = new Matrix3D();
+            matrix.pointAt(new Vector3D(0, 0, 100), Vector3D.X_AXIS, Vector3D.X_AXIS);
+            trace("pointAt(0,0,100), at X, up X: " + matrixString(matrix));
+            matrix = new Matrix3D();
+            matrix.pointAt(new Vector3D(100, 0, 0), Vector3D.Z_AXIS, Vector3D.X_AXIS);
+            trace("pointAt(100,0,0), at Z, up X: " + matrixString(matrix));
+            matrix = new Matrix3D();
+            matrix.appendTranslation(10, 20, 30);
+            matrix.pointAt(new Vector3D(10, 20, 30));
+            trace("pointAt(10,20,30) from (10,20,30): " + matrixString(matrix));
+            matrix = new Matrix3D();
+            matrix.pointAt(new Vector3D(100, 0, 0), new Vector3D(0, 0, 0));
+            trace("pointAt(100,0,0), at 0: " + matrixString(matrix));
+
+            trace("// Transform.getRelativeMatrix3D");
+            var container:Sprite = new Sprite();
+            container.x = 100;
+            container.y = 50;
+            addChild(container);
+            var child:Sprite = new Sprite();
+            child.x = 10;
+            child.z = 20;
+            container.addChild(child);
+            printRelativeMatrix3D("child to container", child, container);
+            printRelativeMatrix3D("child to root", child, this);
+            printRelativeMatrix3D("child to null", child, null);
+            printRelativeMatrix3D("container to child", container, child);
+            printRelativeMatrix3D("child to child", child, child);
+            trace("// container.rotationY = 90");
+            container.rotationY = 90;
+            printRelativeMatrix3D("child to container", child, container);
+            printRelativeMatrix3D("child to root", child, this);
+            printRelativeMatrix3D("container to child", container, child);
+            trace("// flat.scaleX = 0");
+            var flat:Sprite = new Sprite();
+            flat.scaleX = 0;
+            addChild(flat);
+            printRelativeMatrix3D("child to flat", child, flat);
+            printRelativeMatrix3D("flat to child", flat, child);
+        }
+
+        private function matrixString(matrix:Matrix3D):String {
+            var rawData:Array = [];
+            for each (var value:Number in matrix.rawData) {
+                rawData.push(round(value));
+            }
+            return rawData.join(",");
+        }
+
+        private function printRelativeMatrix3D(name:String, clip:Sprite, relativeTo:Sprite):void {
+            var matrix:Matrix3D = clip.transform.getRelativeMatrix3D(relativeTo);
+            trace(name + ": " + (matrix ? matrixString(matrix) : matrix));
+        }
+
+        private function round(value:Number):Number {
+            return Math.round(value * 1000) / 1000;
+        }
+
+        private function printTransform(clip:Sprite):void {
+            var matrix3D:Matrix3D = clip.transform.matrix3D;
+            trace("matrix: " + clip.transform.matrix);
+            if (matrix3D) {
+                var rawData:Array = [];
+                for each (var value:Number in matrix3D.rawData) {
+                    rawData.push(round(value));
+                }
+                trace("matrix3D: " + rawData);
+            } else {
+                trace("matrix3D: " + matrix3D);
+            }
+            trace("x: " + clip.x + ", y: " + clip.y + ", z: " + clip.z);
+            trace("rotationY: " + round(clip.rotationY));
+        }
+
+        // The clip covers 251.9 to 302.2 horizontally when rotated by 60 degrees
+        // around the Y axis, and its right edge is taller than its left edge.
+        private function printHits(clip:Sprite):void {
+            var points:Array = [[240, 200], [300, 200], [300, 252], [260, 248]];
+            for each (var point:Array in points) {
+                trace("hitTestPoint(" + point + "): bounds " + clip.hitTestPoint(point[0], point[1]) +
+                    ", shape " + clip.hitTestPoint(point[0], point[1], true));
+            }
+        }
+
+        private function printProjection(projection:PerspectiveProjection):void {
+            trace("fieldOfView: " + round(projection.fieldOfView) +
+                ", focalLength: " + round(projection.focalLength) +
+                ", projectionCenter: " + projection.projectionCenter);
+        }
+    }
+}
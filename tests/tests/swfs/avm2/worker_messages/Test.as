@@ -0,0 +1,132 @@
+package {
+    import flash.concurrent.Condition;
+    import flash.concurrent.Mutex;
+    import flash.display.Sprite;
+    import flash.events.Event;
+    import flash.system.MessageChannel;
+    import flash.system.Worker;
+    import flash.system.WorkerDomain;
+    import flash.utils.ByteArray;
+
+    // The same movie runs as the primordial worker and as the background worker.
+    public class Test extends Sprite {
+        private var worker:Worker;
+        private var toWorker:MessageChannel;
+        private var fromWorker:MessageChannel;
+        private var bytes:ByteArray;
+        private var mutex:Mutex;
+        private var condition:Condition;
+        private var pongs:int = 0;
+        private var step:int = 0;
+
+        public function Test() {
+            if (Worker.current.isPrimordial) {
+                startPrimordial();
+            } else {
+                startBackground();
+            }
+        }
+
+        private function startPrimordial():void {
+            trace("primordial: " + Worker.current.isPrimordial);
+            worker = WorkerDomain.current.createWorker(loaderInfo.bytes);
+            trace("worker state: " + worker.state);
+            trace("workers: " + Object(WorkerDomain.current.listWorkers()).length);
+
+            toWorker = Worker.current.createMessageChannel(worker);
+            fromWorker = worker.createMessageChannel(Worker.current);
+            bytes = new ByteArray();
+            bytes.shareable = true;
+            bytes.length = 4;
+            bytes[0] = 1;
+            mutex = new Mutex();
+            condition = new Condition(mutex);
+
+            worker.setSharedProperty("toWorker", toWorker);
+            worker.setSharedProperty("fromWorker", fromWorker);
+            worker.setSharedProperty("bytes", bytes);
+            worker.setSharedProperty("mutex", mutex);
+            worker.setSharedProperty("condition", condition);
+            worker.setSharedProperty("greeting", "hello");
+
+            worker.addEventListener(Event.WORKER_STATE, function(e:Event):void {
+                trace("workerState: " + worker.state);
+            });
+            toWorker.addEventListener(Event.CHANNEL_STATE, function(e:Event):void {
+                trace("toWorker channelState: " + toWorker.state);
+            });
+            fromWorker.addEventListener(Event.CHANNEL_MESSAGE, function(e:Event):void {
+                pongs++;
+                trace("primordial received: " + fromWorker.receive());
+            });
+
+            toWorker.send("ping 1");
+            mutex.lock();
+            trace("primordial locked the mutex");
+            worker.start();
+            trace("worker state after start: " + worker.state);
+
+            addEventListener(Event.ENTER_FRAME, onPrimordialFrame);
+        }
+
+        // Each step waits for the answer of the background worker to the previous one.
+        private function onPrimordialFrame(e:Event):void {
+            if (step == 0 && pongs == 1) {
+                step++;
+                trace("bytes[0] in primordial: " + bytes[0]);
+                condition.notify();
+                mutex.unlock();
+                trace("primordial notified and unlocked");
+                toWorker.send("ping 2");
+            } else if (step == 1 && pongs == 2) {
+                step++;
+                trace("bytes[1] in primordial: " + bytes[1]);
+                trace("terminate: " + worker.terminate());
+                trace("worker state: " + worker.state);
+                trace("toWorker state: " + toWorker.state);
+                trace("terminate again: " + worker.terminate());
+                trace("terminate primordial: " + Worker.current.terminate());
+                trace("workers: " + Object(WorkerDomain.current.listWorkers()).length);
+            }
+        }
+
+        private function startBackground():void {
+            trace("worker: primordial: " + Worker.current.isPrimordial);
+            toWorker = Worker.current.getSharedProperty("toWorker");
+            fromWorker = Worker.current.getSharedProperty("fromWorker");
+            bytes = Worker.current.getSharedProperty("bytes");
+            mutex = Worker.current.getSharedProperty("mutex");
+            condition = Worker.current.getSharedProperty("condition");
+
+            trace("worker: messageAvailable: " + toWorker.messageAvailable);
+            trace("worker: received " + toWorker.receive());
+            trace("worker: messageAvailable: " + toWorker.messageAvailable);
+            trace("worker: greeting: " + Worker.current.getSharedProperty("greeting"));
+            trace("worker: bytes[0]: " + bytes[0] + ", shareable: " + bytes.shareable);
+            bytes[0] = 2;
+            trace("worker: tryLock while the primordial worker holds it: " + mutex.tryLock());
+            try {
+                mutex.lock();
+            } catch (e:Error) {
+                trace("worker: lock while the primordial worker holds it: " + Object(e).constructor + " " + e.errorID);
+            }
+            trace("worker: condition.mutex is the shared mutex: " + (condition.mutex === mutex));
+
+            toWorker.addEventListener(Event.CHANNEL_MESSAGE, function(e:Event):void {
+                trace("worker: received " + toWorker.receive());
+                trace("worker: tryLock: " + mutex.tryLock());
+                trace("worker: wait: " + condition.wait());
+                trace("worker: wait with a timeout: " + condition.wait(10));
+                try {
+                    condition.wait();
+                } catch (e:Error) {
+                    trace("worker: wait without a notification: " + Object(e).constructor + " " + e.errorID);
+                }
+                mutex.unlock();
+                bytes[1] = 42;
+                fromWorker.send("pong 2");
+            });
+            fromWorker.send("pong 1");
+        }
+    }
+}
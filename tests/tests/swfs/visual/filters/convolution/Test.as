@@ -0,0 +1,26 @@
+package {
+    import flash.display.Shape;
+    import flash.display.Sprite;
+    import flash.filters.ConvolutionFilter;
+
+    public class Test extends Sprite {
+        public function Test() {
+            // A negative 1x1 kernel with a full bias inverts the colors.
+            addChild(square(10, new ConvolutionFilter(1, 1, [-1], 1, 255, true, true)));
+
+            // A 3x3 kernel with only its center set scales the colors by the divisor.
+            addChild(square(70, new ConvolutionFilter(3, 3, [0, 0, 0, 0, 1, 0, 0, 0, 0], 5, 0, true, true)));
+        }
+
+        private function square(x:Number, filter:ConvolutionFilter):Shape {
+            var shape:Shape = new Shape();
+            shape.graphics.beginFill(0xFF0000);
+            shape.graphics.drawRect(0, 0, 40, 40);
+            shape.graphics.endFill();
+            shape.x = x;
+            shape.y = 10;
+            shape.filters = [filter];
+            return shape;
+        }
+    }
+}
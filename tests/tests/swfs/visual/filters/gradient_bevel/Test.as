@@ -0,0 +1,29 @@
+package {
+    import flash.display.Shape;
+    import flash.display.Sprite;
+    import flash.filters.GradientBevelFilter;
+
+    public class Test extends Sprite {
+        public function Test() {
+            // Lit from the left: the highlight color on the left edge,
+            // the shadow color on the right edge and the shape itself in between.
+            addChild(square(10, new GradientBevelFilter(4, 0, [0xFFFFFF, 0x000000, 0x000000], [1, 0, 1],
+                [0, 128, 255], 0, 0, 1, 1, "inner", false)));
+
+            // Lit from the right, with a knockout: only the two edges remain.
+            addChild(square(70, new GradientBevelFilter(4, 180, [0x00FF00, 0x000000, 0x0000FF], [1, 0, 1],
+                [0, 128, 255], 0, 0, 1, 1, "inner", true)));
+        }
+
+        private function square(x:Number, filter:GradientBevelFilter):Shape {
+            var shape:Shape = new Shape();
+            shape.graphics.beginFill(0xFF0000);
+            shape.graphics.drawRect(0, 0, 40, 40);
+            shape.graphics.endFill();
+            shape.x = x;
+            shape.y = 10;
+            shape.filters = [filter];
+            return shape;
+        }
+    }
+}
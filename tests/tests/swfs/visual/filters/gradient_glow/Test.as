@@ -0,0 +1,28 @@
+package {
+    import flash.display.Shape;
+    import flash.display.Sprite;
+    import flash.filters.GradientGlowFilter;
+
+    public class Test extends Sprite {
+        public function Test() {
+            // Without blur, a full glow covers the shape with the last gradient color.
+            addChild(square(10, new GradientGlowFilter(0, 45, [0x00FF00, 0x0000FF], [0, 1], [0, 255],
+                0, 0, 1, 1, "full", false)));
+
+            // An inner knockout glow replaces the shape with the first gradient color.
+            addChild(square(70, new GradientGlowFilter(0, 45, [0xFFFF00, 0x000000], [1, 1], [0, 255],
+                0, 0, 1, 1, "inner", true)));
+        }
+
+        private function square(x:Number, filter:GradientGlowFilter):Shape {
+            var shape:Shape = new Shape();
+            shape.graphics.beginFill(0xFF0000);
+            shape.graphics.drawRect(0, 0, 40, 40);
+            shape.graphics.endFill();
+            shape.x = x;
+            shape.y = 10;
+            shape.filters = [filter];
+            return shape;
+        }
+    }
+}
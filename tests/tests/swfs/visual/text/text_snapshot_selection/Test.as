@@ -0,0 +1,15 @@
+package {
+	import flash.display.MovieClip;
+	import flash.text.TextSnapshot;
+
+	// The stage contains the static text "aaaa" at (20, 20), in a font whose
+	// glyphs are 20px wide 10px squares, with an ascent of 15px and a descent of 5px.
+	// The second and third glyphs are highlighted with the default selection color.
+	public class Test extends MovieClip {
+		public function Test() {
+			var snapshot:TextSnapshot = this.textSnapshot;
+			snapshot.setSelected(1, 3, true);
+			trace(snapshot.getSelectedText());
+		}
+	}
+}
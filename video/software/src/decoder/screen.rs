@@ -24,6 +24,21 @@ pub enum ScreenError {
 
     #[error("Not all blocks were updated by a supposed keyframe")]
     KeyframeInvalid,
+
+    #[error("Invalid color depth: {0}")]
+    InvalidColorDepth(u8),
+
+    #[error("Invalid image block position")]
+    InvalidBlockPosition,
+
+    #[error("Interframe images are not supported")]
+    UnsupportedIFrameImage,
+
+    #[error("Invalid codec ID: {0}")]
+    InvalidCodecId(u8),
+
+    #[error("Invalid frame size: {0}x{1}")]
+    InvalidFrameSize(usize, usize),
 }
 
 impl From<ScreenError> for Error {
@@ -44,17 +59,17 @@ pub struct ScreenVideoDecoder {
     last_frame: Option<Vec<u8>>,
 }
 
-struct ByteReader<'a> {
+pub(crate) struct ByteReader<'a> {
     data: &'a [u8],
     pos: usize,
 }
 
 impl<'a> ByteReader<'a> {
-    fn new(data: &'a [u8]) -> Self {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
         Self { data, pos: 0 }
     }
 
-    fn read_byte(&mut self) -> Result<u8, ScreenError> {
+    pub(crate) fn read_byte(&mut self) -> Result<u8, ScreenError> {
         if self.pos >= self.data.len() {
             return Err(ScreenError::UnexpectedEOF);
         }
@@ -63,13 +78,13 @@ impl<'a> ByteReader<'a> {
         Ok(byte)
     }
 
-    fn read_u16be(&mut self) -> Result<u16, ScreenError> {
+    pub(crate) fn read_u16be(&mut self) -> Result<u16, ScreenError> {
         let byte1 = self.read_byte()?;
         let byte2 = self.read_byte()?;
         Ok((byte1 as u16) << 8 | (byte2 as u16))
     }
 
-    fn read_buf_ref(&mut self, length: usize) -> Result<&[u8], ScreenError> {
+    pub(crate) fn read_buf_ref(&mut self, length: usize) -> Result<&[u8], ScreenError> {
         if self.pos + length > self.data.len() {
             return Err(ScreenError::UnexpectedEOF);
         }
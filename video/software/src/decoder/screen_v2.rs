@@ -0,0 +1,634 @@
+use crate::decoder::screen::{ByteReader, ScreenError};
+use crate::decoder::VideoDecoder;
+use ruffle_render::bitmap::BitmapFormat;
+use ruffle_video::error::Error;
+
+use flate2::{Decompress, FlushDecompress};
+use ruffle_video::frame::{DecodedFrame, EncodedFrame, FrameDependency};
+
+/// The palette used by hybrid blocks, unless a stream provides its own.
+const DEFAULT_PALETTE: [[u8; 3]; 128] = [
+    [0x00, 0x00, 0x00],
+    [0x33, 0x33, 0x33],
+    [0x66, 0x66, 0x66],
+    [0x99, 0x99, 0x99],
+    [0xCC, 0xCC, 0xCC],
+    [0xFF, 0xFF, 0xFF],
+    [0x33, 0x00, 0x00],
+    [0x66, 0x00, 0x00],
+    [0x99, 0x00, 0x00],
+    [0xCC, 0x00, 0x00],
+    [0xFF, 0x00, 0x00],
+    [0x00, 0x33, 0x00],
+    [0x00, 0x66, 0x00],
+    [0x00, 0x99, 0x00],
+    [0x00, 0xCC, 0x00],
+    [0x00, 0xFF, 0x00],
+    [0x00, 0x00, 0x33],
+    [0x00, 0x00, 0x66],
+    [0x00, 0x00, 0x99],
+    [0x00, 0x00, 0xCC],
+    [0x00, 0x00, 0xFF],
+    [0x33, 0x33, 0x00],
+    [0x66, 0x66, 0x00],
+    [0x99, 0x99, 0x00],
+    [0xCC, 0xCC, 0x00],
+    [0xFF, 0xFF, 0x00],
+    [0x00, 0x33, 0x33],
+    [0x00, 0x66, 0x66],
+    [0x00, 0x99, 0x99],
+    [0x00, 0xCC, 0xCC],
+    [0x00, 0xFF, 0xFF],
+    [0x33, 0x00, 0x33],
+    [0x66, 0x00, 0x66],
+    [0x99, 0x00, 0x99],
+    [0xCC, 0x00, 0xCC],
+    [0xFF, 0x00, 0xFF],
+    [0xFF, 0xFF, 0x33],
+    [0xFF, 0xFF, 0x66],
+    [0xFF, 0xFF, 0x99],
+    [0xFF, 0xFF, 0xCC],
+    [0xFF, 0x33, 0xFF],
+    [0xFF, 0x66, 0xFF],
+    [0xFF, 0x99, 0xFF],
+    [0xFF, 0xCC, 0xFF],
+    [0x33, 0xFF, 0xFF],
+    [0x66, 0xFF, 0xFF],
+    [0x99, 0xFF, 0xFF],
+    [0xCC, 0xFF, 0xFF],
+    [0xCC, 0xCC, 0x33],
+    [0xCC, 0xCC, 0x66],
+    [0xCC, 0xCC, 0x99],
+    [0xCC, 0xCC, 0xFF],
+    [0xCC, 0x33, 0xCC],
+    [0xCC, 0x66, 0xCC],
+    [0xCC, 0x99, 0xCC],
+    [0xCC, 0xFF, 0xCC],
+    [0x33, 0xCC, 0xCC],
+    [0x66, 0xCC, 0xCC],
+    [0x99, 0xCC, 0xCC],
+    [0xFF, 0xCC, 0xCC],
+    [0x99, 0x99, 0x33],
+    [0x99, 0x99, 0x66],
+    [0x99, 0x99, 0xCC],
+    [0x99, 0x99, 0xFF],
+    [0x99, 0x33, 0x99],
+    [0x99, 0x66, 0x99],
+    [0x99, 0xCC, 0x99],
+    [0x99, 0xFF, 0x99],
+    [0x33, 0x99, 0x99],
+    [0x66, 0x99, 0x99],
+    [0xCC, 0x99, 0x99],
+    [0xFF, 0x99, 0x99],
+    [0x66, 0x66, 0x33],
+    [0x66, 0x66, 0x99],
+    [0x66, 0x66, 0xCC],
+    [0x66, 0x66, 0xFF],
+    [0x66, 0x33, 0x66],
+    [0x66, 0x99, 0x66],
+    [0x66, 0xCC, 0x66],
+    [0x66, 0xFF, 0x66],
+    [0x33, 0x66, 0x66],
+    [0x99, 0x66, 0x66],
+    [0xCC, 0x66, 0x66],
+    [0xFF, 0x66, 0x66],
+    [0x33, 0x33, 0x66],
+    [0x33, 0x33, 0x99],
+    [0x33, 0x33, 0xCC],
+    [0x33, 0x33, 0xFF],
+    [0x33, 0x66, 0x33],
+    [0x33, 0x99, 0x33],
+    [0x33, 0xCC, 0x33],
+    [0x33, 0xFF, 0x33],
+    [0x66, 0x33, 0x33],
+    [0x99, 0x33, 0x33],
+    [0xCC, 0x33, 0x33],
+    [0xFF, 0x33, 0x33],
+    [0x00, 0x33, 0x66],
+    [0x33, 0x66, 0x00],
+    [0x66, 0x00, 0x33],
+    [0x00, 0x66, 0x33],
+    [0x33, 0x00, 0x66],
+    [0x66, 0x33, 0x00],
+    [0x33, 0x66, 0x99],
+    [0x66, 0x99, 0x33],
+    [0x99, 0x33, 0x66],
+    [0x33, 0x99, 0x66],
+    [0x66, 0x33, 0x99],
+    [0x99, 0x66, 0x33],
+    [0x66, 0x99, 0xCC],
+    [0x99, 0xCC, 0x66],
+    [0xCC, 0x66, 0x99],
+    [0x66, 0xCC, 0x99],
+    [0x99, 0x66, 0xCC],
+    [0xCC, 0x99, 0x66],
+    [0x99, 0xCC, 0xFF],
+    [0xCC, 0xFF, 0x99],
+    [0xFF, 0x99, 0xCC],
+    [0x99, 0xFF, 0xCC],
+    [0xCC, 0x99, 0xFF],
+    [0xFF, 0xCC, 0x99],
+    [0x11, 0x11, 0x11],
+    [0x22, 0x22, 0x22],
+    [0x44, 0x44, 0x44],
+    [0x55, 0x55, 0x55],
+    [0xAA, 0xAA, 0xAA],
+    [0xBB, 0xBB, 0xBB],
+    [0xDD, 0xDD, 0xDD],
+    [0xEE, 0xEE, 0xEE],
+];
+
+/// Screen Video V2 decoder.
+pub struct ScreenVideoV2Decoder {
+    w: usize,
+    h: usize,
+    block_w: usize,
+    block_h: usize,
+
+    tile: Vec<u8>, // acts as a scratch buffer
+
+    /// The most recently decompressed data of every block, which blocks may
+    /// prime their zlib dictionary with.
+    block_data: Vec<Vec<u8>>,
+
+    /// The colors of 7-bit pixels in hybrid blocks, in RGB order.
+    palette: [[u8; 3]; 128],
+
+    last_frame: Option<Vec<u8>>,
+}
+
+/// Decompress zlib data into a buffer of at most `max_len` bytes.
+///
+/// If `dictionary` isn't empty, the data is instead treated as the
+/// continuation of a zlib stream that started with the dictionary.
+fn inflate(data: &[u8], dictionary: &[u8], max_len: usize) -> Result<Vec<u8>, ScreenError> {
+    let mut output = Vec::with_capacity(max_len);
+    if dictionary.is_empty() {
+        Decompress::new(true).decompress_vec(data, &mut output, FlushDecompress::Finish)?;
+        return Ok(output);
+    }
+
+    // Feed the dictionary through as uncompressed deflate blocks first, so
+    // that the compressed data can refer back to it.
+    let mut primer = Vec::with_capacity(dictionary.len() + (dictionary.len() / 0xFFFF + 1) * 5);
+    for chunk in dictionary.chunks(0xFFFF) {
+        let len = chunk.len() as u16;
+        primer.push(0);
+        primer.extend_from_slice(&len.to_le_bytes());
+        primer.extend_from_slice(&(!len).to_le_bytes());
+        primer.extend_from_slice(chunk);
+    }
+
+    let mut decompress = Decompress::new(false);
+    let mut primed = Vec::with_capacity(dictionary.len());
+    decompress.decompress_vec(&primer, &mut primed, FlushDecompress::Sync)?;
+    decompress.decompress_vec(data, &mut output, FlushDecompress::Finish)?;
+    Ok(output)
+}
+
+/// Decode hybrid pixels into BGR.
+///
+/// Pixels are either a single byte indexing the palette, or two bytes (with
+/// the top bit set) of 15-bit color.
+fn decode_hybrid(src: &[u8], dst: &mut [u8], palette: &[[u8; 3]; 128]) -> Result<(), ScreenError> {
+    let mut src = ByteReader::new(src);
+    for pixel in dst.chunks_exact_mut(3) {
+        let byte = src.read_byte()?;
+        if byte & 0x80 != 0 {
+            let color = (u16::from(byte & 0x7F) << 8) | u16::from(src.read_byte()?);
+            let expand = |c: u16| {
+                let c = (c & 0x1F) as u8;
+                (c << 3) | (c >> 2)
+            };
+            pixel.copy_from_slice(&[expand(color), expand(color >> 5), expand(color >> 10)]);
+        } else {
+            let [r, g, b] = palette[usize::from(byte)];
+            pixel.copy_from_slice(&[b, g, r]);
+        }
+    }
+    Ok(())
+}
+
+impl ScreenVideoV2Decoder {
+    pub fn new() -> Self {
+        Self {
+            w: 0,
+            h: 0,
+            block_w: 0,
+            block_h: 0,
+            tile: vec![],
+            block_data: vec![],
+            palette: DEFAULT_PALETTE,
+            last_frame: None,
+        }
+    }
+
+    fn decode_v2(
+        &mut self,
+        src: &mut ByteReader,
+        data: &mut [u8],
+        stride: usize,
+    ) -> Result<bool, Error> {
+        let blocks_x = self.w.div_ceil(self.block_w);
+        let blocks_y = self.h.div_ceil(self.block_h);
+
+        let mut is_intra = true;
+        for (yy, row) in data.chunks_mut(stride * self.block_h).enumerate() {
+            let cur_h = (self.h - yy * self.block_h).min(self.block_h);
+            for (xx, x) in (0..self.w).step_by(self.block_w).enumerate() {
+                let cur_w = (self.w - x).min(self.block_w);
+                let block = yy * blocks_x + xx;
+
+                let data_size = src.read_u16be()? as usize;
+                if data_size == 0 {
+                    is_intra = false;
+                    continue;
+                }
+
+                let flags = src.read_byte()?;
+                let color_depth = (flags >> 3) & 0x3;
+                let has_diff_blocks = flags & 0x4 != 0;
+                let prime_with_current = flags & 0x2 != 0;
+                let prime_with_previous = flags & 0x1 != 0;
+                let mut header_size = 1;
+
+                // Only some rows of the block may have been updated.
+                let (start_row, num_rows) = if has_diff_blocks {
+                    header_size += 2;
+                    is_intra = false;
+                    (src.read_byte()? as usize, src.read_byte()? as usize)
+                } else {
+                    (0, cur_h)
+                };
+                if start_row + num_rows > cur_h {
+                    return Err(ScreenError::InvalidBlockPosition.into());
+                }
+
+                let mut dictionary = vec![];
+                if prime_with_previous {
+                    dictionary.extend_from_slice(&self.block_data[block]);
+                }
+                if prime_with_current {
+                    header_size += 2;
+                    let prime_x = src.read_byte()? as usize;
+                    let prime_y = src.read_byte()? as usize;
+                    if prime_x >= blocks_x || prime_y >= blocks_y {
+                        return Err(ScreenError::InvalidBlockPosition.into());
+                    }
+                    dictionary.extend_from_slice(&self.block_data[prime_y * blocks_x + prime_x]);
+                }
+
+                let compressed_size = data_size
+                    .checked_sub(header_size)
+                    .ok_or(ScreenError::UnexpectedEOF)?;
+                let pixels = inflate(
+                    src.read_buf_ref(compressed_size)?,
+                    &dictionary,
+                    self.block_w * self.block_h * 3,
+                )?;
+
+                let tile = &mut self.tile[..cur_w * num_rows * 3];
+                match color_depth {
+                    0 => tile.copy_from_slice(
+                        pixels.get(..tile.len()).ok_or(ScreenError::UnexpectedEOF)?,
+                    ),
+                    2 => decode_hybrid(&pixels, tile, &self.palette)?,
+                    depth => return Err(ScreenError::InvalidColorDepth(depth).into()),
+                }
+
+                for (dst, src) in row[start_row * stride + x * 3..]
+                    .chunks_mut(stride)
+                    .zip(tile.chunks(cur_w * 3))
+                {
+                    dst[..cur_w * 3].copy_from_slice(src);
+                }
+
+                self.block_data[block] = pixels;
+            }
+        }
+        Ok(is_intra)
+    }
+
+    fn decode_palette(&mut self, src: &mut ByteReader) -> Result<(), Error> {
+        let size = src.read_u16be()? as usize;
+        let palette = inflate(src.read_buf_ref(size)?, &[], self.palette.len() * 3)?;
+
+        for (color, bgr) in self.palette.iter_mut().zip(palette.chunks_exact(3)) {
+            *color = [bgr[2], bgr[1], bgr[0]];
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) {
+        self.last_frame = None;
+        self.block_data.clear();
+    }
+}
+
+impl VideoDecoder for ScreenVideoV2Decoder {
+    fn preload_frame(&mut self, encoded_frame: EncodedFrame<'_>) -> Result<FrameDependency, Error> {
+        // Like in V1, the FrameType + CodecID byte of the FLV VIDEODATA tag
+        // precedes the SCREENV2VIDEOPACKET.
+        let frame_type = *encoded_frame
+            .data
+            .first()
+            .ok_or(ScreenError::UnexpectedEOF)?;
+        if frame_type & 0xF != 6 {
+            return Err(ScreenError::InvalidCodecId(frame_type & 0xF).into());
+        }
+
+        match frame_type >> 4 {
+            1 => Ok(FrameDependency::None),
+            2 => Ok(FrameDependency::Past),
+            x => Err(ScreenError::InvalidFrameType(x).into()),
+        }
+    }
+
+    fn decode_frame(&mut self, encoded_frame: EncodedFrame<'_>) -> Result<DecodedFrame, Error> {
+        let (frame_type, data) = encoded_frame
+            .data
+            .split_first()
+            .ok_or(ScreenError::UnexpectedEOF)?;
+        let is_keyframe = frame_type >> 4 == 1;
+
+        if !is_keyframe && self.last_frame.is_none() {
+            return Err(ScreenError::MissingReferenceFrame.into());
+        }
+
+        let mut br = ByteReader::new(data);
+
+        let hdr0 = br.read_u16be()? as usize;
+        let blk_w = (hdr0 >> 12) * 16 + 16;
+        let w = hdr0 & 0xFFF;
+
+        let hdr1 = br.read_u16be()? as usize;
+        let blk_h = (hdr1 >> 12) * 16 + 16;
+        let h = hdr1 & 0xFFF;
+
+        // Block sizes can't be zero, but a frame can.
+        if w == 0 || h == 0 {
+            return Err(ScreenError::InvalidFrameSize(w, h).into());
+        }
+
+        if self.w != w || self.h != h || self.block_w != blk_w || self.block_h != blk_h {
+            self.flush();
+            self.tile.resize(blk_w * blk_h * 3, 0);
+            self.w = w;
+            self.h = h;
+            self.block_w = blk_w;
+            self.block_h = blk_h;
+        }
+        self.block_data
+            .resize(w.div_ceil(blk_w) * h.div_ceil(blk_h), vec![]);
+
+        let flags = br.read_byte()?;
+        if flags & 0x2 != 0 {
+            return Err(ScreenError::UnsupportedIFrameImage.into());
+        }
+        if flags & 0x1 != 0 {
+            self.decode_palette(&mut br)?;
+        }
+
+        let mut data = self
+            .last_frame
+            .clone()
+            .unwrap_or_else(|| vec![0; w * h * 3]);
+
+        let stride = w * 3;
+
+        let is_intra = self.decode_v2(&mut br, data.as_mut_slice(), stride)?;
+
+        if is_keyframe && !is_intra {
+            return Err(ScreenError::KeyframeInvalid.into());
+        }
+
+        let mut rgb = vec![0u8; w * h * 3];
+
+        // convert from BGR to RGB and flip Y
+        for y in 0..h {
+            let data_row = &data[y * w * 3..(y + 1) * w * 3];
+            let rgb_row = &mut rgb[(h - y - 1) * w * 3..(h - y) * w * 3];
+
+            for (bgr, rgb) in data_row.chunks(3).zip(rgb_row.chunks_mut(3)) {
+                rgb.copy_from_slice(&[bgr[2], bgr[1], bgr[0]]);
+            }
+        }
+
+        self.last_frame = Some(data);
+
+        Ok(DecodedFrame::new(
+            w as u32,
+            h as u32,
+            BitmapFormat::Rgb,
+            rgb,
+        ))
+    }
+}
+
+impl Default for ScreenVideoV2Decoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::ZlibEncoder;
+    use flate2::{Compress, Compression, FlushCompress};
+    use std::io::Write;
+    use swf::VideoCodec;
+
+    fn zlib(data: &[u8]) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+        encoder
+            .write_all(data)
+            .expect("Writing to a Vec can't fail");
+        encoder.finish().expect("Writing to a Vec can't fail")
+    }
+
+    /// Compresses `data` as the raw deflate continuation of `dictionary`.
+    fn deflate_primed(dictionary: &[u8], data: &[u8]) -> Vec<u8> {
+        let mut compress = Compress::new(Compression::best(), false);
+        let mut primer = Vec::with_capacity(dictionary.len() * 2 + 64);
+        compress
+            .compress_vec(dictionary, &mut primer, FlushCompress::Sync)
+            .expect("Compression can't fail");
+        let mut output = Vec::with_capacity(data.len() * 2 + 64);
+        compress
+            .compress_vec(data, &mut output, FlushCompress::Finish)
+            .expect("Compression can't fail");
+        output
+    }
+
+    /// A block of a packet, made of its flags, its optional header fields and its pixels.
+    fn block(flags: u8, header: &[u8], compressed: &[u8]) -> Vec<u8> {
+        let size = (1 + header.len() + compressed.len()) as u16;
+        let mut block = size.to_be_bytes().to_vec();
+        block.push(flags);
+        block.extend_from_slice(header);
+        block.extend_from_slice(compressed);
+        block
+    }
+
+    /// A packet of a 16x16 blocks frame, including the FLV frame type and codec byte.
+    fn build_packet(
+        keyframe: bool,
+        size: (u16, u16),
+        palette: Option<&[u8]>,
+        blocks: &[u8],
+    ) -> Vec<u8> {
+        let mut packet = vec![if keyframe { 0x16 } else { 0x26 }];
+        packet.extend_from_slice(&size.0.to_be_bytes());
+        packet.extend_from_slice(&size.1.to_be_bytes());
+        match palette {
+            Some(palette) => {
+                let palette = zlib(palette);
+                packet.push(0x1);
+                packet.extend_from_slice(&(palette.len() as u16).to_be_bytes());
+                packet.extend_from_slice(&palette);
+            }
+            None => packet.push(0),
+        }
+        packet.extend_from_slice(blocks);
+        packet
+    }
+
+    fn decode(decoder: &mut ScreenVideoV2Decoder, packet: &[u8]) -> Result<Vec<u8>, Error> {
+        let frame = decoder.decode_frame(EncodedFrame {
+            codec: VideoCodec::ScreenVideoV2,
+            data: packet,
+            frame_id: 0,
+        })?;
+        Ok(frame.data().to_vec())
+    }
+
+    // Pixels are stored as BGR, from the bottom row up.
+    const BOTTOM_ROW: [u8; 6] = [0, 0, 255, 0, 255, 0];
+    const TOP_ROW: [u8; 6] = [255, 0, 0, 255, 255, 255];
+
+    fn keyframe(decoder: &mut ScreenVideoV2Decoder) -> Vec<u8> {
+        let pixels = [BOTTOM_ROW, TOP_ROW].concat();
+        let packet = build_packet(true, (2, 2), None, &block(0, &[], &zlib(&pixels)));
+        decode(decoder, &packet).expect("Keyframe should decode")
+    }
+
+    #[test]
+    fn decode_keyframe() {
+        let mut decoder = ScreenVideoV2Decoder::new();
+        assert_eq!(
+            keyframe(&mut decoder),
+            [0, 0, 255, 255, 255, 255, 255, 0, 0, 0, 255, 0]
+        );
+
+        // A keyframe has to update every block.
+        let packet = build_packet(true, (2, 2), None, &[0, 0]);
+        assert!(decode(&mut decoder, &packet).is_err());
+    }
+
+    #[test]
+    fn decode_interframe() {
+        let mut decoder = ScreenVideoV2Decoder::new();
+        let packet = build_packet(false, (2, 2), None, &[0, 0]);
+        assert!(decode(&mut decoder, &packet).is_err());
+
+        keyframe(&mut decoder);
+        let unchanged = decode(&mut decoder, &packet).expect("Interframe should decode");
+        assert_eq!(unchanged, [0, 0, 255, 255, 255, 255, 255, 0, 0, 0, 255, 0]);
+
+        // Only the top row, starting at row 1 of the block, is updated.
+        let top_row = [255, 255, 0, 0, 0, 0];
+        let packet = build_packet(false, (2, 2), None, &block(0x4, &[1, 1], &zlib(&top_row)));
+        assert_eq!(
+            decode(&mut decoder, &packet).expect("Interframe should decode"),
+            [0, 255, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0]
+        );
+    }
+
+    #[test]
+    fn decode_primed_block() {
+        let mut decoder = ScreenVideoV2Decoder::new();
+        keyframe(&mut decoder);
+
+        // The block repeats its previous data, which it refers to through its dictionary.
+        let previous = [BOTTOM_ROW, TOP_ROW].concat();
+        let pixels = [TOP_ROW, BOTTOM_ROW].concat();
+        let compressed = deflate_primed(&previous, &pixels);
+        let packet = build_packet(false, (2, 2), None, &block(0x1, &[], &compressed));
+        assert_eq!(
+            decode(&mut decoder, &packet).expect("Primed block should decode"),
+            [255, 0, 0, 0, 255, 0, 0, 0, 255, 255, 255, 255]
+        );
+
+        // Blocks can also be primed with the latest data of any block, given by its position.
+        let compressed = deflate_primed(&pixels, &previous);
+        let packet = build_packet(false, (2, 2), None, &block(0x2, &[0, 0], &compressed));
+        assert_eq!(
+            decode(&mut decoder, &packet).expect("Primed block should decode"),
+            [0, 0, 255, 255, 255, 255, 255, 0, 0, 0, 255, 0]
+        );
+        let packet = build_packet(false, (2, 2), None, &block(0x2, &[1, 0], &compressed));
+        assert!(decode(&mut decoder, &packet).is_err());
+    }
+
+    #[test]
+    fn decode_hybrid_palette_block() {
+        let mut decoder = ScreenVideoV2Decoder::new();
+
+        // Palette index 5 is white, and 0x7C00 is 15-bit red. Colors are BGR555.
+        let pixels = [0x05, 0xFC, 0x00, 0x00, 0x85, 0x40];
+        let packet = build_packet(true, (2, 2), None, &block(0x10, &[], &zlib(&pixels)));
+        assert_eq!(
+            decode(&mut decoder, &packet).expect("Hybrid block should decode"),
+            [0, 0, 0, 8, 82, 0, 255, 255, 255, 255, 0, 0]
+        );
+
+        // A custom palette is stored in BGR order.
+        let palette = [0x10, 0x20, 0x30];
+        let pixels = [0x00; 4];
+        let packet = build_packet(
+            true,
+            (2, 2),
+            Some(&palette),
+            &block(0x10, &[], &zlib(&pixels)),
+        );
+        assert_eq!(
+            decode(&mut decoder, &packet).expect("Hybrid block should decode"),
+            [0x30, 0x20, 0x10].repeat(4)
+        );
+    }
+
+    #[test]
+    fn reject_empty_packet() {
+        let mut decoder = ScreenVideoV2Decoder::new();
+        let frame = || EncodedFrame {
+            codec: VideoCodec::ScreenVideoV2,
+            data: &[],
+            frame_id: 0,
+        };
+        assert!(decoder.preload_frame(frame()).is_err());
+        assert!(decoder.decode_frame(frame()).is_err());
+    }
+
+    #[test]
+    fn reject_other_codec() {
+        let mut decoder = ScreenVideoV2Decoder::new();
+        let mut packet = build_packet(true, (2, 2), None, &[]);
+        packet[0] = 0x13;
+        let frame = EncodedFrame {
+            codec: VideoCodec::ScreenVideoV2,
+            data: &packet,
+            frame_id: 0,
+        };
+        assert!(decoder.preload_frame(frame).is_err());
+    }
+
+    #[test]
+    fn reject_empty_frame() {
+        let mut decoder = ScreenVideoV2Decoder::new();
+        for size in [(0, 2), (2, 0)] {
+            let packet = build_packet(true, size, None, &[]);
+            assert!(decode(&mut decoder, &packet).is_err());
+        }
+    }
+}
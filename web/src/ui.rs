@@ -1,10 +1,11 @@
 use super::JavascriptPlayer;
 use rfd::{AsyncFileDialog, FileHandle};
 use ruffle_core::backend::ui::{
-    DialogLoaderError, DialogResultFuture, FileDialogResult, FileFilter,
+    ClipboardData, ClipboardFormat, FontDefinition, FullscreenError, ImeConversionMode,
+    LanguageIdentifier, MouseCursor, UiBackend, US_ENGLISH,
 };
 use ruffle_core::backend::ui::{
-    FontDefinition, FullscreenError, LanguageIdentifier, MouseCursor, UiBackend, US_ENGLISH,
+    DialogLoaderError, DialogResultFuture, FileDialogResult, FileFilter,
 };
 use ruffle_web_common::JsResult;
 use std::borrow::Cow;
@@ -263,6 +264,35 @@ impl UiBackend for WebUiBackend {
         }
     }
 
+    // Only text can be copied to and pasted from the browser's clipboard.
+    fn clipboard_formats(&mut self) -> Vec<ClipboardFormat> {
+        if self.clipboard_content.is_empty() {
+            Vec::new()
+        } else {
+            vec![ClipboardFormat::Text]
+        }
+    }
+
+    fn clipboard_data(&mut self, format: ClipboardFormat) -> Option<ClipboardData> {
+        if format == ClipboardFormat::Text && !self.clipboard_content.is_empty() {
+            Some(ClipboardData::Text(self.clipboard_content.clone()))
+        } else {
+            None
+        }
+    }
+
+    fn set_clipboard_data(&mut self, data: ClipboardData) {
+        if let ClipboardData::Text(text) = data {
+            self.set_clipboard_content(text);
+        }
+    }
+
+    fn clear_clipboard_data(&mut self, format: Option<ClipboardFormat>) {
+        if format.is_none_or(|format| format == ClipboardFormat::Text) {
+            self.clipboard_content.clear();
+        }
+    }
+
     fn set_fullscreen(&mut self, is_full: bool) -> Result<(), FullscreenError> {
         match self.js_player.set_fullscreen(is_full) {
             Ok(_) => Ok(()),
@@ -363,4 +393,17 @@ impl UiBackend for WebUiBackend {
             result
         }))
     }
+
+    fn ime_enabled(&self) -> bool {
+        // Composed text is received through the browser as regular text input.
+        false
+    }
+
+    fn set_ime_enabled(&mut self, _enabled: bool) {}
+
+    fn ime_conversion_mode(&self) -> ImeConversionMode {
+        ImeConversionMode::Unknown
+    }
+
+    fn set_ime_conversion_mode(&mut self, _mode: ImeConversionMode) {}
 }